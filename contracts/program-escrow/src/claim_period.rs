@@ -10,15 +10,32 @@
 // The required DataKey variants are already defined in lib.rs:
 //
 //   DataKey::PendingClaim(String, u64)
-//     → Maps (program_id, schedule_id) to a ClaimRecord
+//     → Maps (program_id, claim_id) to an active (Pending) ClaimRecord
+//
+//   DataKey::ClaimHistory(String, u64)
+//     → Maps (program_id, claim_id) to a resolved ClaimRecord
+//
+//   DataKey::ActiveClaims(String)
+//     → Ids of the program's Pending claims, in creation order
 //
 //   DataKey::ClaimWindow
 //     → Stores the global claim window duration (in seconds)
 //
+//   DataKey::ProgramClaimConfig(String)
+//     → Per-program ClaimConfig overriding the global claim window
+//
+// Pruning: once a claim is executed or cancelled its record is moved out of
+// `PendingClaim` into `ClaimHistory` and its id is dropped from
+// `ActiveClaims`, so scans over pending claims only ever touch live entries.
+// `get_claim` resolves both maps, so callers never see the difference.
+//
 // ============================================================
 
-use crate::{DataKey, ProgramData, PROGRAM_DATA};
-use soroban_sdk::{contracttype, symbol_short, Address, Env, String, Symbol};
+use crate::{
+    anti_abuse, monitoring, payout_callback, program_storage, reentrancy_guard, DataKey,
+    PayoutRecord, ProgramData, ProgramEscrowContract, ProgramKey,
+};
+use soroban_sdk::{contracttype, symbol_short, vec, Address, Bytes, Env, String, Symbol, Vec};
 
 /// The status of a pending claim record.
 #[contracttype]
@@ -27,6 +44,10 @@ pub enum ClaimStatus {
     Pending,
     Completed,
     Cancelled,
+    /// Reclaimed by `sweep_expired_claims` after its deadline and grace.
+    Expired,
+    /// Reclaimed because the recipient was denylisted or fully slashed.
+    Revoked,
 }
 
 /// Created when an admin approves a payout.
@@ -41,13 +62,61 @@ pub struct ClaimRecord {
     pub amount: i128,
     pub claim_deadline: u64, // UNIX timestamp  shows after which claim expires
     pub created_at: u64,
+    /// Ledger sequence the claim was created in.
+    pub created_ledger: u32,
     pub status: ClaimStatus,
+    /// Hash of an off-chain encrypted memo, carried onto the payout record.
+    pub memo_hash: Option<Bytes>,
+    /// Total seconds added to `claim_deadline` by `extend_claim_window`.
+    pub extended_secs: u64,
+    /// Total removed from `amount` by `slash_claim`.
+    pub slashed_amount: i128,
+    /// Portion the recipient donated back to the program when claiming.
+    pub tip_amount: i128,
+}
+
+/// A claim together with values derived from the current ledger, returned
+/// by `get_claim_view`. Never stored, so it cannot go stale.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClaimView {
+    pub claim: ClaimRecord,
+    /// Seconds left before `claim_deadline`. Zero once expired or no longer
+    /// pending.
+    pub seconds_until_expiry: u64,
+}
+
+/// Per-program claim settings set by the program's authorized payout key.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClaimConfig {
+    /// Seconds a recipient has to execute a claim after it is created.
+    pub claim_window: u64,
+    /// Seconds after the deadline during which a claim can still be executed
+    /// at a penalty. Zero means claims expire hard at the deadline.
+    pub grace_secs: u64,
+    /// Share of a late claim (in basis points) withheld and returned to the
+    /// program balance.
+    pub late_penalty_bps: u32,
 }
 
 // Event symbols
 const CLAIM_CREATED: Symbol = symbol_short!("ClmCrtd");
 const CLAIM_EXECUTED: Symbol = symbol_short!("ClmExec");
 const CLAIM_CANCELLED: Symbol = symbol_short!("ClmCncl");
+const CLAIM_CONFIG_UPDATED: Symbol = symbol_short!("ClmCfg");
+const CLAIM_LATE: Symbol = symbol_short!("ClmLate");
+const CLAIM_EXPIRED: Symbol = symbol_short!("ClmExpd");
+const CLAIM_EXTENDED: Symbol = symbol_short!("ClmExt");
+const CLAIM_REVOKED: Symbol = symbol_short!("ClmRevk");
+const CLAIM_SLASHED: Symbol = symbol_short!("ClmSlsh");
+const CLAIM_TIPPED: Symbol = symbol_short!("ClmTip");
+const CLAIM_DECLINED: Symbol = symbol_short!("ClmDecl");
+const CLAIM_ACKNOWLEDGED: Symbol = symbol_short!("ClmAck");
+const CLAIM_SPLIT: Symbol = symbol_short!("ClmSplit");
+const CLAIM_COMPENSATED: Symbol = symbol_short!("ClmComp");
+
+const BASIS_POINTS: i128 = 10_000;
 
 // Storage key for auto-incrementing claim IDs
 const NEXT_CLAIM_ID: Symbol = symbol_short!("NxtClmId");
@@ -62,21 +131,60 @@ fn next_claim_id(env: &Env) -> u64 {
     id
 }
 
-fn get_program(env: &Env) -> ProgramData {
-    env.storage()
-        .instance()
-        .get(&PROGRAM_DATA)
-        .unwrap_or_else(|| panic!("Program not initialized"))
+fn get_program(env: &Env, program_id: &String) -> ProgramData {
+    program_storage::load(env, program_id).unwrap_or_else(|| panic!("Program not found"))
 }
 
 fn save_program(env: &Env, data: &ProgramData) {
-    env.storage().instance().set(&PROGRAM_DATA, data);
+    program_storage::save(env, data);
 }
 
 fn claim_key(program_id: &String, claim_id: u64) -> DataKey {
     DataKey::PendingClaim(program_id.clone(), claim_id)
 }
 
+fn history_key(program_id: &String, claim_id: u64) -> DataKey {
+    DataKey::ClaimHistory(program_id.clone(), claim_id)
+}
+
+fn get_active_ids(env: &Env, program_id: &String) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ActiveClaims(program_id.clone()))
+        .unwrap_or(vec![env])
+}
+
+fn add_active(env: &Env, program_id: &String, claim_id: u64) {
+    let mut ids = get_active_ids(env, program_id);
+    ids.push_back(claim_id);
+    env.storage()
+        .persistent()
+        .set(&DataKey::ActiveClaims(program_id.clone()), &ids);
+}
+
+/// Moves a resolved claim out of the active set and into history.
+fn archive_claim(env: &Env, record: &ClaimRecord) {
+    let program_id = &record.program_id;
+    env.storage()
+        .persistent()
+        .remove(&claim_key(program_id, record.claim_id));
+    env.storage()
+        .persistent()
+        .set(&history_key(program_id, record.claim_id), record);
+
+    let mut ids = get_active_ids(env, program_id);
+    if let Some(index) = ids.first_index_of(record.claim_id) {
+        ids.remove(index);
+        env.storage()
+            .persistent()
+            .set(&DataKey::ActiveClaims(program_id.clone()), &ids);
+    }
+}
+
+fn get_admin(env: &Env) -> Address {
+    anti_abuse::get_admin(env).unwrap_or_else(|| panic!("Not initialized"))
+}
+
 // ── Public functions ─────────────────────────────────────────
 // These functions should be called from the ProgramEscrowContract impl.
 
@@ -94,7 +202,7 @@ pub fn create_pending_claim(
     amount: i128,
     claim_deadline: u64,
 ) -> u64 {
-    let mut program = get_program(env);
+    let mut program = get_program(env, program_id);
 
     // Only the authorized payout key can create a claim.
 
@@ -109,9 +217,28 @@ pub fn create_pending_claim(
     if claim_deadline <= env.ledger().timestamp() {
         panic!("Claim deadline must be in the future");
     }
+
+    let claim_id = reserve_claim(env, &mut program, recipient, amount, claim_deadline, None);
+    save_program(env, &program);
+    claim_id
+}
+
+/// Reserves `amount` out of `program.remaining_balance` as a new pending claim.
+///
+/// Callers are responsible for authorization, validation and persisting
+/// `program` afterwards. Returns the generated `claim_id`.
+pub(crate) fn reserve_claim(
+    env: &Env,
+    program: &mut ProgramData,
+    recipient: &Address,
+    amount: i128,
+    claim_deadline: u64,
+    memo_hash: Option<Bytes>,
+) -> u64 {
+    let program_id = &program.program_id.clone();
+
     // Reserve the funds (deduct from remaining balance)
     program.remaining_balance -= amount;
-    save_program(env, &program);
 
     let claim_id = next_claim_id(env);
     let now = env.ledger().timestamp();
@@ -123,12 +250,18 @@ pub fn create_pending_claim(
         amount,
         claim_deadline,
         created_at: now,
+        created_ledger: env.ledger().sequence(),
         status: ClaimStatus::Pending,
+        memo_hash,
+        extended_secs: 0,
+        slashed_amount: 0,
+        tip_amount: 0,
     };
 
     env.storage()
         .persistent()
         .set(&claim_key(program_id, claim_id), &record);
+    add_active(env, program_id, claim_id);
 
     env.events().publish(
         (CLAIM_CREATED,),
@@ -141,26 +274,95 @@ pub fn create_pending_claim(
         ),
     );
 
+    // Recipient-topic notification so wallets and indexers can watch for
+    // incoming claims by address.
+    env.events().publish(
+        (Symbol::new(env, "claim_available"), recipient.clone()),
+        (claim_id, amount, claim_deadline),
+    );
+
     claim_id
 }
 
 // Executes (redeems) a pending claim before its deadline.
 //
-// Transfers the reserved escrowed funds to the recipient.
+// Transfers the reserved escrowed funds to the recipient. The claim is
+// marked completed and the program updated before the token transfer, and
+// the reentrancy guard is held throughout, so a token or callback that
+// re-enters cannot redeem the same claim twice.
 
 pub fn execute_claim(env: &Env, program_id: &String, claim_id: u64, caller: &Address) {
+    execute_claim_with_tip(env, program_id, claim_id, caller, 0);
+}
+
+/// Executes a pending claim, returning `tip_bps / 10000` of the payout to the
+/// program balance as a donation and transferring the rest to the recipient.
+pub fn execute_claim_with_tip(
+    env: &Env,
+    program_id: &String,
+    claim_id: u64,
+    caller: &Address,
+    tip_bps: u32,
+) {
+    settle_claim(env, program_id, claim_id, caller, tip_bps, None);
+}
+
+/// Executes a pending claim like `execute_claim`, but transfers the payout
+/// across `destinations` instead of to the recipient. `amounts` must sum to
+/// the claimable amount (after any late penalty).
+pub fn claim_payout_split(
+    env: &Env,
+    program_id: &String,
+    claim_id: u64,
+    caller: &Address,
+    destinations: &Vec<Address>,
+    amounts: &Vec<i128>,
+) {
+    settle_claim(
+        env,
+        program_id,
+        claim_id,
+        caller,
+        0,
+        Some((destinations, amounts)),
+    );
+}
+
+fn settle_claim(
+    env: &Env,
+    program_id: &String,
+    claim_id: u64,
+    caller: &Address,
+    tip_bps: u32,
+    split: Option<(&Vec<Address>, &Vec<i128>)>,
+) {
+    if tip_bps as i128 > BASIS_POINTS {
+        panic!("Tip cannot exceed 10000 bps");
+    }
+
+    reentrancy_guard::check_not_entered(env);
+    reentrancy_guard::set_entered(env);
+
     caller.require_auth();
 
-    let key = claim_key(program_id, claim_id);
-    let mut record: ClaimRecord = env
-        .storage()
-        .persistent()
-        .get(&key)
-        .unwrap_or_else(|| panic!("Claim not found"));
+    let mut record = get_claim(env, program_id, claim_id);
     // only the designated recipient can execute their own claim
     if record.recipient != *caller {
         panic!("Unauthorized: only the claim recipient can execute this claim");
     }
+    if env
+        .storage()
+        .persistent()
+        .has(&DataKey::Denylisted(program_id.clone(), caller.clone()))
+    {
+        panic!("Recipient is denylisted");
+    }
+    if env.storage().persistent().has(&ProgramKey::FrozenRecipient(
+        program_id.clone(),
+        caller.clone(),
+    )) {
+        panic!("Recipient is frozen");
+    }
 
     // checks if is still pending.
     match record.status {
@@ -168,23 +370,105 @@ pub fn execute_claim(env: &Env, program_id: &String, claim_id: u64, caller: &Add
         _ => panic!("ClaimAlreadyProcessed"),
     }
 
-    // checks if claim deadline has not expired
-    if env.ledger().timestamp() > record.claim_deadline {
-        panic!("ClaimExpired");
+    // high-value programs may require the claim to mature for some ledgers
+    let min_confirmations = get_claim_min_confirmations(env, program_id);
+    if env.ledger().sequence() < record.created_ledger.saturating_add(min_confirmations) {
+        panic!("ClaimNotMature");
     }
 
-    // transfer funds to recipient
-    let program = get_program(env);
-    let token_client = soroban_sdk::token::Client::new(env, &program.token_address);
-    token_client.transfer(
-        &env.current_contract_address(),
-        &record.recipient,
-        &record.amount,
-    );
+    // past the deadline the claim is either late (within grace) or expired
+    let now = env.ledger().timestamp();
+    let mut penalty: i128 = 0;
+    if now > record.claim_deadline {
+        let config = get_program_claim_config(env, program_id);
+        let grace_secs = config.as_ref().map_or(0, |c| c.grace_secs);
+        if now > record.claim_deadline.saturating_add(grace_secs) {
+            panic!("ClaimExpired");
+        }
+        let penalty_bps = config.map_or(0, |c| c.late_penalty_bps) as i128;
+        penalty = record
+            .amount
+            .checked_mul(penalty_bps)
+            .map_or(0, |x| x / BASIS_POINTS);
+    }
+    let tip = (record.amount - penalty) * tip_bps as i128 / BASIS_POINTS;
+    let payout_amount = record.amount - penalty - tip;
+
+    if let Some((destinations, amounts)) = split {
+        if destinations.is_empty() || destinations.len() != amounts.len() {
+            panic!("Split destinations and amounts must be non-empty and equal length");
+        }
+        let mut total: i128 = 0;
+        for amount in amounts.iter() {
+            if amount <= 0 {
+                panic!("Split amounts must be greater than zero");
+            }
+            total = total.checked_add(amount).expect("Split amount overflow");
+        }
+        if total != payout_amount {
+            panic!("Split amounts must sum to the claimable amount");
+        }
+        // a split must not route funds around the denylist or a freeze
+        for destination in destinations.iter() {
+            ProgramEscrowContract::require_not_denylisted(env, program_id, &destination);
+        }
+    }
 
-    // marks the claim as completed and persist the update.
+    // effects first: mark the claim completed and move it into history
     record.status = ClaimStatus::Completed;
-    env.storage().persistent().set(&key, &record);
+    record.tip_amount = tip;
+    archive_claim(env, &record);
+
+    // any late penalty or tip goes back to the balance
+    let mut program = get_program(env, program_id);
+    program.remaining_balance += penalty + tip;
+    program.payout_history.push_back(PayoutRecord {
+        recipient: record.recipient.clone(),
+        amount: payout_amount,
+        timestamp: now,
+        memo_hash: record.memo_hash.clone(),
+    });
+    save_program(env, &program);
+
+    // interaction last: transfer funds to the recipient or its split destinations
+    let token_client = soroban_sdk::token::Client::new(env, &program.token_address);
+    match split {
+        Some((destinations, amounts)) => {
+            for (destination, amount) in destinations.iter().zip(amounts.iter()) {
+                token_client.transfer(&env.current_contract_address(), &destination, &amount);
+            }
+            env.events().publish(
+                (CLAIM_SPLIT,),
+                (
+                    program_id.clone(),
+                    claim_id,
+                    destinations.clone(),
+                    amounts.clone(),
+                ),
+            );
+        }
+        None if payout_amount > 0 => {
+            token_client.transfer(
+                &env.current_contract_address(),
+                &record.recipient,
+                &payout_amount,
+            );
+        }
+        None => {}
+    }
+
+    if penalty > 0 {
+        env.events().publish(
+            (CLAIM_LATE,),
+            (program_id.clone(), claim_id, record.recipient.clone(), penalty),
+        );
+    }
+    if tip > 0 {
+        env.events().publish(
+            (CLAIM_TIPPED,),
+            (program_id.clone(), claim_id, record.recipient.clone(), tip),
+        );
+    }
 
     env.events().publish(
         (CLAIM_EXECUTED,),
@@ -192,30 +476,30 @@ pub fn execute_claim(env: &Env, program_id: &String, claim_id: u64, caller: &Add
             program_id.clone(),
             claim_id,
             record.recipient.clone(),
-            record.amount,
+            payout_amount,
         ),
     );
+
+    monitoring::count_operation(env, monitoring::OperationType::Claim);
+
+    // notify the integrator's contract; a failing callback never reverts the payout
+    payout_callback::notify(env, program_id, &record.recipient, payout_amount);
+    payout_callback::mint_badge(env, program_id, &record.recipient, payout_amount);
+
+    reentrancy_guard::clear_entered(env);
 }
 /// Admin cancels a claim pending or expired and returns reserved funds to escrow.
 pub fn cancel_claim(env: &Env, program_id: &String, claim_id: u64, admin: &Address) {
     // Only contract admin can cancel
-    let stored_admin: Address = env
-        .storage()
-        .instance()
-        .get(&DataKey::Admin)
-        .unwrap_or_else(|| panic!("Not initialized"));
+    let stored_admin = get_admin(env);
 
     if *admin != stored_admin {
         panic!("Unauthorized: only admin can cancel claims");
     }
     admin.require_auth();
+    enforce_cancel_cooldown(env, program_id);
 
-    let key = claim_key(program_id, claim_id);
-    let mut record: ClaimRecord = env
-        .storage()
-        .persistent()
-        .get(&key)
-        .unwrap_or_else(|| panic!("Claim not found"));
+    let mut record = get_claim(env, program_id, claim_id);
 
     // can only cancel Pending claims (completed claims are final)
     match record.status {
@@ -223,13 +507,15 @@ pub fn cancel_claim(env: &Env, program_id: &String, claim_id: u64, admin: &Addre
         _ => panic!("ClaimAlreadyProcessed"),
     }
     // return reserved funds to escrow balance
-    let mut program = get_program(env);
+    let mut program = get_program(env, program_id);
     program.remaining_balance += record.amount;
     save_program(env, &program);
 
-    // mark claim as cancelled
+    // mark claim as cancelled and move it into history
     record.status = ClaimStatus::Cancelled;
-    env.storage().persistent().set(&key, &record);
+    archive_claim(env, &record);
+    record_cancel(env, program_id);
+    monitoring::count_operation(env, monitoring::OperationType::Cancel);
 
     env.events().publish(
         (CLAIM_CANCELLED,),
@@ -242,24 +528,429 @@ pub fn cancel_claim(env: &Env, program_id: &String, claim_id: u64, admin: &Addre
     );
 }
 
-/// Returns a claim record by its ID.
+/// Cancels each still-pending claim in `claim_ids`, returning the reserved
+/// funds to the program balance. Ids that are unknown or already resolved
+/// (completed, cancelled or expired) are skipped. Only the program's
+/// authorized payout key may call this.
+///
+/// Returns the number of claims cancelled and the total amount restored.
+pub fn batch_cancel_claims(env: &Env, program_id: &String, claim_ids: &Vec<u64>) -> (u32, i128) {
+    let mut program = get_program(env, program_id);
+    program.authorized_payout_key.require_auth();
+    enforce_cancel_cooldown(env, program_id);
+
+    let mut cancelled: u32 = 0;
+    let mut restored: i128 = 0;
+    for claim_id in claim_ids.iter() {
+        let pending: Option<ClaimRecord> =
+            env.storage().persistent().get(&claim_key(program_id, claim_id));
+        let mut record = match pending {
+            Some(record) if record.status == ClaimStatus::Pending => record,
+            _ => continue,
+        };
+
+        program.remaining_balance += record.amount;
+        record.status = ClaimStatus::Cancelled;
+        archive_claim(env, &record);
+
+        env.events().publish(
+            (CLAIM_CANCELLED,),
+            (
+                program_id.clone(),
+                claim_id,
+                record.recipient.clone(),
+                record.amount,
+            ),
+        );
+
+        cancelled += 1;
+        restored += record.amount;
+    }
+
+    if cancelled > 0 {
+        save_program(env, &program);
+        record_cancel(env, program_id);
+        monitoring::count_operation(env, monitoring::OperationType::Cancel);
+    }
+    (cancelled, restored)
+}
+
+/// Lets the recipient turn down a pending claim, returning the reserved
+/// funds to the program balance. Not subject to the cancel cooldown.
+pub fn decline_claim(env: &Env, program_id: &String, claim_id: u64, recipient: &Address) {
+    recipient.require_auth();
+
+    let mut record: ClaimRecord = env
+        .storage()
+        .persistent()
+        .get(&claim_key(program_id, claim_id))
+        .unwrap_or_else(|| panic!("ClaimAlreadyProcessed"));
+    if record.recipient != *recipient {
+        panic!("Unauthorized: only the recipient can decline");
+    }
+
+    let mut program = get_program(env, program_id);
+    program.remaining_balance += record.amount;
+    save_program(env, &program);
+
+    record.status = ClaimStatus::Cancelled;
+    archive_claim(env, &record);
+    monitoring::count_operation(env, monitoring::OperationType::Cancel);
+
+    env.events().publish(
+        (CLAIM_DECLINED,),
+        (
+            program_id.clone(),
+            claim_id,
+            record.recipient.clone(),
+            record.amount,
+        ),
+    );
+}
+
+/// Sets the minimum time between organizer cancellations for a program.
+/// 0 removes it. Only the contract admin may call this, so the organizer
+/// cannot lift the cooldown it is meant to constrain.
+pub fn set_cancel_cooldown(env: &Env, program_id: &String, secs: u64) {
+    get_program(env, program_id);
+    get_admin(env).require_auth();
+
+    let key = ProgramKey::ClaimCancelCooldown(program_id.clone());
+    if secs == 0 {
+        env.storage().instance().remove(&key);
+    } else {
+        env.storage().instance().set(&key, &secs);
+    }
+}
+
+/// Returns the program's cancel cooldown in seconds (0 if unset).
+pub fn get_cancel_cooldown(env: &Env, program_id: &String) -> u64 {
+    env.storage()
+        .instance()
+        .get(&ProgramKey::ClaimCancelCooldown(program_id.clone()))
+        .unwrap_or(0)
+}
+
+fn enforce_cancel_cooldown(env: &Env, program_id: &String) {
+    let cooldown = get_cancel_cooldown(env, program_id);
+    if cooldown == 0 {
+        return;
+    }
+    let last: Option<u64> = env
+        .storage()
+        .instance()
+        .get(&ProgramKey::LastClaimCancel(program_id.clone()));
+    if let Some(last) = last {
+        if env.ledger().timestamp() < last.saturating_add(cooldown) {
+            panic!("Cancel cooldown active");
+        }
+    }
+}
+
+fn record_cancel(env: &Env, program_id: &String) {
+    env.storage().instance().set(
+        &ProgramKey::LastClaimCancel(program_id.clone()),
+        &env.ledger().timestamp(),
+    );
+}
+
+/// Reclaims every pending claim of a program that is past its deadline and
+/// grace period, returning the reserved funds to the program balance.
+///
+/// Callable by anyone: funds only ever move back into the escrow. Each
+/// reclaimed claim triggers the program's expiry callback, if registered.
+/// Returns the number of claims swept.
+pub fn sweep_expired_claims(env: &Env, program_id: &String) -> u32 {
+    let mut program = get_program(env, program_id);
+    let grace_secs = get_program_claim_config(env, program_id).map_or(0, |c| c.grace_secs);
+    let now = env.ledger().timestamp();
+
+    let mut expired: Vec<ClaimRecord> = vec![env];
+    for claim_id in get_active_ids(env, program_id).iter() {
+        let record = get_claim(env, program_id, claim_id);
+        if now > record.claim_deadline.saturating_add(grace_secs) {
+            expired.push_back(record);
+        }
+    }
+    if expired.is_empty() {
+        return 0;
+    }
+
+    for mut record in expired.iter() {
+        program.remaining_balance += record.amount;
+        record.status = ClaimStatus::Expired;
+        archive_claim(env, &record);
+
+        env.events().publish(
+            (CLAIM_EXPIRED,),
+            (
+                program_id.clone(),
+                record.claim_id,
+                record.recipient.clone(),
+                record.amount,
+            ),
+        );
+    }
+    save_program(env, &program);
+
+    // notify after all state is settled; a failing callback never blocks the sweep
+    for record in expired.iter() {
+        payout_callback::notify_claim_expired(
+            env,
+            program_id,
+            record.claim_id,
+            &record.recipient,
+            record.amount,
+        );
+    }
+
+    expired.len()
+}
+
+/// Reduces a pending claim by `bps / 10000` of its amount, returning the
+/// slashed portion to the program balance. A 100% slash revokes the claim
+/// and, like any organizer cancellation, is subject to the cancel cooldown.
+/// Only the program's authorized payout key may call this. Returns the
+/// amount slashed.
+pub fn slash_claim(env: &Env, program_id: &String, claim_id: u64, bps: u32) -> i128 {
+    let mut program = get_program(env, program_id);
+    program.authorized_payout_key.require_auth();
+
+    if bps == 0 {
+        panic!("Slash must be greater than zero");
+    }
+    if bps as i128 > BASIS_POINTS {
+        panic!("Slash cannot exceed 10000 bps");
+    }
+
+    let key = claim_key(program_id, claim_id);
+    let mut record: ClaimRecord = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| panic!("ClaimAlreadyProcessed"));
+
+    let slashed = if bps as i128 == BASIS_POINTS {
+        enforce_cancel_cooldown(env, program_id);
+        record.amount
+    } else {
+        record.amount * bps as i128 / BASIS_POINTS
+    };
+    record.amount -= slashed;
+    record.slashed_amount += slashed;
+    program.remaining_balance += slashed;
+    save_program(env, &program);
+
+    if record.amount == 0 {
+        record.status = ClaimStatus::Revoked;
+        archive_claim(env, &record);
+        record_cancel(env, program_id);
+    } else {
+        env.storage().persistent().set(&key, &record);
+    }
+
+    env.events().publish(
+        (CLAIM_SLASHED,),
+        (
+            program_id.clone(),
+            claim_id,
+            record.recipient.clone(),
+            bps,
+            slashed,
+            record.amount,
+        ),
+    );
+
+    slashed
+}
+
+/// Revokes every pending claim of `recipient` in `program`, returning the
+/// reserved funds to its balance. Revoking counts as an organizer
+/// cancellation for the cancel cooldown. Callers are responsible for
+/// authorization and for persisting `program`. Returns the number revoked
+/// and the total.
+pub(crate) fn revoke_recipient_claims(
+    env: &Env,
+    program: &mut ProgramData,
+    recipient: &Address,
+) -> (u32, i128) {
+    let program_id = &program.program_id.clone();
+    let mut revoked: u32 = 0;
+    let mut restored: i128 = 0;
+    for claim_id in get_active_ids(env, program_id).iter() {
+        let mut record = get_claim(env, program_id, claim_id);
+        if record.recipient != *recipient {
+            continue;
+        }
+        if revoked == 0 {
+            enforce_cancel_cooldown(env, program_id);
+        }
+
+        program.remaining_balance += record.amount;
+        record.status = ClaimStatus::Revoked;
+        archive_claim(env, &record);
+
+        env.events().publish(
+            (CLAIM_REVOKED,),
+            (program_id.clone(), claim_id, recipient.clone(), record.amount),
+        );
+
+        revoked += 1;
+        restored += record.amount;
+    }
+    if revoked > 0 {
+        record_cancel(env, program_id);
+    }
+    (revoked, restored)
+}
+
+/// Cancels every pending claim in `program`, paying each recipient
+/// `compensation_bps` / 10_000 of its amount and returning the rest to the
+/// program balance. Denylisted or frozen recipients get nothing. Callers
+/// are responsible for authorization and for persisting `program`. Returns
+/// the number of claims and the total paid.
+pub(crate) fn compensate_pending_claims(
+    env: &Env,
+    program: &mut ProgramData,
+    compensation_bps: u32,
+) -> (u32, i128) {
+    let program_id = &program.program_id.clone();
+    let token_client = soroban_sdk::token::Client::new(env, &program.token_address);
+    let now = env.ledger().timestamp();
+    let mut compensated: u32 = 0;
+    let mut paid: i128 = 0;
+    for claim_id in get_active_ids(env, program_id).iter() {
+        let mut record = get_claim(env, program_id, claim_id);
+        let blocked = ProgramEscrowContract::is_denylisted(
+            env.clone(),
+            program_id.clone(),
+            record.recipient.clone(),
+        ) || ProgramEscrowContract::is_recipient_frozen(
+            env.clone(),
+            program_id.clone(),
+            record.recipient.clone(),
+        );
+        let compensation = if blocked {
+            0
+        } else {
+            record
+                .amount
+                .checked_mul(compensation_bps as i128)
+                .expect("Compensation overflow")
+                / 10_000
+        };
+
+        program.remaining_balance += record.amount - compensation;
+        record.status = ClaimStatus::Cancelled;
+        archive_claim(env, &record);
+
+        if compensation > 0 {
+            program.payout_history.push_back(PayoutRecord {
+                recipient: record.recipient.clone(),
+                amount: compensation,
+                timestamp: now,
+                memo_hash: record.memo_hash.clone(),
+            });
+            token_client.transfer(
+                &env.current_contract_address(),
+                &record.recipient,
+                &compensation,
+            );
+        }
+
+        env.events().publish(
+            (CLAIM_COMPENSATED,),
+            (
+                program_id.clone(),
+                claim_id,
+                record.recipient.clone(),
+                compensation,
+                record.amount,
+            ),
+        );
+
+        compensated += 1;
+        paid += compensation;
+    }
+    (compensated, paid)
+}
+
+/// Returns a claim record by its ID, whether still active or already resolved.
 ///
 /// Panics if the claim does not exist.
 pub fn get_claim(env: &Env, program_id: &String, claim_id: u64) -> ClaimRecord {
+    find_claim(env, program_id, claim_id).unwrap_or_else(|| panic!("Claim not found"))
+}
+
+/// Returns the claims among `claim_ids` that exist, in the order requested.
+pub fn get_claims(env: &Env, program_id: &String, claim_ids: &Vec<u64>) -> Vec<ClaimRecord> {
+    let mut claims = Vec::new(env);
+    for claim_id in claim_ids.iter() {
+        if let Some(record) = find_claim(env, program_id, claim_id) {
+            claims.push_back(record);
+        }
+    }
+    claims
+}
+
+fn find_claim(env: &Env, program_id: &String, claim_id: u64) -> Option<ClaimRecord> {
     env.storage()
         .persistent()
         .get(&claim_key(program_id, claim_id))
-        .unwrap_or_else(|| panic!("Claim not found"))
+        .or_else(|| env.storage().persistent().get(&history_key(program_id, claim_id)))
+}
+
+/// Returns a claim with its countdown to expiry at the current ledger time.
+///
+/// Panics if the claim does not exist.
+pub fn get_claim_view(env: &Env, program_id: &String, claim_id: u64) -> ClaimView {
+    let claim = get_claim(env, program_id, claim_id);
+    let seconds_until_expiry = match claim.status {
+        ClaimStatus::Pending => claim
+            .claim_deadline
+            .saturating_sub(env.ledger().timestamp()),
+        _ => 0,
+    };
+    ClaimView {
+        claim,
+        seconds_until_expiry,
+    }
+}
+
+/// Returns the status of a claim, whether still active or already resolved.
+///
+/// Panics if the claim does not exist.
+pub fn get_claim_status(env: &Env, program_id: &String, claim_id: u64) -> ClaimStatus {
+    get_claim(env, program_id, claim_id).status
+}
+
+/// Returns the ids of all claims that are still pending for a program.
+///
+/// Resolved claims are pruned from this set, so its size tracks only the
+/// claims that still hold reserved funds.
+pub fn get_pending_claim_ids(env: &Env, program_id: &String) -> Vec<u64> {
+    get_active_ids(env, program_id)
+}
+
+/// Returns the ids of pending claims created more than `older_than_secs`
+/// ago whose deadline has not yet passed, i.e. winners worth nudging.
+pub fn get_stale_pending_claims(env: &Env, program_id: &String, older_than_secs: u64) -> Vec<u64> {
+    let now = env.ledger().timestamp();
+    let mut stale = vec![env];
+    for claim_id in get_active_ids(env, program_id).iter() {
+        let record = get_claim(env, program_id, claim_id);
+        if now.saturating_sub(record.created_at) > older_than_secs && now <= record.claim_deadline
+        {
+            stale.push_back(claim_id);
+        }
+    }
+    stale
 }
 
 /// Set the global default claim window in seconds.
 /// Admin only.
 pub fn set_claim_window(env: &Env, admin: &Address, window_seconds: u64) {
-    let stored_admin: Address = env
-        .storage()
-        .instance()
-        .get(&DataKey::Admin)
-        .unwrap_or_else(|| panic!("Not initialized"));
+    let stored_admin = get_admin(env);
     if *admin != stored_admin {
         panic!("Unauthorized");
     }
@@ -276,3 +967,315 @@ pub fn get_claim_window(env: &Env) -> u64 {
         .get(&DataKey::ClaimWindow)
         .unwrap_or(86_400_u64)
 }
+
+/// Sets the claim window and late-claim grace for a single program.
+///
+/// A claim executed after its deadline but within `grace_secs` pays out
+/// `amount - amount * late_penalty_bps / 10000`; the penalty returns to the
+/// program balance. After the grace period the claim is expired.
+///
+/// When `apply_to_existing` is true every currently pending claim of the
+/// program has its deadline recomputed as `created_at + claim_window`;
+/// otherwise only claims created afterwards use the new window.
+///
+/// Returns the replaced config (`None` if the program used the global
+/// window) and the number of pending claims whose deadline was recomputed.
+pub fn set_program_claim_config(
+    env: &Env,
+    program_id: &String,
+    claim_window: u64,
+    grace_secs: u64,
+    late_penalty_bps: u32,
+    apply_to_existing: bool,
+) -> (Option<ClaimConfig>, u32) {
+    ProgramEscrowContract::require_program_admin_auth(env, program_id);
+
+    if claim_window == 0 {
+        panic!("Claim window must be greater than zero");
+    }
+    if late_penalty_bps as i128 > BASIS_POINTS {
+        panic!("Late penalty cannot exceed 10000 bps");
+    }
+
+    let previous = get_program_claim_config(env, program_id);
+    env.storage().instance().set(
+        &DataKey::ProgramClaimConfig(program_id.clone()),
+        &ClaimConfig {
+            claim_window,
+            grace_secs,
+            late_penalty_bps,
+        },
+    );
+
+    let mut updated: u32 = 0;
+    if apply_to_existing {
+        for claim_id in get_active_ids(env, program_id).iter() {
+            let key = claim_key(program_id, claim_id);
+            if let Some(mut record) = env.storage().persistent().get::<_, ClaimRecord>(&key) {
+                let window = get_recipient_claim_window(env, program_id, &record.recipient)
+                    .unwrap_or(claim_window);
+                // keep any extension already granted to the claim
+                record.claim_deadline = record
+                    .created_at
+                    .saturating_add(window)
+                    .saturating_add(record.extended_secs);
+                env.storage().persistent().set(&key, &record);
+                updated += 1;
+            }
+        }
+    }
+
+    env.events().publish(
+        (CLAIM_CONFIG_UPDATED,),
+        (program_id.clone(), claim_window, apply_to_existing, updated),
+    );
+
+    (previous, updated)
+}
+
+/// Returns the claim config set for a program, if any.
+pub fn get_program_claim_config(env: &Env, program_id: &String) -> Option<ClaimConfig> {
+    env.storage()
+        .instance()
+        .get(&DataKey::ProgramClaimConfig(program_id.clone()))
+}
+
+/// Overrides the claim window for future claims to `recipient` in a program.
+/// 0 removes the override. Only the program's authorized payout key may call
+/// this.
+pub fn set_recipient_claim_window(
+    env: &Env,
+    program_id: &String,
+    recipient: &Address,
+    claim_window: u64,
+) {
+    let program = get_program(env, program_id);
+    program.authorized_payout_key.require_auth();
+
+    let key = DataKey::RecipientClaimWindow(program_id.clone(), recipient.clone());
+    if claim_window == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &claim_window);
+    }
+
+    env.events().publish(
+        (CLAIM_CONFIG_UPDATED,),
+        (program_id.clone(), recipient.clone(), claim_window),
+    );
+}
+
+/// Returns the claim window override for a recipient, if any.
+pub fn get_recipient_claim_window(
+    env: &Env,
+    program_id: &String,
+    recipient: &Address,
+) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RecipientClaimWindow(program_id.clone(), recipient.clone()))
+}
+
+/// Returns the claim window for a new claim to `recipient`: its own override
+/// if set, otherwise the program's claim window.
+pub fn get_claim_window_for(env: &Env, program_id: &String, recipient: &Address) -> u64 {
+    get_recipient_claim_window(env, program_id, recipient)
+        .unwrap_or_else(|| get_program_claim_window(env, program_id))
+}
+
+/// Requires a claim created at ledger sequence `S` to wait until sequence
+/// `S + min_confirmations` before it can be executed. 0 removes the delay.
+/// Only the program's authorized payout key may call this.
+pub fn set_claim_min_confirmations(env: &Env, program_id: &String, min_confirmations: u32) {
+    let program = get_program(env, program_id);
+    program.authorized_payout_key.require_auth();
+
+    let key = DataKey::ClaimMinConfirmations(program_id.clone());
+    if min_confirmations == 0 {
+        env.storage().instance().remove(&key);
+    } else {
+        env.storage().instance().set(&key, &min_confirmations);
+    }
+
+    env.events().publish(
+        (CLAIM_CONFIG_UPDATED,),
+        (program_id.clone(), symbol_short!("min_conf"), min_confirmations),
+    );
+}
+
+/// Returns the number of ledgers a program's claims must wait before
+/// execution (0 if unset).
+pub fn get_claim_min_confirmations(env: &Env, program_id: &String) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::ClaimMinConfirmations(program_id.clone()))
+        .unwrap_or(0)
+}
+
+/// Caps the total seconds any one claim of a program can be extended by
+/// `extend_claim_window`. Pass 0 to remove the cap.
+pub fn set_max_claim_extension(env: &Env, program_id: &String, max_total_secs: u64) {
+    let program = get_program(env, program_id);
+    program.authorized_payout_key.require_auth();
+
+    let key = DataKey::MaxClaimExtension(program_id.clone());
+    if max_total_secs == 0 {
+        env.storage().instance().remove(&key);
+    } else {
+        env.storage().instance().set(&key, &max_total_secs);
+    }
+
+    env.events().publish(
+        (CLAIM_CONFIG_UPDATED,),
+        (program_id.clone(), symbol_short!("max_ext"), max_total_secs),
+    );
+}
+
+/// Returns the per-claim extension cap for a program (0 if uncapped).
+pub fn get_max_claim_extension(env: &Env, program_id: &String) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::MaxClaimExtension(program_id.clone()))
+        .unwrap_or(0)
+}
+
+/// Pushes a pending claim's deadline back by `extra_secs`, bounded by the
+/// program's extension cap. Returns the new deadline.
+pub fn extend_claim_window(
+    env: &Env,
+    program_id: &String,
+    claim_id: u64,
+    extra_secs: u64,
+) -> u64 {
+    let program = get_program(env, program_id);
+    program.authorized_payout_key.require_auth();
+
+    if extra_secs == 0 {
+        panic!("Extension must be greater than zero");
+    }
+
+    let key = claim_key(program_id, claim_id);
+    let mut record: ClaimRecord = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| panic!("ClaimAlreadyProcessed"));
+
+    let extended_secs = record.extended_secs.saturating_add(extra_secs);
+    let cap = get_max_claim_extension(env, program_id);
+    if cap > 0 && extended_secs > cap {
+        panic!("Extension cap reached");
+    }
+
+    record.extended_secs = extended_secs;
+    record.claim_deadline = record.claim_deadline.saturating_add(extra_secs);
+    env.storage().persistent().set(&key, &record);
+
+    env.events().publish(
+        (CLAIM_EXTENDED,),
+        (
+            program_id.clone(),
+            claim_id,
+            record.claim_deadline,
+            extended_secs,
+        ),
+    );
+
+    record.claim_deadline
+}
+
+/// Turns auto-extension on activity on or off for a program. While on, a
+/// recipient acknowledging a pending claim pushes its deadline out to at
+/// least `extend_secs` from now. Only the program admin may call this.
+pub fn set_auto_extend_on_activity(
+    env: &Env,
+    program_id: &String,
+    enabled: bool,
+    extend_secs: u64,
+) {
+    ProgramEscrowContract::require_program_admin_auth(env, program_id);
+
+    let key = ProgramKey::AutoExtendOnActivity(program_id.clone());
+    if enabled {
+        if extend_secs == 0 {
+            panic!("Extension must be greater than zero");
+        }
+        env.storage().instance().set(&key, &extend_secs);
+    } else {
+        env.storage().instance().remove(&key);
+    }
+
+    env.events().publish(
+        (CLAIM_CONFIG_UPDATED,),
+        (program_id.clone(), symbol_short!("auto_ext"), extend_secs),
+    );
+}
+
+/// Returns how far an acknowledgment extends a claim (0 if disabled).
+pub fn get_auto_extend_on_activity(env: &Env, program_id: &String) -> u64 {
+    env.storage()
+        .instance()
+        .get(&ProgramKey::AutoExtendOnActivity(program_id.clone()))
+        .unwrap_or(0)
+}
+
+/// Records that the recipient has seen a pending claim. With auto-extension
+/// on, the deadline moves out to `now + extend_secs`, bounded by the
+/// program's extension cap. Returns the claim's deadline afterwards.
+pub fn acknowledge_claim(
+    env: &Env,
+    program_id: &String,
+    claim_id: u64,
+    recipient: &Address,
+) -> u64 {
+    recipient.require_auth();
+
+    let key = claim_key(program_id, claim_id);
+    let mut record: ClaimRecord = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| panic!("ClaimAlreadyProcessed"));
+    if record.recipient != *recipient {
+        panic!("Unauthorized: only the recipient can acknowledge");
+    }
+
+    let now = env.ledger().timestamp();
+    if now > record.claim_deadline {
+        panic!("ClaimExpired");
+    }
+
+    let extend_secs = get_auto_extend_on_activity(env, program_id);
+    let mut extra = now
+        .saturating_add(extend_secs)
+        .saturating_sub(record.claim_deadline);
+    let cap = get_max_claim_extension(env, program_id);
+    if cap > 0 {
+        extra = extra.min(cap.saturating_sub(record.extended_secs));
+    }
+    if extra > 0 {
+        record.extended_secs = record.extended_secs.saturating_add(extra);
+        record.claim_deadline = record.claim_deadline.saturating_add(extra);
+        env.storage().persistent().set(&key, &record);
+    }
+
+    env.events().publish(
+        (CLAIM_ACKNOWLEDGED,),
+        (
+            program_id.clone(),
+            claim_id,
+            recipient.clone(),
+            record.claim_deadline,
+        ),
+    );
+
+    record.claim_deadline
+}
+
+/// Returns the claim window that applies to new claims of a program: its own
+/// `ClaimConfig` if set, otherwise the global claim window.
+pub fn get_program_claim_window(env: &Env, program_id: &String) -> u64 {
+    get_program_claim_config(env, program_id)
+        .map(|config| config.claim_window)
+        .unwrap_or_else(|| get_claim_window(env))
+}
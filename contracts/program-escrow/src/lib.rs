@@ -71,6 +71,10 @@
 //!
 //! ### Trust Assumptions
 //! - **Authorized Payout Key**: Trusted backend service that triggers payouts
+//! - **Program Admin**: Governs one program's configuration; defaults to the
+//!   payout key unless the contract admin assigns one with
+//!   `initialize_program_with_admin` or `set_program_admin`
+//! - **Contract Admin**: Governs global settings shared by every program
 //! - **Organizer**: Trusted to lock appropriate prize amounts
 //! - **Token Contract**: Standard Stellar Asset Contract (SAC)
 //! - **Contract**: Trustless; operates according to programmed rules
@@ -83,6 +87,16 @@
 //! 5. **Complete Audit Trail**: Full payout history tracking
 //! 6. **Overflow Protection**: Safe arithmetic for all calculations
 //!
+//! ### Authorization Scopes
+//! - **Program admin**: `set_program_claim_config`, `set_operation_flags`
+//!   (pausing the program's lock/payout/claim) and other program settings.
+//!   `set_program_fee_bps` and `set_program_admin` need an assigned program
+//!   admin; until one is assigned they fall to the contract admin, so an
+//!   organizer cannot lower the platform fee on their own program
+//! - **Contract admin**: `update_rate_limit_config`, `set_whitelist`,
+//!   `set_paused`, `update_amount_limits` and other contract-wide settings
+//! - **Payout key**: payouts, schedules and claim management
+//!
 //! ## Usage Example
 //!
 //! ```rust
@@ -118,7 +132,7 @@
 //!     2_000_0000000,  // 3rd place: 2,000 USDC
 //! ];
 //!
-//! escrow_client.batch_payout(&winners, &prizes);
+//! escrow_client.batch_payout(&program_id, &winners, &prizes, &false, &None, &10_000_0000000);
 //! ```
 //!
 //! ## Event System
@@ -140,8 +154,8 @@
 
 #![no_std]
 use soroban_sdk::{
-    contract, contractimpl, contracttype, symbol_short, token, vec, Address, Env, String, Symbol,
-    Vec,
+    contract, contractimpl, contracttype, symbol_short, token, vec, Address, Bytes, BytesN, Env,
+    String, Symbol, Val, Vec,
 };
 
 // Event types
@@ -166,7 +180,18 @@ mod reentrancy_guard;
 // Storage keys
 const PROGRAM_DATA: Symbol = symbol_short!("ProgData");
 const FEE_CONFIG: Symbol = symbol_short!("FeeCfg");
+const AMOUNT_LIMITS: Symbol = symbol_short!("AmtLimit");
+const MAX_ORGANIZER_PROGRAMS: Symbol = symbol_short!("MaxOrgPrg");
 const CONFIG_SNAPSHOT_LIMIT: u32 = 20;
+const MAX_PROGRAM_TAGS: u32 = 10;
+
+// Approximate cost units used by `estimate_batch_cost`
+const BATCH_BASE_COST: u32 = 50_000; // auth, program load and final save
+const BATCH_RECIPIENT_COST: u32 = 40_000; // eligibility checks, transfer and history entry
+const BATCH_FEE_TRANSFER_COST: u32 = 25_000; // per-entry fee transfer when fees apply
+
+// Trailing window checked by `set_daily_payout_cap`
+const DAILY_PAYOUT_WINDOW_SECS: u64 = 86_400;
 
 // Fee rate is stored in basis points (1 basis point = 0.01%)
 // Example: 100 basis points = 1%, 1000 basis points = 10%
@@ -182,6 +207,30 @@ pub struct FeeConfig {
     pub fee_enabled: bool,      // Global fee enable/disable flag
 }
 
+/// One fee taken by the contract, kept per token for the fee collector's
+/// accounting.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeRecord {
+    pub program_id: String,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+/// Contract-wide bounds on lock and payout amounts.
+///
+/// A value of `0` leaves that bound unenforced: a `0` minimum means "no
+/// minimum" and a `0` maximum means "no maximum". The two sides are
+/// independent, so a minimum can stay enforced with an unbounded maximum.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq, Default)]
+pub struct AmountLimits {
+    pub min_lock_amount: i128,
+    pub max_lock_amount: i128,
+    pub min_payout: i128,
+    pub max_payout: i128,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ConfigSnapshot {
@@ -208,6 +257,13 @@ mod monitoring {
     const OPERATION_COUNT: &str = "op_count";
     const USER_COUNT: &str = "usr_count";
     const ERROR_COUNT: &str = "err_count";
+    const OPERATION_COUNTS: &str = "op_counts";
+
+    pub const CONTRACT_VERSION: &str = "1.0.0";
+    // Numeric parts of CONTRACT_VERSION, reported by get_contract_info
+    pub const VERSION_MAJOR: u32 = 1;
+    pub const VERSION_MINOR: u32 = 0;
+    pub const VERSION_PATCH: u32 = 0;
 
     // Event: Operation metric
     #[contracttype]
@@ -258,6 +314,27 @@ mod monitoring {
         pub total_errors: u64,
     }
 
+    // Data: Cumulative invocations per operation type
+    #[contracttype]
+    #[derive(Clone, Debug, Default, Eq, PartialEq)]
+    pub struct OperationCounts {
+        pub inits: u64,
+        pub locks: u64,
+        pub payouts: u64,
+        pub claims: u64,
+        pub cancels: u64,
+    }
+
+    // Operation types counted in `OperationCounts`
+    #[derive(Clone, Copy)]
+    pub enum OperationType {
+        Init,
+        Lock,
+        Payout,
+        Claim,
+        Cancel,
+    }
+
     // Data: Performance stats
     #[contracttype]
     #[derive(Clone, Debug)]
@@ -292,6 +369,30 @@ mod monitoring {
         );
     }
 
+    // Count a successful operation toward the lifetime totals. Unlike the
+    // rate-limit counters these never reset.
+    pub fn count_operation(env: &Env, operation: OperationType) {
+        let key = Symbol::new(env, OPERATION_COUNTS);
+        let mut counts: OperationCounts = env.storage().persistent().get(&key).unwrap_or_default();
+        let counter = match operation {
+            OperationType::Init => &mut counts.inits,
+            OperationType::Lock => &mut counts.locks,
+            OperationType::Payout => &mut counts.payouts,
+            OperationType::Claim => &mut counts.claims,
+            OperationType::Cancel => &mut counts.cancels,
+        };
+        *counter = counter.saturating_add(1);
+        env.storage().persistent().set(&key, &counts);
+    }
+
+    // Get lifetime operation counts
+    pub fn get_operation_counts(env: &Env) -> OperationCounts {
+        env.storage()
+            .persistent()
+            .get(&Symbol::new(env, OPERATION_COUNTS))
+            .unwrap_or_default()
+    }
+
     // Track performance
     pub fn emit_performance(env: &Env, function: Symbol, duration: u64) {
         let count_key = (Symbol::new(env, "perf_cnt"), function.clone());
@@ -324,7 +425,7 @@ mod monitoring {
             is_healthy: true,
             last_operation: env.ledger().timestamp(),
             total_operations: ops,
-            contract_version: String::from_str(env, "1.0.0"),
+            contract_version: String::from_str(env, CONTRACT_VERSION),
         }
     }
 
@@ -409,6 +510,80 @@ mod anti_abuse {
         pub operation_count: u32,
     }
 
+    /// How `check_rate_limit` counts operations against `max_operations`.
+    #[contracttype]
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    pub enum RateLimitMode {
+        /// `max_operations` per fixed window, reset when the window elapses.
+        FixedWindow,
+        /// Bucket of `max_operations` tokens refilled continuously at
+        /// `max_operations / window_size` per second.
+        LeakyBucket,
+        /// Fixed window whose cooldown escalates for repeat offenders (see
+        /// `BackoffConfig`).
+        Backoff,
+    }
+
+    /// Cooldown escalation for `RateLimitMode::Backoff`.
+    ///
+    /// Early operations are rejected exactly as in `FixedWindow` mode. A
+    /// rejected call reverts and cannot leave a trace, so escalation is
+    /// driven by the spacing of successful operations instead: one landing
+    /// within twice the effective cooldown of the previous one raises the
+    /// address's backoff level, and one spaced further apart lowers it.
+    /// The first `grace_violations` levels are free; each level beyond them
+    /// multiplies the cooldown by `multiplier`, up to `max_cooldown`.
+    #[contracttype]
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct BackoffConfig {
+        pub multiplier: u32,
+        pub max_cooldown: u64,
+        pub grace_violations: u32,
+    }
+
+    /// Rate limit standing of an address, from `get_rate_limit_status`.
+    #[contracttype]
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct RateLimitStatus {
+        pub mode: RateLimitMode,
+        pub available_ops: u32,
+        /// Cooldown currently applied to the address, including backoff.
+        pub effective_cooldown: u64,
+        pub backoff_level: u32,
+        /// Earliest timestamp the next operation is outside the cooldown.
+        pub cooldown_ends_at: u64,
+    }
+
+    /// Leaky-bucket level for an address. One operation costs `window_size`
+    /// units and each elapsed second refills `max_operations` units, which
+    /// keeps the refill rate exact in integer arithmetic.
+    #[contracttype]
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct BucketState {
+        pub level: u128,
+        pub last_refill_timestamp: u64,
+    }
+
+    /// Identity tier attested for a rate-limited address.
+    #[contracttype]
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    pub enum IdentityTier {
+        Unverified,
+        Basic,
+        Verified,
+        Premium,
+    }
+
+    /// Factors applied to `max_operations` per identity tier. Unverified
+    /// addresses always use the base limit.
+    #[contracttype]
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct TierRateMultipliers {
+        pub basic: u32,
+        pub verified: u32,
+        pub premium: u32,
+    }
+
     #[contracttype]
     #[derive(Clone, Debug, Eq, PartialEq)]
     pub enum AntiAbuseKey {
@@ -416,6 +591,12 @@ mod anti_abuse {
         State(Address),
         Whitelist(Address),
         Admin,
+        Mode,
+        Bucket(Address),
+        Tier(Address),
+        TierMultipliers,
+        BackoffConfig,
+        BackoffLevel(Address),
     }
 
     pub fn get_config(env: &Env) -> AntiAbuseConfig {
@@ -433,6 +614,240 @@ mod anti_abuse {
         env.storage().instance().set(&AntiAbuseKey::Config, &config);
     }
 
+    /// True once an explicit config has been stored (defaults apply otherwise).
+    pub fn is_configured(env: &Env) -> bool {
+        env.storage().instance().has(&AntiAbuseKey::Config)
+    }
+
+    pub fn get_tier(env: &Env, address: Address) -> IdentityTier {
+        env.storage()
+            .persistent()
+            .get(&AntiAbuseKey::Tier(address))
+            .unwrap_or(IdentityTier::Unverified)
+    }
+
+    pub fn set_tier(env: &Env, address: Address, tier: IdentityTier) {
+        let key = AntiAbuseKey::Tier(address);
+        if tier == IdentityTier::Unverified {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &tier);
+        }
+    }
+
+    pub fn get_tier_multipliers(env: &Env) -> TierRateMultipliers {
+        env.storage()
+            .instance()
+            .get(&AntiAbuseKey::TierMultipliers)
+            .unwrap_or(TierRateMultipliers {
+                basic: 1,
+                verified: 1,
+                premium: 1,
+            })
+    }
+
+    pub fn set_tier_multipliers(env: &Env, multipliers: TierRateMultipliers) {
+        env.storage()
+            .instance()
+            .set(&AntiAbuseKey::TierMultipliers, &multipliers);
+    }
+
+    /// Config for `address` with `max_operations` scaled by its tier.
+    fn effective_config(env: &Env, address: &Address) -> AntiAbuseConfig {
+        let mut config = get_config(env);
+        let multipliers = get_tier_multipliers(env);
+        let factor = match get_tier(env, address.clone()) {
+            IdentityTier::Unverified => 1,
+            IdentityTier::Basic => multipliers.basic,
+            IdentityTier::Verified => multipliers.verified,
+            IdentityTier::Premium => multipliers.premium,
+        };
+        config.max_operations = config.max_operations.saturating_mul(factor);
+        config
+    }
+
+    pub fn get_mode(env: &Env) -> RateLimitMode {
+        env.storage()
+            .instance()
+            .get(&AntiAbuseKey::Mode)
+            .unwrap_or(RateLimitMode::FixedWindow)
+    }
+
+    pub fn set_mode(env: &Env, mode: RateLimitMode) {
+        env.storage().instance().set(&AntiAbuseKey::Mode, &mode);
+    }
+
+    pub fn get_backoff_config(env: &Env) -> BackoffConfig {
+        env.storage()
+            .instance()
+            .get(&AntiAbuseKey::BackoffConfig)
+            .unwrap_or(BackoffConfig {
+                multiplier: 2,
+                max_cooldown: 86_400, // 1 day default
+                grace_violations: 0,
+            })
+    }
+
+    pub fn set_backoff_config(env: &Env, backoff: BackoffConfig) {
+        env.storage()
+            .instance()
+            .set(&AntiAbuseKey::BackoffConfig, &backoff);
+    }
+
+    pub fn get_backoff_level(env: &Env, address: &Address) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&AntiAbuseKey::BackoffLevel(address.clone()))
+            .unwrap_or(0)
+    }
+
+    fn set_backoff_level(env: &Env, address: &Address, level: u32) {
+        let key = AntiAbuseKey::BackoffLevel(address.clone());
+        if level == 0 {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &level);
+            env.storage().persistent().extend_ttl(&key, 17280, 17280);
+        }
+    }
+
+    /// Cooldown applied to `address`: the configured one, escalated by its
+    /// backoff level in `Backoff` mode.
+    pub fn effective_cooldown(env: &Env, config: &AntiAbuseConfig, address: &Address) -> u64 {
+        if get_mode(env) != RateLimitMode::Backoff {
+            return config.cooldown_period;
+        }
+        let backoff = get_backoff_config(env);
+        let cap = backoff.max_cooldown.max(config.cooldown_period);
+        let mut cooldown = config.cooldown_period;
+        let level = get_backoff_level(env, address);
+        for _ in 0..level.saturating_sub(backoff.grace_violations) {
+            cooldown = cooldown.saturating_mul(backoff.multiplier as u64);
+            if cooldown >= cap {
+                return cap;
+            }
+        }
+        cooldown
+    }
+
+    pub fn get_status(env: &Env, address: Address) -> RateLimitStatus {
+        let config = effective_config(env, &address);
+        let cooldown = effective_cooldown(env, &config, &address);
+        let last: u64 = env
+            .storage()
+            .persistent()
+            .get(&AntiAbuseKey::State(address.clone()))
+            .map(|state: AddressState| state.last_operation_timestamp)
+            .unwrap_or(0);
+        RateLimitStatus {
+            mode: get_mode(env),
+            available_ops: get_available_ops(env, address.clone()),
+            effective_cooldown: cooldown,
+            backoff_level: get_backoff_level(env, &address),
+            cooldown_ends_at: if last > 0 {
+                last.saturating_add(cooldown)
+            } else {
+                0
+            },
+        }
+    }
+
+    fn bucket_capacity(config: &AntiAbuseConfig) -> u128 {
+        (config.max_operations as u128) * (config.window_size as u128)
+    }
+
+    /// Returns the bucket for `address` refilled up to `now`.
+    fn refilled_bucket(
+        env: &Env,
+        config: &AntiAbuseConfig,
+        address: &Address,
+        now: u64,
+    ) -> BucketState {
+        let capacity = bucket_capacity(config);
+        let mut bucket: BucketState = env
+            .storage()
+            .persistent()
+            .get(&AntiAbuseKey::Bucket(address.clone()))
+            .unwrap_or(BucketState {
+                level: capacity,
+                last_refill_timestamp: now,
+            });
+
+        let elapsed = now.saturating_sub(bucket.last_refill_timestamp) as u128;
+        let refill = elapsed.saturating_mul(config.max_operations as u128);
+        bucket.level = bucket.level.saturating_add(refill).min(capacity);
+        bucket.last_refill_timestamp = now;
+        bucket
+    }
+
+    /// Number of operations `address` could perform right now under the
+    /// active mode, ignoring the cooldown.
+    pub fn get_available_ops(env: &Env, address: Address) -> u32 {
+        let config = effective_config(env, &address);
+        let now = env.ledger().timestamp();
+
+        match get_mode(env) {
+            RateLimitMode::LeakyBucket => {
+                if config.window_size == 0 {
+                    return config.max_operations;
+                }
+                let bucket = refilled_bucket(env, &config, &address, now);
+                (bucket.level / config.window_size as u128) as u32
+            }
+            RateLimitMode::FixedWindow | RateLimitMode::Backoff => {
+                let state: Option<AddressState> = env
+                    .storage()
+                    .persistent()
+                    .get(&AntiAbuseKey::State(address));
+                match state {
+                    Some(state)
+                        if now
+                            < state
+                                .window_start_timestamp
+                                .saturating_add(config.window_size) =>
+                    {
+                        config.max_operations.saturating_sub(state.operation_count)
+                    }
+                    _ => config.max_operations,
+                }
+            }
+        }
+    }
+
+    /// Number of back-to-back operations `address` can perform at the current
+    /// ledger time before `check_rate_limit` panics, cooldown included.
+    pub fn operations_until_blocked(env: &Env, address: Address) -> u32 {
+        if is_whitelisted(env, address.clone()) {
+            return u32::MAX;
+        }
+
+        let config = effective_config(env, &address);
+        let cooldown = effective_cooldown(env, &config, &address);
+        let now = env.ledger().timestamp();
+        let state: Option<AddressState> = env
+            .storage()
+            .persistent()
+            .get(&AntiAbuseKey::State(address.clone()));
+
+        // An early operation is rejected in every mode
+        if let Some(state) = state {
+            if state.last_operation_timestamp > 0
+                && now < state.last_operation_timestamp.saturating_add(cooldown)
+            {
+                return 0;
+            }
+        }
+
+        let available = get_available_ops(env, address);
+        // Each operation stamps `now`, so a non-zero cooldown blocks the next
+        // one at the same ledger time (a zero timestamp is never enforced).
+        if cooldown > 0 && now > 0 {
+            available.min(1)
+        } else {
+            available
+        }
+    }
+
     pub fn is_whitelisted(env: &Env, address: Address) -> bool {
         env.storage()
             .instance()
@@ -457,6 +872,20 @@ pub enum DataKey {
     SplitConfig(String),              // RateLimitConfig struct
 }
 
+    /// Clears the window counter, cooldown, bucket level and backoff for
+    /// `address`.
+    pub fn reset(env: &Env, address: Address) {
+        env.storage()
+            .persistent()
+            .remove(&AntiAbuseKey::State(address.clone()));
+        env.storage()
+            .persistent()
+            .remove(&AntiAbuseKey::Bucket(address.clone()));
+        env.storage()
+            .persistent()
+            .remove(&AntiAbuseKey::BackoffLevel(address));
+    }
+
     pub fn get_admin(env: &Env) -> Option<Address> {
         env.storage().instance().get(&AntiAbuseKey::Admin)
     }
@@ -474,7 +903,7 @@ pub enum DataKey {
             return;
         }
 
-        let config = get_config(env);
+        let config = effective_config(env, &address);
         let now = env.ledger().timestamp();
         let key = AntiAbuseKey::State(address.clone());
 
@@ -489,11 +918,10 @@ pub enum DataKey {
                 });
 
         // 1. Cooldown check
+        let mode = get_mode(env);
+        let cooldown = effective_cooldown(env, &config, &address);
         if state.last_operation_timestamp > 0
-            && now
-                < state
-                    .last_operation_timestamp
-                    .saturating_add(config.cooldown_period)
+            && now < state.last_operation_timestamp.saturating_add(cooldown)
         {
             env.events().publish(
                 (symbol_short!("abuse"), symbol_short!("cooldown")),
@@ -502,7 +930,48 @@ pub enum DataKey {
             panic!("Operation in cooldown period");
         }
 
-        // 2. Window check
+        // Backoff: crowding the cooldown escalates, wider spacing relaxes
+        if mode == RateLimitMode::Backoff && state.last_operation_timestamp > 0 {
+            let level = get_backoff_level(env, &address);
+            let crowd_until = state
+                .last_operation_timestamp
+                .saturating_add(cooldown.saturating_mul(2));
+            if now < crowd_until {
+                set_backoff_level(env, &address, level + 1);
+                env.events().publish(
+                    (symbol_short!("abuse"), symbol_short!("backoff")),
+                    (address.clone(), level + 1),
+                );
+            } else if level > 0 {
+                set_backoff_level(env, &address, level - 1);
+            }
+        }
+
+        // 2. Leaky bucket: take one token from a continuously refilled bucket
+        if mode == RateLimitMode::LeakyBucket {
+            if config.window_size > 0 {
+                let bucket_key = AntiAbuseKey::Bucket(address.clone());
+                let mut bucket = refilled_bucket(env, &config, &address, now);
+                let cost = config.window_size as u128;
+                if bucket.level < cost {
+                    env.events().publish(
+                        (symbol_short!("abuse"), symbol_short!("limit")),
+                        (address.clone(), now),
+                    );
+                    panic!("Rate limit exceeded");
+                }
+                bucket.level -= cost;
+                env.storage().persistent().set(&bucket_key, &bucket);
+                env.storage().persistent().extend_ttl(&bucket_key, 17280, 17280);
+            }
+
+            state.last_operation_timestamp = now;
+            env.storage().persistent().set(&key, &state);
+            env.storage().persistent().extend_ttl(&key, 17280, 17280);
+            return;
+        }
+
+        // 3. Window check
         if now
             >= state
                 .window_start_timestamp
@@ -534,16 +1003,208 @@ pub enum DataKey {
 // External modules
 mod claim_period;
 mod error_recovery;
+mod multisig_payout;
+mod payout_callback;
+mod payout_condition;
+mod payout_volume;
+mod price_oracle;
+mod program_storage;
 mod reentrancy_guard;
+mod settlement;
 mod threshold_monitor;
 pub mod token_math;
+mod velocity_guard;
 
-pub use claim_period::{ClaimRecord, ClaimStatus};
+pub use claim_period::{ClaimConfig, ClaimRecord, ClaimStatus, ClaimView};
+pub use multisig_payout::{MultisigPayoutConfig, MultisigRequest, MultisigRequestStatus};
+pub use payout_volume::PayoutVolume;
+pub use price_oracle::{OracleConfig, PriceData};
+pub use program_storage::StorageTier;
+pub use settlement::SettlementConfig;
+pub use velocity_guard::VelocityGuard;
 
 #[cfg(test)]
 mod test_claim_period_expiry_cancellation;
 #[cfg(test)]
 mod test_token_math;
+#[cfg(test)]
+mod test_claim_pruning;
+#[cfg(test)]
+mod test_program_tvl_cap;
+#[cfg(test)]
+mod test_single_payout_claim_id;
+#[cfg(test)]
+mod test_rate_limit_mode;
+#[cfg(test)]
+mod test_sponsor_split;
+#[cfg(test)]
+mod test_program_claim_config;
+#[cfg(test)]
+mod test_payout_callback;
+#[cfg(test)]
+mod test_next_due_schedule;
+#[cfg(test)]
+mod test_operation_flags;
+#[cfg(test)]
+mod test_auto_claim_recipients;
+#[cfg(test)]
+mod test_claim_grace_period;
+#[cfg(test)]
+mod test_price_oracle;
+#[cfg(test)]
+mod test_batch_dedupe;
+#[cfg(test)]
+mod test_contract_health;
+#[cfg(test)]
+mod test_admin_nonce;
+#[cfg(test)]
+mod test_min_program_funding;
+#[cfg(test)]
+mod test_rate_limit_predictor;
+#[cfg(test)]
+mod test_program_fee_override;
+#[cfg(test)]
+mod test_claim_available_event;
+#[cfg(test)]
+mod test_withdraw_unlocked;
+#[cfg(test)]
+mod test_max_schedules;
+#[cfg(test)]
+mod test_tier_rate_limits;
+#[cfg(test)]
+mod test_schedule_totals;
+#[cfg(test)]
+mod test_batch_payout_modes;
+#[cfg(test)]
+mod test_clone_program;
+#[cfg(test)]
+mod test_rate_limit_reset;
+#[cfg(test)]
+mod test_claim_expiry_callback;
+#[cfg(test)]
+mod test_remainder_policy;
+#[cfg(test)]
+mod test_payout_reference;
+#[cfg(test)]
+mod test_settlement_batching;
+#[cfg(test)]
+mod test_multisig_payout;
+#[cfg(test)]
+mod test_batch_cancel_claims;
+#[cfg(test)]
+mod test_claim_confirmations;
+#[cfg(test)]
+mod test_organizer_programs;
+#[cfg(test)]
+mod test_payout_memo;
+#[cfg(test)]
+mod test_amount_limits;
+#[cfg(test)]
+mod test_recipient_claim_window;
+#[cfg(test)]
+mod test_batch_total_guard;
+#[cfg(test)]
+mod test_close_program;
+#[cfg(test)]
+mod test_claim_expiry_countdown;
+#[cfg(test)]
+mod test_allowed_operations;
+#[cfg(test)]
+mod test_payout_result;
+#[cfg(test)]
+mod test_organizer_program_cap;
+#[cfg(test)]
+mod test_claim_reentrancy;
+#[cfg(test)]
+mod test_claim_extension_cap;
+#[cfg(test)]
+mod test_payout_volume;
+#[cfg(test)]
+mod test_denylist;
+#[cfg(test)]
+mod test_program_created_event;
+#[cfg(test)]
+mod test_slash_claim;
+#[cfg(test)]
+mod test_accepted_tokens;
+#[cfg(test)]
+mod test_velocity_guard;
+#[cfg(test)]
+mod test_claim_tip;
+#[cfg(test)]
+mod test_program_storage_tier;
+#[cfg(test)]
+mod test_batch_lenient;
+#[cfg(test)]
+mod test_payout_condition;
+#[cfg(test)]
+mod test_claimable_now;
+#[cfg(test)]
+mod test_open_funding;
+#[cfg(test)]
+mod test_funding_goal;
+#[cfg(test)]
+mod test_direct_payout_mode;
+#[cfg(test)]
+mod test_low_balance;
+#[cfg(test)]
+mod test_claim_cancel_cooldown;
+#[cfg(test)]
+mod test_batch_eligibility;
+#[cfg(test)]
+mod test_program_admin;
+#[cfg(test)]
+mod test_recipient_payouts;
+#[cfg(test)]
+mod test_claim_auto_extend;
+#[cfg(test)]
+mod test_operation_counts;
+#[cfg(test)]
+mod test_recipient_precheck;
+#[cfg(test)]
+mod test_payout_fraction_limit;
+#[cfg(test)]
+mod test_program_beneficiary;
+#[cfg(test)]
+mod test_rate_limit_backoff;
+#[cfg(test)]
+mod test_recipient_schedules;
+#[cfg(test)]
+mod test_claim_payout_split;
+#[cfg(test)]
+mod test_program_tags;
+#[cfg(test)]
+mod test_min_claim_amount;
+#[cfg(test)]
+mod test_fee_history;
+#[cfg(test)]
+mod test_batch_cost_estimate;
+#[cfg(test)]
+mod test_scheduled_payout;
+#[cfg(test)]
+mod test_schedule_backing;
+#[cfg(test)]
+mod test_contract_info;
+#[cfg(test)]
+mod test_multisig_expiry;
+#[cfg(test)]
+mod test_initial_funding;
+#[cfg(test)]
+mod test_badge_minter;
+#[cfg(test)]
+mod test_daily_payout_cap;
+#[cfg(test)]
+mod test_get_claims;
+#[cfg(test)]
+mod test_stale_pending_claims;
+#[cfg(test)]
+mod test_prize_tiers;
+#[cfg(test)]
+mod test_recipient_freeze;
+#[cfg(test)]
+mod test_schedule_release_denylist;
+#[cfg(test)]
+mod test_cancel_with_compensation;
 
 // ============================================================================
 // Event Types
@@ -574,6 +1235,7 @@ const PROGRAM_REGISTRY: Symbol = symbol_short!("ProgReg");
 /// * `recipient` - Address that received the payout
 /// * `amount` - Amount transferred (in token's smallest denomination)
 /// * `timestamp` - Unix timestamp when payout was executed
+/// * `memo_hash` - Optional 32-byte hash of an encrypted memo kept off-chain
 ///
 /// # Usage
 /// These records are stored in the payout history to provide a complete
@@ -585,6 +1247,7 @@ const PROGRAM_REGISTRY: Symbol = symbol_short!("ProgReg");
 ///     recipient: winner_address,
 ///     amount: 1000_0000000, // 1000 USDC
 ///     timestamp: env.ledger().timestamp(),
+///     memo_hash: None,
 /// };
 /// ```
 #[contracttype]
@@ -593,6 +1256,7 @@ pub struct PayoutRecord {
     pub recipient: Address,
     pub amount: i128,
     pub timestamp: u64,
+    pub memo_hash: Option<Bytes>,
 }
 
 /// Time-based release schedule for program funds.
@@ -655,10 +1319,23 @@ pub enum ReleaseType {
     Manual,    // Released manually by authorized party
 }
 
-/// Event emitted when a program release schedule is created.
+/// Running vesting totals for a program's release schedules.
+///
+/// `total_scheduled = total_released + total_pending`; cancelled schedules
+/// drop out of both sides.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct ProgramScheduleCreated {
+pub struct ScheduleTotals {
+    pub total_scheduled: i128,
+    pub total_released: i128,
+    pub total_pending: i128,
+    pub next_release_ts: Option<u64>,
+}
+
+/// Event emitted when a program release schedule is created.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProgramScheduleCreated {
     pub program_id: String,
     pub schedule_id: u64,
     pub amount: i128,
@@ -759,6 +1436,159 @@ pub struct ProgramSpendingState {
     pub amount_released: i128,
 }
 
+/// How a `batch_payout` entry is delivered.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PayoutMode {
+    /// Transfer the net amount to the recipient now.
+    Immediate,
+    /// Reserve the net amount as a pending claim the recipient must execute.
+    Claim,
+}
+
+/// Which recipient absorbs the rounding remainder in `batch_payout_by_shares`.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RemainderPolicy {
+    /// Add the remainder to the last recipient.
+    Last,
+    /// Add the remainder to the first recipient.
+    First,
+    /// Add the remainder to the recipient with the largest share (first on ties).
+    LargestShare,
+    /// Pay nothing extra; the remainder stays in the program balance.
+    Burn,
+}
+
+/// Per-program switches for individual mutating operations.
+///
+/// Finer-grained than the global `pause`: an organizer can, for example,
+/// stop new locks while still letting payouts drain the program.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OperationFlags {
+    /// Permits `lock_program_funds`.
+    pub allow_lock: bool,
+    /// Permits `single_payout`, `batch_payout` and `create_pending_claim`.
+    pub allow_payout: bool,
+    /// Permits `execute_claim`.
+    pub allow_claim: bool,
+}
+
+/// Composite status for monitoring dashboards, see `get_contract_health`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractHealth {
+    /// Whether an admin has been set.
+    pub admin_set: bool,
+    /// Whether the global pause is active.
+    pub global_paused: bool,
+    /// Number of registered programs.
+    pub program_count: u32,
+    /// Programs with at least one operation disabled via `set_operation_flags`.
+    pub total_programs_paused: u32,
+    /// Whether the rate limit config was set explicitly rather than defaulted.
+    pub rate_limit_configured: bool,
+    /// Contract version string.
+    pub version: String,
+}
+
+/// Structured version metadata, see `get_contract_info`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractInfo {
+    /// `major * 1_000_000 + minor * 1_000 + patch`, for ordered comparisons.
+    pub version: u32,
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+    /// Build channel, e.g. `stable`.
+    pub build_tag: Symbol,
+}
+
+/// Program-configured share of each payout routed back to a sponsor pool.
+///
+/// Unlike the protocol fee, which is platform-wide, the sponsor split is set
+/// per program by its authorized payout key.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SponsorSplit {
+    /// Address receiving the sponsor share.
+    pub sponsor: Address,
+    /// Sponsor share of each payout in basis points (1-10000).
+    pub bps: u32,
+}
+
+/// Crowdfunding target a program must reach by `deadline` before payouts.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FundingGoal {
+    pub goal: i128,
+    pub deadline: u64,
+}
+
+/// Total an address has contributed to an open-funding program.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Contribution {
+    pub funder: Address,
+    /// Net amount credited to the program, after any lock fee. This is
+    /// what `refund_contribution` returns.
+    pub amount: i128,
+}
+
+/// Breakdown of a single payout, returned by `single_payout`.
+///
+/// `gross == fee + sponsor_split + net` always holds.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutResult {
+    /// Amount deducted from the program balance.
+    pub gross: i128,
+    /// Protocol fee sent to the fee recipient.
+    pub fee: i128,
+    /// Share sent to the program's sponsor, if a split is configured.
+    pub sponsor_split: i128,
+    /// Amount owed to the recipient.
+    pub net: i128,
+    /// Id of the pending claim, or `0` if paid directly or queued.
+    pub claim_id: u64,
+    pub remaining_balance: i128,
+}
+
+/// Why `batch_payout_lenient` skipped an entry.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum BatchSkipReason {
+    /// Amount was zero or negative.
+    InvalidAmount = 1,
+    /// Amount was outside the configured min/max payout.
+    AmountOutOfLimits = 2,
+    /// Recipient is on the program's denylist.
+    RecipientDenylisted = 3,
+    /// Earlier entries used up the program balance.
+    InsufficientBalance = 4,
+    /// The program's payout condition contract rejected the recipient.
+    ConditionNotMet = 5,
+    /// Recipient is frozen in the program.
+    RecipientFrozen = 6,
+}
+
+/// Outcome of `batch_payout_lenient`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LenientBatchResult {
+    /// Number of entries paid out.
+    pub succeeded: u32,
+    /// Gross amount paid across the succeeded entries.
+    pub total_paid: i128,
+    /// Input indices of the skipped entries, ascending.
+    pub skipped_indices: Vec<u32>,
+    /// Reason for each entry in `skipped_indices`.
+    pub skip_reasons: Vec<BatchSkipReason>,
+}
+
 /// Reputation metrics derived from on-chain program behavior.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -788,6 +1618,82 @@ pub enum DataKey {
     IsPaused,                               // Global contract pause state
     ProgramSpendingConfig(String, Address), // (program_id, token) -> ProgramSpendingConfig
     ProgramSpendingState(String, Address),  // (program_id, token) -> ProgramSpendingState
+    SponsorSplit(String),                   // program_id -> SponsorSplit
+    MaxProgramTvl(Address),                 // token -> i128 cap on any single program's balance
+    PendingClaim(String, u64),              // (program_id, claim_id) -> active ClaimRecord
+    ClaimHistory(String, u64),              // (program_id, claim_id) -> resolved ClaimRecord
+    ActiveClaims(String),                   // program_id -> Vec<u64> of pending claim ids
+    ClaimWindow,                            // u64 seconds (global config)
+    ProgramClaimConfig(String),             // program_id -> ClaimConfig
+    PayoutCallback(String),                 // program_id -> callback contract Address
+    TokenDecimals(Address),                 // token -> cached u32 decimals
+    OperationFlags(String),                 // program_id -> OperationFlags
+    AdminNonce,                             // u64 replay-protection nonce for admin methods
+    AutoClaimRecipient(String, Address),    // (program_id, recipient) -> bool, skips claim step
+    PriceOracle(String),                    // program_id -> OracleConfig
+    MinProgramFunding(String),              // program_id -> i128 total_funds required before payouts
+    ProgramFeeBps(String),                  // program_id -> i128 payout fee override (basis points)
+    MaxSchedules(String),                   // program_id -> u32 cap on unreleased schedules
+    ScheduleTotals(String),                 // program_id -> ScheduleTotals
+    ExpiryCallback(String),                 // program_id -> expiry callback contract Address
+    PayoutReference(String, String),        // (program_id, reference) -> first PayoutRecord with it
+    SettlementConfig(String),               // program_id -> SettlementConfig
+    SettlementQueue(String),                // program_id -> Vec<PayoutRecord> awaiting flush
+    MultisigPayoutConfig(String),           // program_id -> MultisigPayoutConfig
+    MultisigRequests(String),               // program_id -> Vec<MultisigRequest> awaiting approval
+    ClaimMinConfirmations(String),          // program_id -> u32 ledgers before a claim can execute
+    OrganizerPrograms(Address),             // payout key -> Vec<String> of program ids it manages
+    RecipientClaimWindow(String, Address),  // (program_id, recipient) -> u64 claim window override
+    DustThreshold(Address),                 // token -> i128 balance swept on close_program
+    ProgramClosed(String),                  // program_id -> bool, set by close_program
+    AllowedOperations(String),              // program_id -> Vec<Symbol> operations the admin allows
+    MaxClaimExtension(String),              // program_id -> u64 cap on claim extension seconds
+    PayoutVolume(String),                   // program_id -> PayoutVolume daily payout counters
+    Denylisted(String, Address),            // (program_id, address) -> bool, blocked from payouts
+    AcceptedTokens(String),                 // program_id -> Vec<Address> tokens besides the primary
+    VelocityGuard(String),                  // program_id -> VelocityGuard outflow limit
+    VelocityState(String),                  // program_id -> VelocityState current window outflow
+    VelocityPaused(String),                 // program_id -> bool, set when the guard trips
+    ProgramStorageTier(String),             // program_id -> StorageTier of its ProgramData
+    PayoutCondition(String),                // program_id -> condition contract gating payouts
+    OpenFunding(String),                    // program_id -> bool, anyone may contribute
+    Contributions(String),                  // program_id -> Vec<Contribution> per funder
+    FundingGoal(String),                    // program_id -> FundingGoal for crowdfunding
+}
+
+/// Further storage keys, mostly per program. A `#[contracttype]` enum's
+/// spec allows at most 50 cases and `DataKey` already has 48, so new keys
+/// go here rather than spending its last two.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ProgramKey {
+    DirectPayoutMode(String),          // program_id -> bool, payouts skip claims
+    LowBalanceThreshold(String),       // program_id -> i128 balance that triggers low_balance
+    LowBalanceAlerted(String),         // program_id -> bool, set while below the threshold
+    ClaimCancelCooldown(String),       // program_id -> u64 secs between organizer cancels
+    LastClaimCancel(String),           // program_id -> u64 timestamp of the last organizer cancel
+    ProgramAdmin(String),              // program_id -> Address governing program config
+    IndexedPayouts(String),            // program_id -> u32 payout_history entries already indexed
+    RecipientPayouts(String, Address), // (program_id, recipient) -> Vec<u32> history positions
+    AutoExtendOnActivity(String),      // program_id -> u64 secs acknowledge_claim extends to
+    RecipientPrecheck(String),         // program_id -> bool, single_payout pre-checks recipients
+    MaxPayoutFractionBps(String),      // program_id -> u32 cap on one payout vs remaining balance
+    Beneficiary(String),               // program_id -> Address receiving withdrawals by default
+    RecipientSchedules(String, Address), // (program_id, recipient) -> Vec<u64> schedule ids
+    Tags(String),                      // program_id -> Vec<Symbol> categorization tags
+    TaggedPrograms(Symbol),            // tag -> Vec<String> program ids carrying it
+    MinClaimAmount(String),            // program_id -> i128 smallest amount reserved as a claim
+    RejectDustPayouts(String),         // program_id -> bool, sub-minimum payouts panic
+    FeeCount(Address),                 // token -> u32 fee records kept for that token
+    FeeRecordAt(Address, u32),         // (token, index) -> FeeRecord, oldest at 0
+    MultisigRequestExpiry(String),     // program_id -> u64 secs a multisig request stays open
+    BadgeMinter(String),               // program_id -> badge minter contract Address
+    DailyPayoutCap(String),            // program_id -> i128 max paid out in any 24h
+    DailyPayoutLog(String),            // program_id -> Vec<(u64, i128)> payouts in the last 24h
+    PrizeTiers(String),                // program_id -> Vec<i128> prize per rank, 1st first
+    AwardedTiers(String),              // program_id -> Vec<u32> tier indexes already awarded
+    FrozenRecipient(String, Address),  // (program_id, address) -> bool, payouts and claims on hold
+    CallerMinVersion(Address),         // caller -> u32 contract version its calls require
 }
 
 // ============================================================================
@@ -920,6 +1826,192 @@ impl ProgramEscrowContract {
             .publish((symbol_short!("unpause"),), (env.ledger().timestamp(),));
     }
 
+    /// Enables or disables individual operations for one program
+    /// (program admin only). All operations are allowed by default.
+    pub fn set_operation_flags(
+        env: Env,
+        program_id: String,
+        allow_lock: bool,
+        allow_payout: bool,
+        allow_claim: bool,
+    ) {
+        Self::require_program_admin_auth(&env, &program_id);
+        if env
+            .storage()
+            .instance()
+            .has(&DataKey::ProgramClosed(program_id.clone()))
+        {
+            panic!("Program is closed");
+        }
+
+        let flags = OperationFlags {
+            allow_lock,
+            allow_payout,
+            allow_claim,
+        };
+        env.storage()
+            .instance()
+            .set(&DataKey::OperationFlags(program_id.clone()), &flags);
+
+        env.events()
+            .publish((symbol_short!("op_flags"),), (program_id, flags));
+    }
+
+    /// Get the operation flags for a program (view function)
+    pub fn get_operation_flags(env: Env, program_id: String) -> OperationFlags {
+        Self::get_operation_flags_internal(&env, &program_id)
+    }
+
+    /// Operation flags for a program, defaulting to everything allowed (internal helper)
+    fn get_operation_flags_internal(env: &Env, program_id: &String) -> OperationFlags {
+        env.storage()
+            .instance()
+            .get(&DataKey::OperationFlags(program_id.clone()))
+            .unwrap_or(OperationFlags {
+                allow_lock: true,
+                allow_payout: true,
+                allow_claim: true,
+            })
+    }
+
+    /// Restricts which operations the program's organizer may invoke (admin
+    /// only). Recognized operations are `lock`, `payout`, `claim` and
+    /// `withdraw`; anything left out of `ops` is rejected for the program.
+    /// Programs without a list allow every operation.
+    pub fn set_program_allowed_operations(env: Env, program_id: String, ops: Vec<Symbol>) {
+        let admin = anti_abuse::get_admin(&env).expect("Admin not set");
+        admin.require_auth();
+
+        if !program_storage::exists(&env, &program_id) {
+            panic!("Program not found");
+        }
+        let known = [
+            symbol_short!("lock"),
+            symbol_short!("payout"),
+            symbol_short!("claim"),
+            symbol_short!("withdraw"),
+        ];
+        for op in ops.iter() {
+            if !known.contains(&op) {
+                panic!("Unknown operation");
+            }
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::AllowedOperations(program_id.clone()), &ops);
+
+        env.events()
+            .publish((symbol_short!("op_allow"),), (program_id, ops));
+    }
+
+    /// Removes a program's operation allow-list so every operation is permitted
+    /// again (admin only).
+    pub fn clear_program_allowed_operations(env: Env, program_id: String) {
+        let admin = anti_abuse::get_admin(&env).expect("Admin not set");
+        admin.require_auth();
+
+        env.storage()
+            .instance()
+            .remove(&DataKey::AllowedOperations(program_id.clone()));
+
+        env.events().publish(
+            (symbol_short!("op_allow"),),
+            (program_id, Vec::<Symbol>::new(&env)),
+        );
+    }
+
+    /// Returns the program's operation allow-list, or `None` if all
+    /// operations are allowed.
+    pub fn get_program_allowed_operations(env: Env, program_id: String) -> Option<Vec<Symbol>> {
+        env.storage()
+            .instance()
+            .get(&DataKey::AllowedOperations(program_id))
+    }
+
+    /// Panics unless `op` is on the program's allow-list, if it has one.
+    fn require_operation_allowed(env: &Env, program_id: &String, op: Symbol) {
+        let allowed: Option<Vec<Symbol>> = env
+            .storage()
+            .instance()
+            .get(&DataKey::AllowedOperations(program_id.clone()));
+        if let Some(ops) = allowed {
+            if !ops.contains(&op) {
+                panic!("Operation not allowed for program");
+            }
+        }
+    }
+
+    /// Returns every token the program accepts, starting with the token it
+    /// was initialized with.
+    pub fn get_accepted_tokens(env: Env, program_id: String) -> Vec<Address> {
+        let program: ProgramData =
+            program_storage::load(&env, &program_id).unwrap_or_else(|| panic!("Program not found"));
+        let mut tokens = Vec::new(&env);
+        tokens.push_back(program.token_address);
+        tokens.append(&Self::extra_accepted_tokens(&env, &program_id));
+        tokens
+    }
+
+    /// Whether the program accepts `token`.
+    pub fn is_token_accepted(env: Env, program_id: String, token: Address) -> bool {
+        Self::get_accepted_tokens(env, program_id).contains(&token)
+    }
+
+    /// Adds `token` to the program's accepted tokens (authorized payout key
+    /// only). Adding an already accepted token is a no-op.
+    pub fn add_accepted_token(env: Env, program_id: String, token: Address) {
+        let program = Self::require_program_payout_auth(&env, &program_id);
+        if program.token_address == token {
+            return;
+        }
+        let mut tokens = Self::extra_accepted_tokens(&env, &program_id);
+        if tokens.contains(&token) {
+            return;
+        }
+        tokens.push_back(token.clone());
+        env.storage()
+            .instance()
+            .set(&DataKey::AcceptedTokens(program_id.clone()), &tokens);
+
+        env.events()
+            .publish((symbol_short!("tok_add"),), (program_id, token));
+    }
+
+    /// Removes `token` from the program's accepted tokens (authorized payout
+    /// key only). The token the program was initialized with can never be
+    /// removed. Funds are only ever locked in that token, so removing an
+    /// added token cannot strand a balance.
+    pub fn remove_accepted_token(env: Env, program_id: String, token: Address) {
+        let program = Self::require_program_payout_auth(&env, &program_id);
+        if program.token_address == token {
+            panic!("Cannot remove program token");
+        }
+        let mut tokens = Self::extra_accepted_tokens(&env, &program_id);
+        let index = tokens
+            .first_index_of(&token)
+            .unwrap_or_else(|| panic!("Token not accepted"));
+        tokens.remove(index);
+
+        let key = DataKey::AcceptedTokens(program_id.clone());
+        if tokens.is_empty() {
+            env.storage().instance().remove(&key);
+        } else {
+            env.storage().instance().set(&key, &tokens);
+        }
+
+        env.events()
+            .publish((symbol_short!("tok_rm"),), (program_id, token));
+    }
+
+    /// Tokens added with `add_accepted_token`, excluding the primary token.
+    fn extra_accepted_tokens(env: &Env, program_id: &String) -> Vec<Address> {
+        env.storage()
+            .instance()
+            .get(&DataKey::AcceptedTokens(program_id.clone()))
+            .unwrap_or(Vec::new(env))
+    }
+
     /// Emergency withdrawal for all contract funds (authorized payout key only, only when paused)
     pub fn emergency_withdraw(env: Env, program_id: String, recipient: Address) -> i128 {
         // Only allow emergency withdrawal when contract is paused
@@ -928,14 +2020,10 @@ impl ProgramEscrowContract {
         }
 
         // Get program data to access token address
-        let program_key = DataKey::Program(program_id.clone());
-        let program_data: ProgramData =
-            env.storage()
-                .instance()
-                .get(&program_key)
-                .unwrap_or_else(|| {
-                    panic!("Program not found");
-                });
+        let program_data: ProgramData = program_storage::load(&env, &program_id)
+            .unwrap_or_else(|| {
+                panic!("Program not found");
+            });
 
         let client = token::Client::new(&env, &program_data.token_address);
         let balance = client.balance(&env.current_contract_address());
@@ -955,1509 +2043,4986 @@ impl ProgramEscrowContract {
         balance
     }
 
-    pub fn initialize_program(
+    /// Withdraws funds not reserved by claims or pending release schedules
+    /// to the program's beneficiary (see `set_program_beneficiary`), which
+    /// defaults to the authorized payout key.
+    pub fn withdraw_unlocked_program_funds(
         env: Env,
         program_id: String,
-        authorized_payout_key: Address,
-        token_address: Address,
+        amount: i128,
     ) -> ProgramData {
-        // Apply rate limiting
-        anti_abuse::check_rate_limit(&env, authorized_payout_key.clone());
-
-        let start = env.ledger().timestamp();
-        let caller = authorized_payout_key.clone();
+        let beneficiary = Self::get_program_beneficiary(env.clone(), program_id.clone());
+        Self::withdraw_unlocked_prog_funds_to(env, program_id, amount, beneficiary)
+    }
 
-        // Validate program_id
-        if program_id.len() == 0 {
-            monitoring::track_operation(&env, symbol_short!("init_prg"), caller, false);
-            panic!("Program ID cannot be empty");
+    /// Withdraws unlocked program funds to `destination` (e.g. a cold wallet).
+    ///
+    /// Requires the program's authorized payout key; only the transfer target
+    /// differs. Funds reserved by pending claims or unreleased schedules
+    /// cannot be withdrawn.
+    pub fn withdraw_unlocked_prog_funds_to(
+        env: Env,
+        program_id: String,
+        amount: i128,
+        destination: Address,
+    ) -> ProgramData {
+        if Self::is_paused_internal(&env) {
+            panic!("Contract is paused");
         }
 
-        // Check if program already exists
-        let program_key = DataKey::Program(program_id.clone());
-        if env.storage().instance().has(&program_key) {
-            monitoring::track_operation(&env, symbol_short!("init_prg"), caller, false);
-            panic!("Program already exists");
-        }
+        let mut program_data = Self::require_program_payout_auth(&env, &program_id);
+        Self::require_operation_allowed(&env, &program_id, symbol_short!("withdraw"));
+        Self::enforce_funding_goal_internal(&env, &program_id, &program_data);
 
-        // Create program data
-        let program_data = ProgramData {
-            program_id: program_id.clone(),
-            total_funds: 0,
-            remaining_balance: 0,
-            authorized_payout_key: authorized_payout_key.clone(),
-            payout_history: vec![&env],
-            token_address: token_address.clone(),
-        };
+        if amount <= 0 {
+            panic!("Amount must be greater than zero");
+        }
 
-        // Initialize fee config with zero fees (disabled by default)
-        let fee_config = FeeConfig {
-            lock_fee_rate: 0,
-            payout_fee_rate: 0,
-            fee_recipient: authorized_payout_key.clone(),
-            fee_enabled: false,
-        };
-        env.storage().instance().set(&FEE_CONFIG, &fee_config);
+        let unlocked = program_data.remaining_balance
+            - get_program_total_scheduled_amount(&env, &program_id);
+        if amount > unlocked {
+            panic!("Insufficient unlocked balance");
+        }
 
-        // Store program data
-        env.storage().instance().set(&program_key, &program_data);
+        let token_client = token::Client::new(&env, &program_data.token_address);
+        token_client.transfer(&env.current_contract_address(), &destination, &amount);
 
-        // Update registry
-        let mut registry: Vec<String> = env
-            .storage()
-            .instance()
-            .get(&PROGRAM_REGISTRY)
-            .unwrap_or(vec![&env]);
-        registry.push_back(program_id.clone());
-        env.storage().instance().set(&PROGRAM_REGISTRY, &registry);
+        program_data.remaining_balance -= amount;
+        program_storage::save(&env, &program_data);
 
-        // Emit registration event
         env.events().publish(
-            (PROGRAM_REGISTERED,),
-            (program_id, authorized_payout_key, token_address, 0i128),
+            (symbol_short!("withdraw"),),
+            (
+                program_id,
+                destination,
+                amount,
+                program_data.remaining_balance,
+            ),
         );
 
-        // Track successful operation
-        monitoring::track_operation(&env, symbol_short!("init_prg"), caller, true);
-
-        // Track performance
-        let duration = env.ledger().timestamp().saturating_sub(start);
-        monitoring::emit_performance(&env, symbol_short!("init_prg"), duration);
-
         program_data
     }
 
-    /// Calculate fee amount based on rate (in basis points)
-    fn calculate_fee(amount: i128, fee_rate: i128) -> i128 {
-        if fee_rate == 0 {
-            return 0;
+    /// Closes a program for good by disabling locks, payouts and claims.
+    ///
+    /// The program must have no pending claims, unreleased schedules or
+    /// multisig requests awaiting approval. A `remaining_balance` at or below
+    /// the token's dust threshold (see `set_dust_threshold`) is swept to
+    /// `dust_destination`; any other non-zero balance blocks closure and must
+    /// be withdrawn first. A closed program no longer counts towards
+    /// `set_max_programs_per_organizer`.
+    ///
+    /// # Panics
+    /// * If the program is already closed
+    /// * If the program has pending claims, unreleased schedules or
+    ///   multisig requests
+    /// * If the balance is above the dust threshold, or is dust and no
+    ///   `dust_destination` was given
+    pub fn close_program(
+        env: Env,
+        program_id: String,
+        dust_destination: Option<Address>,
+    ) -> ProgramData {
+        if Self::is_paused_internal(&env) {
+            panic!("Contract is paused");
         }
-        // Fee = (amount * fee_rate) / BASIS_POINTS
-        amount
-            .checked_mul(fee_rate)
-            .and_then(|x| x.checked_div(BASIS_POINTS))
-            .unwrap_or(0)
-    }
 
-    /// Get fee configuration (internal helper)
-    fn get_fee_config_internal(env: &Env) -> FeeConfig {
-        env.storage()
-            .instance()
-            .get(&FEE_CONFIG)
-            .unwrap_or_else(|| FeeConfig {
-                lock_fee_rate: 0,
-                payout_fee_rate: 0,
-                fee_recipient: env.current_contract_address(),
-                fee_enabled: false,
-            })
+        let mut program_data = Self::require_program_payout_auth(&env, &program_id);
+
+        let closed_key = DataKey::ProgramClosed(program_id.clone());
+        if env.storage().instance().has(&closed_key) {
+            panic!("Program is closed");
+        }
+        if !claim_period::get_pending_claim_ids(&env, &program_id).is_empty() {
+            panic!("Program has pending claims");
+        }
+        if get_program_total_scheduled_amount(&env, &program_id) > 0 {
+            panic!("Program has pending schedules");
+        }
+        if !multisig_payout::get_pending(&env, &program_id).is_empty() {
+            panic!("Program has pending multisig payouts");
+        }
+
+        let dust = program_data.remaining_balance;
+        if dust > 0 {
+            let threshold =
+                Self::get_dust_threshold(env.clone(), program_data.token_address.clone());
+            if dust > threshold {
+                panic!("Program balance is not zero");
+            }
+            Self::enforce_funding_goal_internal(&env, &program_id, &program_data);
+            let destination = dust_destination
+                .clone()
+                .or_else(|| {
+                    env.storage()
+                        .instance()
+                        .get(&ProgramKey::Beneficiary(program_id.clone()))
+                })
+                .unwrap_or_else(|| panic!("Dust destination required"));
+            let token_client = token::Client::new(&env, &program_data.token_address);
+            token_client.transfer(&env.current_contract_address(), &destination, &dust);
+            program_data.remaining_balance = 0;
+            program_storage::save(&env, &program_data);
+        }
+
+        env.storage().instance().set(
+            &DataKey::OperationFlags(program_id.clone()),
+            &OperationFlags {
+                allow_lock: false,
+                allow_payout: false,
+                allow_claim: false,
+            },
+        );
+        env.storage().instance().set(&closed_key, &true);
+
+        env.events().publish(
+            (symbol_short!("prg_close"),),
+            (program_id, dust, dust_destination),
+        );
+
+        program_data
+    }
+
+    /// Cancels a program mid-flight: each pending claim is cancelled and its
+    /// recipient immediately paid `compensation_bps` / 10_000 of the claim
+    /// (nothing if denylisted or frozen), then the whole remaining balance
+    /// is refunded to the program's beneficiary (see
+    /// `get_program_beneficiary`) and the program is closed. Pending release
+    /// schedules and multisig requests must be cancelled first, and
+    /// contributors refunded, since their funds are not the organizer's.
+    /// Only the organizer may call this.
+    ///
+    /// # Returns
+    /// * `(i128, i128)` - Total paid as compensation and amount refunded
+    pub fn cancel_program_with_compensation(
+        env: Env,
+        program_id: String,
+        compensation_bps: u32,
+    ) -> (i128, i128) {
+        if Self::is_paused_internal(&env) {
+            panic!("Contract is paused");
+        }
+        if compensation_bps as i128 > BASIS_POINTS {
+            panic!("Invalid compensation");
+        }
+
+        let mut program_data = Self::require_program_payout_auth(&env, &program_id);
+
+        let closed_key = DataKey::ProgramClosed(program_id.clone());
+        if env.storage().instance().has(&closed_key) {
+            panic!("Program is closed");
+        }
+        if get_program_total_scheduled_amount(&env, &program_id) > 0 {
+            panic!("Program has pending schedules");
+        }
+        if !multisig_payout::get_pending(&env, &program_id).is_empty() {
+            panic!("Program has pending multisig payouts");
+        }
+        if Self::has_contributions(&env, &program_id) {
+            panic!("Program has outstanding contributions");
+        }
+
+        let (_, compensated) =
+            claim_period::compensate_pending_claims(&env, &mut program_data, compensation_bps);
+
+        let refunded = program_data.remaining_balance;
+        program_data.remaining_balance = 0;
+        program_storage::save(&env, &program_data);
+        if refunded > 0 {
+            let beneficiary = Self::get_program_beneficiary(env.clone(), program_id.clone());
+            token::Client::new(&env, &program_data.token_address).transfer(
+                &env.current_contract_address(),
+                &beneficiary,
+                &refunded,
+            );
+        }
+
+        env.storage().instance().set(
+            &DataKey::OperationFlags(program_id.clone()),
+            &OperationFlags {
+                allow_lock: false,
+                allow_payout: false,
+                allow_claim: false,
+            },
+        );
+        env.storage().instance().set(&closed_key, &true);
+
+        env.events().publish(
+            (symbol_short!("prg_cncl"),),
+            (program_id, compensation_bps, compensated, refunded),
+        );
+
+        (compensated, refunded)
+    }
+
+    /// Returns whether `close_program` has been called for a program.
+    pub fn is_program_closed(env: Env, program_id: String) -> bool {
+        env.storage()
+            .instance()
+            .has(&DataKey::ProgramClosed(program_id))
+    }
+
+    /// Registers a new program owned by `authorized_payout_key`.
+    ///
+    /// # Events
+    /// Emits `program_created` with the token address as a second topic and
+    /// data `(program_id, admin, organizer, token, fee_config)`, so indexers
+    /// can build a program catalog, followed by `ProgReg`.
+    pub fn initialize_program(
+        env: Env,
+        program_id: String,
+        authorized_payout_key: Address,
+        token_address: Address,
+    ) -> ProgramData {
+        Self::initialize_program_with_storage(
+            env,
+            program_id,
+            authorized_payout_key,
+            token_address,
+            StorageTier::Persistent,
+        )
+    }
+
+    /// Registers a new program like `initialize_program`, with `program_admin`
+    /// rather than the payout key governing its configuration (claim window,
+    /// fee override, pausing). Requires the contract admin's auth, since the
+    /// program admin may override the platform fee.
+    pub fn initialize_program_with_admin(
+        env: Env,
+        program_id: String,
+        authorized_payout_key: Address,
+        token_address: Address,
+        program_admin: Address,
+    ) -> ProgramData {
+        let admin = anti_abuse::get_admin(&env).expect("Admin not set");
+        admin.require_auth();
+
+        let program_data = Self::initialize_program(
+            env.clone(),
+            program_id.clone(),
+            authorized_payout_key,
+            token_address,
+        );
+        env.storage().instance().set(
+            &ProgramKey::ProgramAdmin(program_id.clone()),
+            &program_admin,
+        );
+        env.events()
+            .publish((symbol_short!("prg_admin"),), (program_id, program_admin));
+        program_data
+    }
+
+    /// Registers a new program like `initialize_program` and locks
+    /// `initial_funding` pulled from `authorized_payout_key` in the same call.
+    /// Requires `authorized_payout_key`'s auth whenever funds are pulled.
+    /// The organizer must first approve this contract to spend the amount;
+    /// if the transfer fails the program is not registered either. An
+    /// `initial_funding` of 0 skips funding.
+    pub fn initialize_program_with_funding(
+        env: Env,
+        program_id: String,
+        authorized_payout_key: Address,
+        token_address: Address,
+        initial_funding: i128,
+    ) -> ProgramData {
+        if initial_funding < 0 {
+            panic!("Amount must be greater than zero");
+        }
+        let program_data = Self::initialize_program(
+            env.clone(),
+            program_id.clone(),
+            authorized_payout_key.clone(),
+            token_address.clone(),
+        );
+        if initial_funding == 0 {
+            return program_data;
+        }
+
+        authorized_payout_key.require_auth();
+        let contract_address = env.current_contract_address();
+        token::Client::new(&env, &token_address).transfer_from(
+            &contract_address,
+            &authorized_payout_key,
+            &contract_address,
+            &initial_funding,
+        );
+        Self::lock_program_funds(env, program_id, initial_funding)
+    }
+
+    /// Registers a new program like `initialize_program`, keeping its data in
+    /// the given storage tier.
+    ///
+    /// `Persistent` data has its TTL extended to ~30 days on every write and
+    /// is archived, not lost, if it lapses. `Temporary` data is cheaper but
+    /// only lives ~1 day past its last write; after that the program and its
+    /// balance accounting are deleted. Its claims, schedules and other
+    /// per-program state remain, so the id cannot be registered again. Use
+    /// `Temporary` only for short-lived or test programs.
+    pub fn initialize_program_with_storage(
+        env: Env,
+        program_id: String,
+        authorized_payout_key: Address,
+        token_address: Address,
+        storage_tier: StorageTier,
+    ) -> ProgramData {
+        // Apply the version pin and rate limiting
+        Self::guard_caller(&env, &authorized_payout_key);
+
+        let start = env.ledger().timestamp();
+        let caller = authorized_payout_key.clone();
+
+        // Validate program_id
+        if program_id.len() == 0 {
+            monitoring::track_operation(&env, symbol_short!("init_prg"), caller, false);
+            panic!("Program ID cannot be empty");
+        }
+
+        // Check if program already exists (or existed and expired)
+        if program_storage::is_registered(&env, &program_id) {
+            monitoring::track_operation(&env, symbol_short!("init_prg"), caller, false);
+            panic!("Program already exists");
+        }
+
+        // Create program data
+        let program_data = ProgramData {
+            program_id: program_id.clone(),
+            total_funds: 0,
+            remaining_balance: 0,
+            authorized_payout_key: authorized_payout_key.clone(),
+            payout_history: vec![&env],
+            token_address: token_address.clone(),
+        };
+
+        // Initialize fee config with zero fees (disabled by default)
+        let fee_config = FeeConfig {
+            lock_fee_rate: 0,
+            payout_fee_rate: 0,
+            fee_recipient: authorized_payout_key.clone(),
+            fee_enabled: false,
+        };
+        env.storage().instance().set(&FEE_CONFIG, &fee_config);
+
+        // Store program data
+        program_storage::set_tier(&env, &program_id, storage_tier);
+        program_storage::save(&env, &program_data);
+
+        // Update registry
+        let mut registry: Vec<String> = env
+            .storage()
+            .instance()
+            .get(&PROGRAM_REGISTRY)
+            .unwrap_or(vec![&env]);
+        registry.push_back(program_id.clone());
+        env.storage().instance().set(&PROGRAM_REGISTRY, &registry);
+        Self::add_organizer_program(&env, &authorized_payout_key, &program_id);
+
+        // Catalog event for indexers, filterable by token
+        env.events().publish(
+            (Symbol::new(&env, "program_created"), token_address.clone()),
+            (
+                program_id.clone(),
+                anti_abuse::get_admin(&env),
+                authorized_payout_key.clone(),
+                token_address.clone(),
+                fee_config,
+            ),
+        );
+
+        // Emit registration event
+        env.events().publish(
+            (PROGRAM_REGISTERED,),
+            (program_id, authorized_payout_key, token_address, 0i128),
+        );
+
+        // Track successful operation
+        monitoring::track_operation(&env, symbol_short!("init_prg"), caller, true);
+        monitoring::count_operation(&env, monitoring::OperationType::Init);
+
+        // Track performance
+        let duration = env.ledger().timestamp().saturating_sub(start);
+        monitoring::emit_performance(&env, symbol_short!("init_prg"), duration);
+
+        program_data
+    }
+
+    /// Registers `new_program_id` with the same payout key, token and
+    /// per-program settings as `source_program_id`, for organizers running
+    /// repeat cohorts.
+    ///
+    /// Copies spending limits, fee override, claim config, operation flags,
+    /// allowed operations, sponsor split, minimum funding, schedule cap,
+    /// payout and expiry callbacks, price oracle, settlement batching,
+    /// multisig signers and request expiry, claim confirmations, maximum
+    /// claim extension, velocity guard, daily payout cap, payout fraction
+    /// cap, payout condition, accepted tokens, program admin, beneficiary,
+    /// direct payout mode, recipient pre-check, low balance threshold,
+    /// claim cancel cooldown, auto-extension, minimum claim amount and dust
+    /// rejection, badge minter and prize tiers.
+    ///
+    /// Not copied: crowdfunding settings (open funding, goal), which belong
+    /// to one campaign; tags, so the catalog only lists programs tagged on
+    /// purpose; and per-recipient entries (denylist, freezes, claim window
+    /// overrides, auto-claim), which cannot be enumerated. The new program
+    /// starts with zero balance and no history, claims or schedules.
+    /// Requires the source program's authorized payout key.
+    pub fn clone_program(env: Env, source_program_id: String, new_program_id: String) {
+        let source = Self::require_program_payout_auth(&env, &source_program_id);
+        Self::guard_caller(&env, &source.authorized_payout_key);
+
+        if new_program_id.is_empty() {
+            panic!("Program ID cannot be empty");
+        }
+        if program_storage::is_registered(&env, &new_program_id) {
+            panic!("Program already exists");
+        }
+
+        let program_data = ProgramData {
+            program_id: new_program_id.clone(),
+            total_funds: 0,
+            remaining_balance: 0,
+            authorized_payout_key: source.authorized_payout_key.clone(),
+            payout_history: vec![&env],
+            token_address: source.token_address.clone(),
+        };
+        let tier = program_storage::get_tier(&env, &source_program_id);
+        program_storage::set_tier(&env, &new_program_id, tier);
+        program_storage::save(&env, &program_data);
+
+        let mut registry: Vec<String> = env
+            .storage()
+            .instance()
+            .get(&PROGRAM_REGISTRY)
+            .unwrap_or(vec![&env]);
+        registry.push_back(new_program_id.clone());
+        env.storage().instance().set(&PROGRAM_REGISTRY, &registry);
+        Self::add_organizer_program(&env, &source.authorized_payout_key, &new_program_id);
+
+        let from = &source_program_id;
+        let to = &new_program_id;
+        let settings = [
+            (
+                DataKey::ProgramSpendingConfig(from.clone(), source.token_address.clone()),
+                DataKey::ProgramSpendingConfig(to.clone(), source.token_address.clone()),
+            ),
+            (
+                DataKey::ProgramFeeBps(from.clone()),
+                DataKey::ProgramFeeBps(to.clone()),
+            ),
+            (
+                DataKey::ProgramClaimConfig(from.clone()),
+                DataKey::ProgramClaimConfig(to.clone()),
+            ),
+            (
+                DataKey::OperationFlags(from.clone()),
+                DataKey::OperationFlags(to.clone()),
+            ),
+            (
+                DataKey::SponsorSplit(from.clone()),
+                DataKey::SponsorSplit(to.clone()),
+            ),
+            (
+                DataKey::MinProgramFunding(from.clone()),
+                DataKey::MinProgramFunding(to.clone()),
+            ),
+            (
+                DataKey::MaxSchedules(from.clone()),
+                DataKey::MaxSchedules(to.clone()),
+            ),
+            (
+                DataKey::PayoutCallback(from.clone()),
+                DataKey::PayoutCallback(to.clone()),
+            ),
+            (
+                DataKey::ExpiryCallback(from.clone()),
+                DataKey::ExpiryCallback(to.clone()),
+            ),
+            (
+                DataKey::PriceOracle(from.clone()),
+                DataKey::PriceOracle(to.clone()),
+            ),
+            (
+                DataKey::SettlementConfig(from.clone()),
+                DataKey::SettlementConfig(to.clone()),
+            ),
+            (
+                DataKey::MultisigPayoutConfig(from.clone()),
+                DataKey::MultisigPayoutConfig(to.clone()),
+            ),
+            (
+                DataKey::ClaimMinConfirmations(from.clone()),
+                DataKey::ClaimMinConfirmations(to.clone()),
+            ),
+            (
+                DataKey::AllowedOperations(from.clone()),
+                DataKey::AllowedOperations(to.clone()),
+            ),
+            (
+                DataKey::MaxClaimExtension(from.clone()),
+                DataKey::MaxClaimExtension(to.clone()),
+            ),
+            (
+                DataKey::VelocityGuard(from.clone()),
+                DataKey::VelocityGuard(to.clone()),
+            ),
+            (
+                DataKey::PayoutCondition(from.clone()),
+                DataKey::PayoutCondition(to.clone()),
+            ),
+            (
+                DataKey::AcceptedTokens(from.clone()),
+                DataKey::AcceptedTokens(to.clone()),
+            ),
+        ];
+        for (from_key, to_key) in settings.iter() {
+            let value: Option<Val> = env.storage().instance().get(from_key);
+            if let Some(value) = value {
+                env.storage().instance().set(to_key, &value);
+            }
+        }
+        let program_settings = [
+            (
+                ProgramKey::DailyPayoutCap(from.clone()),
+                ProgramKey::DailyPayoutCap(to.clone()),
+            ),
+            (
+                ProgramKey::MaxPayoutFractionBps(from.clone()),
+                ProgramKey::MaxPayoutFractionBps(to.clone()),
+            ),
+            (
+                ProgramKey::ProgramAdmin(from.clone()),
+                ProgramKey::ProgramAdmin(to.clone()),
+            ),
+            (
+                ProgramKey::Beneficiary(from.clone()),
+                ProgramKey::Beneficiary(to.clone()),
+            ),
+            (
+                ProgramKey::DirectPayoutMode(from.clone()),
+                ProgramKey::DirectPayoutMode(to.clone()),
+            ),
+            (
+                ProgramKey::RecipientPrecheck(from.clone()),
+                ProgramKey::RecipientPrecheck(to.clone()),
+            ),
+            (
+                ProgramKey::LowBalanceThreshold(from.clone()),
+                ProgramKey::LowBalanceThreshold(to.clone()),
+            ),
+            (
+                ProgramKey::ClaimCancelCooldown(from.clone()),
+                ProgramKey::ClaimCancelCooldown(to.clone()),
+            ),
+            (
+                ProgramKey::AutoExtendOnActivity(from.clone()),
+                ProgramKey::AutoExtendOnActivity(to.clone()),
+            ),
+            (
+                ProgramKey::MinClaimAmount(from.clone()),
+                ProgramKey::MinClaimAmount(to.clone()),
+            ),
+            (
+                ProgramKey::RejectDustPayouts(from.clone()),
+                ProgramKey::RejectDustPayouts(to.clone()),
+            ),
+            (
+                ProgramKey::MultisigRequestExpiry(from.clone()),
+                ProgramKey::MultisigRequestExpiry(to.clone()),
+            ),
+            (
+                ProgramKey::BadgeMinter(from.clone()),
+                ProgramKey::BadgeMinter(to.clone()),
+            ),
+            (
+                ProgramKey::PrizeTiers(from.clone()),
+                ProgramKey::PrizeTiers(to.clone()),
+            ),
+        ];
+        for (from_key, to_key) in program_settings.iter() {
+            let value: Option<Val> = env.storage().instance().get(from_key);
+            if let Some(value) = value {
+                env.storage().instance().set(to_key, &value);
+            }
+        }
+
+        env.events().publish(
+            (PROGRAM_REGISTERED,),
+            (
+                new_program_id,
+                source.authorized_payout_key,
+                source.token_address,
+                0i128,
+            ),
+        );
+        monitoring::count_operation(&env, monitoring::OperationType::Init);
+    }
+
+    /// Calculate fee amount based on rate (in basis points)
+    fn calculate_fee(amount: i128, fee_rate: i128) -> i128 {
+        if fee_rate == 0 {
+            return 0;
+        }
+        // Fee = (amount * fee_rate) / BASIS_POINTS
+        amount
+            .checked_mul(fee_rate)
+            .and_then(|x| x.checked_div(BASIS_POINTS))
+            .unwrap_or(0)
+    }
+
+    /// Payout fee rate for a program: its override when set, else the global
+    /// rate. Always zero while fees are globally disabled.
+    fn effective_payout_fee_rate(env: &Env, program_id: &String, fee_config: &FeeConfig) -> i128 {
+        if !fee_config.fee_enabled {
+            return 0;
+        }
+        env.storage()
+            .instance()
+            .get(&DataKey::ProgramFeeBps(program_id.clone()))
+            .unwrap_or(fee_config.payout_fee_rate)
+    }
+
+    /// Appends a fee taken from `program_id` to `token`'s fee history.
+    fn record_fee(env: &Env, token: &Address, program_id: &String, amount: i128) {
+        // one entry per record, so the history never outgrows an entry
+        let count_key = ProgramKey::FeeCount(token.clone());
+        let count: u32 = env.storage().persistent().get(&count_key).unwrap_or(0);
+        env.storage().persistent().set(
+            &ProgramKey::FeeRecordAt(token.clone(), count),
+            &FeeRecord {
+                program_id: program_id.clone(),
+                amount,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        env.storage().persistent().set(&count_key, &(count + 1));
+    }
+
+    /// Get fee configuration (internal helper)
+    fn get_fee_config_internal(env: &Env) -> FeeConfig {
+        env.storage()
+            .instance()
+            .get(&FEE_CONFIG)
+            .unwrap_or_else(|| FeeConfig {
+                lock_fee_rate: 0,
+                payout_fee_rate: 0,
+                fee_recipient: env.current_contract_address(),
+                fee_enabled: false,
+            })
     }
 
     /// Lock initial funds into the program escrow
     ///
-    /// Lists all registered program IDs in the contract.
+    /// Lists all registered program IDs in the contract.
+    ///
+    /// # Returns
+    /// * `Vec<String>` - List of all program IDs
+    ///
+    /// # Example
+    /// ```rust
+    /// let programs = escrow_client.list_programs();
+    /// for program_id in programs.iter() {
+    ///     println!("Program: {}", program_id);
+    /// }
+    /// ```
+    pub fn list_programs(env: Env) -> Vec<String> {
+        env.storage()
+            .instance()
+            .get(&PROGRAM_REGISTRY)
+            .unwrap_or(vec![&env])
+    }
+
+    /// Lists the programs managed by `organizer` (their authorized payout
+    /// key), in registration order, skipping `offset` and returning at most
+    /// `limit` ids.
+    pub fn get_organizer_programs(
+        env: Env,
+        organizer: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<String> {
+        let programs: Vec<String> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::OrganizerPrograms(organizer))
+            .unwrap_or(vec![&env]);
+        let end = offset.saturating_add(limit).min(programs.len());
+        if offset >= end {
+            return vec![&env];
+        }
+        programs.slice(offset..end)
+    }
+
+    /// Replaces a program's categorization tags (duplicates are ignored),
+    /// keeping the tag index used by `get_programs_by_tag` in sync. Only the
+    /// program's authorized payout key may call this.
+    pub fn set_program_tags(env: Env, program_id: String, tags: Vec<Symbol>) {
+        Self::require_program_payout_auth(&env, &program_id);
+        if tags.len() > MAX_PROGRAM_TAGS {
+            panic!("Too many program tags");
+        }
+
+        let old_tags = Self::get_program_tags(env.clone(), program_id.clone());
+        for tag in old_tags.iter() {
+            let key = ProgramKey::TaggedPrograms(tag);
+            let mut programs: Vec<String> =
+                env.storage().persistent().get(&key).unwrap_or(vec![&env]);
+            if let Some(i) = programs.first_index_of(&program_id) {
+                programs.remove(i);
+            }
+            if programs.is_empty() {
+                env.storage().persistent().remove(&key);
+            } else {
+                env.storage().persistent().set(&key, &programs);
+            }
+        }
+
+        let mut new_tags: Vec<Symbol> = vec![&env];
+        for tag in tags.iter() {
+            if new_tags.contains(&tag) {
+                continue;
+            }
+            let key = ProgramKey::TaggedPrograms(tag.clone());
+            let mut programs: Vec<String> =
+                env.storage().persistent().get(&key).unwrap_or(vec![&env]);
+            programs.push_back(program_id.clone());
+            env.storage().persistent().set(&key, &programs);
+            new_tags.push_back(tag);
+        }
+
+        let key = ProgramKey::Tags(program_id.clone());
+        if new_tags.is_empty() {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &new_tags);
+        }
+        env.events()
+            .publish((symbol_short!("prg_tags"),), (program_id, new_tags));
+    }
+
+    /// Returns a program's categorization tags.
+    pub fn get_program_tags(env: Env, program_id: String) -> Vec<Symbol> {
+        env.storage()
+            .persistent()
+            .get(&ProgramKey::Tags(program_id))
+            .unwrap_or(vec![&env])
+    }
+
+    /// Lists the programs tagged with `tag`, in tagging order, skipping
+    /// `offset` and returning at most `limit` ids.
+    pub fn get_programs_by_tag(env: Env, tag: Symbol, offset: u32, limit: u32) -> Vec<String> {
+        let programs: Vec<String> = env
+            .storage()
+            .persistent()
+            .get(&ProgramKey::TaggedPrograms(tag))
+            .unwrap_or(vec![&env]);
+        let end = offset.saturating_add(limit).min(programs.len());
+        if offset >= end {
+            return vec![&env];
+        }
+        programs.slice(offset..end)
+    }
+
+    /// Hands a program over to a new organizer by replacing its authorized
+    /// payout key. Both the current and the new key must authorize.
+    pub fn transfer_program_ownership(
+        env: Env,
+        program_id: String,
+        new_payout_key: Address,
+    ) -> ProgramData {
+        let mut program_data = Self::require_program_payout_auth(&env, &program_id);
+        new_payout_key.require_auth();
+
+        let previous = program_data.authorized_payout_key.clone();
+        if previous == new_payout_key {
+            return program_data;
+        }
+        program_data.authorized_payout_key = new_payout_key.clone();
+        program_storage::save(&env, &program_data);
+
+        Self::remove_organizer_program(&env, &previous, &program_id);
+        Self::add_organizer_program(&env, &new_payout_key, &program_id);
+
+        env.events().publish(
+            (symbol_short!("prg_owner"),),
+            (program_id, previous, new_payout_key),
+        );
+
+        program_data
+    }
+
+    /// Designates where a program's withdrawals and dust sweeps go by default,
+    /// e.g. a team subaccount, while the organizer keeps control of the
+    /// program. Pass `None` to send them to the organizer again. Only the
+    /// program's authorized payout key may call this.
+    pub fn set_program_beneficiary(env: Env, program_id: String, beneficiary: Option<Address>) {
+        Self::require_program_payout_auth(&env, &program_id);
+        let key = ProgramKey::Beneficiary(program_id.clone());
+        match &beneficiary {
+            Some(beneficiary) => env.storage().instance().set(&key, beneficiary),
+            None => env.storage().instance().remove(&key),
+        }
+        env.events()
+            .publish((symbol_short!("prg_benef"),), (program_id, beneficiary));
+    }
+
+    /// Returns where a program's withdrawals go by default: its beneficiary
+    /// if set, otherwise its authorized payout key.
+    pub fn get_program_beneficiary(env: Env, program_id: String) -> Address {
+        if let Some(beneficiary) = env
+            .storage()
+            .instance()
+            .get(&ProgramKey::Beneficiary(program_id.clone()))
+        {
+            return beneficiary;
+        }
+        program_storage::load(&env, &program_id)
+            .unwrap_or_else(|| panic!("Program not found"))
+            .authorized_payout_key
+    }
+
+    /// Caps how many open (not closed) programs one organizer may own at
+    /// once; `0` removes the cap. Enforced when a program is initialized,
+    /// cloned or transferred. Only the admin can call this.
+    pub fn set_max_programs_per_organizer(env: Env, max_programs: u32) {
+        let admin = anti_abuse::get_admin(&env).expect("Admin not set");
+        admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&MAX_ORGANIZER_PROGRAMS, &max_programs);
+
+        env.events()
+            .publish((symbol_short!("org_cap"),), max_programs);
+    }
+
+    /// Returns the per-organizer program cap, `0` if unlimited.
+    pub fn get_max_programs_per_organizer(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&MAX_ORGANIZER_PROGRAMS)
+            .unwrap_or(0)
+    }
+
+    fn add_organizer_program(env: &Env, organizer: &Address, program_id: &String) {
+        let key = DataKey::OrganizerPrograms(organizer.clone());
+        let mut programs: Vec<String> = env.storage().persistent().get(&key).unwrap_or(vec![env]);
+
+        let max_programs = Self::get_max_programs_per_organizer(env.clone());
+        if max_programs > 0 {
+            let open = programs
+                .iter()
+                .filter(|id| {
+                    !env.storage()
+                        .instance()
+                        .has(&DataKey::ProgramClosed(id.clone()))
+                })
+                .count() as u32;
+            if open >= max_programs {
+                panic!("Organizer program limit reached");
+            }
+        }
+
+        programs.push_back(program_id.clone());
+        env.storage().persistent().set(&key, &programs);
+    }
+
+    fn remove_organizer_program(env: &Env, organizer: &Address, program_id: &String) {
+        let key = DataKey::OrganizerPrograms(organizer.clone());
+        let mut programs: Vec<String> = env.storage().persistent().get(&key).unwrap_or(vec![env]);
+        if let Some(index) = programs.first_index_of(program_id) {
+            programs.remove(index);
+        }
+        if programs.is_empty() {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &programs);
+        }
+    }
+
+    /// Blocks `address` from receiving payouts or executing claims in a
+    /// program (authorized payout key only). With `revoke_claims`, the
+    /// address's pending claims are revoked and their reserved funds returned
+    /// to the program balance instead of being stranded; revoking is subject
+    /// to the claim cancel cooldown.
+    ///
+    /// # Returns
+    /// * `i128` - Total amount reclaimed from revoked claims
+    pub fn add_to_denylist(
+        env: Env,
+        program_id: String,
+        address: Address,
+        revoke_claims: bool,
+    ) -> i128 {
+        let mut program_data = Self::require_program_payout_auth(&env, &program_id);
+
+        env.storage().persistent().set(
+            &DataKey::Denylisted(program_id.clone(), address.clone()),
+            &true,
+        );
+
+        let (revoked, restored) = if revoke_claims {
+            claim_period::revoke_recipient_claims(&env, &mut program_data, &address)
+        } else {
+            (0, 0)
+        };
+        if revoked > 0 {
+            program_storage::save(&env, &program_data);
+        }
+
+        env.events().publish(
+            (symbol_short!("deny_add"),),
+            (program_id, address, revoked, restored),
+        );
+
+        restored
+    }
+
+    /// Lifts a denylisting (authorized payout key only). Revoked claims stay
+    /// revoked.
+    pub fn remove_from_denylist(env: Env, program_id: String, address: Address) {
+        Self::require_program_payout_auth(&env, &program_id);
+
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Denylisted(program_id.clone(), address.clone()));
+
+        env.events()
+            .publish((symbol_short!("deny_rm"),), (program_id, address));
+    }
+
+    /// Returns whether `address` is denylisted in a program.
+    pub fn is_denylisted(env: Env, program_id: String, address: Address) -> bool {
+        env.storage()
+            .persistent()
+            .has(&DataKey::Denylisted(program_id, address))
+    }
+
+    fn require_not_denylisted(env: &Env, program_id: &String, recipient: &Address) {
+        if env
+            .storage()
+            .persistent()
+            .has(&DataKey::Denylisted(program_id.clone(), recipient.clone()))
+        {
+            panic!("Recipient is denylisted");
+        }
+        if Self::is_recipient_frozen(env.clone(), program_id.clone(), recipient.clone()) {
+            panic!("Recipient is frozen");
+        }
+    }
+
+    /// Puts `address` on hold in one program while a dispute is settled
+    /// (admin only): it cannot receive payouts or execute claims there, but
+    /// stays payable in other programs. Unlike the denylist, pending claims
+    /// are left untouched and become executable again once unfrozen.
+    pub fn freeze_recipient(env: Env, program_id: String, address: Address) {
+        let admin = anti_abuse::get_admin(&env).expect("Admin not set");
+        admin.require_auth();
+
+        if !program_storage::exists(&env, &program_id) {
+            panic!("Program not found");
+        }
+        env.storage().persistent().set(
+            &ProgramKey::FrozenRecipient(program_id.clone(), address.clone()),
+            &true,
+        );
+        env.events()
+            .publish((symbol_short!("rcp_frz"),), (program_id, address));
+    }
+
+    /// Lifts a freeze set by `freeze_recipient` (admin only).
+    pub fn unfreeze_recipient(env: Env, program_id: String, address: Address) {
+        let admin = anti_abuse::get_admin(&env).expect("Admin not set");
+        admin.require_auth();
+
+        env.storage()
+            .persistent()
+            .remove(&ProgramKey::FrozenRecipient(
+                program_id.clone(),
+                address.clone(),
+            ));
+        env.events()
+            .publish((symbol_short!("rcp_unfrz"),), (program_id, address));
+    }
+
+    /// Returns whether `address` is frozen in a program.
+    pub fn is_recipient_frozen(env: Env, program_id: String, address: Address) -> bool {
+        env.storage()
+            .persistent()
+            .has(&ProgramKey::FrozenRecipient(program_id, address))
+    }
+
+    /// Whether a due schedule must be held back because its recipient was
+    /// denylisted or frozen after it was created. Emits `schedule_skipped`
+    /// if so; the schedule stays pending until the block is lifted or the
+    /// organizer cancels it.
+    fn skip_blocked_schedule(
+        env: &Env,
+        program_id: &String,
+        schedule: &ProgramReleaseSchedule,
+    ) -> bool {
+        let recipient = schedule.recipient.clone();
+        if !Self::is_denylisted(env.clone(), program_id.clone(), recipient.clone())
+            && !Self::is_recipient_frozen(env.clone(), program_id.clone(), recipient.clone())
+        {
+            return false;
+        }
+
+        env.events().publish(
+            (Symbol::new(env, "schedule_skipped"),),
+            (
+                program_id.clone(),
+                schedule.schedule_id,
+                recipient,
+                schedule.amount,
+            ),
+        );
+        true
+    }
+
+    /// Checks if a program exists.
+    ///
+    /// # Arguments
+    /// * `program_id` - The program ID to check
+    ///
+    /// # Returns
+    /// * `bool` - True if program exists, false otherwise
+    pub fn program_exists(env: Env, program_id: String) -> bool {
+        program_storage::exists(&env, &program_id)
+    }
+
+    // ========================================================================
+    // Fund Management
+    // ========================================================================
+
+    /// Locks funds into the program escrow for prize distribution.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `amount` - Amount of tokens to lock (in token's smallest denomination)
+    ///
+    /// # Returns
+    /// * `ProgramData` - Updated program data with new balance
+    ///
+    /// # Panics
+    /// * If amount is zero or negative
+    /// * If program is not initialized
+    ///
+    /// # State Changes
+    /// - Increases `total_funds` by amount
+    /// - Increases `remaining_balance` by amount
+    /// - Emits FundsLocked event
+    ///
+    /// # Prerequisites
+    /// Before calling this function:
+    /// 1. Caller must have sufficient token balance
+    /// 2. Caller must approve contract for token transfer
+    /// 3. Tokens must actually be transferred to contract
+    ///
+    /// # Security Considerations
+    /// - Amount must be positive
+    /// - This function doesn't perform the actual token transfer
+    /// - Caller is responsible for transferring tokens to contract
+    /// - Consider verifying contract balance matches recorded amount
+    /// - Multiple lock operations are additive (cumulative)
+    ///
+    /// # Events
+    /// Emits: `FundsLocked(program_id, amount, new_remaining_balance)`
+    ///
+    /// # Example
+    /// ```rust
+    /// use soroban_sdk::token;
+    ///
+    /// // 1. Transfer tokens to contract
+    /// let amount = 10_000_0000000; // 10,000 USDC
+    /// token_client.transfer(
+    ///     &organizer,
+    ///     &contract_address,
+    ///     &amount
+    /// );
+    ///
+    /// // 2. Record the locked funds
+    /// let updated = escrow_client.lock_program_funds(&amount);
+    /// println!("Locked: {} USDC", amount / 10_000_000);
+    /// println!("Remaining: {}", updated.remaining_balance);
+    /// ```
+    ///
+    /// # Production Usage
+    /// ```bash
+    /// # 1. Transfer USDC to contract
+    /// stellar contract invoke \
+    ///   --id USDC_TOKEN_ID \
+    ///   --source ORGANIZER_KEY \
+    ///   -- transfer \
+    ///   --from ORGANIZER_ADDRESS \
+    ///   --to CONTRACT_ADDRESS \
+    ///   --amount 10000000000
+    ///
+    /// # 2. Record locked funds
+    /// stellar contract invoke \
+    ///   --id CONTRACT_ID \
+    ///   --source ORGANIZER_KEY \
+    ///   -- lock_program_funds \
+    ///   --amount 10000000000
+    /// ```
+    ///
+    /// # Gas Cost
+    /// Low - Storage update + event emission
+    ///
+    /// # Common Pitfalls
+    /// - Forgetting to transfer tokens before calling
+    /// -  Locking amount that exceeds actual contract balance
+    /// -  Not verifying contract received the tokens
+
+    pub fn lock_program_funds(env: Env, program_id: String, amount: i128) -> ProgramData {
+        // Apply the version pin and rate limiting
+        Self::guard_caller(&env, &env.current_contract_address());
+
+        let start = env.ledger().timestamp();
+        let caller = env.current_contract_address();
+
+        // Check if contract is paused
+        if Self::is_paused_internal(&env) {
+            monitoring::track_operation(&env, symbol_short!("lock"), caller.clone(), false);
+            panic!("Contract is paused");
+        }
+
+        // Validate amount
+        if amount <= 0 {
+            monitoring::track_operation(&env, symbol_short!("lock"), caller.clone(), false);
+            panic!("Amount must be greater than zero");
+        }
+        let limits = Self::get_amount_limits(env.clone());
+        Self::enforce_amount_limits(amount, limits.min_lock_amount, limits.max_lock_amount);
+
+        // Get program data
+        let mut program_data: ProgramData = program_storage::load(&env, &program_id)
+            .unwrap_or_else(|| {
+                monitoring::track_operation(&env, symbol_short!("lock"), caller.clone(), false);
+                panic!("Program not found")
+            });
+
+        if !Self::get_operation_flags_internal(&env, &program_id).allow_lock {
+            monitoring::track_operation(&env, symbol_short!("lock"), caller.clone(), false);
+            panic!("Lock disabled for program");
+        }
+        Self::require_operation_allowed(&env, &program_id, symbol_short!("lock"));
+
+        // Calculate and collect fee if enabled
+        let fee_config = Self::get_fee_config_internal(&env);
+        let fee_amount = if fee_config.fee_enabled && fee_config.lock_fee_rate > 0 {
+            Self::calculate_fee(amount, fee_config.lock_fee_rate)
+        } else {
+            0
+        };
+        let net_amount = amount - fee_amount;
+
+        // Enforce optional per-program TVL cap for this token
+        if let Some(cap) = Self::get_max_program_tvl(env.clone(), program_data.token_address.clone())
+        {
+            if program_data.remaining_balance + net_amount > cap {
+                monitoring::track_operation(&env, symbol_short!("lock"), caller.clone(), false);
+                panic!("Program TVL cap exceeded");
+            }
+        }
+
+        // Update balances with net amount
+        program_data.total_funds += net_amount;
+        program_data.remaining_balance += net_amount;
+
+        // Emit fee collected event if applicable
+        if fee_amount > 0 {
+            Self::record_fee(&env, &program_data.token_address, &program_id, fee_amount);
+            env.events().publish(
+                (symbol_short!("fee"),),
+                (
+                    symbol_short!("lock"),
+                    fee_amount,
+                    fee_config.lock_fee_rate,
+                    fee_config.fee_recipient.clone(),
+                ),
+            );
+        }
+
+        // Store updated data
+        program_storage::save(&env, &program_data);
+        Self::check_low_balance_internal(&env, &program_id, program_data.remaining_balance);
+
+        // Emit FundsLocked event (with net amount after fee)
+        env.events().publish(
+            (FUNDS_LOCKED,),
+            (
+                program_data.program_id.clone(),
+                net_amount,
+                program_data.remaining_balance,
+            ),
+        );
+        monitoring::count_operation(&env, monitoring::OperationType::Lock);
+
+        program_data
+    }
+
+    // ========================================================================
+    // Payout Functions
+    // ========================================================================
+
+    /// Executes batch payouts to multiple recipients simultaneously.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `recipients` - Vector of recipient addresses
+    /// * `amounts` - Vector of amounts (must match recipients length)
+    /// * `dedupe` - Sum amounts of repeated recipients into one transfer each
+    /// * `modes` - Optional per-entry `PayoutMode`; `Claim` entries become
+    ///   pending claims instead of transfers. Defaults to all `Immediate`.
+    /// * `expected_total` - Sum of `amounts` as computed by the caller; guards
+    ///   against fat-fingered amounts before anything is transferred
+    ///
+    /// # Returns
+    /// * `ProgramData` - Updated program data after payouts
+    ///
+    /// # Panics
+    /// * If caller is not the authorized payout key
+    /// * If program is not initialized
+    /// * If recipients and amounts vectors have different lengths
+    /// * If `modes` is given with a different length, or together with `dedupe`
+    /// * If vectors are empty
+    /// * If any amount is zero or negative
+    /// * If the sum of amounts differs from `expected_total`
+    /// * If total payout exceeds remaining balance
+    /// * If arithmetic overflow occurs
+    ///
+    /// # Authorization
+    /// - **CRITICAL**: Only authorized payout key can call
+    /// - Caller must be exact match to `authorized_payout_key`
+    ///
+    /// # State Changes
+    /// - Transfers tokens from contract to each recipient
+    /// - Adds PayoutRecord for each transfer to history
+    /// - Decreases `remaining_balance` by total payout amount
+    /// - Emits BatchPayout event
+    ///
+    /// # Atomicity
+    /// This operation is atomic - either all transfers succeed or all fail.
+    /// If any transfer fails, the entire batch is reverted.
+    ///
+    /// # Security Considerations
+    /// - Verify recipient addresses off-chain before calling
+    /// - Ensure amounts match winner rankings/criteria
+    /// - Total payout is calculated with overflow protection
+    /// - Balance check prevents overdraft
+    /// - All transfers are logged for audit trail
+    /// - Consider implementing payout limits for additional safety
+    ///
+    /// # Events
+    /// Emits: `BatchPayout(program_id, recipient_count, total_amount, new_balance)`
+    ///
+    /// # Example
+    /// ```rust
+    /// use soroban_sdk::{vec, Address};
+    ///
+    /// // Define winners and prizes
+    /// let winners = vec![
+    ///     &env,
+    ///     Address::from_string("GWINNER1..."), // 1st place
+    ///     Address::from_string("GWINNER2..."), // 2nd place
+    ///     Address::from_string("GWINNER3..."), // 3rd place
+    /// ];
+    ///
+    /// let prizes = vec![
+    ///     &env,
+    ///     5_000_0000000,  // $5,000 USDC
+    ///     3_000_0000000,  // $3,000 USDC
+    ///     2_000_0000000,  // $2,000 USDC
+    /// ];
+    ///
+    /// // Execute batch payout (only authorized backend can call)
+    /// let result = escrow_client.batch_payout(
+    ///     &program_id, &winners, &prizes, &false, &None, &10_000_0000000,
+    /// );
+    /// println!("Paid {} winners", winners.len());
+    /// println!("Remaining: {}", result.remaining_balance);
+    /// ```
+    ///
+    /// # Production Usage
+    /// ```bash
+    /// # Batch payout to 3 winners
+    /// stellar contract invoke \
+    ///   --id CONTRACT_ID \
+    ///   --source BACKEND_KEY \
+    ///   -- batch_payout \
+    ///   --recipients '["GWINNER1...", "GWINNER2...", "GWINNER3..."]' \
+    ///   --amounts '[5000000000, 3000000000, 2000000000]' \
+    ///   --dedupe false \
+    ///   --expected_total 10000000000
+    /// ```
+    ///
+    /// # Gas Cost
+    /// High - Multiple token transfers + storage updates
+    /// Cost scales linearly with number of recipients
+    ///
+    /// # Best Practices
+    /// 1. Verify all winner addresses before execution
+    /// 2. Double-check prize amounts match criteria
+    /// 3. Test on testnet with same number of recipients
+    /// 4. Monitor events for successful completion
+    /// 5. Keep batch size reasonable (recommend < 50 recipients)
+    ///
+    /// # Limitations
+    /// - Maximum batch size limited by gas/resource limits
+    /// - For very large batches, consider multiple calls
+    /// - All amounts must be positive  
+    pub fn batch_payout(
+        env: Env,
+        program_id: String,
+        recipients: Vec<Address>,
+        amounts: Vec<i128>,
+        dedupe: bool,
+        modes: Option<Vec<PayoutMode>>,
+        expected_total: i128,
+    ) -> ProgramData {
+        // Check if contract is paused
+        if Self::is_paused_internal(&env) {
+            panic!("Contract is paused");
+        }
+
+        // Apply rate limiting to the contract itself or the program
+        // We can't easily get the caller here without getting program data first
+
+        // Get program data
+        let program_data: ProgramData =
+            program_storage::load(&env, &program_id).unwrap_or_else(|| panic!("Program not found"));
+
+        if !Self::get_operation_flags_internal(&env, &program_id).allow_payout {
+            panic!("Payout disabled for program");
+        }
+        velocity_guard::require_not_paused(&env, &program_id);
+        Self::require_operation_allowed(&env, &program_id, symbol_short!("payout"));
+        Self::enforce_min_funding_internal(&env, &program_id, &program_data);
+        Self::enforce_funding_goal_internal(&env, &program_id, &program_data);
+
+        // Apply the version pin and rate limiting to the authorized payout key
+        Self::guard_caller(&env, &program_data.authorized_payout_key);
+
+        // Verify authorization - CRITICAL
+        program_data.authorized_payout_key.require_auth();
+
+        // Validate inputs
+        if recipients.len() != amounts.len() {
+            panic!("Recipients and amounts vectors must have the same length");
+        }
+
+        if recipients.is_empty() {
+            panic!("Cannot process empty batch");
+        }
+
+        if let Some(modes) = &modes {
+            if modes.len() != amounts.len() {
+                panic!("Modes and amounts vectors must have the same length");
+            }
+            if dedupe {
+                panic!("Cannot dedupe a batch with payout modes");
+            }
+        }
+
+        // Check every recipient before any transfer so an ineligible entry
+        // fails the whole batch up front
+        for (i, recipient) in recipients.iter().enumerate() {
+            if Self::is_denylisted(env.clone(), program_id.clone(), recipient.clone()) {
+                panic!("Ineligible recipient at index {}: denylisted", i);
+            }
+            if Self::is_recipient_frozen(env.clone(), program_id.clone(), recipient.clone()) {
+                panic!("Ineligible recipient at index {}: frozen", i);
+            }
+            if !payout_condition::is_satisfied(&env, &program_id, &recipient) {
+                panic!(
+                    "Ineligible recipient at index {}: payout condition not met",
+                    i
+                );
+            }
+        }
+
+        // Calculate total with overflow protection
+        let limits = Self::get_amount_limits(env.clone());
+        let fraction_limit =
+            Self::payout_fraction_limit(&env, &program_id, program_data.remaining_balance);
+        let mut total_payout: i128 = 0;
+        for i in 0..amounts.len() {
+            let amount = amounts.get(i).unwrap();
+            if amount <= 0 {
+                panic!("All amounts must be greater than zero");
+            }
+            Self::enforce_amount_limits(amount, limits.min_payout, limits.max_payout);
+            Self::enforce_payout_fraction(amount, fraction_limit);
+            total_payout = total_payout
+                .checked_add(amount)
+                .unwrap_or_else(|| panic!("Payout amount overflow"));
+        }
+        if total_payout != expected_total {
+            panic!("Batch total mismatch");
+        }
+
+        // Validate balance
+        if total_payout > program_data.remaining_balance {
+            panic!(
+                "Insufficient balance: requested {}, available {}",
+                total_payout, program_data.remaining_balance
+            );
+        }
+
+        // Enforce optional per-program spending limit for this window
+        Self::enforce_program_spending_limit_internal(
+            &env,
+            &program_id,
+            &program_data.token_address,
+            total_payout,
+        );
+        Self::enforce_daily_payout_cap_internal(&env, &program_id, total_payout);
+
+        // Consolidate repeated recipients into a single transfer each
+        let (recipients, amounts) = if dedupe {
+            Self::consolidate_recipients(&env, &recipients, &amounts)
+        } else {
+            (recipients, amounts)
+        };
+
+        // Calculate fees if enabled
+        let fee_config = Self::get_fee_config_internal(&env);
+        let payout_fee_rate = Self::effective_payout_fee_rate(&env, &program_id, &fee_config);
+        let mut total_fees: i128 = 0;
+
+        // Execute transfers
+        let mut updated_data = program_data.clone();
+        let mut updated_history = program_data.payout_history.clone();
+        let timestamp = env.ledger().timestamp();
+        let contract_address = env.current_contract_address();
+        let token_client = token::Client::new(&env, &program_data.token_address);
+        let direct_mode = Self::is_direct_payout_mode_internal(&env, &program_id);
+
+        for i in 0..recipients.len() {
+            let recipient = recipients.get(i).unwrap();
+            let amount = amounts.get(i).unwrap();
+            let mode = match &modes {
+                Some(modes) if !direct_mode => modes.get(i).unwrap(),
+                _ => PayoutMode::Immediate,
+            };
+
+            // Calculate fee for this payout
+            let fee_amount = Self::calculate_fee(amount, payout_fee_rate);
+            let net_amount = amount - fee_amount;
+            total_fees += fee_amount;
+
+            // Transfer fee to fee recipient if applicable
+            if fee_amount > 0 {
+                token_client.transfer(&contract_address, &fee_config.fee_recipient, &fee_amount);
+            }
+            updated_data.remaining_balance -= fee_amount;
+
+            if mode == PayoutMode::Claim {
+                // Reserve the net amount as a pending claim for the recipient
+                let claim_deadline =
+                    timestamp + claim_period::get_claim_window_for(&env, &program_id, &recipient);
+                claim_period::reserve_claim(
+                    &env,
+                    &mut updated_data,
+                    &recipient,
+                    net_amount,
+                    claim_deadline,
+                    None,
+                );
+                continue;
+            }
+
+            // Transfer net amount to recipient
+            token_client.transfer(&contract_address, &recipient.clone(), &net_amount);
+            updated_data.remaining_balance -= net_amount;
+
+            // Record payout (with net amount)
+            let payout_record = PayoutRecord {
+                recipient: recipient.clone(),
+                amount: net_amount,
+                timestamp,
+                memo_hash: None,
+            };
+            updated_history.push_back(payout_record);
+            
+            // Record outflow for threshold monitoring
+            threshold_monitor::record_outflow(&env, amount);
+        }
+
+        // Emit fee collected event if applicable
+        if total_fees > 0 {
+            Self::record_fee(&env, &program_data.token_address, &program_id, total_fees);
+            env.events().publish(
+                (symbol_short!("fee"),),
+                (
+                    symbol_short!("payout"),
+                    total_fees,
+                    payout_fee_rate,
+                    fee_config.fee_recipient.clone(),
+                ),
+            );
+        }
+
+        // Update program data (remaining balance already reduced per entry)
+        updated_data.payout_history = updated_history;
+
+        // Store updated data
+        program_storage::save(&env, &updated_data);
+        payout_volume::record(&env, &program_id, total_payout);
+        velocity_guard::record(&env, &program_id, total_payout);
+        Self::check_low_balance_internal(&env, &program_id, updated_data.remaining_balance);
+
+        // Emit event
+        env.events().publish(
+            (BATCH_PAYOUT,),
+            (
+                program_id,
+                recipients.len() as u32,
+                total_payout,
+                updated_data.remaining_balance,
+            ),
+        );
+        monitoring::count_operation(&env, monitoring::OperationType::Payout);
+
+        updated_data
+    }
+
+    /// Approximates the cost of a `batch_payout` of `count` recipients on
+    /// this program, in abstract units that grow with the instructions and
+    /// storage IO involved. Clients can compare estimates to pick a chunk
+    /// size; the number is not an exact resource figure.
+    pub fn estimate_batch_cost(env: Env, program_id: String, count: u32) -> u32 {
+        if !program_storage::exists(&env, &program_id) {
+            panic!("Program not found");
+        }
+        let fee_config = Self::get_fee_config_internal(&env);
+        let mut per_recipient = BATCH_RECIPIENT_COST;
+        if Self::effective_payout_fee_rate(&env, &program_id, &fee_config) > 0 {
+            per_recipient += BATCH_FEE_TRANSFER_COST;
+        }
+        BATCH_BASE_COST.saturating_add(per_recipient.saturating_mul(count))
+    }
+
+    /// Splits `total_amount` across `recipients` in proportion to `shares`
+    /// and pays them out through `batch_payout`.
+    ///
+    /// Integer division leaves a remainder of at most `recipients.len() - 1`
+    /// units; `remainder_policy` picks who receives it. With
+    /// `RemainderPolicy::Burn` the remainder is not paid out and stays in the
+    /// program's remaining balance. Entries whose share rounds down to zero
+    /// are skipped.
+    ///
+    /// # Panics
+    /// * If `recipients` and `shares` differ in length or are empty
+    /// * If `total_amount` is not positive or all shares are zero
+    pub fn batch_payout_by_shares(
+        env: Env,
+        program_id: String,
+        recipients: Vec<Address>,
+        shares: Vec<u32>,
+        total_amount: i128,
+        remainder_policy: RemainderPolicy,
+    ) -> ProgramData {
+        if recipients.len() != shares.len() {
+            panic!("Recipients and shares vectors must have the same length");
+        }
+        if recipients.is_empty() {
+            panic!("Cannot process empty batch");
+        }
+        if total_amount <= 0 {
+            panic!("Total amount must be greater than zero");
+        }
+
+        let mut total_shares: i128 = 0;
+        let mut largest_index: u32 = 0;
+        for i in 0..shares.len() {
+            let share = shares.get(i).unwrap();
+            if share > shares.get(largest_index).unwrap() {
+                largest_index = i;
+            }
+            total_shares += share as i128;
+        }
+        if total_shares == 0 {
+            panic!("Total shares must be greater than zero");
+        }
+
+        let mut amounts: Vec<i128> = Vec::new(&env);
+        let mut distributed: i128 = 0;
+        for i in 0..shares.len() {
+            let amount = total_amount
+                .checked_mul(shares.get(i).unwrap() as i128)
+                .unwrap_or_else(|| panic!("Payout amount overflow"))
+                / total_shares;
+            amounts.push_back(amount);
+            distributed += amount;
+        }
+
+        let remainder = total_amount - distributed;
+        let remainder_index = match remainder_policy {
+            RemainderPolicy::Last => Some(amounts.len() - 1),
+            RemainderPolicy::First => Some(0),
+            RemainderPolicy::LargestShare => Some(largest_index),
+            RemainderPolicy::Burn => None,
+        };
+        if let Some(index) = remainder_index {
+            amounts.set(index, amounts.get(index).unwrap() + remainder);
+        }
+
+        let mut paid_recipients: Vec<Address> = Vec::new(&env);
+        let mut paid_amounts: Vec<i128> = Vec::new(&env);
+        let mut paid_total: i128 = 0;
+        for i in 0..amounts.len() {
+            let amount = amounts.get(i).unwrap();
+            if amount > 0 {
+                paid_recipients.push_back(recipients.get(i).unwrap());
+                paid_amounts.push_back(amount);
+                paid_total += amount;
+            }
+        }
+
+        Self::batch_payout(
+            env,
+            program_id,
+            paid_recipients,
+            paid_amounts,
+            false,
+            None,
+            paid_total,
+        )
+    }
+
+    /// Runs `batch_payout` and indexes each recorded payout under the
+    /// caller-supplied reference (e.g. an invoice or accounting id) so it can
+    /// be fetched with `get_payout_by_reference`.
+    ///
+    /// References are not required to be unique; the index keeps the first
+    /// payout recorded under a reference and later duplicates are ignored.
+    /// `expected_total` is forwarded to `batch_payout` unchanged.
+    ///
+    /// # Panics
+    /// * If `references` and `amounts` differ in length
+    /// * Any panic raised by `batch_payout`
+    pub fn batch_payout_with_references(
+        env: Env,
+        program_id: String,
+        recipients: Vec<Address>,
+        amounts: Vec<i128>,
+        references: Vec<String>,
+        expected_total: i128,
+    ) -> ProgramData {
+        if references.len() != amounts.len() {
+            panic!("References and amounts vectors must have the same length");
+        }
+
+        let first_index = Self::get_program_info(env.clone(), program_id.clone())
+            .payout_history
+            .len();
+        let updated_data = Self::batch_payout(
+            env.clone(),
+            program_id.clone(),
+            recipients,
+            amounts,
+            false,
+            None,
+            expected_total,
+        );
+
+        for i in 0..references.len() {
+            let key = DataKey::PayoutReference(program_id.clone(), references.get(i).unwrap());
+            if !env.storage().persistent().has(&key) {
+                let record = updated_data.payout_history.get(first_index + i).unwrap();
+                env.storage().persistent().set(&key, &record);
+            }
+        }
+
+        updated_data
+    }
+
+    /// Like `batch_payout`, but skips invalid entries instead of reverting the
+    /// whole batch. Entries are checked in order; one is skipped if its amount
+    /// is not positive, is outside the amount limits, goes to a denylisted
+    /// recipient, or no longer fits in the remaining balance. The valid
+    /// entries are paid with `batch_payout`.
+    ///
+    /// # Events
+    /// If any entry was skipped, emits `batch_partial` with data
+    /// `(program_id, skipped_indices, skip_reasons, succeeded)`.
+    ///
+    /// # Panics
+    /// * If `recipients` and `amounts` differ in length or are empty
+    /// * Any panic raised by `batch_payout`
+    pub fn batch_payout_lenient(
+        env: Env,
+        program_id: String,
+        recipients: Vec<Address>,
+        amounts: Vec<i128>,
+    ) -> LenientBatchResult {
+        let program_data = Self::require_program_payout_auth(&env, &program_id);
+        if recipients.len() != amounts.len() {
+            panic!("Recipients and amounts vectors must have the same length");
+        }
+        if recipients.is_empty() {
+            panic!("Cannot process empty batch");
+        }
+
+        let limits = Self::get_amount_limits(env.clone());
+        let mut valid_recipients: Vec<Address> = Vec::new(&env);
+        let mut valid_amounts: Vec<i128> = Vec::new(&env);
+        let mut skipped_indices: Vec<u32> = Vec::new(&env);
+        let mut skip_reasons: Vec<BatchSkipReason> = Vec::new(&env);
+        let mut total: i128 = 0;
+        for i in 0..recipients.len() {
+            let recipient = recipients.get(i).unwrap();
+            let amount = amounts.get(i).unwrap();
+            let reason = if amount <= 0 {
+                Some(BatchSkipReason::InvalidAmount)
+            } else if (limits.min_payout != 0 && amount < limits.min_payout)
+                || (limits.max_payout != 0 && amount > limits.max_payout)
+            {
+                Some(BatchSkipReason::AmountOutOfLimits)
+            } else if env
+                .storage()
+                .persistent()
+                .has(&DataKey::Denylisted(program_id.clone(), recipient.clone()))
+            {
+                Some(BatchSkipReason::RecipientDenylisted)
+            } else if Self::is_recipient_frozen(env.clone(), program_id.clone(), recipient.clone())
+            {
+                Some(BatchSkipReason::RecipientFrozen)
+            } else if !payout_condition::is_satisfied(&env, &program_id, &recipient) {
+                Some(BatchSkipReason::ConditionNotMet)
+            } else if total + amount > program_data.remaining_balance {
+                Some(BatchSkipReason::InsufficientBalance)
+            } else {
+                None
+            };
+
+            match reason {
+                Some(reason) => {
+                    skipped_indices.push_back(i);
+                    skip_reasons.push_back(reason);
+                }
+                None => {
+                    total += amount;
+                    valid_recipients.push_back(recipient);
+                    valid_amounts.push_back(amount);
+                }
+            }
+        }
+
+        let succeeded = valid_recipients.len();
+        if succeeded > 0 {
+            Self::batch_payout(
+                env.clone(),
+                program_id.clone(),
+                valid_recipients,
+                valid_amounts,
+                false,
+                None,
+                total,
+            );
+        }
+
+        if !skipped_indices.is_empty() {
+            env.events().publish(
+                (Symbol::new(&env, "batch_partial"),),
+                (
+                    program_id,
+                    skipped_indices.clone(),
+                    skip_reasons.clone(),
+                    succeeded,
+                ),
+            );
+        }
+
+        LenientBatchResult {
+            succeeded,
+            total_paid: total,
+            skipped_indices,
+            skip_reasons,
+        }
+    }
+
+    /// Sums the amounts of repeated recipients, keeping first-seen order.
+    fn consolidate_recipients(
+        env: &Env,
+        recipients: &Vec<Address>,
+        amounts: &Vec<i128>,
+    ) -> (Vec<Address>, Vec<i128>) {
+        let mut unique_recipients: Vec<Address> = Vec::new(env);
+        let mut summed_amounts: Vec<i128> = Vec::new(env);
+
+        for i in 0..recipients.len() {
+            let recipient = recipients.get(i).unwrap();
+            let amount = amounts.get(i).unwrap();
+            match unique_recipients.first_index_of(&recipient) {
+                Some(index) => {
+                    let total = summed_amounts.get(index).unwrap() + amount;
+                    summed_amounts.set(index, total);
+                }
+                None => {
+                    unique_recipients.push_back(recipient);
+                    summed_amounts.push_back(amount);
+                }
+            }
+        }
+
+        (unique_recipients, summed_amounts)
+    }
+
+    /// Executes a single payout to one recipient.
+    ///
+    /// The net amount (after any payout fee) is reserved as a pending claim
+    /// that the recipient redeems via `execute_claim` within the claim window.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `recipient` - Address of the prize recipient
+    /// * `amount` - Amount to transfer (in token's smallest denomination)
+    ///
+    /// Recipients registered via `add_auto_claim_recipient`, and all
+    /// recipients of programs in direct payout mode, are paid directly
+    /// instead, and the returned claim id is `0`.
+    /// Programs with settlement batching enabled queue the payout for the
+    /// next flush instead (see `set_settlement_batching`), also returning `0`.
+    ///
+    /// # Returns
+    /// * `PayoutResult` - Fee and sponsor breakdown, the created claim id and
+    ///   the program's new remaining balance
+    ///
+    /// # Panics
+    /// * If caller is not the authorized payout key
+    /// * If program is not initialized
+    /// * If amount is zero or negative
+    /// * If amount exceeds remaining balance
+    ///
+    /// # Authorization
+    /// - Only authorized payout key can call this function
+    ///
+    /// # State Changes
+    /// - Transfers the sponsor share (see `set_sponsor_split`) and records it in history
+    /// - Creates a pending claim for the recipient holding the net amount
+    /// - Decreases `remaining_balance` by amount
+    /// - Emits Payout event
+    ///
+    /// # Security Considerations
+    /// - Verify recipient address before calling
+    /// - Amount must be positive
+    /// - Balance check prevents overdraft
+    /// - Transfer is logged in payout history once the claim is executed
+    ///
+    /// # Events
+    /// Emits: `Payout(program_id, recipient, amount, new_balance)`
+    ///
+    /// # Example
+    /// ```rust
+    /// use soroban_sdk::Address;
+    ///
+    /// let winner = Address::from_string("GWINNER...");
+    /// let prize = 1_000_0000000; // $1,000 USDC
+    ///
+    /// // Execute single payout
+    /// let result = escrow_client.single_payout(&program_id, &winner, &prize);
+    /// println!("Paid {} to winner (fee {})", result.net, result.fee);
+    /// ```
+    ///
+    /// # Gas Cost
+    /// Medium - Single token transfer + storage update
+    ///
+    /// # Use Cases
+    /// - Individual prize awards
+    /// - Bonus payments
+    /// - Late additions to prize pool distribution
+    pub fn single_payout(
+        env: Env,
+        program_id: String,
+        recipient: Address,
+        amount: i128,
+    ) -> PayoutResult {
+        Self::single_payout_internal(env, program_id, recipient, amount, None)
+    }
+
+    /// Pays `amount` to `recipient` at `release_timestamp` instead of now:
+    /// runs the payout checks of `single_payout`, then reserves the funds as
+    /// a release schedule (see `create_program_release_schedule`) that
+    /// `release_prog_schedule_automatic` pays out once due. The spending
+    /// limit and daily cap are counted at release rather than here. Returns
+    /// the schedule id.
+    pub fn single_payout_scheduled(
+        env: Env,
+        program_id: String,
+        recipient: Address,
+        amount: i128,
+        release_timestamp: u64,
+    ) -> u64 {
+        let program_data = Self::require_payout_allowed(&env, &program_id);
+        program_data.authorized_payout_key.require_auth();
+        Self::guard_caller(&env, &program_data.authorized_payout_key);
+        // the spending limit and daily cap are counted when the schedule
+        // is released, not here
+        Self::require_payout_amount_valid(
+            &env,
+            &program_data,
+            &recipient,
+            amount,
+            program_data.remaining_balance,
+        );
+        Self::create_release_schedule_internal(
+            &env,
+            &program_id,
+            amount,
+            release_timestamp,
+            &recipient,
+        )
+    }
+
+    /// Defines fixed prizes for the program's ranks: `tiers[0]` is the 1st
+    /// prize, `tiers[1]` the 2nd and so on. Each tier is then paid once with
+    /// `award_prize`. Cannot be changed after a tier has been awarded. Only
+    /// the program's authorized payout key may call this.
+    pub fn create_prize_tier_template(env: Env, program_id: String, tiers: Vec<i128>) {
+        Self::require_program_payout_auth(&env, &program_id);
+
+        if tiers.is_empty() {
+            panic!("Prize tiers cannot be empty");
+        }
+        for amount in tiers.iter() {
+            if amount <= 0 {
+                panic!("Amount must be greater than zero");
+            }
+        }
+        if env
+            .storage()
+            .instance()
+            .has(&ProgramKey::AwardedTiers(program_id.clone()))
+        {
+            panic!("Prize tiers already awarded");
+        }
+
+        env.storage()
+            .instance()
+            .set(&ProgramKey::PrizeTiers(program_id.clone()), &tiers);
+        env.events()
+            .publish((symbol_short!("prz_tiers"),), (program_id, tiers));
+    }
+
+    /// Returns the program's prize per rank, if a template is defined.
+    pub fn get_prize_tiers(env: Env, program_id: String) -> Option<Vec<i128>> {
+        env.storage()
+            .instance()
+            .get(&ProgramKey::PrizeTiers(program_id))
+    }
+
+    /// Returns the tier indexes already awarded, in award order.
+    pub fn get_awarded_tiers(env: Env, program_id: String) -> Vec<u32> {
+        env.storage()
+            .instance()
+            .get(&ProgramKey::AwardedTiers(program_id))
+            .unwrap_or(vec![&env])
+    }
+
+    /// Pays the preset prize for `tier_index` to `recipient` through
+    /// `single_payout`. Each tier can be awarded only once.
+    pub fn award_prize(
+        env: Env,
+        program_id: String,
+        recipient: Address,
+        tier_index: u32,
+    ) -> PayoutResult {
+        let tiers = Self::get_prize_tiers(env.clone(), program_id.clone())
+            .unwrap_or_else(|| panic!("Prize tiers not set"));
+        let amount = tiers
+            .get(tier_index)
+            .unwrap_or_else(|| panic!("Prize tier not found"));
+        let mut awarded = Self::get_awarded_tiers(env.clone(), program_id.clone());
+        if awarded.contains(tier_index) {
+            panic!("Prize tier already awarded");
+        }
+
+        let result = Self::single_payout_internal(
+            env.clone(),
+            program_id.clone(),
+            recipient.clone(),
+            amount,
+            None,
+        );
+
+        awarded.push_back(tier_index);
+        env.storage()
+            .instance()
+            .set(&ProgramKey::AwardedTiers(program_id.clone()), &awarded);
+        env.events().publish(
+            (symbol_short!("prz_award"),),
+            (program_id, recipient, tier_index, amount),
+        );
+        result
+    }
+
+    /// Executes a single payout exactly like `single_payout`, binding it to
+    /// `memo_hash`, the hash under which an encrypted memo is stored
+    /// off-chain. The hash is kept on the claim and on the payout record
+    /// once funds are disbursed, and emitted in a `payout_memo` receipt
+    /// event. Payouts queued by settlement batching are consolidated per
+    /// recipient, so their history records carry no memo.
+    pub fn single_payout_with_memo(
+        env: Env,
+        program_id: String,
+        recipient: Address,
+        amount: i128,
+        memo_hash: BytesN<32>,
+    ) -> PayoutResult {
+        Self::single_payout_internal(env, program_id, recipient, amount, Some(memo_hash.into()))
+    }
+
+    /// Checks shared by every single-recipient payout: pause state,
+    /// operation flags, funding requirements, payout key auth and rate
+    /// limit, spending limit and daily cap, amount limits, recipient
+    /// eligibility and balance. Returns the program.
+    fn validate_payout(
+        env: &Env,
+        program_id: &String,
+        recipient: &Address,
+        amount: i128,
+    ) -> ProgramData {
+        let program_data = Self::require_payout_allowed(env, program_id);
+
+        program_data.authorized_payout_key.require_auth();
+        // Apply the version pin and rate limiting to the authorized payout key
+        Self::guard_caller(env, &program_data.authorized_payout_key);
+
+        Self::record_payout_limits(env, &program_data, amount);
+        Self::require_payout_amount_valid(
+            env,
+            &program_data,
+            recipient,
+            amount,
+            program_data.remaining_balance,
+        );
+
+        program_data
+    }
+
+    /// Pause state, operation flags and funding requirements a program must
+    /// pass before paying anyone. Returns the program.
+    fn require_payout_allowed(env: &Env, program_id: &String) -> ProgramData {
+        // Check if contract is paused
+        if Self::is_paused_internal(env) {
+            panic!("Contract is paused");
+        }
+
+        // Get program data
+        let program_data: ProgramData =
+            program_storage::load(env, program_id).unwrap_or_else(|| panic!("Program not found"));
+
+        if !Self::get_operation_flags_internal(env, program_id).allow_payout {
+            panic!("Payout disabled for program");
+        }
+        velocity_guard::require_not_paused(env, program_id);
+        Self::require_operation_allowed(env, program_id, symbol_short!("payout"));
+        Self::enforce_min_funding_internal(env, program_id, &program_data);
+        Self::enforce_funding_goal_internal(env, program_id, &program_data);
+
+        program_data
+    }
+
+    /// Counts `amount` against the program's spending limit and daily cap,
+    /// panicking if either would be exceeded.
+    fn record_payout_limits(env: &Env, program_data: &ProgramData, amount: i128) {
+        // Enforce optional per-program spending limit for this window
+        Self::enforce_program_spending_limit_internal(
+            env,
+            &program_data.program_id,
+            &program_data.token_address,
+            amount,
+        );
+        Self::enforce_daily_payout_cap_internal(env, &program_data.program_id, amount);
+    }
+
+    /// Amount limits, recipient eligibility, balance and payout fraction
+    /// checks for paying `amount` out of `available`.
+    fn require_payout_amount_valid(
+        env: &Env,
+        program_data: &ProgramData,
+        recipient: &Address,
+        amount: i128,
+        available: i128,
+    ) {
+        let program_id = &program_data.program_id;
+
+        // Validate amount
+        if amount <= 0 {
+            panic!("Amount must be greater than zero");
+        }
+        let limits = Self::get_amount_limits(env.clone());
+        Self::enforce_amount_limits(amount, limits.min_payout, limits.max_payout);
+        Self::require_not_denylisted(env, program_id, recipient);
+        payout_condition::require_satisfied(env, program_id, recipient);
+        Self::require_recipient_can_receive(
+            env,
+            program_id,
+            &program_data.token_address,
+            recipient,
+        );
+
+        // Validate balance
+        if amount > available {
+            panic!(
+                "Insufficient balance: requested {}, available {}",
+                amount, available
+            );
+        }
+        Self::enforce_payout_fraction(
+            amount,
+            Self::payout_fraction_limit(env, program_id, available),
+        );
+    }
+
+    fn single_payout_internal(
+        env: Env,
+        program_id: String,
+        recipient: Address,
+        amount: i128,
+        memo_hash: Option<Bytes>,
+    ) -> PayoutResult {
+        let program_data = Self::validate_payout(&env, &program_id, &recipient, amount);
+
+        // Calculate and collect fee if enabled
+        let fee_config = Self::get_fee_config_internal(&env);
+        let payout_fee_rate = Self::effective_payout_fee_rate(&env, &program_id, &fee_config);
+        let fee_amount = Self::calculate_fee(amount, payout_fee_rate);
+        let net_amount = amount - fee_amount;
+
+        let contract_address = env.current_contract_address();
+        let token_client = token::Client::new(&env, &program_data.token_address);
+
+        // Transfer fee to fee recipient if applicable
+        if fee_amount > 0 {
+            token_client.transfer(&contract_address, &fee_config.fee_recipient, &fee_amount);
+            Self::record_fee(&env, &program_data.token_address, &program_id, fee_amount);
+            env.events().publish(
+                (symbol_short!("fee"),),
+                (
+                    symbol_short!("payout"),
+                    fee_amount,
+                    payout_fee_rate,
+                    fee_config.fee_recipient.clone(),
+                ),
+            );
+        }
+
+        let mut updated_data = program_data.clone();
+        updated_data.remaining_balance -= fee_amount;
+
+        // Route the sponsor share (if configured) straight to the sponsor
+        let mut net_amount = net_amount;
+        let mut sponsor_total: i128 = 0;
+        let sponsor_split: Option<SponsorSplit> = env
+            .storage()
+            .instance()
+            .get(&DataKey::SponsorSplit(program_id.clone()));
+        if let Some(split) = sponsor_split {
+            let sponsor_amount = Self::calculate_fee(net_amount, split.bps as i128);
+            if sponsor_amount > 0 {
+                token_client.transfer(&contract_address, &split.sponsor, &sponsor_amount);
+                updated_data.remaining_balance -= sponsor_amount;
+                updated_data.payout_history.push_back(PayoutRecord {
+                    recipient: split.sponsor.clone(),
+                    amount: sponsor_amount,
+                    timestamp: env.ledger().timestamp(),
+                    memo_hash: None,
+                });
+                env.events().publish(
+                    (symbol_short!("sponsor"),),
+                    (program_id.clone(), split.sponsor, sponsor_amount),
+                );
+                net_amount -= sponsor_amount;
+                sponsor_total = sponsor_amount;
+            }
+        }
+
+        let claim_id = if settlement::is_enabled(&env, &program_id) {
+            // Batched settlement: queue the transfer for the next flush
+            settlement::enqueue(&env, &program_id, &mut updated_data, &recipient, net_amount);
+            0
+        } else if Self::is_direct_payout_mode_internal(&env, &program_id)
+            || Self::is_auto_claim_recipient_internal(&env, &program_id, &recipient)
+            || Self::is_dust_claim_internal(&env, &program_id, net_amount)
+        {
+            // Direct mode, trusted recipient or dust: pay out directly, no claim step
+            token_client.transfer(&contract_address, &recipient, &net_amount);
+            updated_data.remaining_balance -= net_amount;
+            updated_data.payout_history.push_back(PayoutRecord {
+                recipient: recipient.clone(),
+                amount: net_amount,
+                timestamp: env.ledger().timestamp(),
+                memo_hash: memo_hash.clone(),
+            });
+            env.events().publish(
+                (symbol_short!("direct"),),
+                (program_id.clone(), recipient.clone(), net_amount),
+            );
+            0
+        } else {
+            // Reserve the net amount as a pending claim for the recipient
+            let claim_deadline = env.ledger().timestamp()
+                + claim_period::get_claim_window_for(&env, &program_id, &recipient);
+            claim_period::reserve_claim(
+                &env,
+                &mut updated_data,
+                &recipient,
+                net_amount,
+                claim_deadline,
+                memo_hash.clone(),
+            )
+        };
+
+        if let Some(memo_hash) = memo_hash {
+            // Receipt binding the disbursement to its off-chain encrypted memo
+            env.events().publish(
+                (Symbol::new(&env, "payout_memo"), recipient.clone()),
+                (program_id.clone(), claim_id, net_amount, memo_hash),
+            );
+        }
+
+        // Store updated data
+        program_storage::save(&env, &updated_data);
+        payout_volume::record(&env, &program_id, amount);
+        velocity_guard::record(&env, &program_id, amount);
+        Self::check_low_balance_internal(&env, &program_id, updated_data.remaining_balance);
+
+        // Emit Payout event (with net amount after fee)
+        env.events().publish(
+            (PAYOUT,),
+            (
+                program_id,
+                recipient,
+                net_amount,
+                updated_data.remaining_balance,
+            ),
+        );
+        monitoring::count_operation(&env, monitoring::OperationType::Payout);
+
+        PayoutResult {
+            gross: amount,
+            fee: fee_amount,
+            sponsor_split: sponsor_total,
+            net: net_amount,
+            claim_id,
+            remaining_balance: updated_data.remaining_balance,
+        }
+    }
+
+    /// Executes a single payout denominated in the program's quote unit.
+    ///
+    /// `quote_amount` is converted to token units through the program's price
+    /// oracle (see `set_price_oracle`) and then paid exactly like
+    /// `single_payout`.
+    ///
+    /// # Panics
+    /// * If no oracle is configured for the program
+    /// * If the oracle price is older than the configured max age
+    /// * If the converted amount fails any `single_payout` check
+    pub fn single_payout_quoted(
+        env: Env,
+        program_id: String,
+        recipient: Address,
+        quote_amount: i128,
+    ) -> PayoutResult {
+        if quote_amount <= 0 {
+            panic!("Amount must be greater than zero");
+        }
+        let amount = price_oracle::quote_to_token(&env, &program_id, quote_amount);
+        Self::single_payout(env, program_id, recipient, amount)
+    }
+
+    /// Enables or disables settlement batching for a program. While enabled,
+    /// `single_payout` queues the net amount instead of creating a claim, and
+    /// queued payouts are settled with one transfer per recipient once the
+    /// queue holds `flush_threshold` entries, the oldest entry is
+    /// `max_delay_secs` old, or `flush_settlements` is called. A zero
+    /// threshold or delay disables that trigger. Disabling flushes the queue.
+    /// Only the program's authorized payout key may call this.
+    pub fn set_settlement_batching(
+        env: Env,
+        program_id: String,
+        enabled: bool,
+        flush_threshold: u32,
+        max_delay_secs: u64,
+    ) {
+        settlement::set_settlement_batching(
+            &env,
+            &program_id,
+            enabled,
+            flush_threshold,
+            max_delay_secs,
+        );
+    }
+
+    /// Returns the settlement batching settings for a program, if any.
+    pub fn get_settlement_config(env: Env, program_id: String) -> Option<SettlementConfig> {
+        settlement::get_settlement_config(&env, &program_id)
+    }
+
+    /// Returns the payouts queued for the next settlement flush.
+    pub fn get_pending_settlements(env: Env, program_id: String) -> Vec<PayoutRecord> {
+        settlement::get_queue(&env, &program_id)
+    }
+
+    /// Settles all queued payouts for a program, one transfer per recipient.
+    /// Queued amounts are already deducted from the balance, so anyone may
+    /// call this. Returns the number of transfers made.
+    pub fn flush_settlements(env: Env, program_id: String) -> u32 {
+        if Self::is_paused_internal(&env) {
+            panic!("Contract is paused");
+        }
+        let mut program_data: ProgramData =
+            program_storage::load(&env, &program_id).unwrap_or_else(|| panic!("Program not found"));
+
+        let transfers = settlement::flush(&env, &program_id, &mut program_data);
+        if transfers > 0 {
+            program_storage::save(&env, &program_data);
+        }
+        transfers
+    }
+
+    /// Sets the signers and approval threshold for multisig payouts on a
+    /// program. Only the program's authorized payout key may call this.
+    pub fn set_multisig_payout_config(
+        env: Env,
+        program_id: String,
+        signers: Vec<Address>,
+        threshold: u32,
+    ) {
+        multisig_payout::set_config(&env, &program_id, signers, threshold);
+    }
+
+    /// Returns the multisig payout configuration for a program, if any.
+    pub fn get_multisig_payout_config(
+        env: Env,
+        program_id: String,
+    ) -> Option<MultisigPayoutConfig> {
+        multisig_payout::get_config(&env, &program_id)
+    }
+
+    /// Opens a payout that executes once enough signers approve it. The
+    /// amount is reserved out of `remaining_balance` until the request is
+    /// executed or cancelled, so other payouts cannot spend it.
+    ///
+    /// # Returns
+    /// * `u64` - The id of the pending request
+    pub fn request_multisig_payout(
+        env: Env,
+        program_id: String,
+        recipient: Address,
+        amount: i128,
+    ) -> u64 {
+        if Self::is_paused_internal(&env) {
+            panic!("Contract is paused");
+        }
+        multisig_payout::request_payout(&env, &program_id, &recipient, amount)
+    }
+
+    /// Approves a pending multisig payout as `signer`. Returns `true` if this
+    /// approval met the threshold and the payout was transferred.
+    pub fn approve_multisig_payout(
+        env: Env,
+        program_id: String,
+        request_id: u64,
+        signer: Address,
+    ) -> bool {
+        if Self::is_paused_internal(&env) {
+            panic!("Contract is paused");
+        }
+        multisig_payout::approve_payout(&env, &program_id, request_id, &signer)
+    }
+
+    /// Cancels a pending multisig payout, releasing its reservation back to
+    /// the program balance.
+    pub fn cancel_multisig_payout(env: Env, program_id: String, request_id: u64) {
+        multisig_payout::cancel_payout(&env, &program_id, request_id);
+    }
+
+    /// Returns the multisig payouts still awaiting approval, with the
+    /// approvals collected so far and the number required.
+    pub fn get_pending_multisig_payouts(env: Env, program_id: String) -> Vec<MultisigRequest> {
+        multisig_payout::get_pending(&env, &program_id)
+    }
+
+    /// Sets how long new multisig requests may collect approvals; pass 0 to
+    /// keep them open indefinitely. Expired requests cannot be approved and
+    /// are dropped by `expire_multisig_payouts`. Only the program's
+    /// authorized payout key may call this.
+    pub fn set_multisig_request_expiry(env: Env, program_id: String, expiry_secs: u64) {
+        multisig_payout::set_request_expiry(&env, &program_id, expiry_secs);
+    }
+
+    /// Returns how long new multisig requests stay open (0 if unlimited).
+    pub fn get_multisig_request_expiry(env: Env, program_id: String) -> u64 {
+        multisig_payout::get_request_expiry(&env, &program_id)
+    }
+
+    /// Returns the approvals collected by a pending multisig request and the
+    /// time it has left.
+    pub fn get_multisig_request_status(
+        env: Env,
+        program_id: String,
+        request_id: u64,
+    ) -> MultisigRequestStatus {
+        multisig_payout::get_request_status(&env, &program_id, request_id)
+    }
+
+    /// Drops expired multisig requests and returns their reservations to the
+    /// program balance. Anyone may call this. Returns the number expired.
+    pub fn expire_multisig_payouts(env: Env, program_id: String) -> u32 {
+        multisig_payout::expire_requests(&env, &program_id)
+    }
+
+    /// Registers the price oracle used by `single_payout_quoted`. Prices older
+    /// than `max_age_secs` are rejected. Only the program's authorized payout
+    /// key may call this.
+    pub fn set_price_oracle(
+        env: Env,
+        program_id: String,
+        oracle_contract: Address,
+        max_age_secs: u64,
+    ) {
+        price_oracle::set_price_oracle(&env, &program_id, oracle_contract, max_age_secs);
+    }
+
+    /// Returns the price oracle registered for a program, if any.
+    pub fn get_price_oracle(env: Env, program_id: String) -> Option<OracleConfig> {
+        price_oracle::get_price_oracle(&env, &program_id)
+    }
+
+    /// Registers (or with `None`, clears) a contract whose
+    /// `is_satisfied(program_id, recipient) -> bool` must return true before
+    /// `single_payout` pays a recipient, e.g. to gate grants on milestones
+    /// tracked elsewhere. Only the program's authorized payout key may call this.
+    pub fn set_payout_condition(
+        env: Env,
+        program_id: String,
+        condition_contract: Option<Address>,
+    ) {
+        payout_condition::set_payout_condition(&env, &program_id, condition_contract);
+    }
+
+    /// Returns the condition contract registered for a program, if any.
+    pub fn get_payout_condition(env: Env, program_id: String) -> Option<Address> {
+        payout_condition::get_payout_condition(&env, &program_id)
+    }
+
+    // ========================================================================
+    // Claim Period Functions
+    // ========================================================================
+
+    /// Reserves `amount` from the program balance as a pending claim that the
+    /// recipient must execute before `claim_deadline`.
+    ///
+    /// # Returns
+    /// * `u64` - The id of the created claim
+    ///
+    /// # Authorization
+    /// - Only the program's authorized payout key can call this function
+    pub fn create_pending_claim(
+        env: Env,
+        program_id: String,
+        recipient: Address,
+        amount: i128,
+        claim_deadline: u64,
+    ) -> u64 {
+        if !Self::get_operation_flags_internal(&env, &program_id).allow_payout {
+            panic!("Payout disabled for program");
+        }
+        velocity_guard::require_not_paused(&env, &program_id);
+        Self::require_operation_allowed(&env, &program_id, symbol_short!("payout"));
+        Self::require_not_denylisted(&env, &program_id, &recipient);
+        claim_period::create_pending_claim(&env, &program_id, &recipient, amount, claim_deadline)
+    }
+
+    /// Executes a pending claim, transferring the reserved funds to the recipient.
+    ///
+    /// The claim is pruned from the program's active set and kept in history.
+    pub fn execute_claim(env: Env, program_id: String, claim_id: u64, caller: Address) {
+        if !Self::get_operation_flags_internal(&env, &program_id).allow_claim {
+            panic!("Claim disabled for program");
+        }
+        Self::require_operation_allowed(&env, &program_id, symbol_short!("claim"));
+        claim_period::execute_claim(&env, &program_id, claim_id, &caller);
+    }
+
+    /// Executes a pending claim like `execute_claim`, donating `tip_bps / 10000`
+    /// of the payout back to the program balance. The tip is stored on the
+    /// claim record and emitted as a `ClmTip` event.
+    pub fn execute_claim_with_tip(
+        env: Env,
+        program_id: String,
+        claim_id: u64,
+        caller: Address,
+        tip_bps: u32,
+    ) {
+        if !Self::get_operation_flags_internal(&env, &program_id).allow_claim {
+            panic!("Claim disabled for program");
+        }
+        Self::require_operation_allowed(&env, &program_id, symbol_short!("claim"));
+        claim_period::execute_claim_with_tip(&env, &program_id, claim_id, &caller, tip_bps);
+    }
+
+    /// Executes a pending claim like `execute_claim`, splitting the payout
+    /// across `destinations`. `amounts` must sum to the claimable amount.
+    /// Only the claim recipient may call this. Emits a `ClmSplit` event.
+    pub fn claim_payout_split(
+        env: Env,
+        program_id: String,
+        claim_id: u64,
+        caller: Address,
+        destinations: Vec<Address>,
+        amounts: Vec<i128>,
+    ) {
+        if !Self::get_operation_flags_internal(&env, &program_id).allow_claim {
+            panic!("Claim disabled for program");
+        }
+        Self::require_operation_allowed(&env, &program_id, symbol_short!("claim"));
+        claim_period::claim_payout_split(
+            &env,
+            &program_id,
+            claim_id,
+            &caller,
+            &destinations,
+            &amounts,
+        );
+    }
+
+    /// Cancels a pending claim and returns its reserved funds to the program (admin only).
+    pub fn cancel_claim(env: Env, program_id: String, claim_id: u64, admin: Address) {
+        claim_period::cancel_claim(&env, &program_id, claim_id, &admin);
+    }
+
+    /// Cancels many pending claims at once, e.g. when an event is called off,
+    /// returning their funds to the program. Already resolved or unknown ids
+    /// are skipped. Only the program's authorized payout key may call this.
+    ///
+    /// # Returns
+    /// * `(u32, i128)` - Number of claims cancelled and total amount restored
+    pub fn batch_cancel_claims(env: Env, program_id: String, claim_ids: Vec<u64>) -> (u32, i128) {
+        claim_period::batch_cancel_claims(&env, &program_id, &claim_ids)
+    }
+
+    /// Lets the recipient turn down a pending claim, returning its funds to
+    /// the program. Declines are never subject to the cancel cooldown.
+    pub fn decline_claim(env: Env, program_id: String, claim_id: u64, recipient: Address) {
+        claim_period::decline_claim(&env, &program_id, claim_id, &recipient);
+    }
+
+    /// Requires `secs` to pass after an organizer cancellation
+    /// (`cancel_claim`, `batch_cancel_claims`, a 10000 bps `slash_claim` or
+    /// `add_to_denylist` revoking claims) before the next one, so claims
+    /// cannot be rapidly cancelled and re-issued. 0 removes the cooldown.
+    /// Only the contract admin may call this.
+    pub fn set_claim_cancel_cooldown(env: Env, program_id: String, secs: u64) {
+        claim_period::set_cancel_cooldown(&env, &program_id, secs);
+    }
+
+    /// Returns the program's claim cancel cooldown in seconds (0 if unset).
+    pub fn get_claim_cancel_cooldown(env: Env, program_id: String) -> u64 {
+        claim_period::get_cancel_cooldown(&env, &program_id)
+    }
+
+    /// Reclaims a program's pending claims that are past their deadline and
+    /// grace period, returning the funds to the program balance. Callable by
+    /// anyone. Returns the number of claims swept.
+    pub fn sweep_expired_claims(env: Env, program_id: String) -> u32 {
+        claim_period::sweep_expired_claims(&env, &program_id)
+    }
+
+    /// Slashes a pending claim by `bps / 10000` for a recipient who broke the
+    /// program's terms before claiming, returning that portion to the
+    /// program balance (authorized payout key only). The recipient can then
+    /// claim only the remainder; a 10000 bps slash revokes the claim. Emits
+    /// a `ClmSlsh` event. Returns the amount slashed.
+    pub fn slash_claim(env: Env, program_id: String, claim_id: u64, bps: u32) -> i128 {
+        claim_period::slash_claim(&env, &program_id, claim_id, bps)
+    }
+
+    /// Returns a claim by id, resolving both pending and already-resolved claims.
+    pub fn get_claim(env: Env, program_id: String, claim_id: u64) -> ClaimRecord {
+        claim_period::get_claim(&env, &program_id, claim_id)
+    }
+
+    /// Returns a claim with the seconds left before it expires at the current
+    /// ledger time, for UIs showing "claim within X".
+    pub fn get_claim_view(env: Env, program_id: String, claim_id: u64) -> ClaimView {
+        claim_period::get_claim_view(&env, &program_id, claim_id)
+    }
+
+    /// Returns several claims at once, in the order of `claim_ids`. Ids with
+    /// no claim are skipped, so compare each record's `claim_id` to spot misses.
+    pub fn get_claims(env: Env, program_id: String, claim_ids: Vec<u64>) -> Vec<ClaimRecord> {
+        claim_period::get_claims(&env, &program_id, &claim_ids)
+    }
+
+    /// Returns the status of a claim, resolving both pending and already-resolved claims.
+    pub fn get_claim_status(env: Env, program_id: String, claim_id: u64) -> ClaimStatus {
+        claim_period::get_claim_status(&env, &program_id, claim_id)
+    }
+
+    /// Returns the ids of the program's claims that are still pending.
+    pub fn get_pending_claim_ids(env: Env, program_id: String) -> Vec<u64> {
+        claim_period::get_pending_claim_ids(&env, &program_id)
+    }
+
+    /// Returns the ids of pending claims created more than `older_than_secs`
+    /// ago that can still be executed, so organizers can follow up with
+    /// recipients who have not claimed yet.
+    pub fn get_stale_pending_claims(
+        env: Env,
+        program_id: String,
+        older_than_secs: u64,
+    ) -> Vec<u64> {
+        claim_period::get_stale_pending_claims(&env, &program_id, older_than_secs)
+    }
+
+    /// Sets the global default claim window in seconds (admin only).
+    pub fn set_claim_window(env: Env, admin: Address, window_seconds: u64) {
+        claim_period::set_claim_window(&env, &admin, window_seconds);
+    }
+
+    /// Returns the global default claim window in seconds.
+    pub fn get_claim_window(env: Env) -> u64 {
+        claim_period::get_claim_window(&env)
+    }
+
+    /// Sets the claim window for one program, overriding the global window.
+    ///
+    /// Claims executed within `grace_secs` after their deadline are still paid,
+    /// minus `late_penalty_bps` which returns to the program balance.
+    ///
+    /// With `apply_to_existing` the deadlines of the program's pending claims
+    /// are recomputed from their creation time; otherwise only claims created
+    /// afterwards use the new window. Returns the config it replaced (see
+    /// `get_program_claim_config`) and the number of claims updated.
+    /// Only the program admin may call this.
+    pub fn set_program_claim_config(
+        env: Env,
+        program_id: String,
+        claim_window: u64,
+        grace_secs: u64,
+        late_penalty_bps: u32,
+        apply_to_existing: bool,
+    ) -> (Option<ClaimConfig>, u32) {
+        claim_period::set_program_claim_config(
+            &env,
+            &program_id,
+            claim_window,
+            grace_secs,
+            late_penalty_bps,
+            apply_to_existing,
+        )
+    }
+
+    /// Gives `recipient` its own claim window for future claims in the
+    /// program, e.g. a longer one for trusted recipients. Pass 0 to remove it.
+    ///
+    /// Windows resolve as: an explicit deadline passed to
+    /// `create_pending_claim`, then this per-recipient window, then the
+    /// program's window (see `set_program_claim_config`), then the global one.
+    pub fn set_recipient_claim_window(
+        env: Env,
+        program_id: String,
+        recipient: Address,
+        claim_window: u64,
+    ) {
+        claim_period::set_recipient_claim_window(&env, &program_id, &recipient, claim_window);
+    }
+
+    /// Returns the claim window override for a recipient, if any.
+    pub fn get_recipient_claim_window(
+        env: Env,
+        program_id: String,
+        recipient: Address,
+    ) -> Option<u64> {
+        claim_period::get_recipient_claim_window(&env, &program_id, &recipient)
+    }
+
+    /// Returns the claim config set for a program, if any.
+    pub fn get_program_claim_config(env: Env, program_id: String) -> Option<ClaimConfig> {
+        claim_period::get_program_claim_config(&env, &program_id)
+    }
+
+    /// Requires claims to mature for `min_confirmations` ledgers: a claim
+    /// created at sequence `S` can only be executed once the current sequence
+    /// is at least `S + min_confirmations`. Pass 0 to remove the delay.
+    pub fn set_claim_min_confirmations(env: Env, program_id: String, min_confirmations: u32) {
+        claim_period::set_claim_min_confirmations(&env, &program_id, min_confirmations);
+    }
+
+    /// Returns the ledger maturity delay for a program's claims (0 if unset).
+    pub fn get_claim_min_confirmations(env: Env, program_id: String) -> u32 {
+        claim_period::get_claim_min_confirmations(&env, &program_id)
+    }
+
+    /// Extends a pending claim's deadline by `extra_secs` (authorized payout
+    /// key only). Returns the new deadline.
+    ///
+    /// # Panics
+    /// * If the claim is not pending
+    /// * "Extension cap reached" if the claim's total extension would exceed
+    ///   the program's cap (see `set_max_claim_extension`)
+    pub fn extend_claim_window(
+        env: Env,
+        program_id: String,
+        claim_id: u64,
+        extra_secs: u64,
+    ) -> u64 {
+        claim_period::extend_claim_window(&env, &program_id, claim_id, extra_secs)
+    }
+
+    /// Rewards engaged recipients: while enabled, `acknowledge_claim` pushes
+    /// a pending claim's deadline out to at least `extend_secs` from now, so
+    /// active users do not miss it by accident. Extensions count toward the
+    /// program's extension cap. Only the program admin may call this.
+    pub fn set_auto_extend_on_activity(
+        env: Env,
+        program_id: String,
+        enabled: bool,
+        extend_secs: u64,
+    ) {
+        claim_period::set_auto_extend_on_activity(&env, &program_id, enabled, extend_secs)
+    }
+
+    /// Returns the activity extension for a program in seconds (0 if disabled).
+    pub fn get_auto_extend_on_activity(env: Env, program_id: String) -> u64 {
+        claim_period::get_auto_extend_on_activity(&env, &program_id)
+    }
+
+    /// Lets the recipient acknowledge a pending claim. With auto-extension
+    /// on (see `set_auto_extend_on_activity`) this also extends the claim's
+    /// deadline. Emits a `ClmAck` event. Returns the claim's deadline.
+    pub fn acknowledge_claim(
+        env: Env,
+        program_id: String,
+        claim_id: u64,
+        recipient: Address,
+    ) -> u64 {
+        claim_period::acknowledge_claim(&env, &program_id, claim_id, &recipient)
+    }
+
+    /// Caps the cumulative extension of any single claim, bounding how long
+    /// funds can stay reserved. Pass 0 to remove the cap.
+    pub fn set_max_claim_extension(env: Env, program_id: String, max_total_secs: u64) {
+        claim_period::set_max_claim_extension(&env, &program_id, max_total_secs);
+    }
+
+    /// Returns the per-claim extension cap for a program (0 if uncapped).
+    pub fn get_max_claim_extension(env: Env, program_id: String) -> u64 {
+        claim_period::get_max_claim_extension(&env, &program_id)
+    }
+
+    // ========================================================================
+    // Payout Callback
+    // ========================================================================
+
+    /// Registers a contract whose `on_payout(program_id, recipient, amount)`
+    /// is invoked after each executed claim. Pass `None` to clear it.
+    /// Callback failures are reported via a `callback_failed` event and never
+    /// revert the payout. Only the program's authorized payout key may call this.
+    pub fn set_payout_callback(env: Env, program_id: String, callback_contract: Option<Address>) {
+        payout_callback::set_payout_callback(&env, &program_id, callback_contract);
+    }
+
+    /// Returns the callback contract registered for a program, if any.
+    pub fn get_payout_callback(env: Env, program_id: String) -> Option<Address> {
+        payout_callback::get_payout_callback(&env, &program_id)
+    }
+
+    /// Registers a contract whose
+    /// `on_claim_expired(program_id, claim_id, recipient, amount)` is invoked
+    /// for each claim reclaimed by `sweep_expired_claims`. Pass `None` to clear
+    /// it. Only the program's authorized payout key may call this.
+    pub fn set_expiry_callback(env: Env, program_id: String, callback_contract: Option<Address>) {
+        payout_callback::set_expiry_callback(&env, &program_id, callback_contract);
+    }
+
+    /// Returns the expiry callback contract registered for a program, if any.
+    pub fn get_expiry_callback(env: Env, program_id: String) -> Option<Address> {
+        payout_callback::get_expiry_callback(&env, &program_id)
+    }
+
+    /// Registers a contract whose `mint(recipient, program_id, amount)` is
+    /// invoked after each executed claim to issue a commemorative badge. Pass
+    /// `None` to clear it. A failed mint is reported via a `bdg_fail` event
+    /// and never reverts the claim. Only the program's authorized payout key
+    /// may call this.
+    pub fn set_badge_minter(env: Env, program_id: String, minter_contract: Option<Address>) {
+        payout_callback::set_badge_minter(&env, &program_id, minter_contract);
+    }
+
+    /// Returns the badge minter registered for a program, if any.
+    pub fn get_badge_minter(env: Env, program_id: String) -> Option<Address> {
+        payout_callback::get_badge_minter(&env, &program_id)
+    }
+
+    // ========================================================================
+    // Release Schedule Functions
+    // ========================================================================
+
+    /// Creates a time-based release schedule for a program.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `program_id` - The program to create schedule for
+    /// * `amount` - Amount to release (in token's smallest denomination)
+    /// * `release_timestamp` - Unix timestamp when funds become available
+    /// * `recipient` - Address that will receive the funds
+    ///
+    /// # Returns
+    /// * `ProgramData` - Updated program data
+    ///
+    /// # Panics
+    /// * If program is not initialized
+    /// * If caller is not authorized payout key
+    /// * If amount is invalid
+    /// * If timestamp is in the past
+    /// * If amount plus all unreleased schedules exceeds remaining balance
+    ///
+    /// # State Changes
+    /// - Creates ProgramReleaseSchedule record
+    /// - Updates next schedule ID
+    /// - Emits ScheduleCreated event
+    ///
+    /// # Authorization
+    /// - Only authorized payout key can call this function
+    ///
+    /// # Example
+    /// ```rust
+    /// let now = env.ledger().timestamp();
+    /// let release_time = now + (30 * 24 * 60 * 60); // 30 days from now
+    /// escrow_client.create_program_release_schedule(
+    ///     &"Hackathon2024",
+    ///     &500_0000000, // 500 tokens
+    ///     &release_time,
+    ///     &winner_address
+    /// );
+    /// ```
+    pub fn create_program_release_schedule(
+        env: Env,
+        program_id: String,
+        amount: i128,
+        release_timestamp: u64,
+        recipient: Address,
+    ) -> ProgramData {
+        let start = env.ledger().timestamp();
+
+        // Check if contract is paused
+        if Self::is_paused_internal(&env) {
+            panic!("Contract is paused");
+        }
+
+        // Get program data
+        let program_data: ProgramData =
+            program_storage::load(&env, &program_id).unwrap_or_else(|| panic!("Program not found"));
+
+        // Apply the version pin and rate limiting to the authorized payout key
+        Self::guard_caller(&env, &program_data.authorized_payout_key);
+
+        // Verify authorization
+        program_data.authorized_payout_key.require_auth();
+
+        Self::create_release_schedule_internal(
+            &env,
+            &program_id,
+            amount,
+            release_timestamp,
+            &recipient,
+        );
+
+        // Track successful operation
+        monitoring::track_operation(
+            &env,
+            symbol_short!("create_p"),
+            program_data.authorized_payout_key,
+            true,
+        );
+
+        // Track performance
+        let duration = env.ledger().timestamp().saturating_sub(start);
+        monitoring::emit_performance(&env, symbol_short!("create_p"), duration);
+
+        // Return updated program data
+        let updated_data: ProgramData = program_storage::load(&env, &program_id).unwrap();
+        updated_data
+    }
+
+    /// Validates and stores a new release schedule for an already authorized
+    /// caller. Returns the new schedule id.
+    fn create_release_schedule_internal(
+        env: &Env,
+        program_id: &String,
+        amount: i128,
+        release_timestamp: u64,
+        recipient: &Address,
+    ) -> u64 {
+        let program_data: ProgramData =
+            program_storage::load(env, program_id).unwrap_or_else(|| panic!("Program not found"));
+
+        // Validate amount
+        if amount <= 0 {
+            panic!("Amount must be greater than zero");
+        }
+
+        // Validate timestamp
+        if release_timestamp <= env.ledger().timestamp() {
+            panic!("Release timestamp must be in the future");
+        }
+
+        // Every unreleased schedule must stay fully backed. Claims, queued
+        // settlements and multisig requests are already deducted from
+        // `remaining_balance`, so only other schedules are added here.
+        let scheduled_total = get_program_total_scheduled_amount(env, program_id)
+            .checked_add(amount)
+            .unwrap_or_else(|| panic!("Schedule amount overflow"));
+        if scheduled_total > program_data.remaining_balance {
+            panic!("Insufficient balance to schedule");
+        }
+
+        // Enforce the optional cap on unreleased schedules
+        let max_schedules: Option<u32> = env
+            .storage()
+            .instance()
+            .get(&DataKey::MaxSchedules(program_id.clone()));
+        if let Some(max) = max_schedules {
+            if get_program_open_schedule_count(env, program_id) >= max {
+                panic!("Too many schedules");
+            }
+        }
+
+        // Get next schedule ID
+        let schedule_id: u64 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::NextScheduleId(program_id.clone()))
+            .unwrap_or(1);
+
+        // Create release schedule
+        let schedule = ProgramReleaseSchedule {
+            schedule_id,
+            amount,
+            release_timestamp,
+            recipient: recipient.clone(),
+            released: false,
+            released_at: None,
+            released_by: None,
+        };
+
+        // Store schedule
+        env.storage().persistent().set(
+            &DataKey::ReleaseSchedule(program_id.clone(), schedule_id),
+            &schedule,
+        );
+
+        // Index it under its recipient
+        let recipient_key = ProgramKey::RecipientSchedules(program_id.clone(), recipient.clone());
+        let mut recipient_schedules: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&recipient_key)
+            .unwrap_or(vec![env]);
+        recipient_schedules.push_back(schedule_id);
+        env.storage()
+            .persistent()
+            .set(&recipient_key, &recipient_schedules);
+
+        // Update next schedule ID
+        env.storage().persistent().set(
+            &DataKey::NextScheduleId(program_id.clone()),
+            &(schedule_id + 1),
+        );
+
+        let mut totals = get_program_schedule_totals(env, program_id);
+        totals.total_scheduled += amount;
+        totals.total_pending += amount;
+        let is_earlier = match totals.next_release_ts {
+            Some(next) => release_timestamp < next,
+            None => true,
+        };
+        if is_earlier {
+            totals.next_release_ts = Some(release_timestamp);
+        }
+        set_program_schedule_totals(env, program_id, &totals);
+
+        // Emit program schedule created event
+        env.events().publish(
+            (PROG_SCHEDULE_CREATED,),
+            ProgramScheduleCreated {
+                program_id: program_id.clone(),
+                schedule_id,
+                amount,
+                release_timestamp,
+                recipient: recipient.clone(),
+                created_by: program_data.authorized_payout_key.clone(),
+            },
+        );
+
+        schedule_id
+    }
+
+    /// Cancels an unreleased schedule, returning its amount to the program's
+    /// unscheduled balance and freeing its slot under `set_max_schedules`.
+    /// Only the program's authorized payout key may call this.
+    pub fn cancel_program_release_schedule(env: Env, program_id: String, schedule_id: u64) {
+        Self::require_program_payout_auth(&env, &program_id);
+
+        let schedule_key = DataKey::ReleaseSchedule(program_id.clone(), schedule_id);
+        let schedule: ProgramReleaseSchedule = env
+            .storage()
+            .persistent()
+            .get(&schedule_key)
+            .unwrap_or_else(|| panic!("Schedule not found"));
+        if schedule.released {
+            panic!("Schedule already released");
+        }
+
+        env.storage().persistent().remove(&schedule_key);
+        record_schedule_resolved(&env, &program_id, &schedule, false);
+
+        env.events().publish(
+            (symbol_short!("sch_cncl"),),
+            (program_id, schedule_id, schedule.amount),
+        );
+    }
+
+    /// Automatically releases funds for program schedules that are due.
+    /// Can be called by anyone after the release timestamp has passed.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `program_id` - The program to check for due schedules
+    /// * `schedule_id` - The specific schedule to release
+    ///
+    /// # Panics
+    /// * If program doesn't exist
+    /// * If schedule doesn't exist
+    /// * If schedule is already released
+    /// * If schedule is not yet due
+    ///
+    /// # State Changes
+    /// - Transfers tokens to recipient
+    /// - Updates schedule status to released
+    /// - Adds to release history
+    /// - Updates program remaining balance
+    /// - Emits ScheduleReleased event
     ///
-    /// # Returns
-    /// * `Vec<String>` - List of all program IDs
+    /// If the recipient has since been denylisted or frozen, nothing is paid:
+    /// a `schedule_skipped` event is emitted and the schedule stays pending.
     ///
     /// # Example
     /// ```rust
-    /// let programs = escrow_client.list_programs();
-    /// for program_id in programs.iter() {
-    ///     println!("Program: {}", program_id);
-    /// }
+    /// // Anyone can call this after the timestamp
+    /// escrow_client.release_program_schedule_automatic(&"Hackathon2024", &1);
     /// ```
-    pub fn list_programs(env: Env) -> Vec<String> {
+    pub fn release_prog_schedule_automatic(env: Env, program_id: String, schedule_id: u64) {
+        let start = env.ledger().timestamp();
+        let caller = env.current_contract_address();
+
+        // Check if contract is paused
+        if Self::is_paused_internal(&env) {
+            panic!("Contract is paused");
+        }
+
+        // Get program data
+        let program_data: ProgramData =
+            program_storage::load(&env, &program_id).unwrap_or_else(|| panic!("Program not found"));
+
+        // Get schedule
+        if !env
+            .storage()
+            .persistent()
+            .has(&DataKey::ReleaseSchedule(program_id.clone(), schedule_id))
+        {
+            panic!("Schedule not found");
+        }
+
+        let mut schedule: ProgramReleaseSchedule = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ReleaseSchedule(program_id.clone(), schedule_id))
+            .unwrap();
+
+        // Check if already released
+        if schedule.released {
+            panic!("Schedule already released");
+        }
+
+        // Check if due for release
+        let now = env.ledger().timestamp();
+        if now < schedule.release_timestamp {
+            panic!("Schedule not yet due for release");
+        }
+
+        if Self::skip_blocked_schedule(&env, &program_id, &schedule) {
+            return;
+        }
+        Self::enforce_funding_goal_internal(&env, &program_id, &program_data);
+
+        // Get token client
+        let contract_address = env.current_contract_address();
+        let token_client = token::Client::new(&env, &program_data.token_address);
+
+        // Enforce optional per-program spending limit for this window
+        Self::enforce_program_spending_limit_internal(
+            &env,
+            &program_id,
+            &program_data.token_address,
+            schedule.amount,
+        );
+        Self::enforce_daily_payout_cap_internal(&env, &program_id, schedule.amount);
+
+        // Transfer funds
+        token_client.transfer(&contract_address, &schedule.recipient, &schedule.amount);
+
+        // Update schedule
+        schedule.released = true;
+        schedule.released_at = Some(now);
+        schedule.released_by = Some(env.current_contract_address());
+        record_schedule_resolved(&env, &program_id, &schedule, true);
+
+        // Update program data
+        let mut updated_data = program_data.clone();
+        updated_data.remaining_balance -= schedule.amount;
+
+        // Add to release history
+        let history_entry = ProgramReleaseHistory {
+            schedule_id,
+            program_id: program_id.clone(),
+            amount: schedule.amount,
+            recipient: schedule.recipient.clone(),
+            released_at: now,
+            released_by: env.current_contract_address(),
+            release_type: ReleaseType::Automatic,
+        };
+
+        let mut history: Vec<ProgramReleaseHistory> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ReleaseHistory(program_id.clone()))
+            .unwrap_or(vec![&env]);
+        history.push_back(history_entry);
+
+        // Store updates
+        env.storage().persistent().set(
+            &DataKey::ReleaseSchedule(program_id.clone(), schedule_id),
+            &schedule,
+        );
+        program_storage::save(&env, &updated_data);
         env.storage()
-            .instance()
-            .get(&PROGRAM_REGISTRY)
-            .unwrap_or(vec![&env])
-    }
+            .persistent()
+            .set(&DataKey::ReleaseHistory(program_id.clone()), &history);
 
-    /// Checks if a program exists.
-    ///
-    /// # Arguments
-    /// * `program_id` - The program ID to check
-    ///
-    /// # Returns
-    /// * `bool` - True if program exists, false otherwise
-    pub fn program_exists(env: Env, program_id: String) -> bool {
-        let program_key = DataKey::Program(program_id);
-        env.storage().instance().has(&program_key)
-    }
+        // Emit program schedule released event
+        env.events().publish(
+            (PROG_SCHEDULE_RELEASED,),
+            ProgramScheduleReleased {
+                program_id: program_id.clone(),
+                schedule_id,
+                amount: schedule.amount,
+                recipient: schedule.recipient.clone(),
+                released_at: now,
+                released_by: env.current_contract_address(),
+                release_type: ReleaseType::Automatic,
+            },
+        );
 
-    // ========================================================================
-    // Fund Management
-    // ========================================================================
+        // Track successful operation
+        monitoring::track_operation(&env, symbol_short!("rel_auto"), caller, true);
 
-    /// Locks funds into the program escrow for prize distribution.
+        // Track performance
+        let duration = env.ledger().timestamp().saturating_sub(start);
+        monitoring::emit_performance(&env, symbol_short!("rel_auto"), duration);
+    }
+
+    /// Manually releases funds for a program schedule (authorized payout key only).
+    /// Can be called before the release timestamp by authorized key.
     ///
     /// # Arguments
     /// * `env` - The contract environment
-    /// * `amount` - Amount of tokens to lock (in token's smallest denomination)
-    ///
-    /// # Returns
-    /// * `ProgramData` - Updated program data with new balance
+    /// * `program_id` - The program containing the schedule
+    /// * `schedule_id` - The schedule to release
     ///
     /// # Panics
-    /// * If amount is zero or negative
-    /// * If program is not initialized
+    /// * If program doesn't exist
+    /// * If caller is not authorized payout key
+    /// * If schedule doesn't exist
+    /// * If schedule is already released
     ///
     /// # State Changes
-    /// - Increases `total_funds` by amount
-    /// - Increases `remaining_balance` by amount
-    /// - Emits FundsLocked event
-    ///
-    /// # Prerequisites
-    /// Before calling this function:
-    /// 1. Caller must have sufficient token balance
-    /// 2. Caller must approve contract for token transfer
-    /// 3. Tokens must actually be transferred to contract
+    /// - Transfers tokens to recipient
+    /// - Updates schedule status to released
+    /// - Adds to release history
+    /// - Updates program remaining balance
+    /// - Emits ScheduleReleased event
     ///
-    /// # Security Considerations
-    /// - Amount must be positive
-    /// - This function doesn't perform the actual token transfer
-    /// - Caller is responsible for transferring tokens to contract
-    /// - Consider verifying contract balance matches recorded amount
-    /// - Multiple lock operations are additive (cumulative)
+    /// A denylisted or frozen recipient is skipped as in
+    /// `release_prog_schedule_automatic`.
     ///
-    /// # Events
-    /// Emits: `FundsLocked(program_id, amount, new_remaining_balance)`
+    /// # Authorization
+    /// - Only authorized payout key can call this function
     ///
     /// # Example
     /// ```rust
-    /// use soroban_sdk::token;
-    ///
-    /// // 1. Transfer tokens to contract
-    /// let amount = 10_000_0000000; // 10,000 USDC
-    /// token_client.transfer(
-    ///     &organizer,
-    ///     &contract_address,
-    ///     &amount
-    /// );
-    ///
-    /// // 2. Record the locked funds
-    /// let updated = escrow_client.lock_program_funds(&amount);
-    /// println!("Locked: {} USDC", amount / 10_000_000);
-    /// println!("Remaining: {}", updated.remaining_balance);
-    /// ```
-    ///
-    /// # Production Usage
-    /// ```bash
-    /// # 1. Transfer USDC to contract
-    /// stellar contract invoke \
-    ///   --id USDC_TOKEN_ID \
-    ///   --source ORGANIZER_KEY \
-    ///   -- transfer \
-    ///   --from ORGANIZER_ADDRESS \
-    ///   --to CONTRACT_ADDRESS \
-    ///   --amount 10000000000
-    ///
-    /// # 2. Record locked funds
-    /// stellar contract invoke \
-    ///   --id CONTRACT_ID \
-    ///   --source ORGANIZER_KEY \
-    ///   -- lock_program_funds \
-    ///   --amount 10000000000
+    /// // Authorized key can release early
+    /// escrow_client.release_program_schedule_manual(&"Hackathon2024", &1);
     /// ```
-    ///
-    /// # Gas Cost
-    /// Low - Storage update + event emission
-    ///
-    /// # Common Pitfalls
-    /// - Forgetting to transfer tokens before calling
-    /// -  Locking amount that exceeds actual contract balance
-    /// -  Not verifying contract received the tokens
+    pub fn release_program_schedule_manual(env: Env, program_id: String, schedule_id: u64) {
+        let start = env.ledger().timestamp();
 
-    pub fn lock_program_funds(env: Env, program_id: String, amount: i128) -> ProgramData {
-        // Apply rate limiting
-        anti_abuse::check_rate_limit(&env, env.current_contract_address());
+        // Get program data
+        let program_data: ProgramData =
+            program_storage::load(&env, &program_id).unwrap_or_else(|| panic!("Program not found"));
 
-        let start = env.ledger().timestamp();
-        let caller = env.current_contract_address();
+        // Apply the version pin and rate limiting to the authorized payout key
+        Self::guard_caller(&env, &program_data.authorized_payout_key);
 
-        // Check if contract is paused
-        if Self::is_paused_internal(&env) {
-            monitoring::track_operation(&env, symbol_short!("lock"), caller.clone(), false);
-            panic!("Contract is paused");
+        // Verify authorization
+        program_data.authorized_payout_key.require_auth();
+
+        Self::assert_dependencies_satisfied(&env, &program_id);
+
+        // Get schedule
+        if !env
+            .storage()
+            .persistent()
+            .has(&DataKey::ReleaseSchedule(program_id.clone(), schedule_id))
+        {
+            panic!("Schedule not found");
+        }
+
+        let mut schedule: ProgramReleaseSchedule = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ReleaseSchedule(program_id.clone(), schedule_id))
+            .unwrap();
+
+        // Check if already released
+        if schedule.released {
+            panic!("Schedule already released");
         }
 
-        // Validate amount
-        if amount <= 0 {
-            monitoring::track_operation(&env, symbol_short!("lock"), caller.clone(), false);
-            panic!("Amount must be greater than zero");
+        if Self::skip_blocked_schedule(&env, &program_id, &schedule) {
+            return;
         }
+        Self::enforce_funding_goal_internal(&env, &program_id, &program_data);
 
-        // Get program data
-        let program_key = DataKey::Program(program_id.clone());
-        let mut program_data: ProgramData = env
-            .storage()
-            .instance()
-            .get(&program_key)
-            .unwrap_or_else(|| {
-                monitoring::track_operation(&env, symbol_short!("lock"), caller.clone(), false);
-                panic!("Program not found")
-            });
+        // Get token client
+        let contract_address = env.current_contract_address();
+        let token_client = token::Client::new(&env, &program_data.token_address);
 
-        // Calculate and collect fee if enabled
-        let fee_config = Self::get_fee_config_internal(&env);
-        let fee_amount = if fee_config.fee_enabled && fee_config.lock_fee_rate > 0 {
-            Self::calculate_fee(amount, fee_config.lock_fee_rate)
-        } else {
-            0
-        };
-        let net_amount = amount - fee_amount;
+        // Enforce optional per-program spending limit for this window
+        Self::enforce_program_spending_limit_internal(
+            &env,
+            &program_id,
+            &program_data.token_address,
+            schedule.amount,
+        );
+        Self::enforce_daily_payout_cap_internal(&env, &program_id, schedule.amount);
 
-        // Update balances with net amount
-        program_data.total_funds += net_amount;
-        program_data.remaining_balance += net_amount;
+        // Transfer funds
+        token_client.transfer(&contract_address, &schedule.recipient, &schedule.amount);
 
-        // Emit fee collected event if applicable
-        if fee_amount > 0 {
-            env.events().publish(
-                (symbol_short!("fee"),),
-                (
-                    symbol_short!("lock"),
-                    fee_amount,
-                    fee_config.lock_fee_rate,
-                    fee_config.fee_recipient.clone(),
-                ),
-            );
-        }
+        // Update schedule
+        let now = env.ledger().timestamp();
+        schedule.released = true;
+        schedule.released_at = Some(now);
+        schedule.released_by = Some(program_data.authorized_payout_key.clone());
+        record_schedule_resolved(&env, &program_id, &schedule, true);
 
-        // Store updated data
-        env.storage().instance().set(&program_key, &program_data);
+        // Update program data
+        let mut updated_data = program_data.clone();
+        updated_data.remaining_balance -= schedule.amount;
 
-        // Emit FundsLocked event (with net amount after fee)
+        // Add to release history
+        let history_entry = ProgramReleaseHistory {
+            schedule_id,
+            program_id: program_id.clone(),
+            amount: schedule.amount,
+            recipient: schedule.recipient.clone(),
+            released_at: now,
+            released_by: program_data.authorized_payout_key.clone(),
+            release_type: ReleaseType::Manual,
+        };
+
+        let mut history: Vec<ProgramReleaseHistory> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ReleaseHistory(program_id.clone()))
+            .unwrap_or(vec![&env]);
+        history.push_back(history_entry);
+
+        // Store updates
+        env.storage().persistent().set(
+            &DataKey::ReleaseSchedule(program_id.clone(), schedule_id),
+            &schedule,
+        );
+        program_storage::save(&env, &updated_data);
+        env.storage()
+            .persistent()
+            .set(&DataKey::ReleaseHistory(program_id.clone()), &history);
+
+        // Emit program schedule released event
         env.events().publish(
-            (FUNDS_LOCKED,),
-            (
-                program_data.program_id.clone(),
-                net_amount,
-                program_data.remaining_balance,
-            ),
+            (PROG_SCHEDULE_RELEASED,),
+            ProgramScheduleReleased {
+                program_id: program_id.clone(),
+                schedule_id,
+                amount: schedule.amount,
+                recipient: schedule.recipient.clone(),
+                released_at: now,
+                released_by: program_data.authorized_payout_key.clone(),
+                release_type: ReleaseType::Manual,
+            },
         );
 
-        program_data
+        // Track successful operation
+        monitoring::track_operation(
+            &env,
+            symbol_short!("rel_man"),
+            program_data.authorized_payout_key,
+            true,
+        );
+
+        // Track performance
+        let duration = env.ledger().timestamp().saturating_sub(start);
+        monitoring::emit_performance(&env, symbol_short!("rel_man"), duration);
     }
 
     // ========================================================================
-    // Payout Functions
+    // View Functions (Read-only)
     // ========================================================================
 
-    /// Executes batch payouts to multiple recipients simultaneously.
+    /// Retrieves complete program information.
     ///
     /// # Arguments
     /// * `env` - The contract environment
-    /// * `recipients` - Vector of recipient addresses
-    /// * `amounts` - Vector of amounts (must match recipients length)
     ///
     /// # Returns
-    /// * `ProgramData` - Updated program data after payouts
+    /// * `ProgramData` - Complete program state including:
+    ///   - Program ID
+    ///   - Total funds locked
+    ///   - Remaining balance
+    ///   - Authorized payout key
+    ///   - Complete payout history
+    ///   - Token contract address
     ///
     /// # Panics
-    /// * If caller is not the authorized payout key
     /// * If program is not initialized
-    /// * If recipients and amounts vectors have different lengths
-    /// * If vectors are empty
-    /// * If any amount is zero or negative
-    /// * If total payout exceeds remaining balance
-    /// * If arithmetic overflow occurs
-    ///
-    /// # Authorization
-    /// - **CRITICAL**: Only authorized payout key can call
-    /// - Caller must be exact match to `authorized_payout_key`
-    ///
-    /// # State Changes
-    /// - Transfers tokens from contract to each recipient
-    /// - Adds PayoutRecord for each transfer to history
-    /// - Decreases `remaining_balance` by total payout amount
-    /// - Emits BatchPayout event
-    ///
-    /// # Atomicity
-    /// This operation is atomic - either all transfers succeed or all fail.
-    /// If any transfer fails, the entire batch is reverted.
-    ///
-    /// # Security Considerations
-    /// - Verify recipient addresses off-chain before calling
-    /// - Ensure amounts match winner rankings/criteria
-    /// - Total payout is calculated with overflow protection
-    /// - Balance check prevents overdraft
-    /// - All transfers are logged for audit trail
-    /// - Consider implementing payout limits for additional safety
     ///
-    /// # Events
-    /// Emits: `BatchPayout(program_id, recipient_count, total_amount, new_balance)`
+    /// # Use Cases
+    /// - Verifying program configuration
+    /// - Checking balances before payouts
+    /// - Auditing payout history
+    /// - Displaying program status in UI
     ///
     /// # Example
     /// ```rust
-    /// use soroban_sdk::{vec, Address};
-    ///
-    /// // Define winners and prizes
-    /// let winners = vec![
-    ///     &env,
-    ///     Address::from_string("GWINNER1..."), // 1st place
-    ///     Address::from_string("GWINNER2..."), // 2nd place
-    ///     Address::from_string("GWINNER3..."), // 3rd place
-    /// ];
-    ///
-    /// let prizes = vec![
-    ///     &env,
-    ///     5_000_0000000,  // $5,000 USDC
-    ///     3_000_0000000,  // $3,000 USDC
-    ///     2_000_0000000,  // $2,000 USDC
-    /// ];
-    ///
-    /// // Execute batch payout (only authorized backend can call)
-    /// let result = escrow_client.batch_payout(&winners, &prizes);
-    /// println!("Paid {} winners", winners.len());
-    /// println!("Remaining: {}", result.remaining_balance);
-    /// ```
-    ///
-    /// # Production Usage
-    /// ```bash
-    /// # Batch payout to 3 winners
-    /// stellar contract invoke \
-    ///   --id CONTRACT_ID \
-    ///   --source BACKEND_KEY \
-    ///   -- batch_payout \
-    ///   --recipients '["GWINNER1...", "GWINNER2...", "GWINNER3..."]' \
-    ///   --amounts '[5000000000, 3000000000, 2000000000]'
+    /// let info = escrow_client.get_program_info();
+    /// println!("Program: {}", info.program_id);
+    /// println!("Total Locked: {}", info.total_funds);
+    /// println!("Remaining: {}", info.remaining_balance);
+    /// println!("Payouts Made: {}", info.payout_history.len());
     /// ```
     ///
     /// # Gas Cost
-    /// High - Multiple token transfers + storage updates
-    /// Cost scales linearly with number of recipients
+    /// Very Low - Single storage read
+    pub fn get_program_info(env: Env, program_id: String) -> ProgramData {
+        program_storage::load(&env, &program_id)
+            .unwrap_or_else(|| panic!("Program not found"))
+    }
+
+    /// Returns the payout recorded under `reference` by
+    /// `batch_payout_with_references`, or `None` if there is none.
     ///
-    /// # Best Practices
-    /// 1. Verify all winner addresses before execution
-    /// 2. Double-check prize amounts match criteria
-    /// 3. Test on testnet with same number of recipients
-    /// 4. Monitor events for successful completion
-    /// 5. Keep batch size reasonable (recommend < 50 recipients)
+    /// If several payouts share a reference, the first one recorded is returned.
+    pub fn get_payout_by_reference(
+        env: Env,
+        program_id: String,
+        reference: String,
+    ) -> Option<PayoutRecord> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::PayoutReference(program_id, reference))
+    }
+
+    /// Returns `recipient`'s payouts from a program in the order they were
+    /// made, skipping `offset` and returning at most `limit` records.
     ///
-    /// # Limitations
-    /// - Maximum batch size limited by gas/resource limits
-    /// - For very large batches, consider multiple calls
-    /// - All amounts must be positive  
-    pub fn batch_payout(
+    /// Backed by a per-recipient index of positions in `payout_history`, so
+    /// the full history is never scanned.
+    pub fn get_payouts_for_recipient(
         env: Env,
         program_id: String,
-        recipients: Vec<Address>,
-        amounts: Vec<i128>,
-    ) -> ProgramData {
-        // Check if contract is paused
-        if Self::is_paused_internal(&env) {
-            panic!("Contract is paused");
+        recipient: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<PayoutRecord> {
+        let program = program_storage::load(&env, &program_id)
+            .unwrap_or_else(|| panic!("Program not found"));
+        program_storage::payouts_for_recipient(&env, &program, &recipient, offset, limit)
+    }
+
+    /// Returns gross payout totals grouped into `num_buckets` buckets of
+    /// `bucket_secs`, most recent first (e.g. `86_400, 30` for daily volume
+    /// over the last 30 days). Bucket sizes must be whole days; only the
+    /// last 90 days are retained.
+    pub fn get_payout_volume(
+        env: Env,
+        program_id: String,
+        bucket_secs: u64,
+        num_buckets: u32,
+    ) -> Vec<i128> {
+        payout_volume::get_volume(&env, &program_id, bucket_secs, num_buckets)
+    }
+
+    /// Pauses payouts for a program once more than `max_outflow_per_window`
+    /// is paid out within `window_secs` (admin only). The payout that trips
+    /// the guard still settles; later ones are rejected and a
+    /// `velocity_breach` event is emitted until `unpause_program` is called.
+    pub fn set_velocity_guard(
+        env: Env,
+        program_id: String,
+        max_outflow_per_window: i128,
+        window_secs: u64,
+    ) {
+        let admin = anti_abuse::get_admin(&env).expect("Admin not set");
+        admin.require_auth();
+
+        if !program_storage::exists(&env, &program_id) {
+            panic!("Program not found");
         }
+        velocity_guard::set_guard(&env, &program_id, max_outflow_per_window, window_secs);
+    }
 
-        // Apply rate limiting to the contract itself or the program
-        // We can't easily get the caller here without getting program data first
+    /// Returns the program's velocity guard, if one is configured.
+    pub fn get_velocity_guard(env: Env, program_id: String) -> Option<VelocityGuard> {
+        velocity_guard::get_guard(&env, &program_id)
+    }
 
-        // Get program data
-        let program_key = DataKey::Program(program_id.clone());
-        let program_data: ProgramData = env
-            .storage()
-            .instance()
-            .get(&program_key)
-            .unwrap_or_else(|| panic!("Program not found"));
+    /// Whether the program's payouts are paused by its velocity guard.
+    pub fn is_program_paused(env: Env, program_id: String) -> bool {
+        velocity_guard::is_paused(&env, &program_id)
+    }
+
+    /// Resumes payouts for a program paused by its velocity guard (admin only).
+    pub fn unpause_program(env: Env, program_id: String) {
+        let admin = anti_abuse::get_admin(&env).expect("Admin not set");
+        admin.require_auth();
 
-        // Apply rate limiting to the authorized payout key
-        anti_abuse::check_rate_limit(&env, program_data.authorized_payout_key.clone());
+        velocity_guard::unpause(&env, &program_id);
+    }
 
-        // Verify authorization - CRITICAL
-        program_data.authorized_payout_key.require_auth();
+    /// Caps the total a program may pay out in any trailing 24 hours (admin
+    /// only). A payout that would exceed it panics with "Daily payout cap
+    /// reached". Pass 0 to remove the cap.
+    pub fn set_daily_payout_cap(env: Env, program_id: String, cap: i128) {
+        let admin = anti_abuse::get_admin(&env).expect("Admin not set");
+        admin.require_auth();
 
-        // Validate inputs
-        if recipients.len() != amounts.len() {
-            panic!("Recipients and amounts vectors must have the same length");
+        if cap < 0 {
+            panic!("Daily payout cap must be non-negative");
         }
-
-        if recipients.is_empty() {
-            panic!("Cannot process empty batch");
+        if !program_storage::exists(&env, &program_id) {
+            panic!("Program not found");
         }
 
-        // Calculate total with overflow protection
-        let mut total_payout: i128 = 0;
-        for i in 0..amounts.len() {
-            let amount = amounts.get(i).unwrap();
-            if amount <= 0 {
-                panic!("All amounts must be greater than zero");
-            }
-            total_payout = total_payout
-                .checked_add(amount)
-                .unwrap_or_else(|| panic!("Payout amount overflow"));
+        let key = ProgramKey::DailyPayoutCap(program_id.clone());
+        if cap == 0 {
+            env.storage().instance().remove(&key);
+        } else {
+            env.storage().instance().set(&key, &cap);
         }
+        env.events()
+            .publish((symbol_short!("daily_cap"),), (program_id, cap));
+    }
 
-        // Validate balance
-        if total_payout > program_data.remaining_balance {
-            panic!(
-                "Insufficient balance: requested {}, available {}",
-                total_payout, program_data.remaining_balance
-            );
-        }
+    /// Returns the program's daily payout cap, if one is set.
+    pub fn get_daily_payout_cap(env: Env, program_id: String) -> Option<i128> {
+        env.storage()
+            .instance()
+            .get(&ProgramKey::DailyPayoutCap(program_id))
+    }
 
-        // Enforce optional per-program spending limit for this window
-        Self::enforce_program_spending_limit_internal(
-            &env,
-            &program_id,
-            &program_data.token_address,
-            total_payout,
-        );
+    /// Retrieves the remaining balance for a specific program.
+    ///
+    /// # Arguments
+    /// * `program_id` - The program ID to query
+    ///
+    /// # Returns
+    /// * `i128` - Remaining balance
+    ///
+    /// # Panics
+    /// * If program doesn't exist
+    pub fn get_remaining_balance(env: Env, program_id: String) -> i128 {
+        let program_data: ProgramData =
+            program_storage::load(&env, &program_id).unwrap_or_else(|| panic!("Program not found"));
 
-        // Calculate fees if enabled
-        let fee_config = Self::get_fee_config_internal(&env);
-        let mut total_fees: i128 = 0;
+        program_data.remaining_balance
+    }
 
-        // Execute transfers
-        let mut updated_history = program_data.payout_history.clone();
-        let timestamp = env.ledger().timestamp();
-        let contract_address = env.current_contract_address();
-        let token_client = token::Client::new(&env, &program_data.token_address);
+    /// Formats raw token units as a decimal string using the program token's
+    /// decimals, e.g. `12_500_000` → `"1.25"` for a 7-decimal token.
+    ///
+    /// # Panics
+    /// * If program doesn't exist
+    pub fn format_amount(env: Env, program_id: String, raw: i128) -> String {
+        let decimals = Self::program_token_decimals(&env, &program_id);
+        token_math::format_amount(&env, raw, decimals)
+            .unwrap_or_else(|| panic!("Unsupported token decimals"))
+    }
 
-        for i in 0..recipients.len() {
-            let recipient = recipients.get(i).unwrap();
-            let amount = amounts.get(i).unwrap();
+    /// Parses a decimal string into raw token units using the program token's
+    /// decimals. Fraction digits beyond the token's precision are truncated.
+    ///
+    /// # Panics
+    /// * If program doesn't exist
+    /// * If the string is not a valid decimal amount
+    pub fn parse_amount(env: Env, program_id: String, s: String) -> i128 {
+        let decimals = Self::program_token_decimals(&env, &program_id);
+        token_math::parse_amount(&s, decimals).unwrap_or_else(|| panic!("Invalid amount string"))
+    }
 
-            // Calculate fee for this payout
-            let fee_amount = if fee_config.fee_enabled && fee_config.payout_fee_rate > 0 {
-                Self::calculate_fee(amount, fee_config.payout_fee_rate)
-            } else {
-                0
-            };
-            let net_amount = amount - fee_amount;
-            total_fees += fee_amount;
+    /// Decimals of the program's token, queried once and cached per token.
+    fn program_token_decimals(env: &Env, program_id: &String) -> u32 {
+        let program_data: ProgramData =
+            program_storage::load(env, program_id).unwrap_or_else(|| panic!("Program not found"));
 
-            // Transfer net amount to recipient
-            token_client.transfer(&contract_address, &recipient.clone(), &net_amount);
+        let key = DataKey::TokenDecimals(program_data.token_address.clone());
+        if let Some(decimals) = env.storage().instance().get(&key) {
+            return decimals;
+        }
+        let decimals = token::Client::new(env, &program_data.token_address).decimals();
+        env.storage().instance().set(&key, &decimals);
+        decimals
+    }
 
-            // Transfer fee to fee recipient if applicable
-            if fee_amount > 0 {
-                token_client.transfer(&contract_address, &fee_config.fee_recipient, &fee_amount);
+    /// Update fee configuration (admin only - uses authorized_payout_key)
+    ///
+    /// # Arguments
+    /// * `lock_fee_rate` - Optional new lock fee rate (basis points)
+    /// * `payout_fee_rate` - Optional new payout fee rate (basis points)
+    /// * `fee_recipient` - Optional new fee recipient address
+    /// * `fee_enabled` - Optional fee enable/disable flag
+    /// * `nonce` - Must equal `get_admin_nonce()`; prevents replay
+    pub fn update_fee_config(
+        env: Env,
+        lock_fee_rate: Option<i128>,
+        payout_fee_rate: Option<i128>,
+        fee_recipient: Option<Address>,
+        fee_enabled: Option<bool>,
+        nonce: u64,
+    ) {
+        let admin = anti_abuse::get_admin(&env).expect("Admin not set");
+        admin.require_auth();
+        Self::consume_admin_nonce(&env, nonce);
+
+        let mut fee_config = Self::get_fee_config_internal(&env);
+
+        if let Some(rate) = lock_fee_rate {
+            if rate < 0 || rate > MAX_FEE_RATE {
+                panic!(
+                    "Invalid lock fee rate: must be between 0 and {}",
+                    MAX_FEE_RATE
+                );
             }
+            fee_config.lock_fee_rate = rate;
+        }
 
-            // Record payout (with net amount)
-            let payout_record = PayoutRecord {
-                recipient: recipient.clone(),
-                amount: net_amount,
-                timestamp,
-            };
-            updated_history.push_back(payout_record);
-            
-            // Record outflow for threshold monitoring
-            threshold_monitor::record_outflow(&env, amount);
+        if let Some(rate) = payout_fee_rate {
+            if rate < 0 || rate > MAX_FEE_RATE {
+                panic!(
+                    "Invalid payout fee rate: must be between 0 and {}",
+                    MAX_FEE_RATE
+                );
+            }
+            fee_config.payout_fee_rate = rate;
         }
 
-        // Emit fee collected event if applicable
-        if total_fees > 0 {
-            env.events().publish(
-                (symbol_short!("fee"),),
-                (
-                    symbol_short!("payout"),
-                    total_fees,
-                    fee_config.payout_fee_rate,
-                    fee_config.fee_recipient.clone(),
-                ),
-            );
+        if let Some(recipient) = fee_recipient {
+            fee_config.fee_recipient = recipient;
         }
 
-        // Update program data
-        let mut updated_data = program_data.clone();
-        updated_data.remaining_balance -= total_payout; // Total includes fees
-        updated_data.payout_history = updated_history;
+        if let Some(enabled) = fee_enabled {
+            fee_config.fee_enabled = enabled;
+        }
 
-        // Store updated data
-        env.storage().instance().set(&program_key, &updated_data);
+        env.storage().instance().set(&FEE_CONFIG, &fee_config);
 
-        // Emit event
+        // Emit fee config updated event
         env.events().publish(
-            (BATCH_PAYOUT,),
+            (symbol_short!("fee_cfg"),),
             (
-                program_id,
-                recipients.len() as u32,
-                total_payout,
-                updated_data.remaining_balance,
+                fee_config.lock_fee_rate,
+                fee_config.payout_fee_rate,
+                fee_config.fee_recipient,
+                fee_config.fee_enabled,
             ),
         );
-
-        updated_data
     }
 
-    /// Executes a single payout to one recipient.
-    ///
-    /// # Arguments
-    /// * `env` - The contract environment
-    /// * `recipient` - Address of the prize recipient
-    /// * `amount` - Amount to transfer (in token's smallest denomination)
-    ///
-    /// # Returns
-    /// * `ProgramData` - Updated program data after payout
-    ///
-    /// # Panics
-    /// * If caller is not the authorized payout key
-    /// * If program is not initialized
-    /// * If amount is zero or negative
-    /// * If amount exceeds remaining balance
-    ///
-    /// # Authorization
-    /// - Only authorized payout key can call this function
-    ///
-    /// # State Changes
-    /// - Transfers tokens from contract to recipient
-    /// - Adds PayoutRecord to history
-    /// - Decreases `remaining_balance` by amount
-    /// - Emits Payout event
-    ///
-    /// # Security Considerations
-    /// - Verify recipient address before calling
-    /// - Amount must be positive
-    /// - Balance check prevents overdraft
-    /// - Transfer is logged in payout history
-    ///
-    /// # Events
-    /// Emits: `Payout(program_id, recipient, amount, new_balance)`
-    ///
-    /// # Example
-    /// ```rust
-    /// use soroban_sdk::Address;
-    ///
-    /// let winner = Address::from_string("GWINNER...");
-    /// let prize = 1_000_0000000; // $1,000 USDC
+    /// Update the contract-wide lock and payout amount limits (admin only).
     ///
-    /// // Execute single payout
-    /// let result = escrow_client.single_payout(&winner, &prize);
-    /// println!("Paid {} to winner", prize);
-    /// ```
-    ///
-    /// # Gas Cost
-    /// Medium - Single token transfer + storage update
+    /// `0` disables a bound: a `0` minimum accepts any positive amount and a
+    /// `0` maximum accepts any amount at or above the minimum. When both
+    /// bounds of a pair are non-zero the minimum may not exceed the maximum.
+    /// Payout limits apply to each `batch_payout` entry individually.
     ///
-    /// # Use Cases
-    /// - Individual prize awards
-    /// - Bonus payments
-    /// - Late additions to prize pool distribution
-    pub fn single_payout(
+    /// # Arguments
+    /// * `nonce` - Must equal `get_admin_nonce()`; prevents replay
+    pub fn update_amount_limits(
         env: Env,
-        program_id: String,
-        recipient: Address,
-        amount: i128,
-    ) -> ProgramData {
-        // Check if contract is paused
-        if Self::is_paused_internal(&env) {
-            panic!("Contract is paused");
+        min_lock_amount: i128,
+        max_lock_amount: i128,
+        min_payout: i128,
+        max_payout: i128,
+        nonce: u64,
+    ) -> AmountLimits {
+        let admin = anti_abuse::get_admin(&env).expect("Admin not set");
+        admin.require_auth();
+        Self::consume_admin_nonce(&env, nonce);
+
+        for (min, max) in [(min_lock_amount, max_lock_amount), (min_payout, max_payout)] {
+            if min < 0 || max < 0 {
+                panic!("Amount limits cannot be negative");
+            }
+            if max != 0 && min > max {
+                panic!("Minimum amount cannot exceed maximum");
+            }
+        }
+
+        let limits = AmountLimits {
+            min_lock_amount,
+            max_lock_amount,
+            min_payout,
+            max_payout,
+        };
+        env.storage().instance().set(&AMOUNT_LIMITS, &limits);
+
+        env.events().publish(
+            (symbol_short!("amt_lim"),),
+            (min_lock_amount, max_lock_amount, min_payout, max_payout),
+        );
+
+        limits
+    }
+
+    /// Get the current amount limits; all zero (unbounded) if never set.
+    pub fn get_amount_limits(env: Env) -> AmountLimits {
+        env.storage()
+            .instance()
+            .get(&AMOUNT_LIMITS)
+            .unwrap_or_default()
+    }
+
+    fn enforce_amount_limits(amount: i128, min: i128, max: i128) {
+        if min != 0 && amount < min {
+            panic!("Amount below minimum");
+        }
+        if max != 0 && amount > max {
+            panic!("Amount above maximum");
+        }
+    }
+
+    /// Get current fee configuration (view function)
+    pub fn get_fee_config(env: Env) -> FeeConfig {
+        Self::get_fee_config_internal(&env)
+    }
+
+    /// Lists the lock and payout fees taken in `token`, oldest first,
+    /// skipping `offset` and returning at most `limit` records. A batch
+    /// payout's fees are recorded as one entry.
+    pub fn get_fee_history(env: Env, token: Address, offset: u32, limit: u32) -> Vec<FeeRecord> {
+        let count = Self::get_fee_history_count(env.clone(), token.clone());
+        let end = offset.saturating_add(limit).min(count);
+        let mut records = vec![&env];
+        for index in offset..end {
+            let record: FeeRecord = env
+                .storage()
+                .persistent()
+                .get(&ProgramKey::FeeRecordAt(token.clone(), index))
+                .unwrap();
+            records.push_back(record);
+        }
+        records
+    }
+
+    /// Number of fee records kept for `token`.
+    pub fn get_fee_history_count(env: Env, token: Address) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&ProgramKey::FeeCount(token))
+            .unwrap_or(0)
+    }
+
+    /// Overrides the global payout fee rate for one program. Requires the
+    /// program's assigned admin, or the contract admin if none was assigned;
+    /// the payout key alone cannot change the platform fee.
+    ///
+    /// # Arguments
+    /// * `program_id` - The program to override
+    /// * `bps` - Payout fee rate in basis points, capped like the global rate
+    pub fn set_program_fee_bps(env: Env, program_id: String, bps: i128) {
+        Self::assigned_admin_authority(&env, &program_id).require_auth();
+        if !(0..=MAX_FEE_RATE).contains(&bps) {
+            panic!(
+                "Invalid payout fee rate: must be between 0 and {}",
+                MAX_FEE_RATE
+            );
         }
 
-        // Get program data
-        let program_key = DataKey::Program(program_id.clone());
-        let program_data: ProgramData = env
+        env.storage()
+            .instance()
+            .set(&DataKey::ProgramFeeBps(program_id.clone()), &bps);
+        env.events()
+            .publish((symbol_short!("prg_fee"),), (program_id, bps));
+    }
+
+    /// Removes a program's fee override so it falls back to the global rate
+    /// (admin only).
+    pub fn clear_program_fee_bps(env: Env, program_id: String) {
+        let admin = anti_abuse::get_admin(&env).expect("Admin not set");
+        admin.require_auth();
+
+        env.storage()
+            .instance()
+            .remove(&DataKey::ProgramFeeBps(program_id));
+    }
+
+    /// Returns a program's payout fee override, if any.
+    pub fn get_program_fee_bps(env: Env, program_id: String) -> Option<i128> {
+        env.storage()
+            .instance()
+            .get(&DataKey::ProgramFeeBps(program_id))
+    }
+
+    /// Caps any single payout at `bps / 10000` of the program's remaining
+    /// balance when the payout is made, so no one payout can drain most of
+    /// the pool. Batch entries are checked against the balance before the
+    /// batch. Passing 0 removes the cap. Only the program admin may call this.
+    pub fn set_max_payout_fraction_bps(env: Env, program_id: String, bps: u32) {
+        Self::require_program_admin_auth(&env, &program_id);
+        if bps > 10_000 {
+            panic!("Payout fraction cannot exceed 10000 bps");
+        }
+
+        let key = ProgramKey::MaxPayoutFractionBps(program_id.clone());
+        if bps == 0 {
+            env.storage().instance().remove(&key);
+        } else {
+            env.storage().instance().set(&key, &bps);
+        }
+        env.events()
+            .publish((symbol_short!("max_frac"),), (program_id, bps));
+    }
+
+    /// Returns a program's payout fraction cap in basis points (0 if unset).
+    pub fn get_max_payout_fraction_bps(env: Env, program_id: String) -> u32 {
+        env.storage()
+            .instance()
+            .get(&ProgramKey::MaxPayoutFractionBps(program_id))
+            .unwrap_or(0)
+    }
+
+    /// Largest single payout the fraction cap allows out of `balance`, if
+    /// the program has a cap.
+    fn payout_fraction_limit(env: &Env, program_id: &String, balance: i128) -> Option<i128> {
+        let bps = Self::get_max_payout_fraction_bps(env.clone(), program_id.clone());
+        if bps == 0 {
+            return None;
+        }
+        Some(balance.max(0) * bps as i128 / 10_000)
+    }
+
+    fn enforce_payout_fraction(amount: i128, limit: Option<i128>) {
+        if let Some(limit) = limit {
+            if amount > limit {
+                panic!("Payout exceeds balance fraction limit");
+            }
+        }
+    }
+
+    /// Gets the total number of programs registered.
+    ///
+    /// # Returns
+    /// * `u32` - Count of registered programs
+    pub fn get_program_count(env: Env) -> u32 {
+        let registry: Vec<String> = env
             .storage()
             .instance()
-            .get(&program_key)
-            .unwrap_or_else(|| panic!("Program not found"));
+            .get(&PROGRAM_REGISTRY)
+            .unwrap_or(vec![&env]);
 
-        program_data.authorized_payout_key.require_auth();
-        // Apply rate limiting to the authorized payout key
-        anti_abuse::check_rate_limit(&env, program_data.authorized_payout_key.clone());
+        registry.len()
+    }
 
-        // Enforce optional per-program spending limit for this window
-        Self::enforce_program_spending_limit_internal(
-            &env,
-            &program_id,
-            &program_data.token_address,
-            amount,
-        );
+    // ========================================================================
+    // Monitoring & Analytics Functions
+    // ========================================================================
 
-        // Validate amount
-        if amount <= 0 {
-            panic!("Amount must be greater than zero");
+    /// Health check - returns contract health status
+    pub fn health_check(env: Env) -> monitoring::HealthStatus {
+        monitoring::health_check(&env)
+    }
+
+    /// Composite health status - one call for monitoring and alerting
+    pub fn get_contract_health(env: Env) -> ContractHealth {
+        let programs = Self::list_programs(env.clone());
+
+        let mut total_programs_paused: u32 = 0;
+        for program_id in programs.iter() {
+            let flags = Self::get_operation_flags_internal(&env, &program_id);
+            if !(flags.allow_lock && flags.allow_payout && flags.allow_claim)
+                || velocity_guard::is_paused(&env, &program_id)
+            {
+                total_programs_paused += 1;
+            }
         }
 
-        // Validate balance
-        if amount > program_data.remaining_balance {
-            panic!(
-                "Insufficient balance: requested {}, available {}",
-                amount, program_data.remaining_balance
-            );
+        ContractHealth {
+            admin_set: anti_abuse::get_admin(&env).is_some(),
+            global_paused: Self::is_paused_internal(&env),
+            program_count: programs.len(),
+            total_programs_paused,
+            rate_limit_configured: anti_abuse::is_configured(&env),
+            version: String::from_str(&env, monitoring::CONTRACT_VERSION),
         }
+    }
 
-        // Calculate and collect fee if enabled
-        let fee_config = Self::get_fee_config_internal(&env);
-        let fee_amount = if fee_config.fee_enabled && fee_config.payout_fee_rate > 0 {
-            Self::calculate_fee(amount, fee_config.payout_fee_rate)
-        } else {
-            0
-        };
-        let net_amount = amount - fee_amount;
+    /// Version metadata for client-side compatibility checks
+    pub fn get_contract_info(_env: Env) -> ContractInfo {
+        ContractInfo {
+            version: monitoring::VERSION_MAJOR * 1_000_000
+                + monitoring::VERSION_MINOR * 1_000
+                + monitoring::VERSION_PATCH,
+            major: monitoring::VERSION_MAJOR,
+            minor: monitoring::VERSION_MINOR,
+            patch: monitoring::VERSION_PATCH,
+            build_tag: symbol_short!("stable"),
+        }
+    }
 
-        // Transfer net amount to recipient
-        // Transfer tokens
-        let contract_address = env.current_contract_address();
-        let token_client = token::Client::new(&env, &program_data.token_address);
-        token_client.transfer(&contract_address, &recipient, &net_amount);
+    /// Panics with "Contract version too old" unless this contract's numeric
+    /// version (see `get_contract_info`) is at least `min_version`. Clients
+    /// simulate it before submitting calls that need newer behavior.
+    pub fn require_min_version(env: Env, min_version: u32) {
+        if Self::get_contract_info(env).version < min_version {
+            panic!("Contract version too old");
+        }
+    }
 
-        // Transfer fee to fee recipient if applicable
-        if fee_amount > 0 {
-            token_client.transfer(&contract_address, &fee_config.fee_recipient, &fee_amount);
-            env.events().publish(
-                (symbol_short!("fee"),),
-                (
-                    symbol_short!("payout"),
-                    fee_amount,
-                    fee_config.payout_fee_rate,
-                    fee_config.fee_recipient.clone(),
-                ),
-            );
+    /// Pins the contract version `caller`'s mutating calls require. While
+    /// set, every rate-limited entry point acting for `caller` (program
+    /// initialization, locks, payouts) panics with "Contract version too
+    /// old" if this contract is older, so a client built against newer
+    /// behavior cannot run against a stale deployment. `None` clears it.
+    pub fn set_caller_min_version(env: Env, caller: Address, min_version: Option<u32>) {
+        caller.require_auth();
+        let key = ProgramKey::CallerMinVersion(caller);
+        match min_version {
+            Some(version) => env.storage().persistent().set(&key, &version),
+            None => env.storage().persistent().remove(&key),
         }
+    }
 
-        // Record payout (with net amount after fee)
-        let timestamp = env.ledger().timestamp();
-        let payout_record = PayoutRecord {
-            recipient: recipient.clone(),
-            amount: net_amount,
-            timestamp,
-        };
+    /// Gets the contract version pinned by `caller`, if any.
+    pub fn get_caller_min_version(env: Env, caller: Address) -> Option<u32> {
+        env.storage()
+            .persistent()
+            .get(&ProgramKey::CallerMinVersion(caller))
+    }
 
-        let mut updated_history = program_data.payout_history.clone();
-        updated_history.push_back(payout_record);
+    /// Shared guard of the mutating entry points: enforces `caller`'s pinned
+    /// minimum version, then its rate limit.
+    fn guard_caller(env: &Env, caller: &Address) {
+        if let Some(min_version) = Self::get_caller_min_version(env.clone(), caller.clone()) {
+            Self::require_min_version(env.clone(), min_version);
+        }
+        anti_abuse::check_rate_limit(env, caller.clone());
+    }
 
-        // Update program data
-        let mut updated_data = program_data.clone();
-        updated_data.remaining_balance -= amount; // Total amount (includes fee)
-        updated_data.payout_history = updated_history;
+    /// Get analytics - returns usage analytics
+    pub fn get_analytics(env: Env) -> monitoring::Analytics {
+        monitoring::get_analytics(&env)
+    }
 
-        // Store updated data
-        env.storage().instance().set(&program_key, &updated_data);
+    /// Get operation counts - lifetime totals of successful inits, locks,
+    /// payouts, claims and cancels
+    pub fn get_operation_counts(env: Env) -> monitoring::OperationCounts {
+        monitoring::get_operation_counts(&env)
+    }
 
-        // Emit Payout event (with net amount after fee)
-        // Emit event
-        env.events().publish(
-            (PAYOUT,),
-            (
-                program_id,
-                recipient,
-                net_amount,
-                updated_data.remaining_balance,
-            ),
-        );
+    /// Get state snapshot - returns current state
+    pub fn get_state_snapshot(env: Env) -> monitoring::StateSnapshot {
+        monitoring::get_state_snapshot(&env)
+    }
 
-        updated_data
+    /// Get performance stats for a function
+    pub fn get_performance_stats(env: Env, function_name: Symbol) -> monitoring::PerformanceStats {
+        monitoring::get_performance_stats(&env, function_name)
     }
 
     // ========================================================================
-    // Release Schedule Functions
+    // Program Spending Limit Helpers & Admin
     // ========================================================================
 
-    /// Creates a time-based release schedule for a program.
-    ///
-    /// # Arguments
-    /// * `env` - The contract environment
-    /// * `program_id` - The program to create schedule for
-    /// * `amount` - Amount to release (in token's smallest denomination)
-    /// * `release_timestamp` - Unix timestamp when funds become available
-    /// * `recipient` - Address that will receive the funds
-    ///
-    /// # Returns
-    /// * `ProgramData` - Updated program data
-    ///
-    /// # Panics
-    /// * If program is not initialized
-    /// * If caller is not authorized payout key
-    /// * If amount is invalid
-    /// * If timestamp is in the past
-    /// * If amount exceeds remaining balance
-    ///
-    /// # State Changes
-    /// - Creates ProgramReleaseSchedule record
-    /// - Updates next schedule ID
-    /// - Emits ScheduleCreated event
-    ///
-    /// # Authorization
-    /// - Only authorized payout key can call this function
+    /// Internal helper that enforces the optional per-program spending limit.
     ///
-    /// # Example
-    /// ```rust
-    /// let now = env.ledger().timestamp();
-    /// let release_time = now + (30 * 24 * 60 * 60); // 30 days from now
-    /// escrow_client.create_program_release_schedule(
-    ///     &"Hackathon2024",
-    ///     &500_0000000, // 500 tokens
-    ///     &release_time,
-    ///     &winner_address
-    /// );
-    /// ```
-    pub fn create_program_release_schedule(
-        env: Env,
-        program_id: String,
+    /// If no limit is configured or the limit is disabled, this is a no-op.
+    /// Otherwise, it tracks the released amount in a fixed-size time window
+    /// and panics if the new total would exceed the configured maximum.
+    fn enforce_program_spending_limit_internal(
+        env: &Env,
+        program_id: &String,
+        token: &Address,
         amount: i128,
-        release_timestamp: u64,
-        recipient: Address,
-    ) -> ProgramData {
-        let start = env.ledger().timestamp();
+    ) {
+        // Zero or negative amounts are ignored by the limiter; other
+        // validation happens in the caller.
+        if amount <= 0 {
+            return;
+        }
 
-        // Check if contract is paused
-        if Self::is_paused_internal(&env) {
-            panic!("Contract is paused");
+        let cfg_key = DataKey::ProgramSpendingConfig(program_id.clone(), token.clone());
+        let config: Option<ProgramSpendingConfig> = env.storage().instance().get(&cfg_key);
+        let config = match config {
+            Some(cfg)
+                if cfg.enabled && cfg.window_size > 0 && cfg.max_amount > 0 =>
+            {
+                cfg
+            }
+            _ => {
+                // No active limit configured for this program/token.
+                return;
+            }
+        };
+
+        let now = env.ledger().timestamp();
+        let state_key = DataKey::ProgramSpendingState(program_id.clone(), token.clone());
+        let mut state: ProgramSpendingState = env
+            .storage()
+            .instance()
+            .get(&state_key)
+            .unwrap_or(ProgramSpendingState {
+                window_start: now,
+                amount_released: 0,
+            });
+
+        // If we're outside the current window, start a new one.
+        if now
+            .saturating_sub(state.window_start)
+            >= config.window_size
+        {
+            state.window_start = now;
+            state.amount_released = 0;
+        }
+
+        let new_total = state
+            .amount_released
+            .checked_add(amount)
+            .unwrap_or_else(|| panic!("Spending amount overflow"));
+
+        if new_total > config.max_amount {
+            env.events().publish(
+                (symbol_short!("limit"), symbol_short!("prog_spend")),
+                (
+                    program_id.clone(),
+                    token.clone(),
+                    amount,
+                    new_total,
+                    config.max_amount,
+                    config.window_size,
+                ),
+            );
+            panic!("Program spending limit exceeded for current window");
         }
 
-        // Get program data
-        let program_key = DataKey::Program(program_id.clone());
-        let program_data: ProgramData = env
+        state.amount_released = new_total;
+        env.storage().instance().set(&state_key, &state);
+    }
+
+    /// Panics if paying `amount` now would push the program's payouts over
+    /// its daily cap within the trailing 24 hours; otherwise records it.
+    fn enforce_daily_payout_cap_internal(env: &Env, program_id: &String, amount: i128) {
+        let cap: i128 = match env
             .storage()
             .instance()
-            .get(&program_key)
-            .unwrap_or_else(|| panic!("Program not found"));
-
-        // Apply rate limiting to the authorized payout key
-        anti_abuse::check_rate_limit(&env, program_data.authorized_payout_key.clone());
-
-        // Verify authorization
-        program_data.authorized_payout_key.require_auth();
-
-        // Validate amount
-        if amount <= 0 {
-            panic!("Amount must be greater than zero");
-        }
+            .get(&ProgramKey::DailyPayoutCap(program_id.clone()))
+        {
+            Some(cap) => cap,
+            None => return,
+        };
 
-        // Validate timestamp
-        if release_timestamp <= env.ledger().timestamp() {
-            panic!("Release timestamp must be in the future");
+        let now = env.ledger().timestamp();
+        let log_key = ProgramKey::DailyPayoutLog(program_id.clone());
+        let log: Vec<(u64, i128)> = env
+            .storage()
+            .persistent()
+            .get(&log_key)
+            .unwrap_or(vec![env]);
+        let mut recent: Vec<(u64, i128)> = vec![env];
+        let mut total = amount;
+        for (paid_at, paid) in log.iter() {
+            if now.saturating_sub(paid_at) < DAILY_PAYOUT_WINDOW_SECS {
+                total = total.saturating_add(paid);
+                recent.push_back((paid_at, paid));
+            }
         }
-
-        // Check sufficient remaining balance
-        let scheduled_total = get_program_total_scheduled_amount(&env, &program_id);
-        if scheduled_total + amount > program_data.remaining_balance {
-            panic!("Insufficient balance for scheduled amount");
+        if total > cap {
+            panic!("Daily payout cap reached");
         }
 
-        // Get next schedule ID
-        let schedule_id: u64 = env
-            .storage()
-            .persistent()
-            .get(&DataKey::NextScheduleId(program_id.clone()))
-            .unwrap_or(1);
+        recent.push_back((now, amount));
+        env.storage().persistent().set(&log_key, &recent);
+    }
 
-        // Create release schedule
-        let schedule = ProgramReleaseSchedule {
-            schedule_id,
-            amount,
-            release_timestamp,
-            recipient: recipient.clone(),
-            released: false,
-            released_at: None,
-            released_by: None,
-        };
+    // ========================================================================
+    // Anti-Abuse Administrative Functions
+    // ========================================================================
 
-        // Store schedule
-        env.storage().persistent().set(
-            &DataKey::ReleaseSchedule(program_id.clone(), schedule_id),
-            &schedule,
-        );
+    /// Sets the administrative address for anti-abuse configuration.
+    /// Can only be called once or by the existing admin.
+    /// `nonce` must equal `get_admin_nonce()`.
+    pub fn set_admin(env: Env, new_admin: Address, nonce: u64) {
+        if let Some(current_admin) = anti_abuse::get_admin(&env) {
+            current_admin.require_auth();
+        }
+        Self::consume_admin_nonce(&env, nonce);
+        anti_abuse::set_admin(&env, new_admin);
+    }
 
-        // Update next schedule ID
-        env.storage().persistent().set(
-            &DataKey::NextScheduleId(program_id.clone()),
-            &(schedule_id + 1),
-        );
+    /// Updates the rate limit configuration.
+    /// Only the admin can call this. `nonce` must equal `get_admin_nonce()`.
+    pub fn update_rate_limit_config(
+        env: Env,
+        window_size: u64,
+        max_operations: u32,
+        cooldown_period: u64,
+        nonce: u64,
+    ) {
+        let admin = anti_abuse::get_admin(&env).expect("Admin not set");
+        admin.require_auth();
+        Self::consume_admin_nonce(&env, nonce);
 
-        // Emit program schedule created event
-        env.events().publish(
-            (PROG_SCHEDULE_CREATED,),
-            ProgramScheduleCreated {
-                program_id: program_id.clone(),
-                schedule_id,
-                amount,
-                release_timestamp,
-                recipient: recipient.clone(),
-                created_by: program_data.authorized_payout_key.clone(),
+        anti_abuse::set_config(
+            &env,
+            anti_abuse::AntiAbuseConfig {
+                window_size,
+                max_operations,
+                cooldown_period,
             },
         );
+    }
 
-        // Track successful operation
-        monitoring::track_operation(
-            &env,
-            symbol_short!("create_p"),
-            program_data.authorized_payout_key,
-            true,
-        );
+    /// Returns the nonce the next sensitive admin call must supply.
+    pub fn get_admin_nonce(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::AdminNonce)
+            .unwrap_or(0)
+    }
 
-        // Track performance
-        let duration = env.ledger().timestamp().saturating_sub(start);
-        monitoring::emit_performance(&env, symbol_short!("create_p"), duration);
+    /// Rejects a replayed admin call: `nonce` must match the stored admin
+    /// nonce, which is then incremented.
+    fn consume_admin_nonce(env: &Env, nonce: u64) {
+        let expected = Self::get_admin_nonce(env.clone());
+        if nonce != expected {
+            panic!("Stale nonce");
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::AdminNonce, &(expected + 1));
+    }
 
-        // Return updated program data
-        let updated_data: ProgramData = env.storage().instance().get(&program_key).unwrap();
-        updated_data
+    /// Adds or removes an address from the whitelist.
+    /// Only the admin can call this.
+    pub fn set_whitelist(env: Env, address: Address, whitelisted: bool) {
+        let admin = anti_abuse::get_admin(&env).expect("Admin not set");
+        admin.require_auth();
+
+        anti_abuse::set_whitelist(&env, address, whitelisted);
     }
 
-    /// Automatically releases funds for program schedules that are due.
-    /// Can be called by anyone after the release timestamp has passed.
-    ///
-    /// # Arguments
-    /// * `env` - The contract environment
-    /// * `program_id` - The program to check for due schedules
-    /// * `schedule_id` - The specific schedule to release
-    ///
-    /// # Panics
-    /// * If program doesn't exist
-    /// * If schedule doesn't exist
-    /// * If schedule is already released
-    /// * If schedule is not yet due
-    ///
-    /// # State Changes
-    /// - Transfers tokens to recipient
-    /// - Updates schedule status to released
-    /// - Adds to release history
-    /// - Updates program remaining balance
-    /// - Emits ScheduleReleased event
-    ///
-    /// # Example
-    /// ```rust
-    /// // Anyone can call this after the timestamp
-    /// escrow_client.release_program_schedule_automatic(&"Hackathon2024", &1);
-    /// ```
-    pub fn release_prog_schedule_automatic(env: Env, program_id: String, schedule_id: u64) {
-        let start = env.ledger().timestamp();
-        let caller = env.current_contract_address();
+    /// Checks if an address is whitelisted.
+    pub fn is_whitelisted(env: Env, address: Address) -> bool {
+        anti_abuse::is_whitelisted(&env, address)
+    }
 
-        // Check if contract is paused
-        if Self::is_paused_internal(&env) {
-            panic!("Contract is paused");
-        }
+    /// Gets the current rate limit configuration.
+    pub fn get_rate_limit_config(env: Env) -> anti_abuse::AntiAbuseConfig {
+        anti_abuse::get_config(&env)
+    }
 
-        // Get program data
-        let program_key = DataKey::Program(program_id.clone());
-        let program_data: ProgramData = env
-            .storage()
-            .instance()
-            .get(&program_key)
-            .unwrap_or_else(|| panic!("Program not found"));
+    /// Switches between fixed-window, leaky-bucket and backoff rate limiting.
+    /// Only the admin can call this.
+    pub fn set_rate_limit_mode(env: Env, mode: anti_abuse::RateLimitMode) {
+        let admin = anti_abuse::get_admin(&env).expect("Admin not set");
+        admin.require_auth();
 
-        // Get schedule
-        if !env
-            .storage()
-            .persistent()
-            .has(&DataKey::ReleaseSchedule(program_id.clone(), schedule_id))
-        {
-            panic!("Schedule not found");
-        }
+        anti_abuse::set_mode(&env, mode);
+    }
 
-        let mut schedule: ProgramReleaseSchedule = env
-            .storage()
-            .persistent()
-            .get(&DataKey::ReleaseSchedule(program_id.clone(), schedule_id))
-            .unwrap();
+    /// Gets the active rate limiting mode.
+    pub fn get_rate_limit_mode(env: Env) -> anti_abuse::RateLimitMode {
+        anti_abuse::get_mode(&env)
+    }
 
-        // Check if already released
-        if schedule.released {
-            panic!("Schedule already released");
-        }
+    /// Configures cooldown escalation for `RateLimitMode::Backoff`: each
+    /// operation within twice the cooldown of the last raises the address's
+    /// level, and each level past `grace_violations` multiplies its cooldown
+    /// by `multiplier`, up to `max_cooldown`.
+    /// Only the admin can call this.
+    pub fn set_rate_limit_backoff(
+        env: Env,
+        multiplier: u32,
+        max_cooldown: u64,
+        grace_violations: u32,
+    ) {
+        let admin = anti_abuse::get_admin(&env).expect("Admin not set");
+        admin.require_auth();
 
-        // Check if due for release
-        let now = env.ledger().timestamp();
-        if now < schedule.release_timestamp {
-            panic!("Schedule not yet due for release");
+        if multiplier < 2 {
+            panic!("Backoff multiplier must be at least 2");
         }
 
-        // Get token client
-        let contract_address = env.current_contract_address();
-        let token_client = token::Client::new(&env, &program_data.token_address);
-
-        // Enforce optional per-program spending limit for this window
-        Self::enforce_program_spending_limit_internal(
+        anti_abuse::set_backoff_config(
             &env,
-            &program_id,
-            &program_data.token_address,
-            schedule.amount,
+            anti_abuse::BackoffConfig {
+                multiplier,
+                max_cooldown,
+                grace_violations,
+            },
         );
+    }
 
-        // Transfer funds
-        token_client.transfer(&contract_address, &schedule.recipient, &schedule.amount);
-
-        // Update schedule
-        schedule.released = true;
-        schedule.released_at = Some(now);
-        schedule.released_by = Some(env.current_contract_address());
+    /// Gets the cooldown escalation used in backoff mode.
+    pub fn get_rate_limit_backoff(env: Env) -> anti_abuse::BackoffConfig {
+        anti_abuse::get_backoff_config(&env)
+    }
 
-        // Update program data
-        let mut updated_data = program_data.clone();
-        updated_data.remaining_balance -= schedule.amount;
+    /// Returns `address`'s rate limit standing: operations left in the
+    /// window, its effective cooldown and backoff level, and when the
+    /// current cooldown ends.
+    pub fn get_rate_limit_status(env: Env, address: Address) -> anti_abuse::RateLimitStatus {
+        anti_abuse::get_status(&env, address)
+    }
 
-        // Add to release history
-        let history_entry = ProgramReleaseHistory {
-            schedule_id,
-            program_id: program_id.clone(),
-            amount: schedule.amount,
-            recipient: schedule.recipient.clone(),
-            released_at: now,
-            released_by: env.current_contract_address(),
-            release_type: ReleaseType::Automatic,
-        };
+    /// Resets `address`'s rate limit window and cooldown so it can operate
+    /// again immediately, e.g. after a legitimate burst. Only the admin can
+    /// call this.
+    pub fn reset_rate_limit_window(env: Env, address: Address) {
+        let admin = anti_abuse::get_admin(&env).expect("Admin not set");
+        admin.require_auth();
 
-        let mut history: Vec<ProgramReleaseHistory> = env
-            .storage()
-            .persistent()
-            .get(&DataKey::ReleaseHistory(program_id.clone()))
-            .unwrap_or(vec![&env]);
-        history.push_back(history_entry);
+        anti_abuse::reset(&env, address.clone());
 
-        // Store updates
-        env.storage().persistent().set(
-            &DataKey::ReleaseSchedule(program_id.clone(), schedule_id),
-            &schedule,
+        env.events().publish(
+            (Symbol::new(&env, "rate_limit_reset"), address),
+            (admin, env.ledger().timestamp()),
         );
-        env.storage().instance().set(&program_key, &updated_data);
-        env.storage()
-            .persistent()
-            .set(&DataKey::ReleaseHistory(program_id.clone()), &history);
+    }
 
-        // Emit program schedule released event
-        env.events().publish(
-            (PROG_SCHEDULE_RELEASED,),
-            ProgramScheduleReleased {
-                program_id: program_id.clone(),
-                schedule_id,
-                amount: schedule.amount,
-                recipient: schedule.recipient.clone(),
-                released_at: now,
-                released_by: env.current_contract_address(),
-                release_type: ReleaseType::Automatic,
+    /// Sets the factors applied to `max_operations` for Basic, Verified and
+    /// Premium tier addresses. Only the admin can call this.
+    pub fn set_tier_rate_multipliers(env: Env, basic: u32, verified: u32, premium: u32) {
+        let admin = anti_abuse::get_admin(&env).expect("Admin not set");
+        admin.require_auth();
+
+        if basic == 0 || verified == 0 || premium == 0 {
+            panic!("Tier multiplier must be at least 1");
+        }
+
+        anti_abuse::set_tier_multipliers(
+            &env,
+            anti_abuse::TierRateMultipliers {
+                basic,
+                verified,
+                premium,
             },
         );
+    }
 
-        // Track successful operation
-        monitoring::track_operation(&env, symbol_short!("rel_auto"), caller, true);
+    /// Gets the per-tier rate limit multipliers.
+    pub fn get_tier_rate_multipliers(env: Env) -> anti_abuse::TierRateMultipliers {
+        anti_abuse::get_tier_multipliers(&env)
+    }
 
-        // Track performance
-        let duration = env.ledger().timestamp().saturating_sub(start);
-        monitoring::emit_performance(&env, symbol_short!("rel_auto"), duration);
+    /// Records the identity tier of a rate-limited address.
+    /// Only the admin can call this.
+    pub fn set_address_tier(env: Env, address: Address, tier: anti_abuse::IdentityTier) {
+        let admin = anti_abuse::get_admin(&env).expect("Admin not set");
+        admin.require_auth();
+
+        anti_abuse::set_tier(&env, address, tier);
     }
 
-    /// Manually releases funds for a program schedule (authorized payout key only).
-    /// Can be called before the release timestamp by authorized key.
-    ///
-    /// # Arguments
-    /// * `env` - The contract environment
-    /// * `program_id` - The program containing the schedule
-    /// * `schedule_id` - The schedule to release
-    ///
-    /// # Panics
-    /// * If program doesn't exist
-    /// * If caller is not authorized payout key
-    /// * If schedule doesn't exist
-    /// * If schedule is already released
-    ///
-    /// # State Changes
-    /// - Transfers tokens to recipient
-    /// - Updates schedule status to released
-    /// - Adds to release history
-    /// - Updates program remaining balance
-    /// - Emits ScheduleReleased event
-    ///
-    /// # Authorization
-    /// - Only authorized payout key can call this function
+    /// Gets the identity tier of an address (Unverified if never set).
+    pub fn get_address_tier(env: Env, address: Address) -> anti_abuse::IdentityTier {
+        anti_abuse::get_tier(&env, address)
+    }
+
+    /// Returns how many rate-limited operations `address` may perform now.
+    /// In leaky-bucket mode this is the current bucket level.
     ///
-    /// # Example
-    /// ```rust
-    /// // Authorized key can release early
-    /// escrow_client.release_program_schedule_manual(&"Hackathon2024", &1);
-    /// ```
-    pub fn release_program_schedule_manual(env: Env, program_id: String, schedule_id: u64) {
-        let start = env.ledger().timestamp();
+    /// Takes the address explicitly: buckets and windows are kept per
+    /// address, and a Soroban call has no implicit caller a no-argument
+    /// view could read.
+    pub fn get_available_ops(env: Env, address: Address) -> u32 {
+        if anti_abuse::is_whitelisted(&env, address.clone()) {
+            return u32::MAX;
+        }
+        anti_abuse::get_available_ops(&env, address)
+    }
 
-        // Get program data
-        let program_key = DataKey::Program(program_id.clone());
-        let program_data: ProgramData = env
-            .storage()
-            .instance()
-            .get(&program_key)
-            .unwrap_or_else(|| panic!("Program not found"));
+    /// Returns how many operations `address` can perform right now before the
+    /// rate limiter rejects one, so schedulers can pace bursts without probing.
+    pub fn operations_until_blocked(env: Env, address: Address) -> u32 {
+        anti_abuse::operations_until_blocked(&env, address)
+    }
 
-        // Apply rate limiting to the authorized payout key
-        anti_abuse::check_rate_limit(&env, program_data.authorized_payout_key.clone());
+    /// Checks if `address` can perform `count` operations right now without
+    /// hitting the rate limit or cooldown.
+    pub fn can_perform_operations(env: Env, address: Address, count: u32) -> bool {
+        count <= anti_abuse::operations_until_blocked(&env, address)
+    }
 
-        // Verify authorization
-        program_data.authorized_payout_key.require_auth();
+    // ========================================================================
+    // Program Spending Limit Admin & Views
+    // ========================================================================
 
-        Self::assert_dependencies_satisfied(&env, &program_id);
+    /// Caps how much any single program holding `token` may have locked at once.
+    ///
+    /// The cap applies to each program's `remaining_balance` independently, not
+    /// to the contract's total holdings, bounding the blast radius of any one
+    /// program. Only the admin can call this.
+    pub fn set_max_program_tvl(env: Env, token: Address, cap: i128) {
+        let admin = anti_abuse::get_admin(&env).expect("Admin not set");
+        admin.require_auth();
 
-        // Get schedule
-        if !env
-            .storage()
-            .persistent()
-            .has(&DataKey::ReleaseSchedule(program_id.clone(), schedule_id))
-        {
-            panic!("Schedule not found");
+        if cap <= 0 {
+            panic!("TVL cap must be greater than zero");
         }
 
-        let mut schedule: ProgramReleaseSchedule = env
-            .storage()
-            .persistent()
-            .get(&DataKey::ReleaseSchedule(program_id.clone(), schedule_id))
-            .unwrap();
+        env.storage()
+            .instance()
+            .set(&DataKey::MaxProgramTvl(token.clone()), &cap);
 
-        // Check if already released
-        if schedule.released {
-            panic!("Schedule already released");
+        env.events()
+            .publish((symbol_short!("tvl_cap"),), (token, cap));
+    }
+
+    /// Returns the per-program TVL cap configured for `token`, if any.
+    pub fn get_max_program_tvl(env: Env, token: Address) -> Option<i128> {
+        env.storage().instance().get(&DataKey::MaxProgramTvl(token))
+    }
+
+    /// Sets the largest balance of `token` that `close_program` treats as
+    /// dust and sweeps instead of refusing to close. Only the admin can call
+    /// this.
+    pub fn set_dust_threshold(env: Env, token: Address, threshold: i128) {
+        let admin = anti_abuse::get_admin(&env).expect("Admin not set");
+        admin.require_auth();
+
+        if threshold < 0 {
+            panic!("Dust threshold cannot be negative");
         }
 
-        // Get token client
-        let contract_address = env.current_contract_address();
-        let token_client = token::Client::new(&env, &program_data.token_address);
+        env.storage()
+            .instance()
+            .set(&DataKey::DustThreshold(token.clone()), &threshold);
 
-        // Enforce optional per-program spending limit for this window
-        Self::enforce_program_spending_limit_internal(
-            &env,
-            &program_id,
-            &program_data.token_address,
-            schedule.amount,
-        );
+        env.events()
+            .publish((symbol_short!("dust_set"),), (token, threshold));
+    }
 
-        // Transfer funds
-        token_client.transfer(&contract_address, &schedule.recipient, &schedule.amount);
+    /// Returns the dust threshold for `token` (default 0: no dust is swept).
+    pub fn get_dust_threshold(env: Env, token: Address) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::DustThreshold(token))
+            .unwrap_or(0)
+    }
 
-        // Update schedule
-        let now = env.ledger().timestamp();
-        schedule.released = true;
-        schedule.released_at = Some(now);
-        schedule.released_by = Some(program_data.authorized_payout_key.clone());
+    /// Configure or update the optional per-program spending limit for the
+    /// current token.
+    ///
+    /// This function can only be called by the program's authorized payout key.
+    /// Passing `enabled = false` stores the configuration but disables
+    /// enforcement until re-enabled.
+    pub fn set_program_spending_limit(
+        env: Env,
+        program_id: String,
+        window_size: u64,
+        max_amount: i128,
+        enabled: bool,
+    ) {
+        if max_amount < 0 {
+            panic!("max_amount must be non-negative");
+        }
 
-        // Update program data
-        let mut updated_data = program_data.clone();
-        updated_data.remaining_balance -= schedule.amount;
+        let program_data: ProgramData =
+            program_storage::load(&env, &program_id).unwrap_or_else(|| panic!("Program not found"));
 
-        // Add to release history
-        let history_entry = ProgramReleaseHistory {
-            schedule_id,
-            program_id: program_id.clone(),
-            amount: schedule.amount,
-            recipient: schedule.recipient.clone(),
-            released_at: now,
-            released_by: program_data.authorized_payout_key.clone(),
-            release_type: ReleaseType::Manual,
+        // Only the authorized payout key for this program may update limits.
+        program_data.authorized_payout_key.require_auth();
+
+        let cfg = ProgramSpendingConfig {
+            window_size,
+            max_amount,
+            enabled,
         };
 
-        let mut history: Vec<ProgramReleaseHistory> = env
-            .storage()
-            .persistent()
-            .get(&DataKey::ReleaseHistory(program_id.clone()))
-            .unwrap_or(vec![&env]);
-        history.push_back(history_entry);
+        let token = program_data.token_address.clone();
+        let cfg_key = DataKey::ProgramSpendingConfig(program_id, token);
+        env.storage().instance().set(&cfg_key, &cfg);
+    }
 
-        // Store updates
-        env.storage().persistent().set(
-            &DataKey::ReleaseSchedule(program_id.clone(), schedule_id),
-            &schedule,
-        );
-        env.storage().instance().set(&program_key, &updated_data);
-        env.storage()
-            .persistent()
-            .set(&DataKey::ReleaseHistory(program_id.clone()), &history);
+    /// Returns the current spending limit configuration for a program and its
+    /// configured token, if any.
+    pub fn get_program_spending_limit(
+        env: Env,
+        program_id: String,
+    ) -> Option<ProgramSpendingConfig> {
+        let program_data: ProgramData =
+            program_storage::load(&env, &program_id).unwrap_or_else(|| panic!("Program not found"));
 
-        // Emit program schedule released event
-        env.events().publish(
-            (PROG_SCHEDULE_RELEASED,),
-            ProgramScheduleReleased {
-                program_id: program_id.clone(),
-                schedule_id,
-                amount: schedule.amount,
-                recipient: schedule.recipient.clone(),
-                released_at: now,
-                released_by: program_data.authorized_payout_key.clone(),
-                release_type: ReleaseType::Manual,
-            },
-        );
+        let cfg_key =
+            DataKey::ProgramSpendingConfig(program_id, program_data.token_address.clone());
+        env.storage().instance().get(&cfg_key)
+    }
 
-        // Track successful operation
-        monitoring::track_operation(
-            &env,
-            symbol_short!("rel_man"),
-            program_data.authorized_payout_key,
-            true,
-        );
+    /// Returns the current spending state (window start and amount released)
+    /// for a program/token pair, if any state has been recorded yet.
+    pub fn get_program_spending_state(
+        env: Env,
+        program_id: String,
+    ) -> Option<ProgramSpendingState> {
+        let program_data: ProgramData =
+            program_storage::load(&env, &program_id).unwrap_or_else(|| panic!("Program not found"));
 
-        // Track performance
-        let duration = env.ledger().timestamp().saturating_sub(start);
-        monitoring::emit_performance(&env, symbol_short!("rel_man"), duration);
+        let state_key =
+            DataKey::ProgramSpendingState(program_id, program_data.token_address.clone());
+        env.storage().instance().get(&state_key)
     }
 
     // ========================================================================
-    // View Functions (Read-only)
+    // Sponsor Split
     // ========================================================================
 
-    /// Retrieves complete program information.
-    ///
-    /// # Arguments
-    /// * `env` - The contract environment
-    ///
-    /// # Returns
-    /// * `ProgramData` - Complete program state including:
-    ///   - Program ID
-    ///   - Total funds locked
-    ///   - Remaining balance
-    ///   - Authorized payout key
-    ///   - Complete payout history
-    ///   - Token contract address
-    ///
-    /// # Panics
-    /// * If program is not initialized
-    ///
-    /// # Use Cases
-    /// - Verifying program configuration
-    /// - Checking balances before payouts
-    /// - Auditing payout history
-    /// - Displaying program status in UI
-    ///
-    /// # Example
-    /// ```rust
-    /// let info = escrow_client.get_program_info();
-    /// println!("Program: {}", info.program_id);
-    /// println!("Total Locked: {}", info.total_funds);
-    /// println!("Remaining: {}", info.remaining_balance);
-    /// println!("Payouts Made: {}", info.payout_history.len());
-    /// ```
-    ///
-    /// # Gas Cost
-    /// Very Low - Single storage read
-    pub fn get_program_info(env: Env, program_id: String) -> ProgramData {
-        let program_key = DataKey::Program(program_id);
-        env.storage()
-            .instance()
-            .get(&program_key)
-            .unwrap_or_else(|| panic!("Program not found"))
+    /// Routes `bps / 10000` of every `single_payout` (after protocol fees) to
+    /// `sponsor`; the recipient's claim holds the remainder. Passing `bps = 0`
+    /// removes the split. Only the program's authorized payout key may call this.
+    pub fn set_sponsor_split(env: Env, program_id: String, sponsor: Address, bps: u32) {
+        if bps as i128 > BASIS_POINTS {
+            panic!("Sponsor split cannot exceed 10000 bps");
+        }
+
+        let program_data: ProgramData =
+            program_storage::load(&env, &program_id).unwrap_or_else(|| panic!("Program not found"));
+
+        program_data.authorized_payout_key.require_auth();
+
+        let split_key = DataKey::SponsorSplit(program_id);
+        if bps == 0 {
+            env.storage().instance().remove(&split_key);
+        } else {
+            env.storage()
+                .instance()
+                .set(&split_key, &SponsorSplit { sponsor, bps });
+        }
     }
 
-    /// Retrieves the remaining balance for a specific program.
-    ///
-    /// # Arguments
-    /// * `program_id` - The program ID to query
-    ///
-    /// # Returns
-    /// * `i128` - Remaining balance
-    ///
-    /// # Panics
-    /// * If program doesn't exist
-    pub fn get_remaining_balance(env: Env, program_id: String) -> i128 {
-        let program_key = DataKey::Program(program_id);
-        let program_data: ProgramData = env
-            .storage()
+    /// Returns the sponsor split configured for a program, if any.
+    pub fn get_sponsor_split(env: Env, program_id: String) -> Option<SponsorSplit> {
+        env.storage()
             .instance()
-            .get(&program_key)
-            .unwrap_or_else(|| panic!("Program not found"));
-
-        program_data.remaining_balance
+            .get(&DataKey::SponsorSplit(program_id))
     }
 
-    /// Update fee configuration (admin only - uses authorized_payout_key)
-    ///
-    /// # Arguments
-    /// * `lock_fee_rate` - Optional new lock fee rate (basis points)
-    /// * `payout_fee_rate` - Optional new payout fee rate (basis points)
-    /// * `fee_recipient` - Optional new fee recipient address
-    /// * `fee_enabled` - Optional fee enable/disable flag
-    pub fn update_fee_config(
-        env: Env,
-        lock_fee_rate: Option<i128>,
-        payout_fee_rate: Option<i128>,
-        fee_recipient: Option<Address>,
-        fee_enabled: Option<bool>,
-    ) {
-        let admin = anti_abuse::get_admin(&env).expect("Admin not set");
-        admin.require_auth();
+    // ========================================================================
+    // Minimum Program Funding
+    // ========================================================================
 
-        let mut fee_config = Self::get_fee_config_internal(&env);
+    /// Holds back payouts until the program's cumulative `total_funds` reaches
+    /// `min`, so early winners are not paid before the full pot is secured.
+    /// Passing `min = 0` removes the requirement. Only the program's authorized
+    /// payout key may call this.
+    pub fn set_min_program_funding(env: Env, program_id: String, min: i128) {
+        if min < 0 {
+            panic!("Minimum funding cannot be negative");
+        }
+        Self::require_program_payout_auth(&env, &program_id);
 
-        if let Some(rate) = lock_fee_rate {
-            if rate < 0 || rate > MAX_FEE_RATE {
-                panic!(
-                    "Invalid lock fee rate: must be between 0 and {}",
-                    MAX_FEE_RATE
-                );
-            }
-            fee_config.lock_fee_rate = rate;
+        let key = DataKey::MinProgramFunding(program_id.clone());
+        if min == 0 {
+            env.storage().instance().remove(&key);
+        } else {
+            env.storage().instance().set(&key, &min);
         }
+        env.events()
+            .publish((symbol_short!("min_fund"),), (program_id, min));
+    }
 
-        if let Some(rate) = payout_fee_rate {
-            if rate < 0 || rate > MAX_FEE_RATE {
-                panic!(
-                    "Invalid payout fee rate: must be between 0 and {}",
-                    MAX_FEE_RATE
-                );
+    /// Returns the minimum funding a program must reach before payouts, if set.
+    pub fn get_min_program_funding(env: Env, program_id: String) -> Option<i128> {
+        env.storage()
+            .instance()
+            .get(&DataKey::MinProgramFunding(program_id))
+    }
+
+    fn enforce_min_funding_internal(env: &Env, program_id: &String, program_data: &ProgramData) {
+        let min: Option<i128> = env
+            .storage()
+            .instance()
+            .get(&DataKey::MinProgramFunding(program_id.clone()));
+        if let Some(min) = min {
+            if program_data.total_funds < min {
+                panic!("Program underfunded");
             }
-            fee_config.payout_fee_rate = rate;
         }
+    }
 
-        if let Some(recipient) = fee_recipient {
-            fee_config.fee_recipient = recipient;
-        }
+    // ========================================================================
+    // Low Balance Warning
+    // ========================================================================
 
-        if let Some(enabled) = fee_enabled {
-            fee_config.fee_enabled = enabled;
+    /// Emits a `low_balance` event when a payout takes the program's
+    /// `remaining_balance` below `threshold`. The event fires once per
+    /// crossing and re-arms when the balance is topped back up to the
+    /// threshold. Passing `threshold = 0` removes the warning. Only the
+    /// program's authorized payout key may call this.
+    pub fn set_low_balance_threshold(env: Env, program_id: String, threshold: i128) {
+        if threshold < 0 {
+            panic!("Threshold cannot be negative");
         }
+        let program_data = Self::require_program_payout_auth(&env, &program_id);
 
-        env.storage().instance().set(&FEE_CONFIG, &fee_config);
-
-        // Emit fee config updated event
-        env.events().publish(
-            (symbol_short!("fee_cfg"),),
-            (
-                fee_config.lock_fee_rate,
-                fee_config.payout_fee_rate,
-                fee_config.fee_recipient,
-                fee_config.fee_enabled,
-            ),
-        );
+        let key = ProgramKey::LowBalanceThreshold(program_id.clone());
+        if threshold == 0 {
+            env.storage().instance().remove(&key);
+        } else {
+            env.storage().instance().set(&key, &threshold);
+        }
+        // Start armed only if the balance is not already below the new threshold
+        let below = program_data.remaining_balance < threshold;
+        Self::set_low_balance_alerted(&env, &program_id, below);
     }
 
-    /// Get current fee configuration (view function)
-    pub fn get_fee_config(env: Env) -> FeeConfig {
-        Self::get_fee_config_internal(&env)
+    /// Returns the program's low balance threshold, if set.
+    pub fn get_low_balance_threshold(env: Env, program_id: String) -> Option<i128> {
+        env.storage()
+            .instance()
+            .get(&ProgramKey::LowBalanceThreshold(program_id))
     }
 
-    /// Gets the total number of programs registered.
-    ///
-    /// # Returns
-    /// * `u32` - Count of registered programs
-    pub fn get_program_count(env: Env) -> u32 {
-        let registry: Vec<String> = env
+    fn check_low_balance_internal(env: &Env, program_id: &String, remaining_balance: i128) {
+        let threshold: Option<i128> = env
             .storage()
             .instance()
-            .get(&PROGRAM_REGISTRY)
-            .unwrap_or(vec![&env]);
+            .get(&ProgramKey::LowBalanceThreshold(program_id.clone()));
+        let threshold = match threshold {
+            Some(threshold) => threshold,
+            None => return,
+        };
 
-        registry.len()
+        let alerted = env
+            .storage()
+            .instance()
+            .has(&ProgramKey::LowBalanceAlerted(program_id.clone()));
+        if remaining_balance >= threshold {
+            if alerted {
+                Self::set_low_balance_alerted(env, program_id, false);
+            }
+        } else if !alerted {
+            Self::set_low_balance_alerted(env, program_id, true);
+            env.events().publish(
+                (Symbol::new(env, "low_balance"),),
+                (program_id.clone(), remaining_balance, threshold),
+            );
+        }
+    }
+
+    fn set_low_balance_alerted(env: &Env, program_id: &String, alerted: bool) {
+        let key = ProgramKey::LowBalanceAlerted(program_id.clone());
+        if alerted {
+            env.storage().instance().set(&key, &true);
+        } else {
+            env.storage().instance().remove(&key);
+        }
     }
 
     // ========================================================================
-    // Monitoring & Analytics Functions
+    // Open Funding
     // ========================================================================
 
-    /// Health check - returns contract health status
-    pub fn health_check(env: Env) -> monitoring::HealthStatus {
-        monitoring::health_check(&env)
+    /// Lets any address add to the program's prize pool through
+    /// `contribute_program_funds`. Only the program's authorized payout key
+    /// may call this.
+    pub fn set_open_funding(env: Env, program_id: String, enabled: bool) {
+        Self::require_program_payout_auth(&env, &program_id);
+
+        let key = DataKey::OpenFunding(program_id.clone());
+        if enabled {
+            env.storage().instance().set(&key, &true);
+        } else {
+            env.storage().instance().remove(&key);
+        }
+        env.events()
+            .publish((symbol_short!("open_fund"),), (program_id, enabled));
     }
 
-    /// Get analytics - returns usage analytics
-    pub fn get_analytics(env: Env) -> monitoring::Analytics {
-        monitoring::get_analytics(&env)
+    /// Whether anyone may contribute funds to the program.
+    pub fn is_open_funding(env: Env, program_id: String) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey::OpenFunding(program_id))
+            .unwrap_or(false)
     }
 
-    /// Get state snapshot - returns current state
-    pub fn get_state_snapshot(env: Env) -> monitoring::StateSnapshot {
-        monitoring::get_state_snapshot(&env)
+    /// Pulls `amount` from `funder` into the escrow and locks it for the
+    /// program, crediting the funder's contribution total with the amount
+    /// left after any lock fee. Requires open funding to be enabled for the
+    /// program.
+    pub fn contribute_program_funds(
+        env: Env,
+        program_id: String,
+        funder: Address,
+        amount: i128,
+    ) -> ProgramData {
+        if !Self::is_open_funding(env.clone(), program_id.clone()) {
+            panic!("Open funding disabled for program");
+        }
+        funder.require_auth();
+        if amount <= 0 {
+            panic!("Amount must be greater than zero");
+        }
+
+        let program_data: ProgramData =
+            program_storage::load(&env, &program_id).unwrap_or_else(|| panic!("Program not found"));
+        if Self::funding_campaign_failed(&env, &program_id, &program_data) {
+            panic!("Funding campaign ended");
+        }
+        token::Client::new(&env, &program_data.token_address).transfer(
+            &funder,
+            &env.current_contract_address(),
+            &amount,
+        );
+        let funds_before = program_data.total_funds;
+        let program_data = Self::lock_program_funds(env.clone(), program_id.clone(), amount);
+        let credited = program_data.total_funds - funds_before;
+
+        let key = DataKey::Contributions(program_id.clone());
+        let mut contributions: Vec<Contribution> =
+            env.storage().persistent().get(&key).unwrap_or(vec![&env]);
+        match contributions.iter().position(|c| c.funder == funder) {
+            Some(i) => {
+                let mut entry = contributions.get(i as u32).unwrap();
+                entry.amount += credited;
+                contributions.set(i as u32, entry);
+            }
+            None => contributions.push_back(Contribution {
+                funder: funder.clone(),
+                amount: credited,
+            }),
+        }
+        env.storage().persistent().set(&key, &contributions);
+
+        env.events()
+            .publish((symbol_short!("contrib"),), (program_id, funder, amount));
+
+        program_data
     }
 
-    /// Get performance stats for a function
-    pub fn get_performance_stats(env: Env, function_name: Symbol) -> monitoring::PerformanceStats {
-        monitoring::get_performance_stats(&env, function_name)
+    /// Sets a crowdfunding goal for the program. Payouts, withdrawals,
+    /// multisig payouts and schedule releases stay blocked until
+    /// `total_funds` reaches `goal`; if it has not by `deadline_ts`, each
+    /// contributor may reclaim their contribution with `refund_contribution`.
+    /// The goal cannot be changed while contributions are recorded. Only the
+    /// program's authorized payout key may call this.
+    pub fn set_funding_goal(env: Env, program_id: String, goal: i128, deadline_ts: u64) {
+        Self::require_program_payout_auth(&env, &program_id);
+        if Self::has_contributions(&env, &program_id) {
+            panic!("Funding goal locked by contributions");
+        }
+        if goal <= 0 || deadline_ts <= env.ledger().timestamp() {
+            panic!("Invalid funding goal");
+        }
+
+        let funding_goal = FundingGoal {
+            goal,
+            deadline: deadline_ts,
+        };
+        env.storage()
+            .instance()
+            .set(&DataKey::FundingGoal(program_id.clone()), &funding_goal);
+        env.events().publish(
+            (symbol_short!("fund_goal"),),
+            (program_id, goal, deadline_ts),
+        );
     }
 
-    // ========================================================================
-    // Program Spending Limit Helpers & Admin
-    // ========================================================================
+    /// Returns the program's crowdfunding goal, if any.
+    pub fn get_funding_goal(env: Env, program_id: String) -> Option<FundingGoal> {
+        env.storage()
+            .instance()
+            .get(&DataKey::FundingGoal(program_id))
+    }
 
-    /// Internal helper that enforces the optional per-program spending limit.
-    ///
-    /// If no limit is configured or the limit is disabled, this is a no-op.
-    /// Otherwise, it tracks the released amount in a fixed-size time window
-    /// and panics if the new total would exceed the configured maximum.
-    fn enforce_program_spending_limit_internal(
-        env: &Env,
-        program_id: &String,
-        token: &Address,
-        amount: i128,
-    ) {
-        // Zero or negative amounts are ignored by the limiter; other
-        // validation happens in the caller.
-        if amount <= 0 {
-            return;
+    /// Returns `funder`'s contribution after the program missed its funding
+    /// goal, and removes it from the program's balance. Returns the amount
+    /// refunded.
+    pub fn refund_contribution(env: Env, program_id: String, funder: Address) -> i128 {
+        funder.require_auth();
+
+        let mut program_data: ProgramData =
+            program_storage::load(&env, &program_id).unwrap_or_else(|| panic!("Program not found"));
+        if !Self::funding_campaign_failed(&env, &program_id, &program_data) {
+            panic!("Refunds not available");
         }
 
-        let cfg_key = DataKey::ProgramSpendingConfig(program_id.clone(), token.clone());
-        let config: Option<ProgramSpendingConfig> = env.storage().instance().get(&cfg_key);
-        let config = match config {
-            Some(cfg)
-                if cfg.enabled && cfg.window_size > 0 && cfg.max_amount > 0 =>
-            {
-                cfg
-            }
-            _ => {
-                // No active limit configured for this program/token.
-                return;
+        let key = DataKey::Contributions(program_id.clone());
+        let mut contributions: Vec<Contribution> =
+            env.storage().persistent().get(&key).unwrap_or(vec![&env]);
+        let index = contributions
+            .iter()
+            .position(|c| c.funder == funder)
+            .unwrap_or_else(|| panic!("No contribution to refund"));
+        let amount = contributions.get(index as u32).unwrap().amount;
+        contributions.remove(index as u32);
+        env.storage().persistent().set(&key, &contributions);
+
+        // Outflows are blocked until the goal is met, so the net
+        // contribution is still on the books.
+        if amount > program_data.remaining_balance {
+            panic!("Insufficient program balance");
+        }
+        program_data.remaining_balance -= amount;
+        program_data.total_funds -= amount;
+        program_storage::save(&env, &program_data);
+
+        token::Client::new(&env, &program_data.token_address).transfer(
+            &env.current_contract_address(),
+            &funder,
+            &amount,
+        );
+
+        env.events()
+            .publish((symbol_short!("ctb_rfnd"),), (program_id, funder, amount));
+
+        amount
+    }
+
+    fn funding_campaign_failed(env: &Env, program_id: &String, program_data: &ProgramData) -> bool {
+        let goal: Option<FundingGoal> = env
+            .storage()
+            .instance()
+            .get(&DataKey::FundingGoal(program_id.clone()));
+        match goal {
+            Some(goal) => {
+                program_data.total_funds < goal.goal && env.ledger().timestamp() >= goal.deadline
             }
-        };
+            None => false,
+        }
+    }
 
-        let now = env.ledger().timestamp();
-        let state_key = DataKey::ProgramSpendingState(program_id.clone(), token.clone());
-        let mut state: ProgramSpendingState = env
+    /// Blocks any outflow of program funds while the funding goal is unmet.
+    /// Once a failed campaign has refunded every contributor, the funds left
+    /// belong to the organizer and are released again.
+    fn enforce_funding_goal_internal(env: &Env, program_id: &String, program_data: &ProgramData) {
+        let goal: Option<FundingGoal> = env
             .storage()
             .instance()
-            .get(&state_key)
-            .unwrap_or(ProgramSpendingState {
-                window_start: now,
-                amount_released: 0,
-            });
+            .get(&DataKey::FundingGoal(program_id.clone()));
+        if let Some(goal) = goal {
+            if program_data.total_funds < goal.goal {
+                let refunded = env.ledger().timestamp() >= goal.deadline
+                    && !Self::has_contributions(env, program_id);
+                if !refunded {
+                    panic!("Funding goal not met");
+                }
+            }
+        }
+    }
 
-        // If we're outside the current window, start a new one.
-        if now
-            .saturating_sub(state.window_start)
-            >= config.window_size
-        {
-            state.window_start = now;
-            state.amount_released = 0;
+    fn has_contributions(env: &Env, program_id: &String) -> bool {
+        env.storage()
+            .persistent()
+            .get::<_, Vec<Contribution>>(&DataKey::Contributions(program_id.clone()))
+            .map(|c| !c.is_empty())
+            .unwrap_or(false)
+    }
+
+    /// Lists per-funder contribution totals for a program in order of first
+    /// contribution, skipping `offset` and returning at most `limit` entries.
+    pub fn get_contributions(
+        env: Env,
+        program_id: String,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<Contribution> {
+        let contributions: Vec<Contribution> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Contributions(program_id))
+            .unwrap_or(vec![&env]);
+        let end = offset.saturating_add(limit).min(contributions.len());
+        if offset >= end {
+            return vec![&env];
         }
+        contributions.slice(offset..end)
+    }
 
-        let new_total = state
-            .amount_released
-            .checked_add(amount)
-            .unwrap_or_else(|| panic!("Spending amount overflow"));
+    // ========================================================================
+    // Schedule Limits
+    // ========================================================================
 
-        if new_total > config.max_amount {
-            env.events().publish(
-                (symbol_short!("limit"), symbol_short!("prog_spend")),
-                (
-                    program_id.clone(),
-                    token.clone(),
-                    amount,
-                    new_total,
-                    config.max_amount,
-                    config.window_size,
-                ),
-            );
-            panic!("Program spending limit exceeded for current window");
+    /// Caps how many unreleased release schedules a program may hold at once.
+    /// Released and cancelled schedules do not count. Passing `max = 0`
+    /// removes the cap. Only the program's authorized payout key may call this.
+    pub fn set_max_schedules(env: Env, program_id: String, max: u32) {
+        Self::require_program_payout_auth(&env, &program_id);
+
+        let key = DataKey::MaxSchedules(program_id);
+        if max == 0 {
+            env.storage().instance().remove(&key);
+        } else {
+            env.storage().instance().set(&key, &max);
         }
+    }
 
-        state.amount_released = new_total;
-        env.storage().instance().set(&state_key, &state);
+    /// Returns a program's schedule cap, if any.
+    pub fn get_max_schedules(env: Env, program_id: String) -> Option<u32> {
+        env.storage()
+            .instance()
+            .get(&DataKey::MaxSchedules(program_id))
     }
 
     // ========================================================================
-    // Anti-Abuse Administrative Functions
+    // Auto-Claim Recipients
     // ========================================================================
 
-    /// Sets the administrative address for anti-abuse configuration.
-    /// Can only be called once or by the existing admin.
-    pub fn set_admin(env: Env, new_admin: Address) {
-        if let Some(current_admin) = anti_abuse::get_admin(&env) {
-            current_admin.require_auth();
-        }
-        anti_abuse::set_admin(&env, new_admin);
+    /// Marks `recipient` as trusted for a program: `single_payout` to them
+    /// transfers immediately instead of creating a pending claim.
+    /// Only the program's authorized payout key may call this.
+    pub fn add_auto_claim_recipient(env: Env, program_id: String, recipient: Address) {
+        Self::require_program_payout_auth(&env, &program_id);
+        env.storage()
+            .instance()
+            .set(&DataKey::AutoClaimRecipient(program_id.clone(), recipient.clone()), &true);
+        env.events()
+            .publish((symbol_short!("auto_add"),), (program_id, recipient));
     }
 
-    /// Updates the rate limit configuration.
-    /// Only the admin can call this.
-    pub fn update_rate_limit_config(
-        env: Env,
-        window_size: u64,
-        max_operations: u32,
-        cooldown_period: u64,
-    ) {
-        let admin = anti_abuse::get_admin(&env).expect("Admin not set");
-        admin.require_auth();
+    /// Removes `recipient` from a program's auto-claim recipients.
+    /// Only the program's authorized payout key may call this.
+    pub fn remove_auto_claim_recipient(env: Env, program_id: String, recipient: Address) {
+        Self::require_program_payout_auth(&env, &program_id);
+        env.storage()
+            .instance()
+            .remove(&DataKey::AutoClaimRecipient(program_id.clone(), recipient.clone()));
+        env.events()
+            .publish((symbol_short!("auto_rm"),), (program_id, recipient));
+    }
 
-        anti_abuse::set_config(
-            &env,
-            anti_abuse::AntiAbuseConfig {
-                window_size,
-                max_operations,
-                cooldown_period,
-            },
-        );
+    /// Checks if `recipient` is an auto-claim recipient of a program.
+    pub fn is_auto_claim_recipient(env: Env, program_id: String, recipient: Address) -> bool {
+        Self::is_auto_claim_recipient_internal(&env, &program_id, &recipient)
     }
 
-    /// Adds or removes an address from the whitelist.
-    /// Only the admin can call this.
-    pub fn set_whitelist(env: Env, address: Address, whitelisted: bool) {
-        let admin = anti_abuse::get_admin(&env).expect("Admin not set");
-        admin.require_auth();
+    fn is_auto_claim_recipient_internal(env: &Env, program_id: &String, recipient: &Address) -> bool {
+        env.storage()
+            .instance()
+            .has(&DataKey::AutoClaimRecipient(program_id.clone(), recipient.clone()))
+    }
 
-        anti_abuse::set_whitelist(&env, address, whitelisted);
+    /// Switches a program to direct payout mode: every `single_payout` and
+    /// `batch_payout` transfers immediately and no claims are created, as if
+    /// all recipients were auto-claim recipients. Only the program's
+    /// authorized payout key may call this.
+    pub fn set_direct_payout_mode(env: Env, program_id: String, direct_payout_mode: bool) {
+        Self::require_program_payout_auth(&env, &program_id);
+        let key = ProgramKey::DirectPayoutMode(program_id.clone());
+        if direct_payout_mode {
+            env.storage().instance().set(&key, &true);
+        } else {
+            env.storage().instance().remove(&key);
+        }
+        env.events().publish(
+            (symbol_short!("dir_mode"),),
+            (program_id, direct_payout_mode),
+        );
     }
 
-    /// Checks if an address is whitelisted.
-    pub fn is_whitelisted(env: Env, address: Address) -> bool {
-        anti_abuse::is_whitelisted(&env, address)
+    /// Checks if a program pays out directly without claims.
+    pub fn is_direct_payout_mode(env: Env, program_id: String) -> bool {
+        Self::is_direct_payout_mode_internal(&env, &program_id)
     }
 
-    /// Gets the current rate limit configuration.
-    pub fn get_rate_limit_config(env: Env) -> anti_abuse::AntiAbuseConfig {
-        anti_abuse::get_config(&env)
+    fn is_direct_payout_mode_internal(env: &Env, program_id: &String) -> bool {
+        env.storage()
+            .instance()
+            .has(&ProgramKey::DirectPayoutMode(program_id.clone()))
     }
 
-    // ========================================================================
-    // Program Spending Limit Admin & Views
-    // ========================================================================
+    /// Sets the smallest amount `single_payout` reserves as a pending claim;
+    /// smaller payouts are transferred directly, or rejected when
+    /// `set_reject_dust_payouts` is on. Pass 0 to disable. Only the program's
+    /// authorized payout key may call this.
+    pub fn set_min_claim_amount(env: Env, program_id: String, min_amount: i128) {
+        Self::require_program_payout_auth(&env, &program_id);
+        if min_amount < 0 {
+            panic!("Minimum claim amount cannot be negative");
+        }
+        let key = ProgramKey::MinClaimAmount(program_id.clone());
+        if min_amount == 0 {
+            env.storage().instance().remove(&key);
+        } else {
+            env.storage().instance().set(&key, &min_amount);
+        }
+        env.events()
+            .publish((symbol_short!("min_claim"),), (program_id, min_amount));
+    }
 
-    /// Configure or update the optional per-program spending limit for the
-    /// current token.
-    ///
-    /// This function can only be called by the program's authorized payout key.
-    /// Passing `enabled = false` stores the configuration but disables
-    /// enforcement until re-enabled.
-    pub fn set_program_spending_limit(
-        env: Env,
-        program_id: String,
-        window_size: u64,
-        max_amount: i128,
-        enabled: bool,
-    ) {
-        if max_amount < 0 {
-            panic!("max_amount must be non-negative");
+    /// Returns a program's minimum claim amount (0 if disabled).
+    pub fn get_min_claim_amount(env: Env, program_id: String) -> i128 {
+        env.storage()
+            .instance()
+            .get(&ProgramKey::MinClaimAmount(program_id))
+            .unwrap_or(0)
+    }
+
+    /// Makes `single_payout` reject payouts below the minimum claim amount
+    /// instead of transferring them directly. Only the program's authorized
+    /// payout key may call this.
+    pub fn set_reject_dust_payouts(env: Env, program_id: String, reject: bool) {
+        Self::require_program_payout_auth(&env, &program_id);
+        let key = ProgramKey::RejectDustPayouts(program_id.clone());
+        if reject {
+            env.storage().instance().set(&key, &true);
+        } else {
+            env.storage().instance().remove(&key);
         }
+        env.events()
+            .publish((symbol_short!("dust_rej"),), (program_id, reject));
+    }
 
-        let program_key = DataKey::Program(program_id.clone());
-        let program_data: ProgramData = env
-            .storage()
+    /// Checks if a program rejects payouts below its minimum claim amount.
+    pub fn is_reject_dust_payouts(env: Env, program_id: String) -> bool {
+        env.storage()
             .instance()
-            .get(&program_key)
-            .unwrap_or_else(|| panic!("Program not found"));
+            .has(&ProgramKey::RejectDustPayouts(program_id))
+    }
 
-        // Only the authorized payout key for this program may update limits.
-        program_data.authorized_payout_key.require_auth();
+    /// True if `amount` is too small to reserve as a claim. Panics instead
+    /// when the program rejects such payouts.
+    fn is_dust_claim_internal(env: &Env, program_id: &String, amount: i128) -> bool {
+        if amount >= Self::get_min_claim_amount(env.clone(), program_id.clone()) {
+            return false;
+        }
+        if Self::is_reject_dust_payouts(env.clone(), program_id.clone()) {
+            panic!("Payout below minimum claim amount");
+        }
+        true
+    }
 
-        let cfg = ProgramSpendingConfig {
-            window_size,
-            max_amount,
-            enabled,
-        };
+    /// Makes `single_payout` check that the recipient can hold the program's
+    /// token (by reading its balance) before paying, so a missing trustline
+    /// fails with "Recipient cannot receive token" instead of an opaque token
+    /// error. Only the program's authorized payout key may call this.
+    pub fn set_recipient_precheck(env: Env, program_id: String, enabled: bool) {
+        Self::require_program_payout_auth(&env, &program_id);
+        let key = ProgramKey::RecipientPrecheck(program_id.clone());
+        if enabled {
+            env.storage().instance().set(&key, &true);
+        } else {
+            env.storage().instance().remove(&key);
+        }
+        env.events()
+            .publish((symbol_short!("rcpt_chk"),), (program_id, enabled));
+    }
 
-        let token = program_data.token_address.clone();
-        let cfg_key = DataKey::ProgramSpendingConfig(program_id, token);
-        env.storage().instance().set(&cfg_key, &cfg);
+    /// Checks if a program pre-checks recipients before single payouts.
+    pub fn is_recipient_precheck(env: Env, program_id: String) -> bool {
+        env.storage()
+            .instance()
+            .has(&ProgramKey::RecipientPrecheck(program_id))
     }
 
-    /// Returns the current spending limit configuration for a program and its
-    /// configured token, if any.
-    pub fn get_program_spending_limit(
-        env: Env,
-        program_id: String,
-    ) -> Option<ProgramSpendingConfig> {
-        let program_key = DataKey::Program(program_id.clone());
-        let program_data: ProgramData = env
+    fn require_recipient_can_receive(
+        env: &Env,
+        program_id: &String,
+        token_address: &Address,
+        recipient: &Address,
+    ) {
+        if !env
             .storage()
             .instance()
-            .get(&program_key)
-            .unwrap_or_else(|| panic!("Program not found"));
+            .has(&ProgramKey::RecipientPrecheck(program_id.clone()))
+        {
+            return;
+        }
+        let token_client = token::Client::new(env, token_address);
+        if !matches!(token_client.try_balance(recipient), Ok(Ok(_))) {
+            panic!("Recipient cannot receive token");
+        }
+    }
 
-        let cfg_key =
-            DataKey::ProgramSpendingConfig(program_id, program_data.token_address.clone());
-        env.storage().instance().get(&cfg_key)
+    /// Hands a program's configuration rights to `new_admin`. Requires the
+    /// current assigned program admin, or the contract admin while the
+    /// program admin is still the payout key by default.
+    pub fn set_program_admin(env: Env, program_id: String, new_admin: Address) {
+        Self::assigned_admin_authority(&env, &program_id).require_auth();
+        env.storage()
+            .instance()
+            .set(&ProgramKey::ProgramAdmin(program_id.clone()), &new_admin);
+        env.events()
+            .publish((symbol_short!("prg_admin"),), (program_id, new_admin));
     }
 
-    /// Returns the current spending state (window start and amount released)
-    /// for a program/token pair, if any state has been recorded yet.
-    pub fn get_program_spending_state(
-        env: Env,
-        program_id: String,
-    ) -> Option<ProgramSpendingState> {
-        let program_key = DataKey::Program(program_id.clone());
-        let program_data: ProgramData = env
-            .storage()
+    /// Returns the program's admin: the address set at initialization or by
+    /// `set_program_admin`, otherwise the authorized payout key.
+    pub fn get_program_admin(env: Env, program_id: String) -> Address {
+        let program_data: ProgramData =
+            program_storage::load(&env, &program_id).unwrap_or_else(|| panic!("Program not found"));
+        Self::program_admin_internal(&env, &program_data)
+    }
+
+    fn program_admin_internal(env: &Env, program_data: &ProgramData) -> Address {
+        env.storage()
             .instance()
-            .get(&program_key)
-            .unwrap_or_else(|| panic!("Program not found"));
+            .get(&ProgramKey::ProgramAdmin(program_data.program_id.clone()))
+            .unwrap_or_else(|| program_data.authorized_payout_key.clone())
+    }
 
-        let state_key =
-            DataKey::ProgramSpendingState(program_id, program_data.token_address.clone());
-        env.storage().instance().get(&state_key)
+    /// The program admin if one was assigned, otherwise the contract admin.
+    /// Gates settings the payout key must not control on its own.
+    fn assigned_admin_authority(env: &Env, program_id: &String) -> Address {
+        if !program_storage::exists(env, program_id) {
+            panic!("Program not found");
+        }
+        env.storage()
+            .instance()
+            .get(&ProgramKey::ProgramAdmin(program_id.clone()))
+            .unwrap_or_else(|| anti_abuse::get_admin(env).expect("Admin not set"))
+    }
+
+    /// Loads a program and requires its program admin's auth.
+    fn require_program_admin_auth(env: &Env, program_id: &String) -> ProgramData {
+        let program_data: ProgramData =
+            program_storage::load(env, program_id).unwrap_or_else(|| panic!("Program not found"));
+        Self::program_admin_internal(env, &program_data).require_auth();
+        program_data
+    }
+
+    /// Loads a program and requires its authorized payout key's auth.
+    fn require_program_payout_auth(env: &Env, program_id: &String) -> ProgramData {
+        let program_data: ProgramData =
+            program_storage::load(env, program_id).unwrap_or_else(|| panic!("Program not found"));
+        program_data.authorized_payout_key.require_auth();
+        program_data
     }
 
     /// Creates an on-chain snapshot of critical configuration (admin-only).
@@ -2601,6 +7166,21 @@ impl ProgramEscrowContract {
             .unwrap_or_else(|| panic!("Schedule not found"))
     }
 
+    /// Returns the amount `release_prog_schedule_automatic` would pay for the
+    /// schedule at the current ledger time: the full amount once the release
+    /// timestamp has passed, otherwise 0. Released schedules return 0.
+    ///
+    /// # Panics
+    /// * If schedule doesn't exist
+    pub fn get_claimable_now(env: Env, program_id: String, schedule_id: u64) -> i128 {
+        let schedule = Self::get_program_release_schedule(env.clone(), program_id, schedule_id);
+        if schedule.released || env.ledger().timestamp() < schedule.release_timestamp {
+            0
+        } else {
+            schedule.amount
+        }
+    }
+
     /// Retrieves all release schedules for a program.
     ///
     /// # Arguments
@@ -2638,6 +7218,35 @@ impl ProgramEscrowContract {
         schedules
     }
 
+    /// Retrieves the release schedules destined for `recipient` in a program,
+    /// released or not, in creation order.
+    pub fn get_schedules_for_recipient(
+        env: Env,
+        program_id: String,
+        recipient: Address,
+    ) -> Vec<ProgramReleaseSchedule> {
+        let schedule_ids: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&ProgramKey::RecipientSchedules(
+                program_id.clone(),
+                recipient,
+            ))
+            .unwrap_or(vec![&env]);
+
+        let mut schedules = Vec::new(&env);
+        for schedule_id in schedule_ids.iter() {
+            if let Some(schedule) = env
+                .storage()
+                .persistent()
+                .get(&DataKey::ReleaseSchedule(program_id.clone(), schedule_id))
+            {
+                schedules.push_back(schedule);
+            }
+        }
+        schedules
+    }
+
     /// Retrieves pending (unreleased) schedules for a program.
     ///
     /// # Arguments
@@ -2684,6 +7293,48 @@ impl ProgramEscrowContract {
         due
     }
 
+    /// Returns the id and release timestamp of the program's earliest
+    /// unreleased schedule, or `None` when nothing is pending.
+    ///
+    /// Lets keepers sleep until the next release instead of polling
+    /// `get_due_program_schedules`. Ties on timestamp resolve to the lower id.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `program_id` - The program to query
+    ///
+    /// # Returns
+    /// * `Option<(u64, u64)>` - `(schedule_id, release_timestamp)` of the next schedule
+    pub fn get_next_due_schedule(env: Env, program_id: String) -> Option<(u64, u64)> {
+        let pending = Self::get_pending_program_schedules(env, program_id);
+        let mut next: Option<(u64, u64)> = None;
+
+        for schedule in pending.iter() {
+            let is_earlier = match next {
+                Some((_, timestamp)) => schedule.release_timestamp < timestamp,
+                None => true,
+            };
+            if is_earlier {
+                next = Some((schedule.schedule_id, schedule.release_timestamp));
+            }
+        }
+
+        next
+    }
+
+    /// Returns running vesting totals for a program's release schedules.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `program_id` - The program to query
+    ///
+    /// # Returns
+    /// * `ScheduleTotals` - Scheduled, released and pending amounts plus the
+    ///   earliest pending release timestamp
+    pub fn get_schedule_totals(env: Env, program_id: String) -> ScheduleTotals {
+        get_program_schedule_totals(&env, &program_id)
+    }
+
     /// Retrieves release history for a program.
     ///
     /// # Arguments
@@ -2706,12 +7357,8 @@ impl ProgramEscrowContract {
     /// - `payout_fulfillment_rate_bps`: funds_distributed / funds_locked
     /// - `overall_score_bps`: weighted average (60% completion, 40% fulfillment)
     pub fn get_program_reputation(env: Env, program_id: String) -> ProgramReputationScore {
-        let program_key = DataKey::Program(program_id.clone());
-        let program_data: ProgramData = env
-            .storage()
-            .instance()
-            .get(&program_key)
-            .unwrap_or_else(|| panic!("Program not found"));
+        let program_data: ProgramData =
+            program_storage::load(&env, &program_id).unwrap_or_else(|| panic!("Program not found"));
         let schedules = Self::get_all_prog_release_schedules(env.clone(), program_id);
 
         let now = env.ledger().timestamp();
@@ -2770,6 +7417,95 @@ impl ProgramEscrowContract {
     }
 }
 
+/// Helper function to load a program's running schedule totals.
+fn get_program_schedule_totals(env: &Env, program_id: &String) -> ScheduleTotals {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ScheduleTotals(program_id.clone()))
+        .unwrap_or(ScheduleTotals {
+            total_scheduled: 0,
+            total_released: 0,
+            total_pending: 0,
+            next_release_ts: None,
+        })
+}
+
+fn set_program_schedule_totals(env: &Env, program_id: &String, totals: &ScheduleTotals) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::ScheduleTotals(program_id.clone()), totals);
+}
+
+/// Moves a schedule out of the pending total once it is released or
+/// cancelled. Only rescans for the next release when the resolved schedule
+/// was the one holding it.
+fn record_schedule_resolved(
+    env: &Env,
+    program_id: &String,
+    schedule: &ProgramReleaseSchedule,
+    released: bool,
+) {
+    let mut totals = get_program_schedule_totals(env, program_id);
+    totals.total_pending -= schedule.amount;
+    if released {
+        totals.total_released += schedule.amount;
+    } else {
+        totals.total_scheduled -= schedule.amount;
+    }
+
+    if totals.next_release_ts == Some(schedule.release_timestamp) {
+        totals.next_release_ts = None;
+        let next_id: u64 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::NextScheduleId(program_id.clone()))
+            .unwrap_or(1);
+        for schedule_id in 1..next_id {
+            if schedule_id == schedule.schedule_id {
+                continue;
+            }
+            let other: Option<ProgramReleaseSchedule> = env
+                .storage()
+                .persistent()
+                .get(&DataKey::ReleaseSchedule(program_id.clone(), schedule_id));
+            if let Some(other) = other {
+                let is_earlier = match totals.next_release_ts {
+                    Some(next) => other.release_timestamp < next,
+                    None => true,
+                };
+                if !other.released && is_earlier {
+                    totals.next_release_ts = Some(other.release_timestamp);
+                }
+            }
+        }
+    }
+
+    set_program_schedule_totals(env, program_id, &totals);
+}
+
+/// Helper function to count a program's unreleased schedules.
+fn get_program_open_schedule_count(env: &Env, program_id: &String) -> u32 {
+    let next_id: u64 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::NextScheduleId(program_id.clone()))
+        .unwrap_or(1);
+
+    let mut count = 0u32;
+    for schedule_id in 1..next_id {
+        let schedule: Option<ProgramReleaseSchedule> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ReleaseSchedule(program_id.clone(), schedule_id));
+        if let Some(schedule) = schedule {
+            if !schedule.released {
+                count += 1;
+            }
+        }
+    }
+    count
+}
+
 /// Helper function to calculate total scheduled amount for a program.
 fn get_program_total_scheduled_amount(env: &Env, program_id: &String) -> i128 {
     let next_id: u64 = env
@@ -3467,7 +8203,7 @@ pub fn preview_split_payout(
         let recipients = soroban_sdk::vec![&env, Address::generate(&env)];
         let amounts = soroban_sdk::vec![&env, 10_000_0000000i128]; // More than available!
 
-        client.batch_payout(&prog_id, &recipients, &amounts);
+        client.batch_payout(&prog_id, &recipients, &amounts, &false, &None, &10_000_0000000);
     }
 
     #[test]
@@ -3503,12 +8239,12 @@ pub fn preview_split_payout(
         // First batch within limit should succeed
         let recipients1 = soroban_sdk::vec![&env, Address::generate(&env)];
         let amounts1 = soroban_sdk::vec![&env, 4_000_0000000i128];
-        client.batch_payout(&prog_id, &recipients1, &amounts1);
+        client.batch_payout(&prog_id, &recipients1, &amounts1, &false, &None, &4_000_0000000);
 
         // Second batch in same window exceeding remaining allowance should panic
         let recipients2 = soroban_sdk::vec![&env, Address::generate(&env)];
         let amounts2 = soroban_sdk::vec![&env, 2_000_0000000i128];
-        client.batch_payout(&prog_id, &recipients2, &amounts2);
+        client.batch_payout(&prog_id, &recipients2, &amounts2, &false, &None, &2_000_0000000);
     }
 
     #[test]
@@ -3544,7 +8280,7 @@ pub fn preview_split_payout(
 
         // First payout within window
         let amounts1 = soroban_sdk::vec![&env, 5_000_0000000i128];
-        client.batch_payout(&prog_id, &recipients, &amounts1);
+        client.batch_payout(&prog_id, &recipients, &amounts1, &false, &None, &5_000_0000000);
 
         // Advance time beyond window to trigger reset
         let current = env.ledger().timestamp();
@@ -3552,7 +8288,7 @@ pub fn preview_split_payout(
 
         // Second payout of the same size should succeed in new window
         let amounts2 = soroban_sdk::vec![&env, 5_000_0000000i128];
-        client.batch_payout(&prog_id, &recipients, &amounts2);
+        client.batch_payout(&prog_id, &recipients, &amounts2, &false, &None, &5_000_0000000);
     }
 
     #[test]
@@ -3590,8 +8326,8 @@ pub fn preview_split_payout(
         let client = ProgramEscrowContractClient::new(&env, &contract_id);
 
         let admin = Address::generate(&env);
-        client.set_admin(&admin);
-        client.update_rate_limit_config(&3600, &10, &60);
+        client.set_admin(&admin, &0);
+        client.update_rate_limit_config(&3600, &10, &60, &1);
 
         let backend = Address::generate(&env);
         let token = Address::generate(&env);
@@ -3614,8 +8350,8 @@ pub fn preview_split_payout(
         let client = ProgramEscrowContractClient::new(&env, &contract_id);
 
         let admin = Address::generate(&env);
-        client.set_admin(&admin);
-        client.update_rate_limit_config(&3600, &2, &0); // 2 ops max, no cooldown
+        client.set_admin(&admin, &0);
+        client.update_rate_limit_config(&3600, &2, &0, &1); // 2 ops max, no cooldown
 
         let backend = Address::generate(&env);
         let token = Address::generate(&env);
@@ -3635,8 +8371,8 @@ pub fn preview_split_payout(
         let client = ProgramEscrowContractClient::new(&env, &contract_id);
 
         let admin = Address::generate(&env);
-        client.set_admin(&admin);
-        client.update_rate_limit_config(&3600, &1, &60); // 1 op max
+        client.set_admin(&admin, &0);
+        client.update_rate_limit_config(&3600, &1, &60, &1); // 1 op max
 
         let backend = Address::generate(&env);
         let token = Address::generate(&env);
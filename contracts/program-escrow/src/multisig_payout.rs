@@ -0,0 +1,380 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/multisig_payout.rs
+//
+// Payouts that require approval from several signers.
+//
+// A program configures a signer set and threshold. The authorized payout
+// key then opens a request with `request_multisig_payout`; the amount is
+// reserved immediately by deducting it from `remaining_balance`, so no
+// other payout can spend it while approvals are collected. Once
+// `threshold` distinct signers have approved, the amount is transferred
+// to the recipient and recorded in the payout history. Cancelling a
+// pending request returns the reservation to the balance.
+//
+// Requests run the same checks as `single_payout` (pause state, operation
+// flags and whitelist, funding goal, amount limits, denylist and freeze,
+// payout fraction), and the final approval runs them again, so a request
+// that no longer passes cannot reach its threshold. The spending limit and
+// daily cap are counted once, when the payout executes.
+//
+// With a request expiry set, requests not fully approved in time can no
+// longer be approved; `expire_requests` drops them and returns their
+// reservations. New requests sweep expired ones first.
+//
+// Storage:
+//   DataKey::MultisigPayoutConfig(String)      → MultisigPayoutConfig
+//   DataKey::MultisigRequests(String)          → Vec<MultisigRequest> (pending only)
+//   ProgramKey::MultisigRequestExpiry(String)  → u64 seconds a request stays open
+// ============================================================
+
+use crate::{
+    program_storage, DataKey, PayoutRecord, ProgramData, ProgramEscrowContract, ProgramKey,
+};
+use soroban_sdk::{contracttype, symbol_short, token, Address, Env, String, Symbol, Vec};
+
+const MULTISIG_CONFIG_SET: Symbol = symbol_short!("ms_cfg");
+const MULTISIG_REQUESTED: Symbol = symbol_short!("ms_req");
+const MULTISIG_APPROVED: Symbol = symbol_short!("ms_appr");
+const MULTISIG_EXECUTED: Symbol = symbol_short!("ms_exec");
+const MULTISIG_CANCELLED: Symbol = symbol_short!("ms_cncl");
+const MULTISIG_EXPIRY_SET: Symbol = symbol_short!("ms_expcfg");
+const MULTISIG_EXPIRED: Symbol = symbol_short!("ms_expd");
+
+// Storage key for auto-incrementing request IDs
+const NEXT_MULTISIG_ID: Symbol = symbol_short!("NxtMsId");
+
+/// Signers allowed to approve a program's multisig payouts.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MultisigPayoutConfig {
+    pub signers: Vec<Address>,
+    pub threshold: u32,
+}
+
+/// A multisig payout awaiting approval.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MultisigRequest {
+    pub request_id: u64,
+    pub recipient: Address,
+    /// Amount reserved out of the program balance.
+    pub amount: i128,
+    pub approvals: Vec<Address>,
+    /// Approvals needed before the payout executes.
+    pub required: u32,
+    pub created_at: u64,
+    /// Time after which the request can no longer be approved (0 = never).
+    pub expires_at: u64,
+}
+
+/// Approval progress of a pending multisig request.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MultisigRequestStatus {
+    pub approvals: u32,
+    pub required: u32,
+    /// 0 if the request never expires.
+    pub expires_at: u64,
+    /// Seconds left to collect approvals (0 once expired or if it never expires).
+    pub seconds_remaining: u64,
+    pub expired: bool,
+}
+
+fn is_expired(env: &Env, request: &MultisigRequest) -> bool {
+    request.expires_at != 0 && env.ledger().timestamp() >= request.expires_at
+}
+
+fn get_program(env: &Env, program_id: &String) -> ProgramData {
+    program_storage::load(env, program_id).unwrap_or_else(|| panic!("Program not found"))
+}
+
+fn save_program(env: &Env, data: &ProgramData) {
+    program_storage::save(env, data);
+}
+
+fn next_request_id(env: &Env) -> u64 {
+    let id: u64 = env
+        .storage()
+        .instance()
+        .get(&NEXT_MULTISIG_ID)
+        .unwrap_or(1_u64);
+    env.storage().instance().set(&NEXT_MULTISIG_ID, &(id + 1));
+    id
+}
+
+fn save_pending(env: &Env, program_id: &String, pending: &Vec<MultisigRequest>) {
+    let key = DataKey::MultisigRequests(program_id.clone());
+    if pending.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, pending);
+    }
+}
+
+fn find_pending(env: &Env, program_id: &String, request_id: u64) -> (Vec<MultisigRequest>, u32) {
+    let pending = get_pending(env, program_id);
+    for i in 0..pending.len() {
+        if pending.get(i).unwrap().request_id == request_id {
+            return (pending, i);
+        }
+    }
+    panic!("Multisig request not found");
+}
+
+/// Sets the signer set and approval threshold for a program's multisig
+/// payouts. Only the program's authorized payout key may call this.
+pub fn set_config(env: &Env, program_id: &String, signers: Vec<Address>, threshold: u32) {
+    let program = get_program(env, program_id);
+    program.authorized_payout_key.require_auth();
+
+    if threshold == 0 || threshold > signers.len() {
+        panic!("Invalid multisig threshold");
+    }
+
+    let config = MultisigPayoutConfig { signers, threshold };
+    env.storage()
+        .instance()
+        .set(&DataKey::MultisigPayoutConfig(program_id.clone()), &config);
+
+    env.events()
+        .publish((MULTISIG_CONFIG_SET,), (program_id.clone(), threshold));
+}
+
+/// Returns the multisig configuration for a program, if any.
+pub fn get_config(env: &Env, program_id: &String) -> Option<MultisigPayoutConfig> {
+    env.storage()
+        .instance()
+        .get(&DataKey::MultisigPayoutConfig(program_id.clone()))
+}
+
+/// Returns the program's pending multisig requests in creation order.
+pub fn get_pending(env: &Env, program_id: &String) -> Vec<MultisigRequest> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::MultisigRequests(program_id.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Sets how long new requests may collect approvals before they expire.
+/// 0 lets requests stay open until approved or cancelled. Only the
+/// program's authorized payout key may call this.
+pub fn set_request_expiry(env: &Env, program_id: &String, expiry_secs: u64) {
+    let program = get_program(env, program_id);
+    program.authorized_payout_key.require_auth();
+
+    let key = ProgramKey::MultisigRequestExpiry(program_id.clone());
+    if expiry_secs == 0 {
+        env.storage().instance().remove(&key);
+    } else {
+        env.storage().instance().set(&key, &expiry_secs);
+    }
+
+    env.events()
+        .publish((MULTISIG_EXPIRY_SET,), (program_id.clone(), expiry_secs));
+}
+
+/// Returns how long new requests stay open for approvals (0 if unlimited).
+pub fn get_request_expiry(env: &Env, program_id: &String) -> u64 {
+    env.storage()
+        .instance()
+        .get(&ProgramKey::MultisigRequestExpiry(program_id.clone()))
+        .unwrap_or(0)
+}
+
+/// Returns the approval progress of a pending request.
+pub fn get_request_status(
+    env: &Env,
+    program_id: &String,
+    request_id: u64,
+) -> MultisigRequestStatus {
+    let (pending, index) = find_pending(env, program_id, request_id);
+    let request = pending.get(index).unwrap();
+    let expired = is_expired(env, &request);
+    let seconds_remaining = if request.expires_at == 0 || expired {
+        0
+    } else {
+        request.expires_at - env.ledger().timestamp()
+    };
+    MultisigRequestStatus {
+        approvals: request.approvals.len(),
+        required: request.required,
+        expires_at: request.expires_at,
+        seconds_remaining,
+        expired,
+    }
+}
+
+/// Drops every expired request, returning its reservation to the program
+/// balance. Anyone may call this. Returns the number of requests expired.
+pub fn expire_requests(env: &Env, program_id: &String) -> u32 {
+    let pending = get_pending(env, program_id);
+    let mut remaining = Vec::new(env);
+    let mut expired: u32 = 0;
+    let mut restored: i128 = 0;
+    for request in pending.iter() {
+        if is_expired(env, &request) {
+            expired += 1;
+            restored += request.amount;
+            env.events().publish(
+                (MULTISIG_EXPIRED,),
+                (program_id.clone(), request.request_id, request.amount),
+            );
+        } else {
+            remaining.push_back(request);
+        }
+    }
+    if expired == 0 {
+        return 0;
+    }
+
+    save_pending(env, program_id, &remaining);
+    let mut program = get_program(env, program_id);
+    program.remaining_balance += restored;
+    save_program(env, &program);
+    expired
+}
+
+/// Opens a multisig payout request and reserves `amount` out of the program
+/// balance. Returns the request id.
+pub fn request_payout(env: &Env, program_id: &String, recipient: &Address, amount: i128) -> u64 {
+    get_program(env, program_id)
+        .authorized_payout_key
+        .require_auth();
+    expire_requests(env, program_id);
+
+    let config = get_config(env, program_id)
+        .unwrap_or_else(|| panic!("Multisig not configured for program"));
+
+    let mut program = ProgramEscrowContract::require_payout_allowed(env, program_id);
+    ProgramEscrowContract::require_payout_amount_valid(
+        env,
+        &program,
+        recipient,
+        amount,
+        program.remaining_balance,
+    );
+
+    program.remaining_balance -= amount;
+    save_program(env, &program);
+
+    let request_id = next_request_id(env);
+    let now = env.ledger().timestamp();
+    let expiry_secs = get_request_expiry(env, program_id);
+    let mut pending = get_pending(env, program_id);
+    pending.push_back(MultisigRequest {
+        request_id,
+        recipient: recipient.clone(),
+        amount,
+        approvals: Vec::new(env),
+        required: config.threshold,
+        created_at: now,
+        expires_at: if expiry_secs == 0 {
+            0
+        } else {
+            now.saturating_add(expiry_secs)
+        },
+    });
+    save_pending(env, program_id, &pending);
+
+    env.events().publish(
+        (MULTISIG_REQUESTED,),
+        (program_id.clone(), request_id, recipient.clone(), amount),
+    );
+
+    request_id
+}
+
+/// Records `signer`'s approval. When the threshold is reached the reserved
+/// amount is transferred and the request is removed. Returns `true` if the
+/// payout executed.
+pub fn approve_payout(env: &Env, program_id: &String, request_id: u64, signer: &Address) -> bool {
+    signer.require_auth();
+
+    let config = get_config(env, program_id)
+        .unwrap_or_else(|| panic!("Multisig not configured for program"));
+    if !config.signers.contains(signer) {
+        panic!("Not a multisig signer");
+    }
+
+    let (mut pending, index) = find_pending(env, program_id, request_id);
+    let mut request = pending.get(index).unwrap();
+    if is_expired(env, &request) {
+        panic!("Multisig request expired");
+    }
+    if request.approvals.contains(signer) {
+        panic!("Already approved");
+    }
+    request.approvals.push_back(signer.clone());
+
+    env.events().publish(
+        (MULTISIG_APPROVED,),
+        (program_id.clone(), request_id, signer.clone()),
+    );
+
+    if request.approvals.len() < request.required {
+        pending.set(index, request);
+        save_pending(env, program_id, &pending);
+        return false;
+    }
+
+    // payout rules may have changed since the request opened; the amount
+    // is still reserved, so it counts as available
+    let mut program = ProgramEscrowContract::require_payout_allowed(env, program_id);
+    ProgramEscrowContract::require_payout_amount_valid(
+        env,
+        &program,
+        &request.recipient,
+        request.amount,
+        program.remaining_balance + request.amount,
+    );
+    ProgramEscrowContract::record_payout_limits(env, &program, request.amount);
+
+    pending.remove(index);
+    save_pending(env, program_id, &pending);
+
+    let token_client = token::Client::new(env, &program.token_address);
+    token_client.transfer(
+        &env.current_contract_address(),
+        &request.recipient,
+        &request.amount,
+    );
+    program.payout_history.push_back(PayoutRecord {
+        recipient: request.recipient.clone(),
+        amount: request.amount,
+        timestamp: env.ledger().timestamp(),
+        memo_hash: None,
+    });
+    save_program(env, &program);
+
+    env.events().publish(
+        (MULTISIG_EXECUTED,),
+        (
+            program_id.clone(),
+            request_id,
+            request.recipient,
+            request.amount,
+        ),
+    );
+
+    true
+}
+
+/// Cancels a pending request and returns its reservation to the program
+/// balance. Only the program's authorized payout key may call this.
+pub fn cancel_payout(env: &Env, program_id: &String, request_id: u64) {
+    let mut program = get_program(env, program_id);
+    program.authorized_payout_key.require_auth();
+
+    let (mut pending, index) = find_pending(env, program_id, request_id);
+    let request = pending.get(index).unwrap();
+    pending.remove(index);
+    save_pending(env, program_id, &pending);
+
+    program.remaining_balance += request.amount;
+    save_program(env, &program);
+
+    env.events().publish(
+        (MULTISIG_CANCELLED,),
+        (program_id.clone(), request_id, request.amount),
+    );
+}
@@ -0,0 +1,184 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/payout_callback.rs
+//
+// Cross-contract notification after a payout is disbursed.
+//
+// A program may register a callback contract. Once a claim is executed
+// and the funds have left the escrow, the contract invokes
+//
+//     on_payout(program_id: String, recipient: Address, amount: i128)
+//
+// on that contract. The call is made with `try_invoke_contract`, so a
+// callback that panics or is missing the function never reverts the
+// payout itself; a `callback_failed` event is emitted instead.
+//
+// An organizer may separately register an expiry callback, invoked as
+//
+//     on_claim_expired(program_id: String, claim_id: u64, recipient: Address, amount: i128)
+//
+// for each claim reclaimed by `sweep_expired_claims`, with the same
+// non-reverting guarantee.
+//
+// A badge minter, if registered, is invoked after each executed claim as
+//
+//     mint(recipient: Address, program_id: String, amount: i128)
+//
+// to issue a commemorative badge. A failed mint emits `bdg_fail`.
+//
+// Storage:
+//   DataKey::PayoutCallback(String)  → callback contract address
+//   DataKey::ExpiryCallback(String)  → expiry callback contract address
+//   ProgramKey::BadgeMinter(String)  → badge minter contract address
+// ============================================================
+
+use crate::{program_storage, DataKey, ProgramData, ProgramKey};
+use soroban_sdk::{symbol_short, Address, Env, IntoVal, String, Symbol, Val, Vec};
+
+const CALLBACK_SET: Symbol = symbol_short!("cb_set");
+const EXPIRY_CALLBACK_SET: Symbol = symbol_short!("ecb_set");
+const BADGE_MINTER_SET: Symbol = symbol_short!("bdg_set");
+const BADGE_MINT_FAILED: Symbol = symbol_short!("bdg_fail");
+
+/// Registers (or with `None`, clears) the callback contract for a program.
+/// Only the program's authorized payout key may call this.
+pub fn set_payout_callback(env: &Env, program_id: &String, callback: Option<Address>) {
+    let program: ProgramData =
+        program_storage::load(env, program_id).unwrap_or_else(|| panic!("Program not found"));
+    program.authorized_payout_key.require_auth();
+
+    let key = DataKey::PayoutCallback(program_id.clone());
+    match &callback {
+        Some(contract) => env.storage().instance().set(&key, contract),
+        None => env.storage().instance().remove(&key),
+    }
+
+    env.events()
+        .publish((CALLBACK_SET,), (program_id.clone(), callback));
+}
+
+/// Returns the callback contract registered for a program, if any.
+pub fn get_payout_callback(env: &Env, program_id: &String) -> Option<Address> {
+    env.storage()
+        .instance()
+        .get(&DataKey::PayoutCallback(program_id.clone()))
+}
+
+/// Invokes `on_payout` on the program's callback contract, if one is set.
+/// Errors from the callback are swallowed and reported as an event.
+pub fn notify(env: &Env, program_id: &String, recipient: &Address, amount: i128) {
+    let callback = match get_payout_callback(env, program_id) {
+        Some(contract) => contract,
+        None => return,
+    };
+
+    let args: Vec<Val> = (program_id.clone(), recipient.clone(), amount).into_val(env);
+    let result = env.try_invoke_contract::<(), soroban_sdk::Error>(
+        &callback,
+        &Symbol::new(env, "on_payout"),
+        args,
+    );
+
+    if !matches!(result, Ok(Ok(()))) {
+        env.events().publish(
+            (Symbol::new(env, "callback_failed"),),
+            (program_id.clone(), callback, recipient.clone(), amount),
+        );
+    }
+}
+
+/// Registers (or with `None`, clears) the expiry callback contract for a
+/// program. Only the program's authorized payout key may call this.
+pub fn set_expiry_callback(env: &Env, program_id: &String, callback: Option<Address>) {
+    let program: ProgramData =
+        program_storage::load(env, program_id).unwrap_or_else(|| panic!("Program not found"));
+    program.authorized_payout_key.require_auth();
+
+    let key = DataKey::ExpiryCallback(program_id.clone());
+    match &callback {
+        Some(contract) => env.storage().instance().set(&key, contract),
+        None => env.storage().instance().remove(&key),
+    }
+
+    env.events()
+        .publish((EXPIRY_CALLBACK_SET,), (program_id.clone(), callback));
+}
+
+/// Returns the expiry callback contract registered for a program, if any.
+pub fn get_expiry_callback(env: &Env, program_id: &String) -> Option<Address> {
+    env.storage()
+        .instance()
+        .get(&DataKey::ExpiryCallback(program_id.clone()))
+}
+
+/// Invokes `on_claim_expired` on the program's expiry callback contract, if
+/// one is set. Errors from the callback are swallowed and reported as an event.
+pub fn notify_claim_expired(
+    env: &Env,
+    program_id: &String,
+    claim_id: u64,
+    recipient: &Address,
+    amount: i128,
+) {
+    let callback = match get_expiry_callback(env, program_id) {
+        Some(contract) => contract,
+        None => return,
+    };
+
+    let args: Vec<Val> = (program_id.clone(), claim_id, recipient.clone(), amount).into_val(env);
+    let result = env.try_invoke_contract::<(), soroban_sdk::Error>(
+        &callback,
+        &Symbol::new(env, "on_claim_expired"),
+        args,
+    );
+
+    if !matches!(result, Ok(Ok(()))) {
+        env.events().publish(
+            (Symbol::new(env, "callback_failed"),),
+            (program_id.clone(), callback, recipient.clone(), amount),
+        );
+    }
+}
+
+/// Registers (or with `None`, clears) the badge minter contract for a
+/// program. Only the program's authorized payout key may call this.
+pub fn set_badge_minter(env: &Env, program_id: &String, minter: Option<Address>) {
+    let program: ProgramData =
+        program_storage::load(env, program_id).unwrap_or_else(|| panic!("Program not found"));
+    program.authorized_payout_key.require_auth();
+
+    let key = ProgramKey::BadgeMinter(program_id.clone());
+    match &minter {
+        Some(contract) => env.storage().instance().set(&key, contract),
+        None => env.storage().instance().remove(&key),
+    }
+
+    env.events()
+        .publish((BADGE_MINTER_SET,), (program_id.clone(), minter));
+}
+
+/// Returns the badge minter contract registered for a program, if any.
+pub fn get_badge_minter(env: &Env, program_id: &String) -> Option<Address> {
+    env.storage()
+        .instance()
+        .get(&ProgramKey::BadgeMinter(program_id.clone()))
+}
+
+/// Invokes `mint` on the program's badge minter, if one is set. Errors from
+/// the minter are swallowed and reported as an event.
+pub fn mint_badge(env: &Env, program_id: &String, recipient: &Address, amount: i128) {
+    let minter = match get_badge_minter(env, program_id) {
+        Some(contract) => contract,
+        None => return,
+    };
+
+    let args: Vec<Val> = (recipient.clone(), program_id.clone(), amount).into_val(env);
+    let result =
+        env.try_invoke_contract::<Val, soroban_sdk::Error>(&minter, &symbol_short!("mint"), args);
+
+    if !matches!(result, Ok(Ok(_))) {
+        env.events().publish(
+            (BADGE_MINT_FAILED,),
+            (program_id.clone(), minter, recipient.clone(), amount),
+        );
+    }
+}
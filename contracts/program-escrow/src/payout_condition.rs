@@ -0,0 +1,72 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/payout_condition.rs
+//
+// Payouts gated on state held by another contract.
+//
+// Grants contingent on milestones tracked elsewhere register a condition
+// contract exposing
+//
+//     is_satisfied(program_id: String, recipient: Address) -> bool
+//
+// `single_payout` calls it before paying and panics with
+// "Payout condition not met" when it returns false. Unlike the payout
+// callback, a failing condition contract reverts the payout.
+//
+// Storage:
+//   DataKey::PayoutCondition(String) → condition contract address
+// ============================================================
+
+use crate::{program_storage, DataKey, ProgramData};
+use soroban_sdk::{contractclient, symbol_short, Address, Env, String, Symbol};
+
+const CONDITION_SET: Symbol = symbol_short!("cond_set");
+
+/// Interface a condition contract must implement.
+#[allow(dead_code)]
+#[contractclient(name = "PayoutConditionClient")]
+pub trait PayoutCondition {
+    fn is_satisfied(env: Env, program_id: String, recipient: Address) -> bool;
+}
+
+/// Registers (or with `None`, clears) the condition contract for a program.
+/// Only the program's authorized payout key may call this.
+pub fn set_payout_condition(env: &Env, program_id: &String, condition: Option<Address>) {
+    let program: ProgramData =
+        program_storage::load(env, program_id).unwrap_or_else(|| panic!("Program not found"));
+    program.authorized_payout_key.require_auth();
+
+    let key = DataKey::PayoutCondition(program_id.clone());
+    match &condition {
+        Some(contract) => env.storage().instance().set(&key, contract),
+        None => env.storage().instance().remove(&key),
+    }
+
+    env.events()
+        .publish((CONDITION_SET,), (program_id.clone(), condition));
+}
+
+/// Returns the condition contract registered for a program, if any.
+pub fn get_payout_condition(env: &Env, program_id: &String) -> Option<Address> {
+    env.storage()
+        .instance()
+        .get(&DataKey::PayoutCondition(program_id.clone()))
+}
+
+/// Whether the program's condition contract, if one is set, reports the
+/// payout to `recipient` as satisfied. Programs without a condition always
+/// pass.
+pub fn is_satisfied(env: &Env, program_id: &String, recipient: &Address) -> bool {
+    match get_payout_condition(env, program_id) {
+        Some(contract) => {
+            PayoutConditionClient::new(env, &contract).is_satisfied(program_id, recipient)
+        }
+        None => true,
+    }
+}
+
+/// Panics unless `is_satisfied` holds for `recipient`.
+pub fn require_satisfied(env: &Env, program_id: &String, recipient: &Address) {
+    if !is_satisfied(env, program_id, recipient) {
+        panic!("Payout condition not met");
+    }
+}
@@ -275,6 +275,7 @@ pub fn execute_split_payout(
             recipient: entry.recipient.clone(),
             amount,
             timestamp: now,
+            memo_hash: None,
         });
     }
 
@@ -0,0 +1,98 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/payout_volume.rs
+//
+// Rolling per-program payout volume for analytics charts.
+//
+// Each payout adds its gross amount to a daily counter. The counters for
+// the last `RETENTION_DAYS` days are kept in a single entry, newest first,
+// so `get_payout_volume` can group them into larger buckets without
+// scanning the payout history.
+//
+// Storage:
+//   DataKey::PayoutVolume(String) → PayoutVolume
+// ============================================================
+
+use crate::DataKey;
+use soroban_sdk::{contracttype, Env, String, Vec};
+
+/// Width of one stored counter in seconds.
+pub const VOLUME_BUCKET_SECS: u64 = 86_400;
+
+/// Number of daily counters kept per program.
+pub const RETENTION_DAYS: u32 = 90;
+
+/// Daily payout totals for a program, newest first.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutVolume {
+    /// Day index (`timestamp / VOLUME_BUCKET_SECS`) of `daily_totals[0]`.
+    pub latest_day: u64,
+    pub daily_totals: Vec<i128>,
+}
+
+fn load(env: &Env, program_id: &String) -> Option<PayoutVolume> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PayoutVolume(program_id.clone()))
+}
+
+/// Adds `amount` to today's counter for the program, rolling the window
+/// forward when a new day has started.
+pub fn record(env: &Env, program_id: &String, amount: i128) {
+    let today = env.ledger().timestamp() / VOLUME_BUCKET_SECS;
+    let mut volume = load(env, program_id).unwrap_or(PayoutVolume {
+        latest_day: today,
+        daily_totals: Vec::new(env),
+    });
+
+    let elapsed = today.saturating_sub(volume.latest_day);
+    if elapsed >= RETENTION_DAYS as u64 {
+        volume.daily_totals = Vec::new(env);
+    } else {
+        for _ in 0..elapsed {
+            volume.daily_totals.push_front(0);
+        }
+    }
+    if volume.daily_totals.is_empty() {
+        volume.daily_totals.push_back(0);
+    }
+    while volume.daily_totals.len() > RETENTION_DAYS {
+        volume.daily_totals.pop_back();
+    }
+    volume.latest_day = volume.latest_day.max(today);
+
+    let current = volume.daily_totals.get(0).unwrap();
+    volume.daily_totals.set(0, current + amount);
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::PayoutVolume(program_id.clone()), &volume);
+}
+
+/// Returns payout totals grouped into `num_buckets` buckets of `bucket_secs`,
+/// most recent first. Bucket 0 ends with the current day. Days older than
+/// the retention window count as zero.
+pub fn get_volume(env: &Env, program_id: &String, bucket_secs: u64, num_buckets: u32) -> Vec<i128> {
+    if bucket_secs == 0 || !bucket_secs.is_multiple_of(VOLUME_BUCKET_SECS) {
+        panic!("Bucket size must be a whole number of days");
+    }
+    let days_per_bucket = bucket_secs / VOLUME_BUCKET_SECS;
+
+    let mut buckets: Vec<i128> = Vec::new(env);
+    for _ in 0..num_buckets {
+        buckets.push_back(0);
+    }
+
+    if let Some(volume) = load(env, program_id) {
+        let today = env.ledger().timestamp() / VOLUME_BUCKET_SECS;
+        let offset = today.saturating_sub(volume.latest_day);
+        for (i, amount) in volume.daily_totals.iter().enumerate() {
+            let bucket = (offset + i as u64) / days_per_bucket;
+            if bucket < num_buckets as u64 {
+                let total = buckets.get(bucket as u32).unwrap();
+                buckets.set(bucket as u32, total + amount);
+            }
+        }
+    }
+    buckets
+}
@@ -0,0 +1,107 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/price_oracle.rs
+//
+// Quote-denominated payouts via a per-program price oracle.
+//
+// Programs that denominate prizes in a quote unit (e.g. USD cents) but pay
+// in their token register an oracle contract exposing
+//
+//     get_rate() -> PriceData
+//
+// where `rate` is the number of token base units per quote unit, scaled by
+// RATE_SCALE. A payout of `quote_amount` transfers
+//
+//     quote_amount * rate / RATE_SCALE
+//
+// token units. Prices older than the program's `max_age_secs` are rejected.
+//
+// Storage:
+//   DataKey::PriceOracle(String) → OracleConfig
+// ============================================================
+
+use crate::{program_storage, DataKey, ProgramData};
+use soroban_sdk::{contractclient, contracttype, symbol_short, Address, Env, String, Symbol};
+
+/// Fixed-point scale of `PriceData::rate` (7 decimals).
+pub const RATE_SCALE: i128 = 10_000_000;
+
+const ORACLE_SET: Symbol = symbol_short!("oracle");
+
+/// Price reported by an oracle contract.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PriceData {
+    /// Token base units per quote unit, scaled by `RATE_SCALE`.
+    pub rate: i128,
+    /// Ledger timestamp the rate was last updated at.
+    pub timestamp: u64,
+}
+
+/// Oracle registered for a program.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OracleConfig {
+    pub oracle: Address,
+    /// Maximum age in seconds of a price accepted for a payout.
+    pub max_age_secs: u64,
+}
+
+/// Interface an oracle contract must implement.
+#[allow(dead_code)]
+#[contractclient(name = "PriceOracleClient")]
+pub trait PriceOracle {
+    fn get_rate(env: Env) -> PriceData;
+}
+
+/// Registers the oracle for a program. Only the program's authorized payout
+/// key may call this.
+pub fn set_price_oracle(env: &Env, program_id: &String, oracle: Address, max_age_secs: u64) {
+    let program: ProgramData =
+        program_storage::load(env, program_id).unwrap_or_else(|| panic!("Program not found"));
+    program.authorized_payout_key.require_auth();
+
+    if max_age_secs == 0 {
+        panic!("Max price age must be greater than zero");
+    }
+
+    let config = OracleConfig {
+        oracle,
+        max_age_secs,
+    };
+    env.storage()
+        .instance()
+        .set(&DataKey::PriceOracle(program_id.clone()), &config);
+
+    env.events()
+        .publish((ORACLE_SET,), (program_id.clone(), config));
+}
+
+/// Returns the oracle registered for a program, if any.
+pub fn get_price_oracle(env: &Env, program_id: &String) -> Option<OracleConfig> {
+    env.storage()
+        .instance()
+        .get(&DataKey::PriceOracle(program_id.clone()))
+}
+
+/// Converts `quote_amount` to token units at the oracle's current rate.
+///
+/// Rounds down. Panics if no oracle is set, the price is stale or
+/// non-positive, or the conversion overflows.
+pub fn quote_to_token(env: &Env, program_id: &String, quote_amount: i128) -> i128 {
+    let config =
+        get_price_oracle(env, program_id).unwrap_or_else(|| panic!("Price oracle not configured"));
+    let price = PriceOracleClient::new(env, &config.oracle).get_rate();
+
+    let now = env.ledger().timestamp();
+    if now.saturating_sub(price.timestamp) > config.max_age_secs {
+        panic!("Stale oracle price");
+    }
+    if price.rate <= 0 {
+        panic!("Invalid oracle price");
+    }
+
+    quote_amount
+        .checked_mul(price.rate)
+        .map(|x| x / RATE_SCALE)
+        .unwrap_or_else(|| panic!("Quote conversion overflow"))
+}
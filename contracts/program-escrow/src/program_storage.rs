@@ -0,0 +1,175 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/program_storage.rs
+//
+// Storage tier selection for `ProgramData`.
+//
+// Each program picks a tier when it is initialized:
+//
+//   - `Persistent` (default): the entry's TTL is extended to
+//     `PERSISTENT_PROGRAM_TTL` ledgers (~30 days) on every write. If it
+//     still lapses, the entry is archived and can be restored, never lost.
+//   - `Temporary`: cheaper, for short-lived or test programs. The TTL is
+//     extended to `TEMPORARY_PROGRAM_TTL` ledgers (~1 day) on every write.
+//     Once it lapses the program data is deleted for good, together with
+//     its balance accounting.
+//
+// Claims, schedules, denylist entries and the payout index are keyed by
+// program id and outlive a lapsed temporary program, so an id is never
+// registered twice (`is_registered`): a new owner would inherit them.
+//
+// Programs created before storage tiers existed kept `ProgramData` in
+// instance storage. `load` and `exists` fall back to that entry, and the
+// first `save` moves it into persistent storage.
+//
+// All reads and writes of `DataKey::Program` go through this module so the
+// tier is honoured everywhere. Because of that, `save` is also where the
+// per-recipient payout index is kept up to date: every payout path appends
+// to `payout_history` and then saves, so new records are indexed by
+// position without each path having to do it.
+//
+// Storage:
+//   DataKey::Program(String)                       → ProgramData (in the program's tier;
+//                                                    instance for legacy programs)
+//   DataKey::ProgramStorageTier(String)            → StorageTier (instance)
+//   ProgramKey::IndexedPayouts(String)             → u32 history entries indexed (persistent)
+//   ProgramKey::RecipientPayouts(String, Address)  → Vec<u32> history positions (persistent)
+// ============================================================
+
+use crate::{DataKey, PayoutRecord, ProgramData, ProgramKey};
+use soroban_sdk::{contracttype, Address, Env, String, Vec};
+
+/// Ledgers a persistent program stays live after its last write (~30 days).
+pub const PERSISTENT_PROGRAM_TTL: u32 = 518_400;
+
+/// Ledgers a temporary program stays live after its last write (~1 day).
+pub const TEMPORARY_PROGRAM_TTL: u32 = 17_280;
+
+/// Where a program's `ProgramData` is kept.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum StorageTier {
+    Persistent,
+    Temporary,
+}
+
+/// Returns the storage tier of a program, defaulting to `Persistent`.
+pub fn get_tier(env: &Env, program_id: &String) -> StorageTier {
+    env.storage()
+        .instance()
+        .get(&DataKey::ProgramStorageTier(program_id.clone()))
+        .unwrap_or(StorageTier::Persistent)
+}
+
+/// Records the storage tier of a new program. Must be called before the
+/// program's first `save`.
+pub fn set_tier(env: &Env, program_id: &String, tier: StorageTier) {
+    env.storage()
+        .instance()
+        .set(&DataKey::ProgramStorageTier(program_id.clone()), &tier);
+}
+
+/// Loads a program's data from its tier.
+pub fn load(env: &Env, program_id: &String) -> Option<ProgramData> {
+    let key = DataKey::Program(program_id.clone());
+    match get_tier(env, program_id) {
+        StorageTier::Persistent => env
+            .storage()
+            .persistent()
+            .get(&key)
+            .or_else(|| env.storage().instance().get(&key)),
+        StorageTier::Temporary => env.storage().temporary().get(&key),
+    }
+}
+
+/// Whether a program's data exists (and, for temporary programs, has not
+/// expired).
+pub fn exists(env: &Env, program_id: &String) -> bool {
+    let key = DataKey::Program(program_id.clone());
+    match get_tier(env, program_id) {
+        StorageTier::Persistent => {
+            env.storage().persistent().has(&key) || env.storage().instance().has(&key)
+        }
+        StorageTier::Temporary => env.storage().temporary().has(&key),
+    }
+}
+
+/// Whether `program_id` was ever registered, including temporary programs
+/// whose data has since expired.
+pub fn is_registered(env: &Env, program_id: &String) -> bool {
+    exists(env, program_id)
+        || env
+            .storage()
+            .instance()
+            .has(&DataKey::ProgramStorageTier(program_id.clone()))
+}
+
+/// Writes a program's data to its tier and refreshes the entry's TTL.
+pub fn save(env: &Env, data: &ProgramData) {
+    index_new_payouts(env, data);
+    let key = DataKey::Program(data.program_id.clone());
+    match get_tier(env, &data.program_id) {
+        StorageTier::Persistent => {
+            let storage = env.storage().persistent();
+            storage.set(&key, data);
+            storage.extend_ttl(&key, PERSISTENT_PROGRAM_TTL, PERSISTENT_PROGRAM_TTL);
+            // migrate a legacy program out of instance storage
+            env.storage().instance().remove(&key);
+        }
+        StorageTier::Temporary => {
+            let storage = env.storage().temporary();
+            storage.set(&key, data);
+            storage.extend_ttl(&key, TEMPORARY_PROGRAM_TTL, TEMPORARY_PROGRAM_TTL);
+        }
+    }
+}
+
+/// Adds any payout records appended since the last save to the
+/// per-recipient index.
+fn index_new_payouts(env: &Env, data: &ProgramData) {
+    let cursor_key = ProgramKey::IndexedPayouts(data.program_id.clone());
+    let indexed: u32 = env.storage().persistent().get(&cursor_key).unwrap_or(0);
+    let len = data.payout_history.len();
+    if len <= indexed {
+        return;
+    }
+
+    for position in indexed..len {
+        let record = data.payout_history.get(position).unwrap();
+        let key = ProgramKey::RecipientPayouts(data.program_id.clone(), record.recipient);
+        let mut positions: Vec<u32> = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(Vec::new(env));
+        positions.push_back(position);
+        env.storage().persistent().set(&key, &positions);
+    }
+    env.storage().persistent().set(&cursor_key, &len);
+}
+
+/// Returns a recipient's payout records in the order they were paid,
+/// skipping `offset` and returning at most `limit` entries.
+pub fn payouts_for_recipient(
+    env: &Env,
+    data: &ProgramData,
+    recipient: &Address,
+    offset: u32,
+    limit: u32,
+) -> Vec<PayoutRecord> {
+    let positions: Vec<u32> = env
+        .storage()
+        .persistent()
+        .get(&ProgramKey::RecipientPayouts(
+            data.program_id.clone(),
+            recipient.clone(),
+        ))
+        .unwrap_or(Vec::new(env));
+
+    let mut records = Vec::new(env);
+    let end = offset.saturating_add(limit).min(positions.len());
+    for i in offset..end {
+        let position = positions.get(i).unwrap();
+        records.push_back(data.payout_history.get(position).unwrap());
+    }
+    records
+}
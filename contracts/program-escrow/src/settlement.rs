@@ -0,0 +1,173 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/settlement.rs
+//
+// Settlement batching for high-frequency programs.
+//
+// When enabled for a program, `single_payout` does not transfer or reserve
+// a claim. The net amount is deducted from `remaining_balance` and queued
+// instead. A flush sums the queue per recipient and performs one token
+// transfer for each recipient.
+//
+// A flush happens when:
+//   - anyone calls `flush_settlements(program_id)`,
+//   - the queue reaches `flush_threshold` entries (0 disables this trigger),
+//   - the oldest queued entry is `max_delay_secs` old when another payout
+//     is queued (0 disables this trigger), or
+//   - batching is switched off.
+//
+// Storage:
+//   DataKey::SettlementConfig(String) → SettlementConfig
+//   DataKey::SettlementQueue(String)  → Vec<PayoutRecord>
+// ============================================================
+
+use crate::{program_storage, DataKey, PayoutRecord, ProgramData, ProgramEscrowContract};
+use soroban_sdk::{contracttype, symbol_short, token, Address, Env, String, Symbol, Vec};
+
+const SETTLEMENT_SET: Symbol = symbol_short!("stl_set");
+const SETTLEMENT_QUEUED: Symbol = symbol_short!("stl_q");
+const SETTLEMENT_FLUSHED: Symbol = symbol_short!("stl_flush");
+
+/// Settlement batching settings for a program.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SettlementConfig {
+    pub enabled: bool,
+    /// Queue length that triggers a flush; 0 disables the trigger.
+    pub flush_threshold: u32,
+    /// Age in seconds of the oldest entry that triggers a flush; 0 disables it.
+    pub max_delay_secs: u64,
+}
+
+/// Configures settlement batching for a program. Disabling it flushes any
+/// queued payouts. Only the program's authorized payout key may call this.
+pub fn set_settlement_batching(
+    env: &Env,
+    program_id: &String,
+    enabled: bool,
+    flush_threshold: u32,
+    max_delay_secs: u64,
+) {
+    let mut program: ProgramData =
+        program_storage::load(env, program_id).unwrap_or_else(|| panic!("Program not found"));
+    program.authorized_payout_key.require_auth();
+
+    let config = SettlementConfig {
+        enabled,
+        flush_threshold,
+        max_delay_secs,
+    };
+    env.storage()
+        .instance()
+        .set(&DataKey::SettlementConfig(program_id.clone()), &config);
+
+    if !enabled && flush(env, program_id, &mut program) > 0 {
+        program_storage::save(env, &program);
+    }
+
+    env.events()
+        .publish((SETTLEMENT_SET,), (program_id.clone(), config));
+}
+
+/// Returns the settlement batching settings for a program, if any.
+pub fn get_settlement_config(env: &Env, program_id: &String) -> Option<SettlementConfig> {
+    env.storage()
+        .instance()
+        .get(&DataKey::SettlementConfig(program_id.clone()))
+}
+
+/// Whether payouts for the program are currently queued instead of paid.
+pub fn is_enabled(env: &Env, program_id: &String) -> bool {
+    get_settlement_config(env, program_id)
+        .map(|config| config.enabled)
+        .unwrap_or(false)
+}
+
+/// Returns the payouts waiting for the next flush, oldest first.
+pub fn get_queue(env: &Env, program_id: &String) -> Vec<PayoutRecord> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::SettlementQueue(program_id.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Queues `amount` for `recipient`, deducting it from the program balance,
+/// and flushes if the threshold or delay has been reached.
+pub fn enqueue(
+    env: &Env,
+    program_id: &String,
+    program: &mut ProgramData,
+    recipient: &Address,
+    amount: i128,
+) {
+    let now = env.ledger().timestamp();
+    let mut queue = get_queue(env, program_id);
+    queue.push_back(PayoutRecord {
+        recipient: recipient.clone(),
+        amount,
+        timestamp: now,
+        memo_hash: None,
+    });
+    program.remaining_balance -= amount;
+    env.storage()
+        .persistent()
+        .set(&DataKey::SettlementQueue(program_id.clone()), &queue);
+
+    env.events().publish(
+        (SETTLEMENT_QUEUED,),
+        (program_id.clone(), recipient.clone(), amount),
+    );
+
+    let config = get_settlement_config(env, program_id).unwrap();
+    let oldest = queue.get(0).unwrap().timestamp;
+    let threshold_reached = config.flush_threshold > 0 && queue.len() >= config.flush_threshold;
+    let delay_reached = config.max_delay_secs > 0 && now - oldest >= config.max_delay_secs;
+    if threshold_reached || delay_reached {
+        flush(env, program_id, program);
+    }
+}
+
+/// Pays out the queue with one transfer per recipient and records each in
+/// the program's payout history. Returns the number of transfers made.
+pub fn flush(env: &Env, program_id: &String, program: &mut ProgramData) -> u32 {
+    let queue = get_queue(env, program_id);
+    if queue.is_empty() {
+        return 0;
+    }
+
+    let mut recipients: Vec<Address> = Vec::new(env);
+    let mut amounts: Vec<i128> = Vec::new(env);
+    for entry in queue.iter() {
+        recipients.push_back(entry.recipient);
+        amounts.push_back(entry.amount);
+    }
+    let (recipients, amounts) =
+        ProgramEscrowContract::consolidate_recipients(env, &recipients, &amounts);
+
+    let contract_address = env.current_contract_address();
+    let token_client = token::Client::new(env, &program.token_address);
+    let now = env.ledger().timestamp();
+    let mut total: i128 = 0;
+    for i in 0..recipients.len() {
+        let recipient = recipients.get(i).unwrap();
+        let amount = amounts.get(i).unwrap();
+        token_client.transfer(&contract_address, &recipient, &amount);
+        program.payout_history.push_back(PayoutRecord {
+            recipient,
+            amount,
+            timestamp: now,
+            memo_hash: None,
+        });
+        total += amount;
+    }
+
+    env.storage()
+        .persistent()
+        .remove(&DataKey::SettlementQueue(program_id.clone()));
+
+    env.events().publish(
+        (SETTLEMENT_FLUSHED,),
+        (program_id.clone(), recipients.len(), total),
+    );
+
+    recipients.len()
+}
@@ -0,0 +1,95 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_accepted_tokens.rs
+//
+// Tests for the accepted token list of multi-token programs.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+
+use crate::{ProgramEscrowContract, ProgramEscrowContractClient};
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    token: Address,
+    program_id: String,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let payout_key = Address::generate(&env);
+    let token = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let program_id = String::from_str(&env, "MultiToken");
+    client.initialize_program(&program_id, &payout_key, &token);
+
+    TestSetup {
+        env,
+        client,
+        token,
+        program_id,
+    }
+}
+
+#[test]
+fn test_program_token_is_listed() {
+    let t = setup();
+
+    assert_eq!(
+        t.client.get_accepted_tokens(&t.program_id),
+        vec![&t.env, t.token.clone()]
+    );
+    assert!(t.client.is_token_accepted(&t.program_id, &t.token));
+    assert!(!t
+        .client
+        .is_token_accepted(&t.program_id, &Address::generate(&t.env)));
+}
+
+#[test]
+fn test_add_and_remove_token() {
+    let t = setup();
+    let usdc = Address::generate(&t.env);
+
+    t.client.add_accepted_token(&t.program_id, &usdc);
+    t.client.add_accepted_token(&t.program_id, &usdc);
+    assert_eq!(
+        t.client.get_accepted_tokens(&t.program_id),
+        vec![&t.env, t.token.clone(), usdc.clone()]
+    );
+    assert!(t.client.is_token_accepted(&t.program_id, &usdc));
+
+    t.client.remove_accepted_token(&t.program_id, &usdc);
+    assert!(!t.client.is_token_accepted(&t.program_id, &usdc));
+    assert_eq!(t.client.get_accepted_tokens(&t.program_id).len(), 1);
+}
+
+#[test]
+#[should_panic(expected = "Cannot remove program token")]
+fn test_cannot_remove_funded_program_token() {
+    let t = setup();
+    t.client.lock_program_funds(&t.program_id, &5_000);
+
+    t.client.remove_accepted_token(&t.program_id, &t.token);
+}
+
+#[test]
+#[should_panic(expected = "Cannot remove program token")]
+fn test_cannot_remove_empty_program_token() {
+    let t = setup();
+    t.client.remove_accepted_token(&t.program_id, &t.token);
+}
+
+#[test]
+#[should_panic(expected = "Token not accepted")]
+fn test_remove_unknown_token_rejected() {
+    let t = setup();
+    t.client
+        .remove_accepted_token(&t.program_id, &Address::generate(&t.env));
+}
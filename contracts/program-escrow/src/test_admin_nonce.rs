@@ -0,0 +1,81 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_admin_nonce.rs
+//
+// Tests for admin nonce replay protection on `set_admin`,
+// `update_rate_limit_config` and `update_fee_config`.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, Address, Env};
+
+use crate::{ProgramEscrowContract, ProgramEscrowContractClient};
+
+fn setup<'a>(env: &Env) -> (ProgramEscrowContractClient<'a>, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    client.set_admin(&admin, &0);
+    (client, admin)
+}
+
+#[test]
+fn test_nonce_starts_at_zero_and_increments() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+    assert_eq!(client.get_admin_nonce(), 0);
+
+    client.set_admin(&Address::generate(&env), &0);
+    assert_eq!(client.get_admin_nonce(), 1);
+
+    client.update_rate_limit_config(&3600, &5, &0, &1);
+    assert_eq!(client.get_admin_nonce(), 2);
+    assert_eq!(client.get_rate_limit_config().max_operations, 5);
+
+    client.update_fee_config(&None, &Some(100), &None, &Some(true), &2);
+    assert_eq!(client.get_admin_nonce(), 3);
+    assert_eq!(client.get_fee_config().payout_fee_rate, 100);
+}
+
+#[test]
+#[should_panic(expected = "Stale nonce")]
+fn test_replayed_rate_limit_update_panics() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+
+    client.update_rate_limit_config(&3600, &5, &0, &1);
+    client.update_rate_limit_config(&3600, &5, &0, &1);
+}
+
+#[test]
+#[should_panic(expected = "Stale nonce")]
+fn test_replayed_fee_update_panics() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+
+    client.update_fee_config(&None, &Some(100), &None, &Some(true), &0);
+}
+
+#[test]
+#[should_panic(expected = "Stale nonce")]
+fn test_replayed_set_admin_panics() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+
+    client.set_admin(&admin, &0);
+}
+
+#[test]
+fn test_stale_nonce_does_not_increment() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+
+    assert!(client.try_set_admin(&admin, &5).is_err());
+    assert_eq!(client.get_admin_nonce(), 1);
+    client.set_admin(&admin, &1);
+    assert_eq!(client.get_admin_nonce(), 2);
+}
@@ -0,0 +1,94 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_allowed_operations.rs
+//
+// Tests for the admin-managed per-program operation allow-list.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{symbol_short, testutils::Address as _, token, vec, Address, Env, String};
+
+use crate::{ProgramEscrowContract, ProgramEscrowContractClient};
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    program_id: String,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let payout_key = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&contract_id, &10_000);
+
+    let program_id = String::from_str(&env, "Tenant");
+    client.initialize_program(&program_id, &payout_key, &sac.address());
+    client.set_admin(&admin, &0);
+    client.lock_program_funds(&program_id, &5_000);
+
+    TestSetup {
+        env,
+        client,
+        program_id,
+    }
+}
+
+#[test]
+fn test_all_operations_allowed_by_default() {
+    let t = setup();
+    assert_eq!(t.client.get_program_allowed_operations(&t.program_id), None);
+
+    t.client.lock_program_funds(&t.program_id, &1_000);
+    t.client
+        .single_payout(&t.program_id, &Address::generate(&t.env), &1_000);
+}
+
+#[test]
+fn test_payout_only_program_rejects_locks() {
+    let t = setup();
+    t.client
+        .set_program_allowed_operations(&t.program_id, &vec![&t.env, symbol_short!("payout")]);
+
+    assert!(t
+        .client
+        .try_lock_program_funds(&t.program_id, &1_000)
+        .is_err());
+    assert!(t
+        .client
+        .try_withdraw_unlocked_program_funds(&t.program_id, &1_000)
+        .is_err());
+    assert_eq!(t.client.get_remaining_balance(&t.program_id), 5_000);
+
+    t.client
+        .single_payout(&t.program_id, &Address::generate(&t.env), &2_000);
+    assert_eq!(t.client.get_remaining_balance(&t.program_id), 3_000);
+}
+
+#[test]
+fn test_clearing_restores_all_operations() {
+    let t = setup();
+    t.client
+        .set_program_allowed_operations(&t.program_id, &vec![&t.env, symbol_short!("payout")]);
+    t.client.clear_program_allowed_operations(&t.program_id);
+
+    t.client.lock_program_funds(&t.program_id, &1_000);
+    assert_eq!(t.client.get_remaining_balance(&t.program_id), 6_000);
+}
+
+#[test]
+#[should_panic(expected = "Unknown operation")]
+fn test_unknown_operation_rejected() {
+    let t = setup();
+    t.client.set_program_allowed_operations(
+        &t.program_id,
+        &vec![&t.env, symbol_short!("payout"), symbol_short!("mint")],
+    );
+}
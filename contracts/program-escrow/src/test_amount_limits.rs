@@ -0,0 +1,95 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_amount_limits.rs
+//
+// Tests for `update_amount_limits`, where a `0` bound means unbounded.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env, String};
+
+use crate::{AmountLimits, ProgramEscrowContract, ProgramEscrowContractClient};
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    program_id: String,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let payout_key = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&contract_id, &1_000_000);
+
+    let program_id = String::from_str(&env, "Limited");
+    client.initialize_program(&program_id, &payout_key, &sac.address());
+    client.set_admin(&admin, &0);
+
+    TestSetup {
+        env,
+        client,
+        program_id,
+    }
+}
+
+#[test]
+fn test_limits_default_to_unbounded() {
+    let t = setup();
+    assert_eq!(t.client.get_amount_limits(), AmountLimits::default());
+
+    t.client.lock_program_funds(&t.program_id, &1_000_000);
+}
+
+#[test]
+fn test_zero_max_disables_upper_bound_but_keeps_min() {
+    let t = setup();
+    t.client.update_amount_limits(&100, &0, &50, &0, &1);
+
+    t.client.lock_program_funds(&t.program_id, &900_000);
+    assert!(t.client.try_lock_program_funds(&t.program_id, &99).is_err());
+
+    let recipient = Address::generate(&t.env);
+    t.client.single_payout(&t.program_id, &recipient, &800_000);
+    assert!(t
+        .client
+        .try_single_payout(&t.program_id, &recipient, &49)
+        .is_err());
+}
+
+#[test]
+fn test_zero_min_disables_lower_bound_but_keeps_max() {
+    let t = setup();
+    t.client.update_amount_limits(&0, &5_000, &0, &1_000, &1);
+
+    t.client.lock_program_funds(&t.program_id, &1);
+    t.client.lock_program_funds(&t.program_id, &5_000);
+    assert!(t
+        .client
+        .try_lock_program_funds(&t.program_id, &5_001)
+        .is_err());
+
+    let result = t.client.try_batch_payout(
+        &t.program_id,
+        &vec![&t.env, Address::generate(&t.env), Address::generate(&t.env)],
+        &vec![&t.env, 1, 1_001],
+        &false,
+        &None,
+        &1_002,
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+#[should_panic(expected = "Minimum amount cannot exceed maximum")]
+fn test_min_above_nonzero_max_rejected() {
+    let t = setup();
+    t.client.update_amount_limits(&0, &0, &500, &100, &1);
+}
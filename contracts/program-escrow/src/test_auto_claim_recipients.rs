@@ -0,0 +1,104 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_auto_claim_recipients.rs
+//
+// Tests for auto-claim (trusted) recipients on `single_payout`.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, token, Address, Env, String};
+
+use crate::{ProgramEscrowContract, ProgramEscrowContractClient};
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    token: token::Client<'a>,
+    program_id: String,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let payout_key = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    let token = token::Client::new(&env, &sac.address());
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&contract_id, &100_000);
+
+    client.set_admin(&admin, &0);
+    client.set_whitelist(&payout_key, &true);
+
+    let program_id = String::from_str(&env, "Trusted");
+    client.initialize_program(&program_id, &payout_key, &token.address);
+    client.lock_program_funds(&program_id, &100_000);
+
+    TestSetup {
+        env,
+        client,
+        token,
+        program_id,
+    }
+}
+
+#[test]
+fn test_auto_claim_recipient_paid_instantly() {
+    let t = setup();
+    let trusted = Address::generate(&t.env);
+    t.client.add_auto_claim_recipient(&t.program_id, &trusted);
+    assert!(t.client.is_auto_claim_recipient(&t.program_id, &trusted));
+
+    let result = t.client.single_payout(&t.program_id, &trusted, &3_000);
+
+    assert_eq!(result.claim_id, 0);
+    assert_eq!(t.token.balance(&trusted), 3_000);
+    assert_eq!(result.remaining_balance, 97_000);
+    let data = t.client.get_program_info(&t.program_id);
+    assert_eq!(data.payout_history.len(), 1);
+    assert_eq!(data.payout_history.get(0).unwrap().recipient, trusted);
+    assert_eq!(t.client.get_pending_claim_ids(&t.program_id).len(), 0);
+}
+
+#[test]
+fn test_normal_recipient_still_claims() {
+    let t = setup();
+    let trusted = Address::generate(&t.env);
+    let normal = Address::generate(&t.env);
+    t.client.add_auto_claim_recipient(&t.program_id, &trusted);
+
+    let claim_id = t
+        .client
+        .single_payout(&t.program_id, &normal, &3_000)
+        .claim_id;
+
+    assert_ne!(claim_id, 0);
+    assert_eq!(t.token.balance(&normal), 0);
+    assert_eq!(
+        t.client
+            .get_program_info(&t.program_id)
+            .payout_history
+            .len(),
+        0
+    );
+
+    t.client.execute_claim(&t.program_id, &claim_id, &normal);
+    assert_eq!(t.token.balance(&normal), 3_000);
+}
+
+#[test]
+fn test_removed_recipient_goes_through_claim() {
+    let t = setup();
+    let recipient = Address::generate(&t.env);
+    t.client.add_auto_claim_recipient(&t.program_id, &recipient);
+    t.client.remove_auto_claim_recipient(&t.program_id, &recipient);
+    assert!(!t.client.is_auto_claim_recipient(&t.program_id, &recipient));
+
+    let claim_id = t.client.single_payout(&t.program_id, &recipient, &1_000).claim_id;
+    assert_ne!(claim_id, 0);
+    assert_eq!(t.token.balance(&recipient), 0);
+}
@@ -0,0 +1,153 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_badge_minter.rs
+//
+// Tests for minting a commemorative badge on each executed
+// claim.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{
+    contract, contractimpl, symbol_short, testutils::Address as _, token, Address, Env, String,
+    Symbol,
+};
+
+use crate::{ClaimStatus, ProgramEscrowContract, ProgramEscrowContractClient};
+
+const LAST: Symbol = symbol_short!("last");
+const MINTED: Symbol = symbol_short!("minted");
+
+/// Counts the badges it is asked to mint.
+#[contract]
+pub struct MockMinter;
+
+#[contractimpl]
+impl MockMinter {
+    pub fn mint(env: Env, recipient: Address, program_id: String, amount: i128) -> u32 {
+        let minted: u32 = env.storage().instance().get(&MINTED).unwrap_or(0) + 1;
+        env.storage().instance().set(&MINTED, &minted);
+        env.storage()
+            .instance()
+            .set(&LAST, &(recipient, program_id, amount));
+        minted
+    }
+
+    pub fn minted(env: Env) -> u32 {
+        env.storage().instance().get(&MINTED).unwrap_or(0)
+    }
+
+    pub fn last(env: Env) -> (Address, String, i128) {
+        env.storage().instance().get(&LAST).unwrap()
+    }
+}
+
+// Separate module: two `#[contractimpl]`s exporting the same fn name can't share one.
+mod failing {
+    use soroban_sdk::{contract, contractimpl, Address, Env, String};
+
+    /// Always fails.
+    #[contract]
+    pub struct FailingMinter;
+
+    #[contractimpl]
+    impl FailingMinter {
+        pub fn mint(_env: Env, _recipient: Address, _program_id: String, _amount: i128) {
+            panic!("minter exploded");
+        }
+    }
+}
+use failing::FailingMinter;
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    token: token::Client<'a>,
+    program_id: String,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let payout_key = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    let token = token::Client::new(&env, &sac.address());
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&contract_id, &100_000);
+
+    let program_id = String::from_str(&env, "Hackathon");
+    client.initialize_program(&program_id, &payout_key, &token.address);
+    client.lock_program_funds(&program_id, &100_000);
+
+    TestSetup {
+        env,
+        client,
+        token,
+        program_id,
+    }
+}
+
+#[test]
+fn test_minter_invoked_on_claim() {
+    let t = setup();
+    let minter_id = t.env.register_contract(None, MockMinter);
+    let minter = MockMinterClient::new(&t.env, &minter_id);
+    t.client
+        .set_badge_minter(&t.program_id, &Some(minter_id.clone()));
+    assert_eq!(t.client.get_badge_minter(&t.program_id), Some(minter_id));
+
+    let recipient = Address::generate(&t.env);
+    let claim_id = t
+        .client
+        .single_payout(&t.program_id, &recipient, &2_500)
+        .claim_id;
+    assert_eq!(minter.minted(), 0);
+
+    t.client.execute_claim(&t.program_id, &claim_id, &recipient);
+
+    assert_eq!(minter.minted(), 1);
+    assert_eq!(minter.last(), (recipient, t.program_id.clone(), 2_500));
+}
+
+#[test]
+fn test_failing_minter_does_not_block_payout() {
+    let t = setup();
+    let minter_id = t.env.register_contract(None, FailingMinter);
+    t.client.set_badge_minter(&t.program_id, &Some(minter_id));
+
+    let recipient = Address::generate(&t.env);
+    let claim_id = t
+        .client
+        .single_payout(&t.program_id, &recipient, &2_500)
+        .claim_id;
+    t.client.execute_claim(&t.program_id, &claim_id, &recipient);
+
+    assert_eq!(t.token.balance(&recipient), 2_500);
+    assert_eq!(
+        t.client.get_claim_status(&t.program_id, &claim_id),
+        ClaimStatus::Completed
+    );
+}
+
+#[test]
+fn test_cleared_minter_is_not_invoked() {
+    let t = setup();
+    let minter_id = t.env.register_contract(None, MockMinter);
+    let minter = MockMinterClient::new(&t.env, &minter_id);
+    t.client.set_badge_minter(&t.program_id, &Some(minter_id));
+    t.client.set_badge_minter(&t.program_id, &None);
+    assert!(t.client.get_badge_minter(&t.program_id).is_none());
+
+    let recipient = Address::generate(&t.env);
+    let claim_id = t
+        .client
+        .single_payout(&t.program_id, &recipient, &1_000)
+        .claim_id;
+    t.client.execute_claim(&t.program_id, &claim_id, &recipient);
+
+    assert_eq!(minter.minted(), 0);
+}
@@ -0,0 +1,121 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_batch_cancel_claims.rs
+//
+// Tests for cancelling many pending claims with `batch_cancel_claims`.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, Env, String,
+};
+
+use crate::{ClaimStatus, ProgramEscrowContract, ProgramEscrowContractClient};
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    admin: Address,
+    program_id: String,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let payout_key = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&contract_id, &10_000);
+
+    let program_id = String::from_str(&env, "Cancelled");
+    client.initialize_program(&program_id, &payout_key, &sac.address());
+    client.lock_program_funds(&program_id, &10_000);
+    client.set_admin(&admin, &0);
+
+    env.ledger().set_timestamp(1_000);
+
+    TestSetup {
+        env,
+        client,
+        admin,
+        program_id,
+    }
+}
+
+fn create_claim(t: &TestSetup, amount: i128) -> (u64, Address) {
+    let recipient = Address::generate(&t.env);
+    let claim_id = t
+        .client
+        .create_pending_claim(&t.program_id, &recipient, &amount, &5_000);
+    (claim_id, recipient)
+}
+
+#[test]
+fn test_cancels_pending_and_skips_resolved() {
+    let t = setup();
+    let (pending_a, _) = create_claim(&t, 1_000);
+    let (pending_b, _) = create_claim(&t, 2_000);
+    let (executed, recipient) = create_claim(&t, 500);
+    let (cancelled, _) = create_claim(&t, 300);
+    assert_eq!(t.client.get_remaining_balance(&t.program_id), 6_200);
+
+    t.client.execute_claim(&t.program_id, &executed, &recipient);
+    t.client.cancel_claim(&t.program_id, &cancelled, &t.admin);
+
+    let (count, restored) = t.client.batch_cancel_claims(
+        &t.program_id,
+        &vec![&t.env, pending_a, executed, pending_b, cancelled, 999],
+    );
+
+    assert_eq!(count, 2);
+    assert_eq!(restored, 3_000);
+    assert_eq!(t.client.get_remaining_balance(&t.program_id), 9_500);
+    assert_eq!(
+        t.client.get_claim_status(&t.program_id, &pending_a),
+        ClaimStatus::Cancelled
+    );
+    assert_eq!(
+        t.client.get_claim_status(&t.program_id, &executed),
+        ClaimStatus::Completed
+    );
+    assert!(t.client.get_pending_claim_ids(&t.program_id).is_empty());
+}
+
+#[test]
+fn test_swept_claims_are_skipped() {
+    let t = setup();
+    let (expired, _) = create_claim(&t, 1_000);
+    let (also_expired, _) = create_claim(&t, 2_000);
+
+    t.env.ledger().set_timestamp(5_500);
+    let fresh =
+        t.client
+            .create_pending_claim(&t.program_id, &Address::generate(&t.env), &400, &9_000);
+    t.client.sweep_expired_claims(&t.program_id);
+    assert_eq!(t.client.get_remaining_balance(&t.program_id), 9_600);
+
+    let (count, restored) = t
+        .client
+        .batch_cancel_claims(&t.program_id, &vec![&t.env, expired, also_expired, fresh]);
+
+    assert_eq!(count, 1);
+    assert_eq!(restored, 400);
+    assert_eq!(t.client.get_remaining_balance(&t.program_id), 10_000);
+}
+
+#[test]
+fn test_nothing_to_cancel_leaves_balance() {
+    let t = setup();
+    let (count, restored) = t
+        .client
+        .batch_cancel_claims(&t.program_id, &vec![&t.env, 1, 2, 3]);
+
+    assert_eq!((count, restored), (0, 0));
+    assert_eq!(t.client.get_remaining_balance(&t.program_id), 10_000);
+}
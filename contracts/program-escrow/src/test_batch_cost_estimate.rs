@@ -0,0 +1,79 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_batch_cost_estimate.rs
+//
+// Tests for estimating the cost of a batch payout before it
+// is submitted.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+use crate::{ProgramEscrowContract, ProgramEscrowContractClient};
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    program_id: String,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+    let token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+
+    client.set_admin(&admin, &0);
+
+    let program_id = String::from_str(&env, "Estimate");
+    client.initialize_program(&program_id, &Address::generate(&env), &token);
+
+    TestSetup {
+        env,
+        client,
+        program_id,
+    }
+}
+
+#[test]
+fn test_estimate_scales_monotonically_with_count() {
+    let t = setup();
+    let mut previous = t.client.estimate_batch_cost(&t.program_id, &0);
+    for count in [1u32, 2, 10, 50, 100] {
+        let estimate = t.client.estimate_batch_cost(&t.program_id, &count);
+        assert!(estimate > previous);
+        previous = estimate;
+    }
+    assert_eq!(
+        t.client.estimate_batch_cost(&t.program_id, &u32::MAX),
+        u32::MAX
+    );
+}
+
+#[test]
+fn test_estimate_includes_fee_transfers() {
+    let t = setup();
+    let without_fees = t.client.estimate_batch_cost(&t.program_id, &10);
+
+    t.client.update_fee_config(
+        &None,
+        &Some(100),
+        &Some(Address::generate(&t.env)),
+        &Some(true),
+        &1,
+    );
+    assert!(t.client.estimate_batch_cost(&t.program_id, &10) > without_fees);
+}
+
+#[test]
+#[should_panic(expected = "Program not found")]
+fn test_estimate_unknown_program_panics() {
+    let t = setup();
+    t.client
+        .estimate_batch_cost(&String::from_str(&t.env, "Missing"), &1);
+}
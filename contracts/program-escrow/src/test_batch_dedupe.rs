@@ -0,0 +1,90 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_batch_dedupe.rs
+//
+// Tests for `batch_payout` consolidation of repeated recipients.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env, String};
+
+use crate::{ProgramEscrowContract, ProgramEscrowContractClient};
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    token: token::Client<'a>,
+    program_id: String,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let payout_key = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    let token = token::Client::new(&env, &sac.address());
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&contract_id, &100_000);
+
+    let program_id = String::from_str(&env, "Dedupe");
+    client.initialize_program(&program_id, &payout_key, &token.address);
+    client.lock_program_funds(&program_id, &100_000);
+
+    TestSetup {
+        env,
+        client,
+        token,
+        program_id,
+    }
+}
+
+#[test]
+fn test_dedupe_consolidates_duplicate_recipient() {
+    let t = setup();
+    let alice = Address::generate(&t.env);
+    let bob = Address::generate(&t.env);
+
+    let data = t.client.batch_payout(
+        &t.program_id,
+        &vec![&t.env, alice.clone(), bob.clone(), alice.clone()],
+        &vec![&t.env, 1_000, 2_000, 500],
+        &true,
+        &None,
+        &3_500,
+    );
+
+    assert_eq!(data.payout_history.len(), 2);
+    let first = data.payout_history.get(0).unwrap();
+    assert_eq!(first.recipient, alice);
+    assert_eq!(first.amount, 1_500);
+    assert_eq!(t.token.balance(&alice), 1_500);
+    assert_eq!(t.token.balance(&bob), 2_000);
+    assert_eq!(data.remaining_balance, 96_500);
+}
+
+#[test]
+fn test_without_dedupe_pays_separately() {
+    let t = setup();
+    let alice = Address::generate(&t.env);
+    let bob = Address::generate(&t.env);
+
+    let data = t.client.batch_payout(
+        &t.program_id,
+        &vec![&t.env, alice.clone(), bob.clone(), alice.clone()],
+        &vec![&t.env, 1_000, 2_000, 500],
+        &false,
+        &None,
+        &3_500,
+    );
+
+    assert_eq!(data.payout_history.len(), 3);
+    assert_eq!(data.payout_history.get(0).unwrap().amount, 1_000);
+    assert_eq!(data.payout_history.get(2).unwrap().amount, 500);
+    assert_eq!(t.token.balance(&alice), 1_500);
+    assert_eq!(data.remaining_balance, 96_500);
+}
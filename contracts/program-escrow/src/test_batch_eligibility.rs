@@ -0,0 +1,147 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_batch_eligibility.rs
+//
+// Tests for the up-front recipient eligibility pass in
+// `batch_payout`.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env, String, Vec};
+
+use crate::test_payout_condition::{MockCondition, MockConditionClient};
+use crate::{BatchSkipReason, ProgramEscrowContract, ProgramEscrowContractClient};
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    token: token::Client<'a>,
+    program_id: String,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let payout_key = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    let token = token::Client::new(&env, &sac.address());
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&contract_id, &10_000);
+
+    let program_id = String::from_str(&env, "Eligible");
+    client.initialize_program(&program_id, &payout_key, &sac.address());
+    client.lock_program_funds(&program_id, &10_000);
+
+    TestSetup {
+        env,
+        client,
+        token,
+        program_id,
+    }
+}
+
+fn recipients(t: &TestSetup, count: u32) -> Vec<Address> {
+    let mut recipients = Vec::new(&t.env);
+    for _ in 0..count {
+        recipients.push_back(Address::generate(&t.env));
+    }
+    recipients
+}
+
+fn pay(t: &TestSetup, recipients: &Vec<Address>) {
+    let mut amounts: Vec<i128> = Vec::new(&t.env);
+    for _ in recipients.iter() {
+        amounts.push_back(1_000);
+    }
+    let total = 1_000 * recipients.len() as i128;
+    t.client
+        .batch_payout(&t.program_id, recipients, &amounts, &false, &None, &total);
+}
+
+#[test]
+#[should_panic(expected = "Ineligible recipient at index 2: denylisted")]
+fn test_denylisted_recipient_index_reported() {
+    let t = setup();
+    let batch = recipients(&t, 4);
+    t.client
+        .add_to_denylist(&t.program_id, &batch.get(2).unwrap(), &false);
+
+    pay(&t, &batch);
+}
+
+#[test]
+fn test_ineligible_batch_makes_no_transfers() {
+    let t = setup();
+    let batch = recipients(&t, 3);
+    t.client
+        .add_to_denylist(&t.program_id, &batch.get(2).unwrap(), &false);
+
+    let amounts = vec![&t.env, 1_000_i128, 1_000, 1_000];
+    let result = t
+        .client
+        .try_batch_payout(&t.program_id, &batch, &amounts, &false, &None, &3_000);
+
+    assert!(result.is_err());
+    for recipient in batch.iter() {
+        assert_eq!(t.token.balance(&recipient), 0);
+    }
+    assert_eq!(t.client.get_remaining_balance(&t.program_id), 10_000);
+}
+
+#[test]
+#[should_panic(expected = "Ineligible recipient at index 1: payout condition not met")]
+fn test_condition_failure_index_reported() {
+    let t = setup();
+    let condition_id = t.env.register_contract(None, MockCondition);
+    let condition = MockConditionClient::new(&t.env, &condition_id);
+    t.client
+        .set_payout_condition(&t.program_id, &Some(condition_id));
+
+    let batch = recipients(&t, 3);
+    condition.complete(&batch.get(0).unwrap());
+    condition.complete(&batch.get(2).unwrap());
+
+    pay(&t, &batch);
+}
+
+#[test]
+fn test_eligible_batch_pays_everyone() {
+    let t = setup();
+    let batch = recipients(&t, 3);
+
+    pay(&t, &batch);
+
+    for recipient in batch.iter() {
+        assert_eq!(t.token.balance(&recipient), 1_000);
+    }
+    assert_eq!(t.client.get_remaining_balance(&t.program_id), 7_000);
+}
+
+#[test]
+fn test_lenient_batch_skips_condition_failures() {
+    let t = setup();
+    let condition_id = t.env.register_contract(None, MockCondition);
+    let condition = MockConditionClient::new(&t.env, &condition_id);
+    t.client
+        .set_payout_condition(&t.program_id, &Some(condition_id));
+
+    let batch = recipients(&t, 2);
+    condition.complete(&batch.get(1).unwrap());
+    let amounts = vec![&t.env, 1_000_i128, 1_000];
+
+    let result = t
+        .client
+        .batch_payout_lenient(&t.program_id, &batch, &amounts);
+
+    assert_eq!(result.succeeded, 1);
+    assert_eq!(result.skipped_indices, vec![&t.env, 0_u32]);
+    assert_eq!(
+        result.skip_reasons,
+        vec![&t.env, BatchSkipReason::ConditionNotMet]
+    );
+}
@@ -0,0 +1,155 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_batch_lenient.rs
+//
+// Tests for `batch_payout_lenient` and its `batch_partial` event.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Events},
+    token, vec, Address, Env, IntoVal, String, Symbol, Val, Vec,
+};
+
+use crate::{BatchSkipReason, ProgramEscrowContract, ProgramEscrowContractClient};
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    token: token::Client<'a>,
+    program_id: String,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let payout_key = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    let token = token::Client::new(&env, &sac.address());
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&contract_id, &10_000);
+
+    let program_id = String::from_str(&env, "Lenient");
+    client.initialize_program(&program_id, &payout_key, &token.address);
+    client.set_admin(&admin, &0);
+    client.lock_program_funds(&program_id, &10_000);
+    client.update_amount_limits(&0, &0, &0, &5_000, &1);
+
+    TestSetup {
+        env,
+        client,
+        token,
+        program_id,
+    }
+}
+
+fn partial_events(t: &TestSetup) -> Vec<(String, Vec<u32>, Vec<BatchSkipReason>, u32)> {
+    let expected: Vec<Val> = (Symbol::new(&t.env, "batch_partial"),).into_val(&t.env);
+    let mut found = Vec::new(&t.env);
+    for (_, topics, data) in t.env.events().all().iter() {
+        if topics == expected {
+            found.push_back(data.into_val(&t.env));
+        }
+    }
+    found
+}
+
+#[test]
+fn test_lenient_batch_skips_invalid_entries() {
+    let t = setup();
+    let denied = Address::generate(&t.env);
+    t.client.add_to_denylist(&t.program_id, &denied, &false);
+
+    let paid_a = Address::generate(&t.env);
+    let paid_b = Address::generate(&t.env);
+    let recipients = vec![
+        &t.env,
+        paid_a.clone(),
+        Address::generate(&t.env),
+        denied,
+        Address::generate(&t.env),
+        paid_b.clone(),
+        Address::generate(&t.env),
+    ];
+    let amounts = vec![&t.env, 4_000, 0, 1_000, 6_000, 5_000, 2_000];
+
+    let result = t
+        .client
+        .batch_payout_lenient(&t.program_id, &recipients, &amounts);
+
+    assert_eq!(result.succeeded, 2);
+    assert_eq!(result.total_paid, 9_000);
+    assert_eq!(result.skipped_indices, vec![&t.env, 1, 2, 3, 5]);
+    assert_eq!(
+        result.skip_reasons,
+        vec![
+            &t.env,
+            BatchSkipReason::InvalidAmount,
+            BatchSkipReason::RecipientDenylisted,
+            BatchSkipReason::AmountOutOfLimits,
+            BatchSkipReason::InsufficientBalance,
+        ]
+    );
+    assert_eq!(t.token.balance(&paid_a), 4_000);
+    assert_eq!(t.token.balance(&paid_b), 5_000);
+    assert_eq!(t.client.get_remaining_balance(&t.program_id), 1_000);
+
+    assert_eq!(
+        partial_events(&t),
+        vec![
+            &t.env,
+            (
+                t.program_id.clone(),
+                result.skipped_indices.clone(),
+                result.skip_reasons.clone(),
+                2
+            )
+        ]
+    );
+}
+
+#[test]
+fn test_fully_valid_batch_emits_no_partial_event() {
+    let t = setup();
+    let recipients = vec![&t.env, Address::generate(&t.env), Address::generate(&t.env)];
+    let amounts = vec![&t.env, 1_000, 2_000];
+
+    let result = t
+        .client
+        .batch_payout_lenient(&t.program_id, &recipients, &amounts);
+
+    assert_eq!(result.succeeded, 2);
+    assert!(result.skipped_indices.is_empty());
+    assert!(partial_events(&t).is_empty());
+}
+
+#[test]
+fn test_all_entries_skipped_pays_nothing() {
+    let t = setup();
+    let recipients = vec![&t.env, Address::generate(&t.env)];
+    let amounts = vec![&t.env, -5];
+
+    let result = t
+        .client
+        .batch_payout_lenient(&t.program_id, &recipients, &amounts);
+
+    assert_eq!(result.succeeded, 0);
+    assert_eq!(t.client.get_remaining_balance(&t.program_id), 10_000);
+    assert_eq!(
+        partial_events(&t),
+        vec![
+            &t.env,
+            (
+                t.program_id.clone(),
+                vec![&t.env, 0],
+                vec![&t.env, BatchSkipReason::InvalidAmount],
+                0
+            )
+        ]
+    );
+}
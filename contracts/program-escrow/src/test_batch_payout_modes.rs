@@ -0,0 +1,123 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_batch_payout_modes.rs
+//
+// Tests for mixing immediate transfers and pending claims in one
+// `batch_payout` via per-entry `PayoutMode`s.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env, String};
+
+use crate::{ClaimStatus, PayoutMode, ProgramEscrowContract, ProgramEscrowContractClient};
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    token: token::Client<'a>,
+    program_id: String,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let payout_key = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    let token = token::Client::new(&env, &sac.address());
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&contract_id, &10_000);
+
+    let program_id = String::from_str(&env, "Mixed");
+    client.initialize_program(&program_id, &payout_key, &token.address);
+    client.lock_program_funds(&program_id, &10_000);
+
+    TestSetup {
+        env,
+        client,
+        token,
+        program_id,
+    }
+}
+
+#[test]
+fn test_mixed_batch_transfers_immediate_and_reserves_claims() {
+    let t = setup();
+    let trusted = Address::generate(&t.env);
+    let claimant = Address::generate(&t.env);
+
+    let data = t.client.batch_payout(
+        &t.program_id,
+        &vec![&t.env, trusted.clone(), claimant.clone()],
+        &vec![&t.env, 1_000, 2_000],
+        &false,
+        &Some(vec![&t.env, PayoutMode::Immediate, PayoutMode::Claim]),
+        &3_000,
+    );
+
+    assert_eq!(data.remaining_balance, 7_000);
+    assert_eq!(data.payout_history.len(), 1);
+    assert_eq!(t.token.balance(&trusted), 1_000);
+    assert_eq!(t.token.balance(&claimant), 0);
+
+    let pending = t.client.get_pending_claim_ids(&t.program_id);
+    assert_eq!(pending.len(), 1);
+    let claim = t.client.get_claim(&t.program_id, &pending.get(0).unwrap());
+    assert_eq!(claim.recipient, claimant);
+    assert_eq!(claim.amount, 2_000);
+    assert_eq!(claim.status, ClaimStatus::Pending);
+
+    t.client
+        .execute_claim(&t.program_id, &claim.claim_id, &claimant);
+    assert_eq!(t.token.balance(&claimant), 2_000);
+}
+
+#[test]
+fn test_batch_without_modes_pays_immediately() {
+    let t = setup();
+    let recipient = Address::generate(&t.env);
+
+    t.client.batch_payout(
+        &t.program_id,
+        &vec![&t.env, recipient.clone()],
+        &vec![&t.env, 500],
+        &false,
+        &None,
+        &500,
+    );
+
+    assert_eq!(t.token.balance(&recipient), 500);
+    assert_eq!(t.client.get_pending_claim_ids(&t.program_id).len(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Modes and amounts vectors must have the same length")]
+fn test_mismatched_modes_length_panics() {
+    let t = setup();
+    t.client.batch_payout(
+        &t.program_id,
+        &vec![&t.env, Address::generate(&t.env), Address::generate(&t.env)],
+        &vec![&t.env, 500, 500],
+        &false,
+        &Some(vec![&t.env, PayoutMode::Claim]),
+        &1_000,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Cannot dedupe a batch with payout modes")]
+fn test_modes_with_dedupe_panics() {
+    let t = setup();
+    t.client.batch_payout(
+        &t.program_id,
+        &vec![&t.env, Address::generate(&t.env)],
+        &vec![&t.env, 500],
+        &true,
+        &Some(vec![&t.env, PayoutMode::Claim]),
+        &500,
+    );
+}
@@ -0,0 +1,104 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_batch_total_guard.rs
+//
+// Tests for the `expected_total` guard on `batch_payout`.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env, String, Vec};
+
+use crate::{ProgramEscrowContract, ProgramEscrowContractClient};
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    token: token::Client<'a>,
+    program_id: String,
+    recipients: Vec<Address>,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let payout_key = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    let token = token::Client::new(&env, &sac.address());
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&contract_id, &100_000);
+
+    let program_id = String::from_str(&env, "Guarded");
+    client.initialize_program(&program_id, &payout_key, &token.address);
+    client.lock_program_funds(&program_id, &100_000);
+
+    let recipients = vec![&env, Address::generate(&env), Address::generate(&env)];
+
+    TestSetup {
+        env,
+        client,
+        token,
+        program_id,
+        recipients,
+    }
+}
+
+#[test]
+fn test_matching_total_pays_out() {
+    let t = setup();
+
+    let data = t.client.batch_payout(
+        &t.program_id,
+        &t.recipients,
+        &vec![&t.env, 1_000, 2_000],
+        &false,
+        &None,
+        &3_000,
+    );
+
+    assert_eq!(data.remaining_balance, 97_000);
+    assert_eq!(t.token.balance(&t.recipients.get(1).unwrap()), 2_000);
+}
+
+#[test]
+fn test_mismatched_total_aborts_without_transfers() {
+    let t = setup();
+
+    // An extra zero on the second amount
+    let result = t.client.try_batch_payout(
+        &t.program_id,
+        &t.recipients,
+        &vec![&t.env, 1_000, 20_000],
+        &false,
+        &None,
+        &3_000,
+    );
+
+    assert!(result.is_err());
+    assert_eq!(t.token.balance(&t.recipients.get(0).unwrap()), 0);
+    assert_eq!(t.token.balance(&t.recipients.get(1).unwrap()), 0);
+    assert_eq!(t.client.get_remaining_balance(&t.program_id), 100_000);
+    assert!(t
+        .client
+        .get_program_info(&t.program_id)
+        .payout_history
+        .is_empty());
+}
+
+#[test]
+#[should_panic(expected = "Batch total mismatch")]
+fn test_mismatch_panics_with_message() {
+    let t = setup();
+    t.client.batch_payout(
+        &t.program_id,
+        &t.recipients,
+        &vec![&t.env, 1_000, 2_000],
+        &false,
+        &None,
+        &2_999,
+    );
+}
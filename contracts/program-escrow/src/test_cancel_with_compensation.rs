@@ -0,0 +1,190 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_cancel_with_compensation.rs
+//
+// Tests for cancelling a program while paying recipients of
+// pending claims a share of what they were owed.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env, String};
+
+use crate::{ClaimStatus, ProgramEscrowContract, ProgramEscrowContractClient};
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    token: token::Client<'a>,
+    program_id: String,
+    organizer: Address,
+    alice: Address,
+    bob: Address,
+    alice_claim: u64,
+    bob_claim: u64,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let organizer = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    let token = token::Client::new(&env, &sac.address());
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&contract_id, &10_000);
+
+    client.set_admin(&admin, &0);
+    client.set_whitelist(&organizer, &true);
+
+    let program_id = String::from_str(&env, "Cancelled");
+    client.initialize_program(&program_id, &organizer, &token.address);
+    client.lock_program_funds(&program_id, &10_000);
+
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let alice_claim = client.single_payout(&program_id, &alice, &2_000).claim_id;
+    let bob_claim = client.single_payout(&program_id, &bob, &4_000).claim_id;
+
+    TestSetup {
+        env,
+        client,
+        token,
+        program_id,
+        organizer,
+        alice,
+        bob,
+        alice_claim,
+        bob_claim,
+    }
+}
+
+#[test]
+fn test_half_compensation_splits_pending_claims() {
+    let t = setup();
+
+    let (compensated, refunded) = t
+        .client
+        .cancel_program_with_compensation(&t.program_id, &5_000);
+
+    assert_eq!(compensated, 3_000);
+    assert_eq!(refunded, 7_000);
+    assert_eq!(t.token.balance(&t.alice), 1_000);
+    assert_eq!(t.token.balance(&t.bob), 2_000);
+    assert_eq!(t.token.balance(&t.organizer), 7_000);
+    assert_eq!(t.client.get_remaining_balance(&t.program_id), 0);
+    assert!(t.client.is_program_closed(&t.program_id));
+    assert_eq!(
+        t.client.get_claim_status(&t.program_id, &t.alice_claim),
+        ClaimStatus::Cancelled
+    );
+    assert!(t
+        .client
+        .try_execute_claim(&t.program_id, &t.bob_claim, &t.bob)
+        .is_err());
+}
+
+#[test]
+fn test_zero_compensation_refunds_everything() {
+    let t = setup();
+
+    let (compensated, refunded) = t.client.cancel_program_with_compensation(&t.program_id, &0);
+
+    assert_eq!(compensated, 0);
+    assert_eq!(refunded, 10_000);
+    assert_eq!(t.token.balance(&t.alice), 0);
+    assert_eq!(t.token.balance(&t.bob), 0);
+    assert_eq!(t.token.balance(&t.organizer), 10_000);
+    assert_eq!(t.client.get_pending_claim_ids(&t.program_id).len(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Invalid compensation")]
+fn test_compensation_above_full_rejected() {
+    let t = setup();
+    t.client
+        .cancel_program_with_compensation(&t.program_id, &10_001);
+}
+
+#[test]
+fn test_denylisted_recipient_gets_no_compensation() {
+    let t = setup();
+    t.client.add_to_denylist(&t.program_id, &t.alice, &false);
+
+    let (compensated, refunded) = t
+        .client
+        .cancel_program_with_compensation(&t.program_id, &5_000);
+
+    assert_eq!(compensated, 2_000);
+    assert_eq!(refunded, 8_000);
+    assert_eq!(t.token.balance(&t.alice), 0);
+    assert_eq!(t.token.balance(&t.bob), 2_000);
+    assert_eq!(t.token.balance(&t.organizer), 8_000);
+    assert_eq!(
+        t.client.get_claim_status(&t.program_id, &t.alice_claim),
+        ClaimStatus::Cancelled
+    );
+}
+
+#[test]
+fn test_frozen_recipient_gets_no_compensation() {
+    let t = setup();
+    t.client.freeze_recipient(&t.program_id, &t.bob);
+
+    let (compensated, refunded) = t
+        .client
+        .cancel_program_with_compensation(&t.program_id, &5_000);
+
+    assert_eq!(compensated, 1_000);
+    assert_eq!(refunded, 9_000);
+    assert_eq!(t.token.balance(&t.alice), 1_000);
+    assert_eq!(t.token.balance(&t.bob), 0);
+    assert_eq!(t.token.balance(&t.organizer), 9_000);
+}
+
+#[test]
+#[should_panic(expected = "Program has pending multisig payouts")]
+fn test_pending_multisig_payouts_block_cancel() {
+    let t = setup();
+    t.client.set_multisig_payout_config(
+        &t.program_id,
+        &vec![&t.env, Address::generate(&t.env), Address::generate(&t.env)],
+        &2,
+    );
+    t.client
+        .request_multisig_payout(&t.program_id, &Address::generate(&t.env), &1_000);
+
+    t.client
+        .cancel_program_with_compensation(&t.program_id, &5_000);
+}
+
+#[test]
+fn test_refund_goes_to_beneficiary() {
+    let t = setup();
+    let treasury = Address::generate(&t.env);
+    t.client
+        .set_program_beneficiary(&t.program_id, &Some(treasury.clone()));
+
+    let (_, refunded) = t.client.cancel_program_with_compensation(&t.program_id, &0);
+
+    assert_eq!(refunded, 10_000);
+    assert_eq!(t.token.balance(&treasury), 10_000);
+    assert_eq!(t.token.balance(&t.organizer), 0);
+}
+
+#[test]
+#[should_panic(expected = "Program has outstanding contributions")]
+fn test_contributions_block_cancel() {
+    let t = setup();
+    let funder = Address::generate(&t.env);
+    token::StellarAssetClient::new(&t.env, &t.token.address).mint(&funder, &500);
+    t.client.set_open_funding(&t.program_id, &true);
+    t.client
+        .contribute_program_funds(&t.program_id, &funder, &500);
+
+    t.client
+        .cancel_program_with_compensation(&t.program_id, &5_000);
+}
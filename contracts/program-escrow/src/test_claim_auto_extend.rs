@@ -0,0 +1,159 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_claim_auto_extend.rs
+//
+// Tests for extending pending claims when their recipient
+// acknowledges them.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env, String,
+};
+
+use crate::{ProgramEscrowContract, ProgramEscrowContractClient};
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    token: token::Client<'a>,
+    program_id: String,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let payout_key = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&contract_id, &10_000);
+    let token = token::Client::new(&env, &sac.address());
+
+    let program_id = String::from_str(&env, "AutoExtend");
+    client.initialize_program(&program_id, &payout_key, &sac.address());
+    client.lock_program_funds(&program_id, &10_000);
+
+    env.ledger().set_timestamp(1_000);
+
+    TestSetup {
+        env,
+        client,
+        token,
+        program_id,
+    }
+}
+
+/// Creates a claim expiring at 2_000.
+fn create_claim(t: &TestSetup) -> (u64, Address) {
+    let recipient = Address::generate(&t.env);
+    let claim_id = t
+        .client
+        .create_pending_claim(&t.program_id, &recipient, &1_000, &2_000);
+    (claim_id, recipient)
+}
+
+#[test]
+fn test_auto_extend_disabled_by_default() {
+    let t = setup();
+    assert_eq!(t.client.get_auto_extend_on_activity(&t.program_id), 0);
+
+    let (claim_id, recipient) = create_claim(&t);
+    t.env.ledger().set_timestamp(1_900);
+    let deadline = t
+        .client
+        .acknowledge_claim(&t.program_id, &claim_id, &recipient);
+    assert_eq!(deadline, 2_000);
+}
+
+#[test]
+fn test_acknowledgment_allows_claim_past_original_window() {
+    let t = setup();
+    t.client
+        .set_auto_extend_on_activity(&t.program_id, &true, &3_600);
+    let (claim_id, recipient) = create_claim(&t);
+
+    t.env.ledger().set_timestamp(1_900);
+    let deadline = t
+        .client
+        .acknowledge_claim(&t.program_id, &claim_id, &recipient);
+    assert_eq!(deadline, 5_500);
+
+    let claim = t.client.get_claim(&t.program_id, &claim_id);
+    assert_eq!(claim.claim_deadline, 5_500);
+    assert_eq!(claim.extended_secs, 3_500);
+
+    t.env.ledger().set_timestamp(5_000);
+    t.client.execute_claim(&t.program_id, &claim_id, &recipient);
+    assert_eq!(t.token.balance(&recipient), 1_000);
+}
+
+#[test]
+fn test_acknowledgment_never_shortens_deadline() {
+    let t = setup();
+    t.client
+        .set_auto_extend_on_activity(&t.program_id, &true, &300);
+    let (claim_id, recipient) = create_claim(&t);
+
+    let deadline = t
+        .client
+        .acknowledge_claim(&t.program_id, &claim_id, &recipient);
+    assert_eq!(deadline, 2_000);
+}
+
+#[test]
+fn test_acknowledgment_respects_extension_cap() {
+    let t = setup();
+    t.client
+        .set_auto_extend_on_activity(&t.program_id, &true, &3_600);
+    t.client.set_max_claim_extension(&t.program_id, &1_000);
+    let (claim_id, recipient) = create_claim(&t);
+
+    t.env.ledger().set_timestamp(1_900);
+    let deadline = t
+        .client
+        .acknowledge_claim(&t.program_id, &claim_id, &recipient);
+    assert_eq!(deadline, 3_000);
+}
+
+#[test]
+#[should_panic(expected = "ClaimExpired")]
+fn test_acknowledging_expired_claim_panics() {
+    let t = setup();
+    t.client
+        .set_auto_extend_on_activity(&t.program_id, &true, &3_600);
+    let (claim_id, recipient) = create_claim(&t);
+
+    t.env.ledger().set_timestamp(2_001);
+    t.client
+        .acknowledge_claim(&t.program_id, &claim_id, &recipient);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized: only the recipient can acknowledge")]
+fn test_only_recipient_can_acknowledge() {
+    let t = setup();
+    t.client
+        .set_auto_extend_on_activity(&t.program_id, &true, &3_600);
+    let (claim_id, _) = create_claim(&t);
+
+    t.client
+        .acknowledge_claim(&t.program_id, &claim_id, &Address::generate(&t.env));
+}
+
+#[test]
+fn test_disabling_auto_extend() {
+    let t = setup();
+    t.client
+        .set_auto_extend_on_activity(&t.program_id, &true, &3_600);
+    assert_eq!(t.client.get_auto_extend_on_activity(&t.program_id), 3_600);
+
+    t.client
+        .set_auto_extend_on_activity(&t.program_id, &false, &0);
+    assert_eq!(t.client.get_auto_extend_on_activity(&t.program_id), 0);
+}
@@ -0,0 +1,106 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_claim_available_event.rs
+//
+// Tests for the recipient-topic `claim_available` event emitted
+// when `single_payout` reserves a claim.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger},
+    token, Address, Env, IntoVal, String, Symbol, Val, Vec,
+};
+
+use crate::{ProgramEscrowContract, ProgramEscrowContractClient};
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    program_id: String,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let admin = Address::generate(&env);
+    let payout_key = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&contract_id, &100_000);
+
+    client.set_admin(&admin, &0);
+    client.set_whitelist(&payout_key, &true);
+
+    let program_id = String::from_str(&env, "Notify");
+    client.initialize_program(&program_id, &payout_key, &sac.address());
+    client.lock_program_funds(&program_id, &100_000);
+
+    TestSetup {
+        env,
+        client,
+        program_id,
+    }
+}
+
+/// Returns the data of every `claim_available` event whose recipient
+/// topic matches `recipient`.
+fn claim_available_events(t: &TestSetup, recipient: &Address) -> Vec<(u64, i128, u64)> {
+    let expected: Vec<Val> =
+        (Symbol::new(&t.env, "claim_available"), recipient.clone()).into_val(&t.env);
+    let mut found = Vec::new(&t.env);
+    for (_, topics, data) in t.env.events().all().iter() {
+        if topics == expected {
+            found.push_back(data.into_val(&t.env));
+        }
+    }
+    found
+}
+
+#[test]
+fn test_single_payout_emits_claim_available_for_recipient() {
+    let t = setup();
+    let recipient = Address::generate(&t.env);
+
+    let claim_id = t
+        .client
+        .single_payout(&t.program_id, &recipient, &2_500)
+        .claim_id;
+
+    let events = claim_available_events(&t, &recipient);
+    assert_eq!(events.len(), 1);
+    let claim = t.client.get_claim(&t.program_id, &claim_id);
+    assert_eq!(
+        events.get(0).unwrap(),
+        (claim_id, 2_500, claim.claim_deadline)
+    );
+    assert_eq!(claim.claim_deadline, 1_000 + t.client.get_claim_window());
+}
+
+#[test]
+fn test_claim_available_is_scoped_to_recipient_topic() {
+    let t = setup();
+    let recipient = Address::generate(&t.env);
+    let bystander = Address::generate(&t.env);
+
+    t.client.single_payout(&t.program_id, &recipient, &1_000);
+
+    assert_eq!(claim_available_events(&t, &recipient).len(), 1);
+    assert_eq!(claim_available_events(&t, &bystander).len(), 0);
+}
+
+#[test]
+fn test_auto_claim_payout_emits_no_claim_available() {
+    let t = setup();
+    let recipient = Address::generate(&t.env);
+    t.client.add_auto_claim_recipient(&t.program_id, &recipient);
+
+    t.client.single_payout(&t.program_id, &recipient, &1_000);
+
+    assert_eq!(claim_available_events(&t, &recipient).len(), 0);
+}
@@ -0,0 +1,212 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_claim_cancel_cooldown.rs
+//
+// Tests for the cooldown between organizer claim cancellations
+// and for recipient-initiated declines.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger, MockAuth, MockAuthInvoke},
+    token, vec, Address, Env, IntoVal, String,
+};
+
+use crate::{ClaimStatus, ProgramEscrowContract, ProgramEscrowContractClient};
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    contract_id: Address,
+    admin: Address,
+    payout_key: Address,
+    program_id: String,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let payout_key = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&contract_id, &10_000);
+
+    let program_id = String::from_str(&env, "Cooldown");
+    client.initialize_program(&program_id, &payout_key, &sac.address());
+    client.lock_program_funds(&program_id, &10_000);
+    client.set_admin(&admin, &0);
+
+    env.ledger().set_timestamp(1_000);
+    client.set_claim_cancel_cooldown(&program_id, &600);
+
+    TestSetup {
+        env,
+        client,
+        contract_id,
+        admin,
+        payout_key,
+        program_id,
+    }
+}
+
+fn create_claim(t: &TestSetup, amount: i128) -> (u64, Address) {
+    let recipient = Address::generate(&t.env);
+    let claim_id = t
+        .client
+        .create_pending_claim(&t.program_id, &recipient, &amount, &5_000);
+    (claim_id, recipient)
+}
+
+#[test]
+fn test_cooldown_configured() {
+    let t = setup();
+    assert_eq!(t.client.get_claim_cancel_cooldown(&t.program_id), 600);
+
+    t.client.set_claim_cancel_cooldown(&t.program_id, &0);
+    assert_eq!(t.client.get_claim_cancel_cooldown(&t.program_id), 0);
+}
+
+#[test]
+#[should_panic(expected = "Cancel cooldown active")]
+fn test_second_cancel_within_cooldown_panics() {
+    let t = setup();
+    let (first, _) = create_claim(&t, 1_000);
+    let (second, _) = create_claim(&t, 1_000);
+
+    t.client.cancel_claim(&t.program_id, &first, &t.admin);
+    t.env.ledger().set_timestamp(1_599);
+    t.client.cancel_claim(&t.program_id, &second, &t.admin);
+}
+
+#[test]
+#[should_panic(expected = "Cancel cooldown active")]
+fn test_batch_cancel_within_cooldown_panics() {
+    let t = setup();
+    let (first, _) = create_claim(&t, 1_000);
+    let (second, _) = create_claim(&t, 1_000);
+
+    t.client
+        .batch_cancel_claims(&t.program_id, &vec![&t.env, first]);
+    t.client
+        .batch_cancel_claims(&t.program_id, &vec![&t.env, second]);
+}
+
+#[test]
+fn test_cancel_allowed_after_cooldown() {
+    let t = setup();
+    let (first, _) = create_claim(&t, 1_000);
+    let (second, _) = create_claim(&t, 1_000);
+
+    t.client.cancel_claim(&t.program_id, &first, &t.admin);
+    t.env.ledger().set_timestamp(1_600);
+    t.client.cancel_claim(&t.program_id, &second, &t.admin);
+
+    assert_eq!(
+        t.client.get_claim_status(&t.program_id, &second),
+        ClaimStatus::Cancelled
+    );
+    assert_eq!(t.client.get_remaining_balance(&t.program_id), 10_000);
+}
+
+#[test]
+fn test_recipient_decline_exempt_from_cooldown() {
+    let t = setup();
+    let (first, _) = create_claim(&t, 1_000);
+    let (second, recipient) = create_claim(&t, 2_000);
+
+    t.client.cancel_claim(&t.program_id, &first, &t.admin);
+    t.client.decline_claim(&t.program_id, &second, &recipient);
+
+    assert_eq!(
+        t.client.get_claim_status(&t.program_id, &second),
+        ClaimStatus::Cancelled
+    );
+    assert_eq!(t.client.get_remaining_balance(&t.program_id), 10_000);
+}
+
+#[test]
+fn test_decline_does_not_start_cooldown() {
+    let t = setup();
+    let (first, recipient) = create_claim(&t, 1_000);
+    let (second, _) = create_claim(&t, 1_000);
+
+    t.client.decline_claim(&t.program_id, &first, &recipient);
+    t.client.cancel_claim(&t.program_id, &second, &t.admin);
+    assert_eq!(t.client.get_pending_claim_ids(&t.program_id).len(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized: only the recipient can decline")]
+fn test_decline_by_other_address_panics() {
+    let t = setup();
+    let (claim_id, _) = create_claim(&t, 1_000);
+    t.client
+        .decline_claim(&t.program_id, &claim_id, &Address::generate(&t.env));
+}
+
+#[test]
+fn test_payout_key_cannot_change_cooldown() {
+    let t = setup();
+    t.env.mock_auths(&[MockAuth {
+        address: &t.payout_key,
+        invoke: &MockAuthInvoke {
+            contract: &t.contract_id,
+            fn_name: "set_claim_cancel_cooldown",
+            args: (t.program_id.clone(), 0_u64).into_val(&t.env),
+            sub_invokes: &[],
+        },
+    }]);
+
+    assert!(t
+        .client
+        .try_set_claim_cancel_cooldown(&t.program_id, &0)
+        .is_err());
+}
+
+#[test]
+#[should_panic(expected = "Cancel cooldown active")]
+fn test_full_slash_within_cooldown_panics() {
+    let t = setup();
+    let (first, _) = create_claim(&t, 1_000);
+    let (second, _) = create_claim(&t, 1_000);
+
+    t.client.cancel_claim(&t.program_id, &first, &t.admin);
+    t.client.slash_claim(&t.program_id, &second, &10_000);
+}
+
+#[test]
+#[should_panic(expected = "Cancel cooldown active")]
+fn test_full_slash_starts_cooldown() {
+    let t = setup();
+    let (first, _) = create_claim(&t, 1_000);
+    let (second, _) = create_claim(&t, 1_000);
+
+    t.client.slash_claim(&t.program_id, &first, &10_000);
+    t.client.cancel_claim(&t.program_id, &second, &t.admin);
+}
+
+#[test]
+fn test_partial_slash_not_subject_to_cooldown() {
+    let t = setup();
+    let (first, _) = create_claim(&t, 1_000);
+    let (second, _) = create_claim(&t, 1_000);
+
+    t.client.cancel_claim(&t.program_id, &first, &t.admin);
+    assert_eq!(t.client.slash_claim(&t.program_id, &second, &5_000), 500);
+}
+
+#[test]
+#[should_panic(expected = "Cancel cooldown active")]
+fn test_denylist_revocation_within_cooldown_panics() {
+    let t = setup();
+    let (first, _) = create_claim(&t, 1_000);
+    let (_, recipient) = create_claim(&t, 1_000);
+
+    t.client.cancel_claim(&t.program_id, &first, &t.admin);
+    t.client.add_to_denylist(&t.program_id, &recipient, &true);
+}
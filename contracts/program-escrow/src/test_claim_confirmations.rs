@@ -0,0 +1,102 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_claim_confirmations.rs
+//
+// Tests for the per-program ledger maturity delay on claim execution.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env, String,
+};
+
+use crate::{ProgramEscrowContract, ProgramEscrowContractClient};
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    token: token::Client<'a>,
+    program_id: String,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let payout_key = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    let token = token::Client::new(&env, &sac.address());
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&contract_id, &10_000);
+
+    let program_id = String::from_str(&env, "Confirmed");
+    client.initialize_program(&program_id, &payout_key, &token.address);
+    client.lock_program_funds(&program_id, &10_000);
+
+    env.ledger().set_sequence_number(100);
+
+    TestSetup {
+        env,
+        client,
+        token,
+        program_id,
+    }
+}
+
+fn create_claim(t: &TestSetup) -> (u64, Address) {
+    let recipient = Address::generate(&t.env);
+    let claim_id = t
+        .client
+        .create_pending_claim(&t.program_id, &recipient, &1_000, &5_000);
+    (claim_id, recipient)
+}
+
+#[test]
+fn test_claim_before_confirmation_gap_fails() {
+    let t = setup();
+    t.client.set_claim_min_confirmations(&t.program_id, &10);
+    let (claim_id, recipient) = create_claim(&t);
+
+    t.env.ledger().set_sequence_number(109);
+    let result = t
+        .client
+        .try_execute_claim(&t.program_id, &claim_id, &recipient);
+
+    assert!(result.is_err());
+    assert_eq!(t.token.balance(&recipient), 0);
+}
+
+#[test]
+fn test_claim_succeeds_after_confirmation_gap() {
+    let t = setup();
+    t.client.set_claim_min_confirmations(&t.program_id, &10);
+    let (claim_id, recipient) = create_claim(&t);
+    assert_eq!(
+        t.client.get_claim(&t.program_id, &claim_id).created_ledger,
+        100
+    );
+
+    t.env.ledger().set_sequence_number(110);
+    t.client.execute_claim(&t.program_id, &claim_id, &recipient);
+
+    assert_eq!(t.token.balance(&recipient), 1_000);
+}
+
+#[test]
+fn test_zero_confirmations_removes_delay() {
+    let t = setup();
+    t.client.set_claim_min_confirmations(&t.program_id, &10);
+    assert_eq!(t.client.get_claim_min_confirmations(&t.program_id), 10);
+
+    t.client.set_claim_min_confirmations(&t.program_id, &0);
+    assert_eq!(t.client.get_claim_min_confirmations(&t.program_id), 0);
+
+    let (claim_id, recipient) = create_claim(&t);
+    t.client.execute_claim(&t.program_id, &claim_id, &recipient);
+    assert_eq!(t.token.balance(&recipient), 1_000);
+}
@@ -0,0 +1,181 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_claim_expiry_callback.rs
+//
+// Tests for `sweep_expired_claims` and the organizer's
+// `on_claim_expired` callback.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{
+    contract, contractimpl, symbol_short,
+    testutils::{Address as _, Ledger},
+    token, Address, Env, String, Symbol, Vec,
+};
+
+use crate::{ClaimStatus, ProgramEscrowContract, ProgramEscrowContractClient};
+
+const SEEN: Symbol = symbol_short!("seen");
+
+/// Records every expired claim it is told about.
+#[contract]
+pub struct ExpiryRecorder;
+
+#[contractimpl]
+impl ExpiryRecorder {
+    pub fn on_claim_expired(
+        env: Env,
+        program_id: String,
+        claim_id: u64,
+        recipient: Address,
+        amount: i128,
+    ) {
+        let mut seen: Vec<(String, u64, Address, i128)> = env
+            .storage()
+            .instance()
+            .get(&SEEN)
+            .unwrap_or(Vec::new(&env));
+        seen.push_back((program_id, claim_id, recipient, amount));
+        env.storage().instance().set(&SEEN, &seen);
+    }
+
+    pub fn seen(env: Env) -> Vec<(String, u64, Address, i128)> {
+        env.storage()
+            .instance()
+            .get(&SEEN)
+            .unwrap_or(Vec::new(&env))
+    }
+}
+
+// Separate module: two `#[contractimpl]`s exporting the same fn name can't share one.
+mod failing {
+    use soroban_sdk::{contract, contractimpl, Address, Env, String};
+
+    /// Always fails.
+    #[contract]
+    pub struct FailingExpiryCallback;
+
+    #[contractimpl]
+    impl FailingExpiryCallback {
+        pub fn on_claim_expired(
+            _env: Env,
+            _program_id: String,
+            _claim_id: u64,
+            _recipient: Address,
+            _amount: i128,
+        ) {
+            panic!("callback exploded");
+        }
+    }
+}
+use failing::FailingExpiryCallback;
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    program_id: String,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let admin = Address::generate(&env);
+    let payout_key = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&contract_id, &10_000);
+
+    client.set_admin(&admin, &0);
+    client.set_whitelist(&payout_key, &true);
+
+    let program_id = String::from_str(&env, "Expiring");
+    client.initialize_program(&program_id, &payout_key, &sac.address());
+    client.lock_program_funds(&program_id, &10_000);
+    client.set_program_claim_config(&program_id, &100, &0, &0, &false);
+
+    TestSetup {
+        env,
+        client,
+        program_id,
+    }
+}
+
+#[test]
+fn test_sweep_invokes_expiry_callback() {
+    let t = setup();
+    let recorder_id = t.env.register_contract(None, ExpiryRecorder);
+    let recorder = ExpiryRecorderClient::new(&t.env, &recorder_id);
+    t.client
+        .set_expiry_callback(&t.program_id, &Some(recorder_id.clone()));
+
+    let recipient = Address::generate(&t.env);
+    let claim_id = t
+        .client
+        .single_payout(&t.program_id, &recipient, &1_500)
+        .claim_id;
+    assert_eq!(t.client.get_remaining_balance(&t.program_id), 8_500);
+
+    t.env.ledger().set_timestamp(1_101);
+    assert_eq!(t.client.sweep_expired_claims(&t.program_id), 1);
+
+    assert_eq!(t.client.get_remaining_balance(&t.program_id), 10_000);
+    assert_eq!(
+        t.client.get_claim_status(&t.program_id, &claim_id),
+        ClaimStatus::Expired
+    );
+    let seen = recorder.seen();
+    assert_eq!(seen.len(), 1);
+    assert_eq!(
+        seen.get(0).unwrap(),
+        (t.program_id.clone(), claim_id, recipient, 1_500)
+    );
+}
+
+#[test]
+fn test_sweep_skips_claims_within_deadline() {
+    let t = setup();
+    let recorder_id = t.env.register_contract(None, ExpiryRecorder);
+    let recorder = ExpiryRecorderClient::new(&t.env, &recorder_id);
+    t.client
+        .set_expiry_callback(&t.program_id, &Some(recorder_id));
+
+    t.client
+        .single_payout(&t.program_id, &Address::generate(&t.env), &1_000);
+
+    t.env.ledger().set_timestamp(1_100);
+    assert_eq!(t.client.sweep_expired_claims(&t.program_id), 0);
+    assert_eq!(recorder.seen().len(), 0);
+    assert_eq!(t.client.get_pending_claim_ids(&t.program_id).len(), 1);
+}
+
+#[test]
+fn test_failing_callback_does_not_block_sweep() {
+    let t = setup();
+    let failing_id = t.env.register_contract(None, FailingExpiryCallback);
+    t.client
+        .set_expiry_callback(&t.program_id, &Some(failing_id));
+
+    t.client
+        .single_payout(&t.program_id, &Address::generate(&t.env), &1_000);
+
+    t.env.ledger().set_timestamp(1_101);
+    assert_eq!(t.client.sweep_expired_claims(&t.program_id), 1);
+    assert_eq!(t.client.get_remaining_balance(&t.program_id), 10_000);
+    assert_eq!(t.client.get_pending_claim_ids(&t.program_id).len(), 0);
+}
+
+#[test]
+fn test_sweep_without_callback() {
+    let t = setup();
+    assert_eq!(t.client.get_expiry_callback(&t.program_id), None);
+    t.client
+        .single_payout(&t.program_id, &Address::generate(&t.env), &1_000);
+
+    t.env.ledger().set_timestamp(1_101);
+    assert_eq!(t.client.sweep_expired_claims(&t.program_id), 1);
+}
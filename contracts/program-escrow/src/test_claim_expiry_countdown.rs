@@ -0,0 +1,128 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_claim_expiry_countdown.rs
+//
+// Tests for the `seconds_until_expiry` countdown returned by
+// `get_claim_view`.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env, String,
+};
+
+use crate::{ProgramEscrowContract, ProgramEscrowContractClient};
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    program_id: String,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let payout_key = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&contract_id, &10_000);
+
+    let program_id = String::from_str(&env, "Countdown");
+    client.initialize_program(&program_id, &payout_key, &sac.address());
+    client.lock_program_funds(&program_id, &10_000);
+
+    env.ledger().set_timestamp(1_000);
+
+    TestSetup {
+        env,
+        client,
+        program_id,
+    }
+}
+
+#[test]
+fn test_countdown_decreases_as_ledger_advances() {
+    let t = setup();
+    let recipient = Address::generate(&t.env);
+    let claim_id = t
+        .client
+        .create_pending_claim(&t.program_id, &recipient, &500, &4_000);
+
+    assert_eq!(
+        t.client
+            .get_claim_view(&t.program_id, &claim_id)
+            .seconds_until_expiry,
+        3_000
+    );
+
+    t.env.ledger().set_timestamp(2_500);
+    assert_eq!(
+        t.client
+            .get_claim_view(&t.program_id, &claim_id)
+            .seconds_until_expiry,
+        1_500
+    );
+}
+
+#[test]
+fn test_countdown_hits_zero_at_and_after_expiry() {
+    let t = setup();
+    let recipient = Address::generate(&t.env);
+    let claim_id = t
+        .client
+        .create_pending_claim(&t.program_id, &recipient, &500, &4_000);
+
+    t.env.ledger().set_timestamp(4_000);
+    assert_eq!(
+        t.client
+            .get_claim_view(&t.program_id, &claim_id)
+            .seconds_until_expiry,
+        0
+    );
+
+    t.env.ledger().set_timestamp(9_000);
+    assert_eq!(
+        t.client
+            .get_claim_view(&t.program_id, &claim_id)
+            .seconds_until_expiry,
+        0
+    );
+}
+
+#[test]
+fn test_resolved_claim_reports_zero() {
+    let t = setup();
+    let recipient = Address::generate(&t.env);
+    let claim_id = t
+        .client
+        .create_pending_claim(&t.program_id, &recipient, &500, &4_000);
+
+    t.client.execute_claim(&t.program_id, &claim_id, &recipient);
+
+    assert_eq!(
+        t.client
+            .get_claim_view(&t.program_id, &claim_id)
+            .seconds_until_expiry,
+        0
+    );
+}
+
+#[test]
+fn test_view_wraps_stored_claim() {
+    let t = setup();
+    let recipient = Address::generate(&t.env);
+    let claim_id = t
+        .client
+        .create_pending_claim(&t.program_id, &recipient, &500, &4_000);
+
+    t.env.ledger().set_timestamp(3_000);
+    let view = t.client.get_claim_view(&t.program_id, &claim_id);
+    assert_eq!(view.claim, t.client.get_claim(&t.program_id, &claim_id));
+    assert_eq!(view.seconds_until_expiry, 1_000);
+}
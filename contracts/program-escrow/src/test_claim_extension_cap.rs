@@ -0,0 +1,106 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_claim_extension_cap.rs
+//
+// Tests for `extend_claim_window` and the per-claim extension cap.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, token, Address, Env, String};
+
+use crate::{ProgramEscrowContract, ProgramEscrowContractClient};
+
+struct TestSetup<'a> {
+    client: ProgramEscrowContractClient<'a>,
+    program_id: String,
+    claim_id: u64,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let payout_key = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&contract_id, &10_000);
+
+    let program_id = String::from_str(&env, "Extended");
+    client.initialize_program(&program_id, &payout_key, &sac.address());
+    client.lock_program_funds(&program_id, &10_000);
+
+    let claim_id =
+        client.create_pending_claim(&program_id, &Address::generate(&env), &1_000, &5_000);
+
+    TestSetup {
+        client,
+        program_id,
+        claim_id,
+    }
+}
+
+#[test]
+fn test_uncapped_extension_moves_deadline() {
+    let t = setup();
+    assert_eq!(t.client.get_max_claim_extension(&t.program_id), 0);
+
+    let deadline = t
+        .client
+        .extend_claim_window(&t.program_id, &t.claim_id, &100_000);
+
+    assert_eq!(deadline, 105_000);
+    let claim = t.client.get_claim(&t.program_id, &t.claim_id);
+    assert_eq!(claim.claim_deadline, 105_000);
+    assert_eq!(claim.extended_secs, 100_000);
+}
+
+#[test]
+fn test_extensions_up_to_cap_succeed() {
+    let t = setup();
+    t.client.set_max_claim_extension(&t.program_id, &3_000);
+
+    t.client
+        .extend_claim_window(&t.program_id, &t.claim_id, &1_000);
+    let deadline = t
+        .client
+        .extend_claim_window(&t.program_id, &t.claim_id, &2_000);
+
+    assert_eq!(deadline, 8_000);
+    assert_eq!(
+        t.client.get_claim(&t.program_id, &t.claim_id).extended_secs,
+        3_000
+    );
+}
+
+#[test]
+#[should_panic(expected = "Extension cap reached")]
+fn test_extension_beyond_cap_rejected() {
+    let t = setup();
+    t.client.set_max_claim_extension(&t.program_id, &3_000);
+
+    t.client
+        .extend_claim_window(&t.program_id, &t.claim_id, &2_000);
+    t.client
+        .extend_claim_window(&t.program_id, &t.claim_id, &1_001);
+}
+
+#[test]
+fn test_rejected_extension_leaves_deadline_unchanged() {
+    let t = setup();
+    t.client.set_max_claim_extension(&t.program_id, &500);
+
+    assert!(t
+        .client
+        .try_extend_claim_window(&t.program_id, &t.claim_id, &501)
+        .is_err());
+    assert_eq!(
+        t.client
+            .get_claim(&t.program_id, &t.claim_id)
+            .claim_deadline,
+        5_000
+    );
+}
@@ -0,0 +1,133 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_claim_grace_period.rs
+//
+// Tests for the late-claim grace period configured through
+// `set_program_claim_config(.., grace_secs, late_penalty_bps, ..)`.
+//
+// Timeline for a claim created at T with a 1 day window and 1 day grace:
+//   T .. T+1d        → on time, full amount
+//   T+1d .. T+2d     → late, amount minus penalty (penalty back to balance)
+//   after T+2d       → expired
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env, String,
+};
+
+use crate::{ClaimStatus, ProgramEscrowContract, ProgramEscrowContractClient};
+
+const START: u64 = 1_000;
+const DAY: u64 = 86_400;
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    token: token::Client<'a>,
+    program_id: String,
+    recipient: Address,
+    claim_id: u64,
+}
+
+/// Program with a 1 day window, 1 day grace and 10% late penalty, holding one
+/// 10_000 unit claim out of a 100_000 balance.
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(START);
+
+    let admin = Address::generate(&env);
+    let payout_key = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    let token = token::Client::new(&env, &sac.address());
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&contract_id, &100_000);
+
+    client.set_admin(&admin, &0);
+    client.set_whitelist(&payout_key, &true);
+
+    let program_id = String::from_str(&env, "Grace");
+    client.initialize_program(&program_id, &payout_key, &token.address);
+    client.lock_program_funds(&program_id, &100_000);
+    client.set_program_claim_config(&program_id, &DAY, &DAY, &1_000, &false);
+
+    let recipient = Address::generate(&env);
+    let claim_id = client
+        .single_payout(&program_id, &recipient, &10_000)
+        .claim_id;
+
+    TestSetup {
+        env,
+        client,
+        token,
+        program_id,
+        recipient,
+        claim_id,
+    }
+}
+
+#[test]
+fn test_on_time_claim_pays_full_amount() {
+    let t = setup();
+    t.env.ledger().set_timestamp(START + DAY);
+
+    t.client
+        .execute_claim(&t.program_id, &t.claim_id, &t.recipient);
+
+    assert_eq!(t.token.balance(&t.recipient), 10_000);
+    assert_eq!(t.client.get_remaining_balance(&t.program_id), 90_000);
+}
+
+#[test]
+fn test_claim_in_grace_is_penalized() {
+    let t = setup();
+    t.env.ledger().set_timestamp(START + DAY + 1);
+
+    t.client
+        .execute_claim(&t.program_id, &t.claim_id, &t.recipient);
+
+    assert_eq!(t.token.balance(&t.recipient), 9_000);
+    // the 1_000 penalty returns to the program balance
+    assert_eq!(t.client.get_remaining_balance(&t.program_id), 91_000);
+    assert_eq!(
+        t.client.get_claim_status(&t.program_id, &t.claim_id),
+        ClaimStatus::Completed
+    );
+    let history = t.client.get_program_info(&t.program_id).payout_history;
+    assert_eq!(history.get(0).unwrap().amount, 9_000);
+}
+
+#[test]
+#[should_panic(expected = "ClaimExpired")]
+fn test_claim_after_grace_rejected() {
+    let t = setup();
+    t.env.ledger().set_timestamp(START + 2 * DAY + 1);
+
+    t.client
+        .execute_claim(&t.program_id, &t.claim_id, &t.recipient);
+}
+
+#[test]
+#[should_panic(expected = "ClaimExpired")]
+fn test_no_grace_expires_at_deadline() {
+    let t = setup();
+    t.client
+        .set_program_claim_config(&t.program_id, &DAY, &0, &1_000, &false);
+    t.env.ledger().set_timestamp(START + DAY + 1);
+
+    t.client
+        .execute_claim(&t.program_id, &t.claim_id, &t.recipient);
+}
+
+#[test]
+#[should_panic(expected = "Late penalty cannot exceed 10000 bps")]
+fn test_penalty_above_100_percent_rejected() {
+    let t = setup();
+    t.client
+        .set_program_claim_config(&t.program_id, &DAY, &DAY, &10_001, &false);
+}
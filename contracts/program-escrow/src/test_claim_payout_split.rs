@@ -0,0 +1,155 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_claim_payout_split.rs
+//
+// Tests for executing a claim across several destinations.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, Env, String,
+};
+
+use crate::{claim_period::ClaimStatus, ProgramEscrowContract, ProgramEscrowContractClient};
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    token: token::Client<'a>,
+    program_id: String,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let payout_key = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&contract_id, &10_000);
+    let token = token::Client::new(&env, &sac.address());
+
+    let program_id = String::from_str(&env, "SplitClaim");
+    client.initialize_program(&program_id, &payout_key, &sac.address());
+    client.lock_program_funds(&program_id, &10_000);
+
+    env.ledger().set_timestamp(1_000);
+
+    TestSetup {
+        env,
+        client,
+        token,
+        program_id,
+    }
+}
+
+#[test]
+fn test_claim_split_across_two_destinations() {
+    let t = setup();
+    let recipient = Address::generate(&t.env);
+    let personal = Address::generate(&t.env);
+    let savings = Address::generate(&t.env);
+    let claim_id = t
+        .client
+        .create_pending_claim(&t.program_id, &recipient, &1_000, &2_000);
+
+    t.client.claim_payout_split(
+        &t.program_id,
+        &claim_id,
+        &recipient,
+        &vec![&t.env, personal.clone(), savings.clone()],
+        &vec![&t.env, 700, 300],
+    );
+
+    assert_eq!(t.token.balance(&personal), 700);
+    assert_eq!(t.token.balance(&savings), 300);
+    assert_eq!(t.token.balance(&recipient), 0);
+    assert_eq!(
+        t.client.get_claim(&t.program_id, &claim_id).status,
+        ClaimStatus::Completed
+    );
+    assert_eq!(t.client.get_remaining_balance(&t.program_id), 9_000);
+}
+
+#[test]
+#[should_panic(expected = "Split amounts must sum to the claimable amount")]
+fn test_claim_split_rejects_mismatched_sum() {
+    let t = setup();
+    let recipient = Address::generate(&t.env);
+    let claim_id = t
+        .client
+        .create_pending_claim(&t.program_id, &recipient, &1_000, &2_000);
+
+    t.client.claim_payout_split(
+        &t.program_id,
+        &claim_id,
+        &recipient,
+        &vec![&t.env, Address::generate(&t.env), Address::generate(&t.env)],
+        &vec![&t.env, 700, 200],
+    );
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized: only the claim recipient can execute this claim")]
+fn test_claim_split_requires_recipient() {
+    let t = setup();
+    let recipient = Address::generate(&t.env);
+    let claim_id = t
+        .client
+        .create_pending_claim(&t.program_id, &recipient, &1_000, &2_000);
+
+    let other = Address::generate(&t.env);
+    t.client.claim_payout_split(
+        &t.program_id,
+        &claim_id,
+        &other,
+        &vec![&t.env, other.clone()],
+        &vec![&t.env, 1_000],
+    );
+}
+
+#[test]
+#[should_panic(expected = "Recipient is denylisted")]
+fn test_claim_split_rejects_denylisted_destination() {
+    let t = setup();
+    let recipient = Address::generate(&t.env);
+    let blocked = Address::generate(&t.env);
+    let claim_id = t
+        .client
+        .create_pending_claim(&t.program_id, &recipient, &1_000, &2_000);
+    t.client.add_to_denylist(&t.program_id, &blocked, &false);
+
+    t.client.claim_payout_split(
+        &t.program_id,
+        &claim_id,
+        &recipient,
+        &vec![&t.env, recipient.clone(), blocked],
+        &vec![&t.env, 500, 500],
+    );
+}
+
+#[test]
+#[should_panic(expected = "Recipient is frozen")]
+fn test_claim_split_rejects_frozen_destination() {
+    let t = setup();
+    let recipient = Address::generate(&t.env);
+    let frozen = Address::generate(&t.env);
+    let claim_id = t
+        .client
+        .create_pending_claim(&t.program_id, &recipient, &1_000, &2_000);
+    t.client.set_admin(&Address::generate(&t.env), &0);
+    t.client.freeze_recipient(&t.program_id, &frozen);
+
+    t.client.claim_payout_split(
+        &t.program_id,
+        &claim_id,
+        &recipient,
+        &vec![&t.env, frozen],
+        &vec![&t.env, 1_000],
+    );
+}
@@ -70,7 +70,7 @@ fn setup<'a>() -> TestSetup<'a> {
     // lock funds
     client.lock_program_funds(&500_000_i128);
 
-    client.set_admin(&admin);
+    client.set_admin(&admin, &0);
 
     //  ledger timestamp
     env.ledger().set(LedgerInfo {
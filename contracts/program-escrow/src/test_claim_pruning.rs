@@ -0,0 +1,140 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_claim_pruning.rs
+//
+// Tests for pruning resolved claims out of the active claim set.
+//
+// - Executed and cancelled claims leave `get_pending_claim_ids`
+// - `get_claim` / `get_claim_status` still resolve them from history
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env, String, Vec,
+};
+
+use crate::{ClaimStatus, ProgramEscrowContract, ProgramEscrowContractClient};
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    token: token::Client<'a>,
+    admin: Address,
+    program_id: String,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let payout_key = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    let token = token::Client::new(&env, &sac.address());
+    let token_admin = token::StellarAssetClient::new(&env, &sac.address());
+
+    let program_id = String::from_str(&env, "PruneProgram");
+    client.initialize_program(&program_id, &payout_key, &token.address);
+
+    token_admin.mint(&contract_id, &1_000_000);
+    client.lock_program_funds(&program_id, &1_000_000);
+    client.set_admin(&admin, &0);
+
+    env.ledger().set_timestamp(1_000);
+
+    TestSetup {
+        env,
+        client,
+        token,
+        admin,
+        program_id,
+    }
+}
+
+fn create_claims(t: &TestSetup, count: u32) -> (Vec<u64>, Vec<Address>) {
+    let mut ids = Vec::new(&t.env);
+    let mut recipients = Vec::new(&t.env);
+    for _ in 0..count {
+        let recipient = Address::generate(&t.env);
+        let id = t
+            .client
+            .create_pending_claim(&t.program_id, &recipient, &1_000, &10_000);
+        ids.push_back(id);
+        recipients.push_back(recipient);
+    }
+    (ids, recipients)
+}
+
+#[test]
+fn test_executed_claims_are_pruned_from_active_set() {
+    let t = setup();
+    let (ids, recipients) = create_claims(&t, 10);
+    assert_eq!(t.client.get_pending_claim_ids(&t.program_id).len(), 10);
+
+    for i in 0..6 {
+        t.client
+            .execute_claim(&t.program_id, &ids.get(i).unwrap(), &recipients.get(i).unwrap());
+    }
+
+    let active = t.client.get_pending_claim_ids(&t.program_id);
+    assert_eq!(active.len(), 4);
+    for i in 6..10 {
+        assert!(active.contains(ids.get(i).unwrap()));
+    }
+}
+
+#[test]
+fn test_completed_claim_resolves_from_history() {
+    let t = setup();
+    let (ids, recipients) = create_claims(&t, 1);
+    let claim_id = ids.get(0).unwrap();
+    let recipient = recipients.get(0).unwrap();
+
+    t.client.execute_claim(&t.program_id, &claim_id, &recipient);
+
+    let claim = t.client.get_claim(&t.program_id, &claim_id);
+    assert_eq!(claim.status, ClaimStatus::Completed);
+    assert_eq!(claim.recipient, recipient);
+    assert_eq!(claim.amount, 1_000);
+    assert_eq!(
+        t.client.get_claim_status(&t.program_id, &claim_id),
+        ClaimStatus::Completed
+    );
+    assert_eq!(t.token.balance(&recipient), 1_000);
+}
+
+#[test]
+fn test_cancelled_claim_is_pruned_and_restores_balance() {
+    let t = setup();
+    let (ids, _) = create_claims(&t, 2);
+    assert_eq!(t.client.get_remaining_balance(&t.program_id), 998_000);
+
+    t.client
+        .cancel_claim(&t.program_id, &ids.get(0).unwrap(), &t.admin);
+
+    let active = t.client.get_pending_claim_ids(&t.program_id);
+    assert_eq!(active.len(), 1);
+    assert_eq!(active.get(0).unwrap(), ids.get(1).unwrap());
+    assert_eq!(
+        t.client.get_claim_status(&t.program_id, &ids.get(0).unwrap()),
+        ClaimStatus::Cancelled
+    );
+    assert_eq!(t.client.get_remaining_balance(&t.program_id), 999_000);
+}
+
+#[test]
+#[should_panic(expected = "ClaimAlreadyProcessed")]
+fn test_executing_archived_claim_again_panics() {
+    let t = setup();
+    let (ids, recipients) = create_claims(&t, 1);
+    let claim_id = ids.get(0).unwrap();
+    let recipient = recipients.get(0).unwrap();
+
+    t.client.execute_claim(&t.program_id, &claim_id, &recipient);
+    t.client.execute_claim(&t.program_id, &claim_id, &recipient);
+}
@@ -0,0 +1,84 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_claim_tip.rs
+//
+// Tests for donating part of a claim back with `execute_claim_with_tip`.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, token, Address, Env, String};
+
+use crate::{ProgramEscrowContract, ProgramEscrowContractClient};
+
+struct TestSetup<'a> {
+    client: ProgramEscrowContractClient<'a>,
+    token: token::Client<'a>,
+    program_id: String,
+    recipient: Address,
+    claim_id: u64,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let payout_key = Address::generate(&env);
+    let recipient = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    let token = token::Client::new(&env, &sac.address());
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&contract_id, &10_000);
+
+    let program_id = String::from_str(&env, "Community");
+    client.initialize_program(&program_id, &payout_key, &token.address);
+    client.lock_program_funds(&program_id, &10_000);
+    let claim_id = client.create_pending_claim(&program_id, &recipient, &2_000, &5_000);
+
+    TestSetup {
+        client,
+        token,
+        program_id,
+        recipient,
+        claim_id,
+    }
+}
+
+#[test]
+fn test_claim_without_tip() {
+    let t = setup();
+
+    t.client
+        .execute_claim_with_tip(&t.program_id, &t.claim_id, &t.recipient, &0);
+
+    assert_eq!(t.token.balance(&t.recipient), 2_000);
+    assert_eq!(t.client.get_remaining_balance(&t.program_id), 8_000);
+    assert_eq!(t.client.get_claim(&t.program_id, &t.claim_id).tip_amount, 0);
+}
+
+#[test]
+fn test_claim_with_ten_percent_tip() {
+    let t = setup();
+
+    t.client
+        .execute_claim_with_tip(&t.program_id, &t.claim_id, &t.recipient, &1_000);
+
+    assert_eq!(t.token.balance(&t.recipient), 1_800);
+    assert_eq!(t.client.get_remaining_balance(&t.program_id), 8_200);
+    assert_eq!(t.token.balance(&t.client.address), 8_200);
+    assert_eq!(
+        t.client.get_claim(&t.program_id, &t.claim_id).tip_amount,
+        200
+    );
+}
+
+#[test]
+#[should_panic(expected = "Tip cannot exceed 10000 bps")]
+fn test_tip_above_100_percent_rejected() {
+    let t = setup();
+    t.client
+        .execute_claim_with_tip(&t.program_id, &t.claim_id, &t.recipient, &10_001);
+}
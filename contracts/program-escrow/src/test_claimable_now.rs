@@ -0,0 +1,136 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_claimable_now.rs
+//
+// Tests for `get_claimable_now`.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env, String,
+};
+
+use crate::{ProgramEscrowContract, ProgramEscrowContractClient};
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    token: token::Client<'a>,
+    program_id: String,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let admin = Address::generate(&env);
+    let payout_key = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&contract_id, &100_000);
+    let token = token::Client::new(&env, &sac.address());
+
+    client.set_admin(&admin, &0);
+    client.set_whitelist(&payout_key, &true);
+
+    let program_id = String::from_str(&env, "Vesting");
+    client.initialize_program(&program_id, &payout_key, &sac.address());
+    client.lock_program_funds(&program_id, &100_000);
+
+    TestSetup {
+        env,
+        client,
+        token,
+        program_id,
+    }
+}
+
+fn schedule(t: &TestSetup, amount: i128, release_timestamp: u64) -> (u64, Address) {
+    let recipient = Address::generate(&t.env);
+    t.client.create_program_release_schedule(
+        &t.program_id,
+        &amount,
+        &release_timestamp,
+        &recipient,
+    );
+    let id = t
+        .client
+        .get_all_prog_release_schedules(&t.program_id)
+        .last()
+        .unwrap()
+        .schedule_id;
+    (id, recipient)
+}
+
+fn claimable(t: &TestSetup, schedule_id: u64) -> i128 {
+    t.client.get_claimable_now(&t.program_id, &schedule_id)
+}
+
+#[test]
+fn test_nothing_claimable_before_release() {
+    let t = setup();
+    let (id, _) = schedule(&t, 4_000, 5_000);
+
+    assert_eq!(claimable(&t, id), 0);
+    t.env.ledger().set_timestamp(4_999);
+    assert_eq!(claimable(&t, id), 0);
+}
+
+#[test]
+fn test_claimable_at_release_matches_payout() {
+    let t = setup();
+    let (id, recipient) = schedule(&t, 4_000, 5_000);
+
+    t.env.ledger().set_timestamp(5_000);
+    let expected = claimable(&t, id);
+    assert_eq!(expected, 4_000);
+
+    t.client.release_prog_schedule_automatic(&t.program_id, &id);
+    assert_eq!(t.token.balance(&recipient), expected);
+}
+
+#[test]
+fn test_claimable_after_release_time_matches_payout() {
+    let t = setup();
+    let (id, recipient) = schedule(&t, 2_500, 5_000);
+
+    t.env.ledger().set_timestamp(9_000);
+    let expected = claimable(&t, id);
+    assert_eq!(expected, 2_500);
+
+    t.client.release_prog_schedule_automatic(&t.program_id, &id);
+    assert_eq!(t.token.balance(&recipient), expected);
+}
+
+#[test]
+fn test_nothing_claimable_once_released() {
+    let t = setup();
+    let (id, _) = schedule(&t, 4_000, 5_000);
+
+    t.env.ledger().set_timestamp(5_000);
+    t.client.release_prog_schedule_automatic(&t.program_id, &id);
+    assert_eq!(claimable(&t, id), 0);
+}
+
+#[test]
+fn test_schedules_are_independent() {
+    let t = setup();
+    let (early, _) = schedule(&t, 1_000, 2_000);
+    let (late, _) = schedule(&t, 3_000, 6_000);
+
+    t.env.ledger().set_timestamp(3_000);
+    assert_eq!(claimable(&t, early), 1_000);
+    assert_eq!(claimable(&t, late), 0);
+}
+
+#[test]
+#[should_panic(expected = "Schedule not found")]
+fn test_unknown_schedule_panics() {
+    let t = setup();
+    claimable(&t, 42);
+}
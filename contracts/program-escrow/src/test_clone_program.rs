@@ -0,0 +1,162 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_clone_program.rs
+//
+// Tests for cloning a configured program for a repeat cohort.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{symbol_short, testutils::Address as _, token, vec, Address, Env, String};
+
+use crate::{OperationFlags, ProgramEscrowContract, ProgramEscrowContractClient};
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    payout_key: Address,
+    source: String,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let payout_key = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&contract_id, &10_000);
+
+    client.set_admin(&admin, &0);
+    client.set_whitelist(&payout_key, &true);
+    client.set_whitelist(&contract_id, &true);
+
+    let source = String::from_str(&env, "Cohort-Q1");
+    client.initialize_program(&source, &payout_key, &sac.address());
+    client.lock_program_funds(&source, &10_000);
+    client.single_payout(&source, &Address::generate(&env), &1_000);
+
+    TestSetup {
+        env,
+        client,
+        payout_key,
+        source,
+    }
+}
+
+#[test]
+fn test_clone_copies_settings() {
+    let t = setup();
+    let flags = OperationFlags {
+        allow_lock: true,
+        allow_payout: true,
+        allow_claim: false,
+    };
+    t.client
+        .set_program_spending_limit(&t.source, &3_600, &5_000, &true);
+    t.client.set_program_fee_bps(&t.source, &250);
+    t.client
+        .set_program_claim_config(&t.source, &7_200, &600, &1_000, &false);
+    t.client
+        .set_operation_flags(&t.source, &true, &true, &false);
+    t.client.set_min_program_funding(&t.source, &8_000);
+    t.client.set_max_schedules(&t.source, &4);
+
+    let clone = String::from_str(&t.env, "Cohort-Q2");
+    t.client.clone_program(&t.source, &clone);
+
+    assert_eq!(
+        t.client.get_program_spending_limit(&clone),
+        t.client.get_program_spending_limit(&t.source)
+    );
+    assert_eq!(t.client.get_program_fee_bps(&clone), Some(250));
+    assert_eq!(
+        t.client.get_program_claim_config(&clone),
+        t.client.get_program_claim_config(&t.source)
+    );
+    assert_eq!(t.client.get_operation_flags(&clone), flags);
+    assert_eq!(t.client.get_min_program_funding(&clone), Some(8_000));
+    assert_eq!(t.client.get_max_schedules(&clone), Some(4));
+}
+
+#[test]
+fn test_clone_starts_with_empty_balance_and_history() {
+    let t = setup();
+    let clone = String::from_str(&t.env, "Cohort-Q2");
+    t.client.clone_program(&t.source, &clone);
+
+    let source = t.client.get_program_info(&t.source);
+    let cloned = t.client.get_program_info(&clone);
+    assert_eq!(source.remaining_balance, 9_000);
+    assert_eq!(cloned.total_funds, 0);
+    assert_eq!(cloned.remaining_balance, 0);
+    assert_eq!(cloned.payout_history.len(), 0);
+    assert_eq!(cloned.authorized_payout_key, t.payout_key);
+    assert_eq!(cloned.token_address, source.token_address);
+    assert_eq!(t.client.get_pending_claim_ids(&t.source).len(), 1);
+    assert_eq!(t.client.get_pending_claim_ids(&clone).len(), 0);
+    assert!(t.client.program_exists(&clone));
+    assert_eq!(t.client.get_program_count(), 2);
+}
+
+#[test]
+fn test_clone_of_unconfigured_program_uses_defaults() {
+    let t = setup();
+    let clone = String::from_str(&t.env, "Cohort-Q2");
+    t.client.clone_program(&t.source, &clone);
+
+    assert_eq!(t.client.get_program_fee_bps(&clone), None);
+    assert_eq!(t.client.get_program_claim_config(&clone), None);
+    assert_eq!(t.client.get_min_program_funding(&clone), None);
+}
+
+#[test]
+#[should_panic(expected = "Program already exists")]
+fn test_clone_onto_existing_program_panics() {
+    let t = setup();
+    t.client.clone_program(&t.source, &t.source);
+}
+
+#[test]
+fn test_clone_copies_admin_limits() {
+    let t = setup();
+    let program_admin = Address::generate(&t.env);
+    t.client
+        .set_program_allowed_operations(&t.source, &vec![&t.env, symbol_short!("payout")]);
+    t.client.set_velocity_guard(&t.source, &4_000, &3_600);
+    t.client.set_daily_payout_cap(&t.source, &6_000);
+    t.client.set_max_claim_extension(&t.source, &86_400);
+    t.client.set_max_payout_fraction_bps(&t.source, &5_000);
+    t.client.set_program_admin(&t.source, &program_admin);
+
+    let clone = String::from_str(&t.env, "Cohort-Q2");
+    t.client.clone_program(&t.source, &clone);
+
+    assert_eq!(
+        t.client.get_program_allowed_operations(&clone),
+        Some(vec![&t.env, symbol_short!("payout")])
+    );
+    assert_eq!(
+        t.client.get_velocity_guard(&clone),
+        t.client.get_velocity_guard(&t.source)
+    );
+    assert_eq!(t.client.get_daily_payout_cap(&clone), Some(6_000));
+    assert_eq!(t.client.get_max_claim_extension(&clone), 86_400);
+    assert_eq!(t.client.get_max_payout_fraction_bps(&clone), 5_000);
+    assert_eq!(t.client.get_program_admin(&clone), program_admin);
+}
+
+#[test]
+#[should_panic(expected = "Operation not allowed for program")]
+fn test_clone_cannot_escape_allowed_operations() {
+    let t = setup();
+    t.client
+        .set_program_allowed_operations(&t.source, &vec![&t.env, symbol_short!("payout")]);
+
+    let clone = String::from_str(&t.env, "Cohort-Q2");
+    t.client.clone_program(&t.source, &clone);
+    t.client.lock_program_funds(&clone, &1_000);
+}
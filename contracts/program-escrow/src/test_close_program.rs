@@ -0,0 +1,149 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_close_program.rs
+//
+// Tests for `close_program` and sweeping dust to a destination.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env, String};
+
+use crate::{ProgramEscrowContract, ProgramEscrowContractClient};
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    token: token::Client<'a>,
+    program_id: String,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let payout_key = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    let token = token::Client::new(&env, &sac.address());
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&contract_id, &10_000);
+
+    let program_id = String::from_str(&env, "Closing");
+    client.initialize_program(&program_id, &payout_key, &token.address);
+    client.set_admin(&admin, &0);
+    client.lock_program_funds(&program_id, &10_000);
+
+    TestSetup {
+        env,
+        client,
+        token,
+        program_id,
+    }
+}
+
+#[test]
+fn test_close_sweeps_dust_to_destination() {
+    let t = setup();
+    t.client.set_dust_threshold(&t.token.address, &10);
+    t.client
+        .withdraw_unlocked_program_funds(&t.program_id, &9_993);
+
+    let treasury = Address::generate(&t.env);
+    let data = t
+        .client
+        .close_program(&t.program_id, &Some(treasury.clone()));
+
+    assert_eq!(data.remaining_balance, 0);
+    assert_eq!(t.client.get_remaining_balance(&t.program_id), 0);
+    assert_eq!(t.token.balance(&treasury), 7);
+    assert!(t.client.is_program_closed(&t.program_id));
+}
+
+#[test]
+fn test_close_with_zero_balance_needs_no_destination() {
+    let t = setup();
+    t.client
+        .withdraw_unlocked_program_funds(&t.program_id, &10_000);
+
+    t.client.close_program(&t.program_id, &None);
+    assert!(t.client.is_program_closed(&t.program_id));
+}
+
+#[test]
+fn test_balance_above_threshold_blocks_close() {
+    let t = setup();
+    t.client.set_dust_threshold(&t.token.address, &10);
+    t.client
+        .withdraw_unlocked_program_funds(&t.program_id, &9_000);
+
+    let treasury = Address::generate(&t.env);
+    assert!(t
+        .client
+        .try_close_program(&t.program_id, &Some(treasury.clone()))
+        .is_err());
+    assert_eq!(t.token.balance(&treasury), 0);
+    assert!(!t.client.is_program_closed(&t.program_id));
+}
+
+#[test]
+#[should_panic(expected = "Dust destination required")]
+fn test_dust_without_destination_panics() {
+    let t = setup();
+    t.client.set_dust_threshold(&t.token.address, &10);
+    t.client
+        .withdraw_unlocked_program_funds(&t.program_id, &9_995);
+
+    t.client.close_program(&t.program_id, &None);
+}
+
+#[test]
+#[should_panic(expected = "Program has pending claims")]
+fn test_pending_claims_block_close() {
+    let t = setup();
+    t.client.set_dust_threshold(&t.token.address, &10_000);
+    t.client
+        .create_pending_claim(&t.program_id, &Address::generate(&t.env), &500, &5_000);
+
+    t.client
+        .close_program(&t.program_id, &Some(Address::generate(&t.env)));
+}
+
+#[test]
+#[should_panic(expected = "Program has pending multisig payouts")]
+fn test_pending_multisig_payouts_block_close() {
+    let t = setup();
+    t.client.set_dust_threshold(&t.token.address, &10_000);
+    t.client.set_multisig_payout_config(
+        &t.program_id,
+        &vec![&t.env, Address::generate(&t.env), Address::generate(&t.env)],
+        &2,
+    );
+    t.client
+        .request_multisig_payout(&t.program_id, &Address::generate(&t.env), &500);
+
+    t.client
+        .close_program(&t.program_id, &Some(Address::generate(&t.env)));
+}
+
+#[test]
+fn test_closed_program_rejects_further_activity() {
+    let t = setup();
+    t.client.set_dust_threshold(&t.token.address, &10_000);
+    t.client
+        .close_program(&t.program_id, &Some(Address::generate(&t.env)));
+
+    let recipient = Address::generate(&t.env);
+    assert!(t.client.try_lock_program_funds(&t.program_id, &1).is_err());
+    assert!(t
+        .client
+        .try_single_payout(&t.program_id, &recipient, &1)
+        .is_err());
+    assert!(t
+        .client
+        .try_set_operation_flags(&t.program_id, &true, &true, &true)
+        .is_err());
+    assert!(t.client.try_close_program(&t.program_id, &None).is_err());
+}
@@ -0,0 +1,62 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_contract_health.rs
+//
+// Tests for the `get_contract_health` composite status.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+use crate::{ContractHealth, ProgramEscrowContract, ProgramEscrowContractClient};
+
+#[test]
+fn test_health_of_fresh_contract() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    assert_eq!(
+        client.get_contract_health(),
+        ContractHealth {
+            admin_set: false,
+            global_paused: false,
+            program_count: 0,
+            total_programs_paused: 0,
+            rate_limit_configured: false,
+            version: String::from_str(&env, "1.0.0"),
+        }
+    );
+}
+
+#[test]
+fn test_health_reflects_configured_state() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let payout_key = Address::generate(&env);
+    let token = Address::generate(&env);
+    client.set_admin(&admin, &0);
+    client.update_rate_limit_config(&3600, &100, &0, &1);
+
+    let first = String::from_str(&env, "First");
+    let second = String::from_str(&env, "Second");
+    let third = String::from_str(&env, "Third");
+    client.initialize_program(&first, &payout_key, &token);
+    client.initialize_program(&second, &payout_key, &token);
+    client.initialize_program(&third, &payout_key, &token);
+
+    client.set_operation_flags(&first, &false, &true, &true);
+    client.set_operation_flags(&second, &true, &true, &true);
+    client.pause();
+
+    let health = client.get_contract_health();
+    assert!(health.admin_set);
+    assert!(health.global_paused);
+    assert_eq!(health.program_count, 3);
+    assert_eq!(health.total_programs_paused, 1);
+    assert!(health.rate_limit_configured);
+}
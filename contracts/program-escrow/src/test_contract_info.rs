@@ -0,0 +1,88 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_contract_info.rs
+//
+// Tests for `get_contract_info`, the minimum version gate and
+// the per-caller version pin on mutating entry points.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env, String};
+
+use crate::{ProgramEscrowContract, ProgramEscrowContractClient};
+
+struct TestSetup<'a> {
+    client: ProgramEscrowContractClient<'a>,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+    TestSetup { client }
+}
+
+#[test]
+fn test_contract_info_fields() {
+    let t = setup();
+    let info = t.client.get_contract_info();
+    assert_eq!(info.major, 1);
+    assert_eq!(info.minor, 0);
+    assert_eq!(info.patch, 0);
+    assert_eq!(info.version, 1_000_000);
+    assert_eq!(info.build_tag, symbol_short!("stable"));
+    assert_eq!(
+        t.client.get_contract_health().version,
+        String::from_str(&t.client.env, "1.0.0")
+    );
+}
+
+#[test]
+fn test_min_version_gate_accepts_current_and_older() {
+    let t = setup();
+    t.client.require_min_version(&0);
+    t.client.require_min_version(&1_000_000);
+}
+
+#[test]
+#[should_panic(expected = "Contract version too old")]
+fn test_min_version_gate_rejects_newer_requirement() {
+    let t = setup();
+    t.client.require_min_version(&1_001_000);
+}
+
+#[test]
+#[should_panic(expected = "Contract version too old")]
+fn test_caller_min_version_gates_mutating_calls() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+    let payout_key = Address::generate(&env);
+
+    client.set_caller_min_version(&payout_key, &Some(1_001_000));
+    assert_eq!(client.get_caller_min_version(&payout_key), Some(1_001_000));
+    client.initialize_program(
+        &String::from_str(&env, "Pinned"),
+        &payout_key,
+        &Address::generate(&env),
+    );
+}
+
+#[test]
+fn test_caller_min_version_met_or_cleared() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+    let payout_key = Address::generate(&env);
+    let token = Address::generate(&env);
+
+    client.set_caller_min_version(&payout_key, &Some(1_000_000));
+    client.initialize_program(&String::from_str(&env, "Current"), &payout_key, &token);
+
+    client.set_caller_min_version(&payout_key, &Some(2_000_000));
+    client.set_caller_min_version(&payout_key, &None);
+    assert_eq!(client.get_caller_min_version(&payout_key), None);
+    client.initialize_program(&String::from_str(&env, "Cleared"), &payout_key, &token);
+}
@@ -0,0 +1,112 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_daily_payout_cap.rs
+//
+// Tests for capping the value a program pays out in any
+// trailing 24 hours.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, Env, String,
+};
+
+use crate::{ProgramEscrowContract, ProgramEscrowContractClient};
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    token: token::Client<'a>,
+    program_id: String,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let admin = Address::generate(&env);
+    let payout_key = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    let token = token::Client::new(&env, &sac.address());
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&contract_id, &100_000);
+
+    client.set_admin(&admin, &0);
+    client.set_whitelist(&payout_key, &true);
+
+    let program_id = String::from_str(&env, "Throttled");
+    client.initialize_program(&program_id, &payout_key, &token.address);
+    client.lock_program_funds(&program_id, &100_000);
+    client.set_direct_payout_mode(&program_id, &true);
+    client.set_daily_payout_cap(&program_id, &10_000);
+
+    TestSetup {
+        env,
+        client,
+        token,
+        program_id,
+    }
+}
+
+#[test]
+fn test_payouts_up_to_cap_succeed() {
+    let t = setup();
+    assert_eq!(t.client.get_daily_payout_cap(&t.program_id), Some(10_000));
+
+    let r1 = Address::generate(&t.env);
+    let r2 = Address::generate(&t.env);
+    t.client.single_payout(&t.program_id, &r1, &4_000);
+    t.client.batch_payout(
+        &t.program_id,
+        &vec![&t.env, r1.clone(), r2.clone()],
+        &vec![&t.env, 3_000, 3_000],
+        &false,
+        &None,
+        &6_000,
+    );
+
+    assert_eq!(t.token.balance(&r1), 7_000);
+    assert_eq!(t.token.balance(&r2), 3_000);
+}
+
+#[test]
+#[should_panic(expected = "Daily payout cap reached")]
+fn test_payout_beyond_cap_fails() {
+    let t = setup();
+    t.client
+        .single_payout(&t.program_id, &Address::generate(&t.env), &8_000);
+
+    t.env.ledger().set_timestamp(50_000);
+    t.client
+        .single_payout(&t.program_id, &Address::generate(&t.env), &2_001);
+}
+
+#[test]
+fn test_cap_resets_after_24h() {
+    let t = setup();
+    t.client
+        .single_payout(&t.program_id, &Address::generate(&t.env), &10_000);
+
+    t.env.ledger().set_timestamp(1_000 + 86_400);
+    let recipient = Address::generate(&t.env);
+    t.client.single_payout(&t.program_id, &recipient, &10_000);
+
+    assert_eq!(t.token.balance(&recipient), 10_000);
+}
+
+#[test]
+fn test_removed_cap_no_longer_applies() {
+    let t = setup();
+    t.client.set_daily_payout_cap(&t.program_id, &0);
+    assert_eq!(t.client.get_daily_payout_cap(&t.program_id), None);
+
+    let recipient = Address::generate(&t.env);
+    t.client.single_payout(&t.program_id, &recipient, &20_000);
+
+    assert_eq!(t.token.balance(&recipient), 20_000);
+}
@@ -0,0 +1,129 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_denylist.rs
+//
+// Tests for the per-program denylist and revoking a denylisted
+// recipient's pending claims.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env, String};
+
+use crate::{ClaimStatus, ProgramEscrowContract, ProgramEscrowContractClient};
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    token: token::Client<'a>,
+    program_id: String,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let payout_key = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    let token = token::Client::new(&env, &sac.address());
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&contract_id, &10_000);
+
+    let program_id = String::from_str(&env, "Denied");
+    client.initialize_program(&program_id, &payout_key, &token.address);
+    client.lock_program_funds(&program_id, &10_000);
+
+    TestSetup {
+        env,
+        client,
+        token,
+        program_id,
+    }
+}
+
+#[test]
+fn test_denylist_with_revoke_reclaims_pending_claims() {
+    let t = setup();
+    let bad = Address::generate(&t.env);
+    let good = Address::generate(&t.env);
+    let first = t.client.single_payout(&t.program_id, &bad, &1_000).claim_id;
+    let second = t.client.single_payout(&t.program_id, &bad, &2_000).claim_id;
+    let other = t.client.single_payout(&t.program_id, &good, &500).claim_id;
+    assert_eq!(t.client.get_remaining_balance(&t.program_id), 6_500);
+
+    let reclaimed = t.client.add_to_denylist(&t.program_id, &bad, &true);
+
+    assert_eq!(reclaimed, 3_000);
+    assert_eq!(t.client.get_remaining_balance(&t.program_id), 9_500);
+    assert!(t.client.is_denylisted(&t.program_id, &bad));
+    for claim_id in [first, second] {
+        assert_eq!(
+            t.client.get_claim_status(&t.program_id, &claim_id),
+            ClaimStatus::Revoked
+        );
+    }
+    assert_eq!(
+        t.client.get_pending_claim_ids(&t.program_id),
+        vec![&t.env, other]
+    );
+
+    assert!(t
+        .client
+        .try_execute_claim(&t.program_id, &first, &bad)
+        .is_err());
+    assert_eq!(t.token.balance(&bad), 0);
+}
+
+#[test]
+fn test_denylist_without_revoke_keeps_claim_unclaimable() {
+    let t = setup();
+    let bad = Address::generate(&t.env);
+    let claim_id = t.client.single_payout(&t.program_id, &bad, &1_000).claim_id;
+
+    assert_eq!(t.client.add_to_denylist(&t.program_id, &bad, &false), 0);
+
+    assert_eq!(t.client.get_remaining_balance(&t.program_id), 9_000);
+    assert_eq!(
+        t.client.get_claim_status(&t.program_id, &claim_id),
+        ClaimStatus::Pending
+    );
+    assert!(t
+        .client
+        .try_execute_claim(&t.program_id, &claim_id, &bad)
+        .is_err());
+
+    t.client.remove_from_denylist(&t.program_id, &bad);
+    t.client.execute_claim(&t.program_id, &claim_id, &bad);
+    assert_eq!(t.token.balance(&bad), 1_000);
+}
+
+#[test]
+fn test_denylisted_recipient_rejected_for_new_payouts() {
+    let t = setup();
+    let bad = Address::generate(&t.env);
+    t.client.add_to_denylist(&t.program_id, &bad, &true);
+
+    assert!(t
+        .client
+        .try_single_payout(&t.program_id, &bad, &100)
+        .is_err());
+    assert!(t
+        .client
+        .try_batch_payout(
+            &t.program_id,
+            &vec![&t.env, Address::generate(&t.env), bad.clone()],
+            &vec![&t.env, 100, 100],
+            &false,
+            &None,
+            &200,
+        )
+        .is_err());
+    assert!(t
+        .client
+        .try_create_pending_claim(&t.program_id, &bad, &100, &5_000)
+        .is_err());
+    assert_eq!(t.client.get_remaining_balance(&t.program_id), 10_000);
+}
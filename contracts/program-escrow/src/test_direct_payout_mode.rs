@@ -0,0 +1,110 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_direct_payout_mode.rs
+//
+// Tests for program-wide direct payout mode, which skips the
+// claim step for every payout.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env, String};
+
+use crate::{PayoutMode, ProgramEscrowContract, ProgramEscrowContractClient};
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    token: token::Client<'a>,
+    program_id: String,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let payout_key = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    let token = token::Client::new(&env, &sac.address());
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&contract_id, &100_000);
+
+    client.set_admin(&admin, &0);
+    client.set_whitelist(&payout_key, &true);
+
+    let program_id = String::from_str(&env, "Direct");
+    client.initialize_program(&program_id, &payout_key, &token.address);
+    client.lock_program_funds(&program_id, &100_000);
+
+    TestSetup {
+        env,
+        client,
+        token,
+        program_id,
+    }
+}
+
+#[test]
+fn test_direct_mode_off_by_default() {
+    let t = setup();
+    assert!(!t.client.is_direct_payout_mode(&t.program_id));
+}
+
+#[test]
+fn test_single_payout_transfers_immediately() {
+    let t = setup();
+    t.client.set_direct_payout_mode(&t.program_id, &true);
+    let recipient = Address::generate(&t.env);
+
+    let result = t.client.single_payout(&t.program_id, &recipient, &3_000);
+
+    assert_eq!(result.claim_id, 0);
+    assert_eq!(result.remaining_balance, 97_000);
+    assert_eq!(t.token.balance(&recipient), 3_000);
+    assert_eq!(t.client.get_pending_claim_ids(&t.program_id).len(), 0);
+    let data = t.client.get_program_info(&t.program_id);
+    assert_eq!(data.payout_history.len(), 1);
+}
+
+#[test]
+fn test_batch_claim_mode_overridden() {
+    let t = setup();
+    t.client.set_direct_payout_mode(&t.program_id, &true);
+    let first = Address::generate(&t.env);
+    let second = Address::generate(&t.env);
+
+    let data = t.client.batch_payout(
+        &t.program_id,
+        &vec![&t.env, first.clone(), second.clone()],
+        &vec![&t.env, 1_000, 2_000],
+        &false,
+        &Some(vec![&t.env, PayoutMode::Claim, PayoutMode::Claim]),
+        &3_000,
+    );
+
+    assert_eq!(data.remaining_balance, 97_000);
+    assert_eq!(data.payout_history.len(), 2);
+    assert_eq!(t.token.balance(&first), 1_000);
+    assert_eq!(t.token.balance(&second), 2_000);
+    assert_eq!(t.client.get_pending_claim_ids(&t.program_id).len(), 0);
+}
+
+#[test]
+fn test_disabling_direct_mode_restores_claims() {
+    let t = setup();
+    t.client.set_direct_payout_mode(&t.program_id, &true);
+    t.client.set_direct_payout_mode(&t.program_id, &false);
+    let recipient = Address::generate(&t.env);
+
+    let claim_id = t
+        .client
+        .single_payout(&t.program_id, &recipient, &3_000)
+        .claim_id;
+
+    assert_ne!(claim_id, 0);
+    assert_eq!(t.token.balance(&recipient), 0);
+    assert_eq!(t.client.get_pending_claim_ids(&t.program_id).len(), 1);
+}
@@ -0,0 +1,128 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_fee_history.rs
+//
+// Tests for the per-token history of fees taken by the
+// contract.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, Env, String,
+};
+
+use crate::{FeeRecord, ProgramEscrowContract, ProgramEscrowContractClient};
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    token: Address,
+    alpha: String,
+    beta: String,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let payout_key = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&contract_id, &200_000);
+
+    client.set_admin(&admin, &0);
+    client.set_whitelist(&payout_key, &true);
+
+    let alpha = String::from_str(&env, "Alpha");
+    let beta = String::from_str(&env, "Beta");
+    for program_id in [&alpha, &beta] {
+        client.initialize_program(program_id, &payout_key, &sac.address());
+        client.lock_program_funds(program_id, &100_000);
+        client.set_direct_payout_mode(program_id, &true);
+    }
+    // `initialize_program` resets the fee config, so enable fees afterwards.
+    client.update_fee_config(
+        &None,
+        &Some(100),
+        &Some(Address::generate(&env)),
+        &Some(true),
+        &1,
+    );
+
+    TestSetup {
+        env,
+        client,
+        token: sac.address(),
+        alpha,
+        beta,
+    }
+}
+
+fn record(program_id: &String, amount: i128, timestamp: u64) -> FeeRecord {
+    FeeRecord {
+        program_id: program_id.clone(),
+        amount,
+        timestamp,
+    }
+}
+
+#[test]
+fn test_fee_history_attributes_fees_to_programs() {
+    let t = setup();
+    assert_eq!(t.client.get_fee_history(&t.token, &0, &10), vec![&t.env]);
+
+    t.env.ledger().set_timestamp(1_000);
+    t.client
+        .single_payout(&t.alpha, &Address::generate(&t.env), &10_000);
+
+    t.env.ledger().set_timestamp(2_000);
+    let recipients = vec![&t.env, Address::generate(&t.env), Address::generate(&t.env)];
+    t.client.batch_payout(
+        &t.beta,
+        &recipients,
+        &vec![&t.env, 5_000, 3_000],
+        &false,
+        &None,
+        &8_000,
+    );
+
+    t.env.ledger().set_timestamp(3_000);
+    t.client
+        .single_payout(&t.alpha, &Address::generate(&t.env), &20_000);
+
+    assert_eq!(
+        t.client.get_fee_history(&t.token, &0, &10),
+        vec![
+            &t.env,
+            record(&t.alpha, 100, 1_000),
+            record(&t.beta, 80, 2_000),
+            record(&t.alpha, 200, 3_000),
+        ]
+    );
+}
+
+#[test]
+fn test_fee_history_pagination() {
+    let t = setup();
+    for amount in [1_000, 2_000, 3_000] {
+        t.client
+            .single_payout(&t.alpha, &Address::generate(&t.env), &amount);
+    }
+
+    assert_eq!(t.client.get_fee_history_count(&t.token), 3);
+    let page = t.client.get_fee_history(&t.token, &1, &1);
+    assert_eq!(page, vec![&t.env, record(&t.alpha, 20, 0)]);
+    assert_eq!(t.client.get_fee_history(&t.token, &3, &10), vec![&t.env]);
+
+    let other_token = Address::generate(&t.env);
+    assert_eq!(
+        t.client.get_fee_history(&other_token, &0, &10),
+        vec![&t.env]
+    );
+    assert_eq!(t.client.get_fee_history_count(&other_token), 0);
+}
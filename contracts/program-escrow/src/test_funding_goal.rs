@@ -0,0 +1,278 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_funding_goal.rs
+//
+// Tests for crowdfunding goals and contributor refunds.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, Env, String,
+};
+
+use crate::{ProgramEscrowContract, ProgramEscrowContractClient};
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    token: token::Client<'a>,
+    contract_id: Address,
+    program_id: String,
+    alice: Address,
+    bob: Address,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let admin = Address::generate(&env);
+    let payout_key = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    let token = token::Client::new(&env, &sac.address());
+    let token_admin = token::StellarAssetClient::new(&env, &sac.address());
+
+    client.set_admin(&admin, &0);
+    client.set_whitelist(&payout_key, &true);
+    client.set_whitelist(&contract_id, &true);
+
+    let program_id = String::from_str(&env, "Campaign");
+    client.initialize_program(&program_id, &payout_key, &sac.address());
+    client.set_open_funding(&program_id, &true);
+    client.set_funding_goal(&program_id, &5_000, &2_000);
+
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    token_admin.mint(&alice, &10_000);
+    token_admin.mint(&bob, &10_000);
+
+    TestSetup {
+        env,
+        client,
+        token,
+        contract_id,
+        program_id,
+        alice,
+        bob,
+    }
+}
+
+#[test]
+fn test_failed_campaign_refunds_exact_contributions() {
+    let t = setup();
+    t.client
+        .contribute_program_funds(&t.program_id, &t.alice, &1_000);
+    t.client
+        .contribute_program_funds(&t.program_id, &t.bob, &1_500);
+    t.client
+        .contribute_program_funds(&t.program_id, &t.alice, &500);
+
+    t.env.ledger().set_timestamp(2_000);
+
+    assert_eq!(t.client.refund_contribution(&t.program_id, &t.alice), 1_500);
+    assert_eq!(t.client.refund_contribution(&t.program_id, &t.bob), 1_500);
+
+    assert_eq!(t.token.balance(&t.alice), 10_000);
+    assert_eq!(t.token.balance(&t.bob), 10_000);
+    assert_eq!(t.token.balance(&t.contract_id), 0);
+
+    let data = t.client.get_program_info(&t.program_id);
+    assert_eq!(data.remaining_balance, 0);
+    assert_eq!(t.client.get_contributions(&t.program_id, &0, &10).len(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Funding goal not met")]
+fn test_failed_campaign_blocks_payouts() {
+    let t = setup();
+    t.client
+        .contribute_program_funds(&t.program_id, &t.alice, &3_000);
+
+    t.env.ledger().set_timestamp(2_000);
+    t.client
+        .single_payout(&t.program_id, &Address::generate(&t.env), &1_000);
+}
+
+#[test]
+#[should_panic(expected = "Funding goal not met")]
+fn test_batch_payout_blocked_before_goal() {
+    let t = setup();
+    t.client
+        .contribute_program_funds(&t.program_id, &t.alice, &3_000);
+    let recipients = vec![&t.env, Address::generate(&t.env)];
+    let amounts = vec![&t.env, 1_000_i128];
+    t.client
+        .batch_payout(&t.program_id, &recipients, &amounts, &false, &None, &1_000);
+}
+
+#[test]
+#[should_panic(expected = "No contribution to refund")]
+fn test_refund_twice_panics() {
+    let t = setup();
+    t.client
+        .contribute_program_funds(&t.program_id, &t.alice, &1_000);
+
+    t.env.ledger().set_timestamp(2_000);
+    t.client.refund_contribution(&t.program_id, &t.alice);
+    t.client.refund_contribution(&t.program_id, &t.alice);
+}
+
+#[test]
+#[should_panic(expected = "Refunds not available")]
+fn test_refund_before_deadline_panics() {
+    let t = setup();
+    t.client
+        .contribute_program_funds(&t.program_id, &t.alice, &1_000);
+    t.client.refund_contribution(&t.program_id, &t.alice);
+}
+
+#[test]
+#[should_panic(expected = "Funding campaign ended")]
+fn test_contribution_after_failed_deadline_panics() {
+    let t = setup();
+    t.env.ledger().set_timestamp(2_000);
+    t.client
+        .contribute_program_funds(&t.program_id, &t.alice, &1_000);
+}
+
+#[test]
+fn test_successful_campaign_allows_payouts() {
+    let t = setup();
+    t.client
+        .contribute_program_funds(&t.program_id, &t.alice, &3_000);
+    t.client
+        .contribute_program_funds(&t.program_id, &t.bob, &2_000);
+
+    t.env.ledger().set_timestamp(2_000);
+    t.client
+        .single_payout(&t.program_id, &Address::generate(&t.env), &1_000);
+
+    let data = t.client.get_program_info(&t.program_id);
+    assert_eq!(data.remaining_balance, 4_000);
+}
+
+#[test]
+#[should_panic(expected = "Refunds not available")]
+fn test_successful_campaign_disables_refunds() {
+    let t = setup();
+    t.client
+        .contribute_program_funds(&t.program_id, &t.alice, &5_000);
+
+    t.env.ledger().set_timestamp(2_000);
+    t.client.refund_contribution(&t.program_id, &t.alice);
+}
+
+#[test]
+#[should_panic(expected = "Invalid funding goal")]
+fn test_deadline_in_past_panics() {
+    let t = setup();
+    t.client.set_funding_goal(&t.program_id, &5_000, &1_000);
+}
+
+#[test]
+fn test_refund_returns_net_of_lock_fee() {
+    let t = setup();
+    // `initialize_program` resets the fee config, so enable fees afterwards.
+    t.client.update_fee_config(
+        &Some(1_000),
+        &None,
+        &Some(Address::generate(&t.env)),
+        &Some(true),
+        &1,
+    );
+    t.client
+        .contribute_program_funds(&t.program_id, &t.alice, &1_000);
+    let contributions = t.client.get_contributions(&t.program_id, &0, &10);
+    assert_eq!(contributions.get(0).unwrap().amount, 900);
+
+    t.env.ledger().set_timestamp(2_000);
+    assert_eq!(t.client.refund_contribution(&t.program_id, &t.alice), 900);
+    assert_eq!(t.token.balance(&t.alice), 9_900);
+    // the lock fee stays with the contract
+    assert_eq!(t.token.balance(&t.contract_id), 100);
+    assert_eq!(
+        t.client.get_program_info(&t.program_id).remaining_balance,
+        0
+    );
+}
+
+#[test]
+#[should_panic(expected = "Funding goal not met")]
+fn test_withdraw_blocked_before_goal() {
+    let t = setup();
+    t.client
+        .contribute_program_funds(&t.program_id, &t.alice, &3_000);
+    t.client
+        .withdraw_unlocked_prog_funds_to(&t.program_id, &3_000, &Address::generate(&t.env));
+}
+
+#[test]
+#[should_panic(expected = "Funding goal not met")]
+fn test_multisig_request_blocked_before_goal() {
+    let t = setup();
+    t.client
+        .contribute_program_funds(&t.program_id, &t.alice, &3_000);
+    let signer = Address::generate(&t.env);
+    t.client
+        .set_multisig_payout_config(&t.program_id, &vec![&t.env, signer], &1);
+    t.client
+        .request_multisig_payout(&t.program_id, &Address::generate(&t.env), &1_000);
+}
+
+#[test]
+#[should_panic(expected = "Funding goal not met")]
+fn test_schedule_release_blocked_before_goal() {
+    let t = setup();
+    t.client
+        .contribute_program_funds(&t.program_id, &t.alice, &3_000);
+    t.client.create_program_release_schedule(
+        &t.program_id,
+        &1_000,
+        &1_500,
+        &Address::generate(&t.env),
+    );
+    let schedule_id = t
+        .client
+        .get_all_prog_release_schedules(&t.program_id)
+        .get(0)
+        .unwrap()
+        .schedule_id;
+
+    t.env.ledger().set_timestamp(1_500);
+    t.client
+        .release_prog_schedule_automatic(&t.program_id, &schedule_id);
+}
+
+#[test]
+#[should_panic(expected = "Funding goal locked by contributions")]
+fn test_goal_locked_once_contributions_exist() {
+    let t = setup();
+    t.client
+        .contribute_program_funds(&t.program_id, &t.alice, &1_000);
+
+    t.env.ledger().set_timestamp(2_000);
+    t.client.set_funding_goal(&t.program_id, &1_000, &3_000);
+}
+
+#[test]
+fn test_organizer_funds_released_after_all_refunds() {
+    let t = setup();
+    token::StellarAssetClient::new(&t.env, &t.token.address).mint(&t.contract_id, &2_000);
+    t.client.lock_program_funds(&t.program_id, &2_000);
+    t.client
+        .contribute_program_funds(&t.program_id, &t.alice, &1_000);
+
+    t.env.ledger().set_timestamp(2_000);
+    t.client.refund_contribution(&t.program_id, &t.alice);
+
+    let destination = Address::generate(&t.env);
+    t.client
+        .withdraw_unlocked_prog_funds_to(&t.program_id, &2_000, &destination);
+    assert_eq!(t.token.balance(&destination), 2_000);
+}
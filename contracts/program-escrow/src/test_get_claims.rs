@@ -0,0 +1,82 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_get_claims.rs
+//
+// Tests for fetching several claims in one call.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env, String};
+
+use crate::{ClaimStatus, ProgramEscrowContract, ProgramEscrowContractClient};
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    program_id: String,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let payout_key = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&contract_id, &100_000);
+
+    let program_id = String::from_str(&env, "Dashboard");
+    client.initialize_program(&program_id, &payout_key, &sac.address());
+    client.lock_program_funds(&program_id, &100_000);
+
+    TestSetup {
+        env,
+        client,
+        program_id,
+    }
+}
+
+#[test]
+fn test_get_claims_keeps_requested_order_and_skips_misses() {
+    let t = setup();
+    let recipient = Address::generate(&t.env);
+    let first = t
+        .client
+        .single_payout(&t.program_id, &recipient, &1_000)
+        .claim_id;
+    let second = t
+        .client
+        .single_payout(&t.program_id, &recipient, &2_000)
+        .claim_id;
+    let third = t
+        .client
+        .single_payout(&t.program_id, &recipient, &3_000)
+        .claim_id;
+    t.client.execute_claim(&t.program_id, &second, &recipient);
+
+    let claims = t.client.get_claims(
+        &t.program_id,
+        &vec![&t.env, third, 999, first, second, 1_000],
+    );
+
+    assert_eq!(claims.len(), 3);
+    assert_eq!(claims.get(0).unwrap().claim_id, third);
+    assert_eq!(claims.get(0).unwrap().amount, 3_000);
+    assert_eq!(claims.get(1).unwrap().claim_id, first);
+    assert_eq!(claims.get(1).unwrap().status, ClaimStatus::Pending);
+    assert_eq!(claims.get(2).unwrap().claim_id, second);
+    assert_eq!(claims.get(2).unwrap().status, ClaimStatus::Completed);
+}
+
+#[test]
+fn test_get_claims_with_only_unknown_ids_is_empty() {
+    let t = setup();
+
+    let claims = t.client.get_claims(&t.program_id, &vec![&t.env, 7, 8]);
+
+    assert_eq!(claims.len(), 0);
+}
@@ -0,0 +1,119 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_initial_funding.rs
+//
+// Tests for registering a program and locking its first funds
+// from the organizer in a single call.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, token, Address, Env, String};
+
+use crate::{ProgramEscrowContract, ProgramEscrowContractClient};
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    token: token::Client<'a>,
+    contract_id: Address,
+    organizer: Address,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let organizer = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    let token = token::Client::new(&env, &sac.address());
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&organizer, &10_000);
+
+    TestSetup {
+        env,
+        client,
+        token,
+        contract_id,
+        organizer,
+    }
+}
+
+#[test]
+fn test_initial_funding_locks_balance() {
+    let t = setup();
+    let program_id = String::from_str(&t.env, "Funded");
+    t.token
+        .approve(&t.organizer, &t.contract_id, &6_000, &1_000);
+
+    let program = t.client.initialize_program_with_funding(
+        &program_id,
+        &t.organizer,
+        &t.token.address,
+        &6_000,
+    );
+
+    assert_eq!(program.total_funds, 6_000);
+    assert_eq!(program.remaining_balance, 6_000);
+    assert_eq!(t.client.get_remaining_balance(&program_id), 6_000);
+    assert_eq!(t.token.balance(&t.contract_id), 6_000);
+    assert_eq!(t.token.balance(&t.organizer), 4_000);
+}
+
+#[test]
+fn test_zero_initial_funding_only_registers() {
+    let t = setup();
+    let program_id = String::from_str(&t.env, "Unfunded");
+
+    let program =
+        t.client
+            .initialize_program_with_funding(&program_id, &t.organizer, &t.token.address, &0);
+
+    assert_eq!(program.remaining_balance, 0);
+    assert!(t.client.program_exists(&program_id));
+    assert_eq!(t.token.balance(&t.organizer), 10_000);
+}
+
+#[test]
+fn test_insufficient_allowance_reverts_initialization() {
+    let t = setup();
+    let program_id = String::from_str(&t.env, "Underfunded");
+    t.token
+        .approve(&t.organizer, &t.contract_id, &1_000, &1_000);
+
+    let result = t.client.try_initialize_program_with_funding(
+        &program_id,
+        &t.organizer,
+        &t.token.address,
+        &6_000,
+    );
+
+    assert!(result.is_err());
+    assert!(!t.client.program_exists(&program_id));
+    assert_eq!(t.token.balance(&t.organizer), 10_000);
+    assert_eq!(t.token.balance(&t.contract_id), 0);
+}
+
+#[test]
+fn test_initial_funding_requires_organizer_auth() {
+    let t = setup();
+    let program_id = String::from_str(&t.env, "Swept");
+    t.token
+        .approve(&t.organizer, &t.contract_id, &6_000, &1_000);
+    // drop the blanket mock so the organizer has not signed this call
+    t.env.set_auths(&[]);
+
+    let result = t.client.try_initialize_program_with_funding(
+        &program_id,
+        &t.organizer,
+        &t.token.address,
+        &6_000,
+    );
+
+    assert!(result.is_err());
+    assert!(!t.client.program_exists(&program_id));
+    assert_eq!(t.token.balance(&t.organizer), 10_000);
+}
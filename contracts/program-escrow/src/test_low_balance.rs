@@ -0,0 +1,128 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_low_balance.rs
+//
+// Tests for the per-program low balance warning event.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Events},
+    token, Address, Env, IntoVal, String, Symbol, Val, Vec,
+};
+
+use crate::{ProgramEscrowContract, ProgramEscrowContractClient};
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    program_id: String,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let payout_key = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&contract_id, &100_000);
+
+    client.set_admin(&admin, &0);
+    client.set_whitelist(&payout_key, &true);
+    client.set_whitelist(&contract_id, &true);
+
+    let program_id = String::from_str(&env, "TopUp");
+    client.initialize_program(&program_id, &payout_key, &sac.address());
+    client.lock_program_funds(&program_id, &10_000);
+    client.set_low_balance_threshold(&program_id, &5_000);
+
+    TestSetup {
+        env,
+        client,
+        program_id,
+    }
+}
+
+/// Low balance warnings emitted so far.
+fn warnings(t: &TestSetup) -> Vec<(String, i128, i128)> {
+    let expected: Vec<Val> = (Symbol::new(&t.env, "low_balance"),).into_val(&t.env);
+    let mut found = Vec::new(&t.env);
+    for (_, topics, data) in t.env.events().all().iter() {
+        if topics == expected {
+            found.push_back(data.into_val(&t.env));
+        }
+    }
+    found
+}
+
+fn pay(t: &TestSetup, amount: i128) {
+    t.client
+        .single_payout(&t.program_id, &Address::generate(&t.env), &amount);
+}
+
+#[test]
+fn test_no_warning_above_threshold() {
+    let t = setup();
+    pay(&t, 3_000);
+    assert_eq!(warnings(&t).len(), 0);
+}
+
+#[test]
+fn test_warning_fires_on_downward_crossing() {
+    let t = setup();
+    pay(&t, 3_000);
+    pay(&t, 3_000);
+
+    let found = warnings(&t);
+    assert_eq!(found.len(), 1);
+    assert_eq!(found.get(0).unwrap(), (t.program_id.clone(), 4_000, 5_000));
+}
+
+#[test]
+fn test_warning_does_not_refire_below_threshold() {
+    let t = setup();
+    pay(&t, 6_000);
+    assert_eq!(warnings(&t).len(), 1);
+
+    pay(&t, 1_000);
+    pay(&t, 1_000);
+    assert_eq!(warnings(&t).len(), 1);
+}
+
+#[test]
+fn test_top_up_rearms_warning() {
+    let t = setup();
+    pay(&t, 6_000);
+    assert_eq!(warnings(&t).len(), 1);
+
+    t.client.lock_program_funds(&t.program_id, &4_000);
+    pay(&t, 1_000);
+    assert_eq!(warnings(&t).len(), 1);
+
+    pay(&t, 3_000);
+    let found = warnings(&t);
+    assert_eq!(found.len(), 2);
+    assert_eq!(found.get(1).unwrap(), (t.program_id.clone(), 4_000, 5_000));
+}
+
+#[test]
+fn test_removed_threshold_stops_warnings() {
+    let t = setup();
+    t.client.set_low_balance_threshold(&t.program_id, &0);
+    assert_eq!(t.client.get_low_balance_threshold(&t.program_id), None);
+
+    pay(&t, 8_000);
+    assert_eq!(warnings(&t).len(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Threshold cannot be negative")]
+fn test_negative_threshold_panics() {
+    let t = setup();
+    t.client.set_low_balance_threshold(&t.program_id, &-1);
+}
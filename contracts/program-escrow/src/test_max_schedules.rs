@@ -0,0 +1,131 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_max_schedules.rs
+//
+// Tests for the per-program cap on unreleased release schedules.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env, String,
+};
+
+use crate::{ProgramEscrowContract, ProgramEscrowContractClient};
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    program_id: String,
+    recipient: Address,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let admin = Address::generate(&env);
+    let payout_key = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&contract_id, &100_000);
+
+    client.set_admin(&admin, &0);
+    client.set_whitelist(&payout_key, &true);
+    client.set_whitelist(&contract_id, &true);
+
+    let program_id = String::from_str(&env, "Scheduled");
+    client.initialize_program(&program_id, &payout_key, &sac.address());
+    client.lock_program_funds(&program_id, &100_000);
+
+    TestSetup {
+        recipient: Address::generate(&env),
+        env,
+        client,
+        program_id,
+    }
+}
+
+fn create(t: &TestSetup) {
+    t.client
+        .create_program_release_schedule(&t.program_id, &1_000, &5_000, &t.recipient);
+}
+
+#[test]
+fn test_schedules_unlimited_by_default() {
+    let t = setup();
+    for _ in 0..5 {
+        create(&t);
+    }
+    assert_eq!(t.client.get_max_schedules(&t.program_id), None);
+    assert_eq!(
+        t.client.get_pending_program_schedules(&t.program_id).len(),
+        5
+    );
+}
+
+#[test]
+#[should_panic(expected = "Too many schedules")]
+fn test_creating_beyond_cap_panics() {
+    let t = setup();
+    t.client.set_max_schedules(&t.program_id, &3);
+    for _ in 0..4 {
+        create(&t);
+    }
+}
+
+#[test]
+fn test_cancellation_frees_a_slot() {
+    let t = setup();
+    t.client.set_max_schedules(&t.program_id, &2);
+    create(&t);
+    create(&t);
+    assert!(t
+        .client
+        .try_create_program_release_schedule(&t.program_id, &1_000, &5_000, &t.recipient)
+        .is_err());
+
+    t.client.cancel_program_release_schedule(&t.program_id, &1);
+    assert!(t
+        .client
+        .try_get_program_release_schedule(&t.program_id, &1)
+        .is_err());
+
+    create(&t);
+    assert_eq!(
+        t.client.get_pending_program_schedules(&t.program_id).len(),
+        2
+    );
+}
+
+#[test]
+fn test_released_schedule_frees_a_slot() {
+    let t = setup();
+    t.client.set_max_schedules(&t.program_id, &1);
+    create(&t);
+
+    t.env.ledger().set_timestamp(5_000);
+    t.client.release_program_schedule_manual(&t.program_id, &1);
+
+    t.client
+        .create_program_release_schedule(&t.program_id, &1_000, &9_000, &t.recipient);
+    assert_eq!(
+        t.client.get_all_prog_release_schedules(&t.program_id).len(),
+        2
+    );
+}
+
+#[test]
+#[should_panic(expected = "Schedule already released")]
+fn test_cancelling_released_schedule_panics() {
+    let t = setup();
+    create(&t);
+    t.env.ledger().set_timestamp(5_000);
+    t.client.release_program_schedule_manual(&t.program_id, &1);
+
+    t.client.cancel_program_release_schedule(&t.program_id, &1);
+}
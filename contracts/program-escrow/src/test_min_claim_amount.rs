@@ -0,0 +1,80 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_min_claim_amount.rs
+//
+// Tests for keeping dust payouts out of the claim flow.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env, String};
+
+use crate::{ProgramEscrowContract, ProgramEscrowContractClient};
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    token: token::Client<'a>,
+    program_id: String,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let payout_key = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&contract_id, &10_000);
+    let token = token::Client::new(&env, &sac.address());
+
+    client.set_admin(&admin, &0);
+    client.set_whitelist(&payout_key, &true);
+
+    let program_id = String::from_str(&env, "DustClaims");
+    client.initialize_program(&program_id, &payout_key, &sac.address());
+    client.lock_program_funds(&program_id, &10_000);
+    client.set_min_claim_amount(&program_id, &100);
+
+    TestSetup {
+        env,
+        client,
+        token,
+        program_id,
+    }
+}
+
+#[test]
+fn test_sub_minimum_payout_transfers_directly() {
+    let t = setup();
+    assert_eq!(t.client.get_min_claim_amount(&t.program_id), 100);
+    assert!(!t.client.is_reject_dust_payouts(&t.program_id));
+
+    let recipient = Address::generate(&t.env);
+    t.client.single_payout(&t.program_id, &recipient, &99);
+
+    assert_eq!(t.token.balance(&recipient), 99);
+    assert_eq!(t.client.get_pending_claim_ids(&t.program_id), vec![&t.env]);
+}
+
+#[test]
+fn test_payout_at_minimum_creates_claim() {
+    let t = setup();
+    let recipient = Address::generate(&t.env);
+    t.client.single_payout(&t.program_id, &recipient, &100);
+
+    assert_eq!(t.token.balance(&recipient), 0);
+    assert_eq!(t.client.get_pending_claim_ids(&t.program_id).len(), 1);
+}
+
+#[test]
+#[should_panic(expected = "Payout below minimum claim amount")]
+fn test_sub_minimum_payout_rejected_when_configured() {
+    let t = setup();
+    t.client.set_reject_dust_payouts(&t.program_id, &true);
+    t.client
+        .single_payout(&t.program_id, &Address::generate(&t.env), &99);
+}
@@ -0,0 +1,116 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_min_program_funding.rs
+//
+// Tests for holding back payouts until a program reaches its
+// minimum cumulative funding.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env, String};
+
+use crate::{ProgramEscrowContract, ProgramEscrowContractClient};
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    program_id: String,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let payout_key = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&contract_id, &100_000);
+
+    client.set_admin(&admin, &0);
+    client.set_whitelist(&payout_key, &true);
+    client.set_whitelist(&contract_id, &true);
+
+    let program_id = String::from_str(&env, "Threshold");
+    client.initialize_program(&program_id, &payout_key, &sac.address());
+    client.lock_program_funds(&program_id, &4_000);
+    client.set_min_program_funding(&program_id, &10_000);
+
+    TestSetup {
+        env,
+        client,
+        program_id,
+    }
+}
+
+#[test]
+#[should_panic(expected = "Program underfunded")]
+fn test_single_payout_below_threshold_panics() {
+    let t = setup();
+    t.client
+        .single_payout(&t.program_id, &Address::generate(&t.env), &1_000);
+}
+
+#[test]
+#[should_panic(expected = "Program underfunded")]
+fn test_batch_payout_below_threshold_panics() {
+    let t = setup();
+    let recipients = vec![&t.env, Address::generate(&t.env)];
+    let amounts = vec![&t.env, 1_000i128];
+    t.client
+        .batch_payout(&t.program_id, &recipients, &amounts, &false, &None, &1_000);
+}
+
+#[test]
+fn test_payouts_succeed_once_threshold_reached() {
+    let t = setup();
+    let recipient = Address::generate(&t.env);
+    assert_eq!(
+        t.client.get_min_program_funding(&t.program_id),
+        Some(10_000)
+    );
+
+    t.client.lock_program_funds(&t.program_id, &5_999);
+    assert!(t
+        .client
+        .try_single_payout(&t.program_id, &recipient, &1_000)
+        .is_err());
+
+    t.client.lock_program_funds(&t.program_id, &1);
+    let result = t.client.single_payout(&t.program_id, &recipient, &1_000);
+    assert_eq!(result.remaining_balance, 9_000);
+
+    let recipients = vec![&t.env, Address::generate(&t.env)];
+    let amounts = vec![&t.env, 500i128];
+    let data = t
+        .client
+        .batch_payout(&t.program_id, &recipients, &amounts, &false, &None, &500);
+    assert_eq!(data.remaining_balance, 8_500);
+}
+
+#[test]
+fn test_threshold_uses_cumulative_funding_after_payouts() {
+    let t = setup();
+    let recipient = Address::generate(&t.env);
+    t.client.lock_program_funds(&t.program_id, &6_000);
+
+    t.client.single_payout(&t.program_id, &recipient, &5_000);
+    t.client.single_payout(&t.program_id, &recipient, &1_000);
+
+    let data = t.client.get_program_info(&t.program_id);
+    assert_eq!(data.total_funds, 10_000);
+    assert_eq!(data.remaining_balance, 4_000);
+}
+
+#[test]
+fn test_zero_minimum_removes_requirement() {
+    let t = setup();
+    t.client.set_min_program_funding(&t.program_id, &0);
+
+    assert_eq!(t.client.get_min_program_funding(&t.program_id), None);
+    t.client
+        .single_payout(&t.program_id, &Address::generate(&t.env), &1_000);
+}
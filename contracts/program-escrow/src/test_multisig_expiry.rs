@@ -0,0 +1,127 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_multisig_expiry.rs
+//
+// Tests for expiring multisig payout requests that do not
+// collect enough approvals in time.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, Env, String,
+};
+
+use crate::{ProgramEscrowContract, ProgramEscrowContractClient};
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    program_id: String,
+    signer_a: Address,
+    signer_b: Address,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let admin = Address::generate(&env);
+    let payout_key = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&contract_id, &10_000);
+
+    let program_id = String::from_str(&env, "MultisigExpiry");
+    client.initialize_program(&program_id, &payout_key, &sac.address());
+    client.lock_program_funds(&program_id, &10_000);
+
+    let signer_a = Address::generate(&env);
+    let signer_b = Address::generate(&env);
+    client.set_multisig_payout_config(
+        &program_id,
+        &vec![&env, signer_a.clone(), signer_b.clone()],
+        &2,
+    );
+    client.set_multisig_request_expiry(&program_id, &3_600);
+
+    TestSetup {
+        env,
+        client,
+        program_id,
+        signer_a,
+        signer_b,
+    }
+}
+
+#[test]
+fn test_request_status_shows_approvals_and_time_remaining() {
+    let t = setup();
+    assert_eq!(t.client.get_multisig_request_expiry(&t.program_id), 3_600);
+
+    let request_id =
+        t.client
+            .request_multisig_payout(&t.program_id, &Address::generate(&t.env), &4_000);
+    t.client
+        .approve_multisig_payout(&t.program_id, &request_id, &t.signer_a);
+
+    t.env.ledger().set_timestamp(1_600);
+    let status = t
+        .client
+        .get_multisig_request_status(&t.program_id, &request_id);
+    assert_eq!(status.approvals, 1);
+    assert_eq!(status.required, 2);
+    assert_eq!(status.expires_at, 4_600);
+    assert_eq!(status.seconds_remaining, 3_000);
+    assert!(!status.expired);
+}
+
+#[test]
+fn test_underapproved_request_expires_and_frees_reservation() {
+    let t = setup();
+    let request_id =
+        t.client
+            .request_multisig_payout(&t.program_id, &Address::generate(&t.env), &4_000);
+    t.client
+        .approve_multisig_payout(&t.program_id, &request_id, &t.signer_a);
+    assert_eq!(t.client.get_remaining_balance(&t.program_id), 6_000);
+
+    t.env.ledger().set_timestamp(4_600);
+    let status = t
+        .client
+        .get_multisig_request_status(&t.program_id, &request_id);
+    assert!(status.expired);
+    assert_eq!(status.seconds_remaining, 0);
+    assert!(t
+        .client
+        .try_approve_multisig_payout(&t.program_id, &request_id, &t.signer_b)
+        .is_err());
+
+    assert_eq!(t.client.expire_multisig_payouts(&t.program_id), 1);
+    assert_eq!(t.client.get_remaining_balance(&t.program_id), 10_000);
+    assert_eq!(
+        t.client.get_pending_multisig_payouts(&t.program_id),
+        vec![&t.env]
+    );
+}
+
+#[test]
+fn test_new_request_sweeps_expired_ones() {
+    let t = setup();
+    t.client
+        .request_multisig_payout(&t.program_id, &Address::generate(&t.env), &8_000);
+
+    // The expired reservation is freed before the new request is checked
+    t.env.ledger().set_timestamp(5_000);
+    t.client
+        .request_multisig_payout(&t.program_id, &Address::generate(&t.env), &8_000);
+    assert_eq!(
+        t.client.get_pending_multisig_payouts(&t.program_id).len(),
+        1
+    );
+    assert_eq!(t.client.get_remaining_balance(&t.program_id), 2_000);
+}
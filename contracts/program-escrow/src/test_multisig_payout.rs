@@ -0,0 +1,251 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_multisig_payout.rs
+//
+// Tests for multisig payouts and the balance they reserve while
+// awaiting approval.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{symbol_short, testutils::Address as _, token, vec, Address, Env, String};
+
+use crate::{ProgramEscrowContract, ProgramEscrowContractClient};
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    token: token::Client<'a>,
+    program_id: String,
+    signer_a: Address,
+    signer_b: Address,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let payout_key = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    let token = token::Client::new(&env, &sac.address());
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&contract_id, &10_000);
+
+    let program_id = String::from_str(&env, "Multisig");
+    client.initialize_program(&program_id, &payout_key, &token.address);
+    client.lock_program_funds(&program_id, &10_000);
+
+    let signer_a = Address::generate(&env);
+    let signer_b = Address::generate(&env);
+    client.set_multisig_payout_config(
+        &program_id,
+        &vec![
+            &env,
+            signer_a.clone(),
+            signer_b.clone(),
+            Address::generate(&env),
+        ],
+        &2,
+    );
+
+    TestSetup {
+        env,
+        client,
+        token,
+        program_id,
+        signer_a,
+        signer_b,
+    }
+}
+
+#[test]
+fn test_pending_request_reserves_balance() {
+    let t = setup();
+    let recipient = Address::generate(&t.env);
+
+    let request_id = t
+        .client
+        .request_multisig_payout(&t.program_id, &recipient, &6_000);
+    t.client
+        .approve_multisig_payout(&t.program_id, &request_id, &t.signer_a);
+
+    assert_eq!(t.client.get_remaining_balance(&t.program_id), 4_000);
+    assert_eq!(t.token.balance(&recipient), 0);
+
+    let pending = t.client.get_pending_multisig_payouts(&t.program_id);
+    assert_eq!(pending.len(), 1);
+    let request = pending.get(0).unwrap();
+    assert_eq!(request.amount, 6_000);
+    assert_eq!(request.approvals.len(), 1);
+    assert_eq!(request.required, 2);
+}
+
+#[test]
+fn test_other_payouts_cannot_spend_reserved_funds() {
+    let t = setup();
+    t.client
+        .request_multisig_payout(&t.program_id, &Address::generate(&t.env), &6_000);
+
+    let result = t.client.try_batch_payout(
+        &t.program_id,
+        &vec![&t.env, Address::generate(&t.env)],
+        &vec![&t.env, 5_000],
+        &false,
+        &None,
+        &5_000,
+    );
+    assert!(result.is_err());
+    assert_eq!(t.client.get_remaining_balance(&t.program_id), 4_000);
+}
+
+#[test]
+fn test_threshold_approval_executes_payout() {
+    let t = setup();
+    let recipient = Address::generate(&t.env);
+    let request_id = t
+        .client
+        .request_multisig_payout(&t.program_id, &recipient, &6_000);
+
+    assert!(!t
+        .client
+        .approve_multisig_payout(&t.program_id, &request_id, &t.signer_a));
+    assert!(t
+        .client
+        .approve_multisig_payout(&t.program_id, &request_id, &t.signer_b));
+
+    assert_eq!(t.token.balance(&recipient), 6_000);
+    assert_eq!(t.client.get_remaining_balance(&t.program_id), 4_000);
+    assert!(t
+        .client
+        .get_pending_multisig_payouts(&t.program_id)
+        .is_empty());
+}
+
+#[test]
+fn test_cancel_releases_reservation() {
+    let t = setup();
+    let request_id =
+        t.client
+            .request_multisig_payout(&t.program_id, &Address::generate(&t.env), &6_000);
+
+    t.client.cancel_multisig_payout(&t.program_id, &request_id);
+
+    assert_eq!(t.client.get_remaining_balance(&t.program_id), 10_000);
+    assert!(t
+        .client
+        .get_pending_multisig_payouts(&t.program_id)
+        .is_empty());
+}
+
+#[test]
+#[should_panic(expected = "Already approved")]
+fn test_duplicate_approval_rejected() {
+    let t = setup();
+    let request_id =
+        t.client
+            .request_multisig_payout(&t.program_id, &Address::generate(&t.env), &100);
+
+    t.client
+        .approve_multisig_payout(&t.program_id, &request_id, &t.signer_a);
+    t.client
+        .approve_multisig_payout(&t.program_id, &request_id, &t.signer_a);
+}
+
+#[test]
+#[should_panic(expected = "Recipient is denylisted")]
+fn test_request_to_denylisted_recipient_rejected() {
+    let t = setup();
+    let recipient = Address::generate(&t.env);
+    t.client.add_to_denylist(&t.program_id, &recipient, &false);
+
+    t.client
+        .request_multisig_payout(&t.program_id, &recipient, &1_000);
+}
+
+#[test]
+fn test_recipient_denylisted_after_request_is_not_paid() {
+    let t = setup();
+    let recipient = Address::generate(&t.env);
+    let request_id = t
+        .client
+        .request_multisig_payout(&t.program_id, &recipient, &6_000);
+    t.client
+        .approve_multisig_payout(&t.program_id, &request_id, &t.signer_a);
+
+    t.client.add_to_denylist(&t.program_id, &recipient, &false);
+
+    assert!(t
+        .client
+        .try_approve_multisig_payout(&t.program_id, &request_id, &t.signer_b)
+        .is_err());
+    assert_eq!(t.token.balance(&recipient), 0);
+
+    // The reservation can still be returned to the balance
+    t.client.cancel_multisig_payout(&t.program_id, &request_id);
+    assert_eq!(t.client.get_remaining_balance(&t.program_id), 10_000);
+}
+
+#[test]
+#[should_panic(expected = "Payout disabled for program")]
+fn test_request_rejected_when_payouts_disabled() {
+    let t = setup();
+    t.client
+        .set_operation_flags(&t.program_id, &true, &false, &true);
+
+    t.client
+        .request_multisig_payout(&t.program_id, &Address::generate(&t.env), &1_000);
+}
+
+#[test]
+#[should_panic(expected = "Operation not allowed for program")]
+fn test_request_rejected_outside_allowed_operations() {
+    let t = setup();
+    t.client.set_admin(&Address::generate(&t.env), &0);
+    t.client
+        .set_program_allowed_operations(&t.program_id, &vec![&t.env, symbol_short!("lock")]);
+
+    t.client
+        .request_multisig_payout(&t.program_id, &Address::generate(&t.env), &1_000);
+}
+
+#[test]
+#[should_panic(expected = "Payout exceeds balance fraction limit")]
+fn test_request_rejected_above_payout_fraction() {
+    let t = setup();
+    t.client.set_max_payout_fraction_bps(&t.program_id, &2_500);
+
+    t.client
+        .request_multisig_payout(&t.program_id, &Address::generate(&t.env), &3_000);
+}
+
+#[test]
+fn test_daily_cap_counted_once_on_execution() {
+    let t = setup();
+    t.client.set_admin(&Address::generate(&t.env), &0);
+    t.client.set_daily_payout_cap(&t.program_id, &5_000);
+    let recipient = Address::generate(&t.env);
+
+    let first = t
+        .client
+        .request_multisig_payout(&t.program_id, &recipient, &3_000);
+    let second = t
+        .client
+        .request_multisig_payout(&t.program_id, &recipient, &3_000);
+    t.client
+        .approve_multisig_payout(&t.program_id, &first, &t.signer_a);
+    t.client
+        .approve_multisig_payout(&t.program_id, &first, &t.signer_b);
+    assert_eq!(t.token.balance(&recipient), 3_000);
+
+    // the second execution would take today's total to 6_000
+    t.client
+        .approve_multisig_payout(&t.program_id, &second, &t.signer_a);
+    assert!(t
+        .client
+        .try_approve_multisig_payout(&t.program_id, &second, &t.signer_b)
+        .is_err());
+    assert_eq!(t.token.balance(&recipient), 3_000);
+}
@@ -0,0 +1,100 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_next_due_schedule.rs
+//
+// Tests for `get_next_due_schedule`.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env, String,
+};
+
+use crate::{ProgramEscrowContract, ProgramEscrowContractClient};
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    program_id: String,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let admin = Address::generate(&env);
+    let payout_key = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&contract_id, &100_000);
+
+    // Whitelist the payout key so schedules can be created back to back.
+    client.set_admin(&admin, &0);
+    client.set_whitelist(&payout_key, &true);
+
+    let program_id = String::from_str(&env, "Keeper");
+    client.initialize_program(&program_id, &payout_key, &sac.address());
+    client.lock_program_funds(&program_id, &100_000);
+
+    TestSetup {
+        env,
+        client,
+        program_id,
+    }
+}
+
+fn schedule(t: &TestSetup, release_timestamp: u64) -> u64 {
+    t.client.create_program_release_schedule(
+        &t.program_id,
+        &1_000,
+        &release_timestamp,
+        &Address::generate(&t.env),
+    );
+    t.client
+        .get_all_prog_release_schedules(&t.program_id)
+        .last()
+        .unwrap()
+        .schedule_id
+}
+
+#[test]
+fn test_no_schedules_returns_none() {
+    let t = setup();
+    assert_eq!(t.client.get_next_due_schedule(&t.program_id), None);
+}
+
+#[test]
+fn test_returns_chronologically_earliest() {
+    let t = setup();
+    schedule(&t, 5_000);
+    let earliest = schedule(&t, 2_000);
+    schedule(&t, 9_000);
+
+    assert_eq!(
+        t.client.get_next_due_schedule(&t.program_id),
+        Some((earliest, 2_000))
+    );
+}
+
+#[test]
+fn test_updates_after_releases() {
+    let t = setup();
+    let late = schedule(&t, 5_000);
+    let early = schedule(&t, 2_000);
+
+    t.env.ledger().set_timestamp(2_000);
+    t.client.release_prog_schedule_automatic(&t.program_id, &early);
+    assert_eq!(
+        t.client.get_next_due_schedule(&t.program_id),
+        Some((late, 5_000))
+    );
+
+    t.env.ledger().set_timestamp(5_000);
+    t.client.release_prog_schedule_automatic(&t.program_id, &late);
+    assert_eq!(t.client.get_next_due_schedule(&t.program_id), None);
+}
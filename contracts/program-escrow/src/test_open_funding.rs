@@ -0,0 +1,151 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_open_funding.rs
+//
+// Tests for crowdfunding a program's prize pool from many
+// contributors.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, token, Address, Env, String};
+
+use crate::{Contribution, ProgramEscrowContract, ProgramEscrowContractClient};
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    token: token::Client<'a>,
+    token_admin: token::StellarAssetClient<'a>,
+    contract_id: Address,
+    program_id: String,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let payout_key = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    let token = token::Client::new(&env, &sac.address());
+    let token_admin = token::StellarAssetClient::new(&env, &sac.address());
+
+    client.set_admin(&admin, &0);
+    client.set_whitelist(&payout_key, &true);
+    client.set_whitelist(&contract_id, &true);
+
+    let program_id = String::from_str(&env, "Crowdfund");
+    client.initialize_program(&program_id, &payout_key, &sac.address());
+
+    TestSetup {
+        env,
+        client,
+        token,
+        token_admin,
+        contract_id,
+        program_id,
+    }
+}
+
+fn funder(t: &TestSetup, balance: i128) -> Address {
+    let funder = Address::generate(&t.env);
+    t.token_admin.mint(&funder, &balance);
+    funder
+}
+
+#[test]
+fn test_open_funding_disabled_by_default() {
+    let t = setup();
+    assert!(!t.client.is_open_funding(&t.program_id));
+}
+
+#[test]
+#[should_panic(expected = "Open funding disabled for program")]
+fn test_contribute_requires_open_funding() {
+    let t = setup();
+    let alice = funder(&t, 1_000);
+    t.client
+        .contribute_program_funds(&t.program_id, &alice, &500);
+}
+
+#[test]
+fn test_multiple_funders_tracked_separately() {
+    let t = setup();
+    t.client.set_open_funding(&t.program_id, &true);
+    let alice = funder(&t, 5_000);
+    let bob = funder(&t, 5_000);
+
+    t.client
+        .contribute_program_funds(&t.program_id, &alice, &1_000);
+    t.client
+        .contribute_program_funds(&t.program_id, &bob, &2_500);
+    let data = t
+        .client
+        .contribute_program_funds(&t.program_id, &alice, &500);
+
+    assert_eq!(data.total_funds, 4_000);
+    assert_eq!(data.remaining_balance, 4_000);
+    assert_eq!(t.token.balance(&t.contract_id), 4_000);
+    assert_eq!(t.token.balance(&alice), 3_500);
+    assert_eq!(t.token.balance(&bob), 2_500);
+
+    let contributions = t.client.get_contributions(&t.program_id, &0, &10);
+    assert_eq!(contributions.len(), 2);
+    assert_eq!(
+        contributions.get(0).unwrap(),
+        Contribution {
+            funder: alice,
+            amount: 1_500,
+        }
+    );
+    assert_eq!(
+        contributions.get(1).unwrap(),
+        Contribution {
+            funder: bob,
+            amount: 2_500,
+        }
+    );
+}
+
+#[test]
+fn test_get_contributions_paginates() {
+    let t = setup();
+    t.client.set_open_funding(&t.program_id, &true);
+    let funders = [funder(&t, 100), funder(&t, 100), funder(&t, 100)];
+    for f in funders.iter() {
+        t.client.contribute_program_funds(&t.program_id, f, &100);
+    }
+
+    let page = t.client.get_contributions(&t.program_id, &1, &5);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().funder, funders[1]);
+    assert_eq!(page.get(1).unwrap().funder, funders[2]);
+    assert_eq!(t.client.get_contributions(&t.program_id, &3, &5).len(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Open funding disabled for program")]
+fn test_disabling_open_funding_blocks_contributions() {
+    let t = setup();
+    let alice = funder(&t, 1_000);
+    t.client.set_open_funding(&t.program_id, &true);
+    t.client
+        .contribute_program_funds(&t.program_id, &alice, &500);
+
+    t.client.set_open_funding(&t.program_id, &false);
+    t.client
+        .contribute_program_funds(&t.program_id, &alice, &500);
+}
+
+#[test]
+#[should_panic(expected = "Amount must be greater than zero")]
+fn test_zero_contribution_panics() {
+    let t = setup();
+    t.client.set_open_funding(&t.program_id, &true);
+    let alice = funder(&t, 1_000);
+    t.client.contribute_program_funds(&t.program_id, &alice, &0);
+}
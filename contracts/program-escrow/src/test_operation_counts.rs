@@ -0,0 +1,115 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_operation_counts.rs
+//
+// Tests for the lifetime operation counters returned by
+// `get_operation_counts`.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, Env, String,
+};
+
+use crate::{monitoring::OperationCounts, ProgramEscrowContract, ProgramEscrowContractClient};
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    admin: Address,
+    payout_key: Address,
+    token_address: Address,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let admin = Address::generate(&env);
+    let payout_key = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&contract_id, &100_000);
+
+    client.set_admin(&admin, &0);
+    client.set_whitelist(&payout_key, &true);
+    client.set_whitelist(&contract_id, &true);
+
+    TestSetup {
+        env,
+        client,
+        admin,
+        payout_key,
+        token_address: sac.address(),
+    }
+}
+
+#[test]
+fn test_counts_start_at_zero() {
+    let t = setup();
+    assert_eq!(t.client.get_operation_counts(), OperationCounts::default());
+}
+
+#[test]
+fn test_counts_accumulate_across_operations() {
+    let t = setup();
+    let first = String::from_str(&t.env, "First");
+    let second = String::from_str(&t.env, "Second");
+
+    t.client
+        .initialize_program(&first, &t.payout_key, &t.token_address);
+    t.client.clone_program(&first, &second);
+    t.client.lock_program_funds(&first, &50_000);
+    t.client.lock_program_funds(&second, &10_000);
+
+    // Claims are created by default, so each payout leaves a pending claim
+    let alice = Address::generate(&t.env);
+    let bob = Address::generate(&t.env);
+    t.client.single_payout(&first, &alice, &1_000);
+    t.client.single_payout(&first, &bob, &2_000);
+    let recipients = vec![&t.env, Address::generate(&t.env)];
+    let amounts = vec![&t.env, 500_i128];
+    t.client
+        .batch_payout(&first, &recipients, &amounts, &false, &None, &500);
+
+    let claims = t.client.get_pending_claim_ids(&first);
+    t.client
+        .execute_claim(&first, &claims.get(0).unwrap(), &alice);
+    t.client
+        .cancel_claim(&first, &claims.get(1).unwrap(), &t.admin);
+
+    assert_eq!(
+        t.client.get_operation_counts(),
+        OperationCounts {
+            inits: 2,
+            locks: 2,
+            payouts: 3,
+            claims: 1,
+            cancels: 1,
+        }
+    );
+}
+
+#[test]
+fn test_failed_operations_are_not_counted() {
+    let t = setup();
+    let program_id = String::from_str(&t.env, "Program");
+    t.client
+        .initialize_program(&program_id, &t.payout_key, &t.token_address);
+    t.client.lock_program_funds(&program_id, &1_000);
+
+    let result = t
+        .client
+        .try_single_payout(&program_id, &Address::generate(&t.env), &5_000);
+    assert!(result.is_err());
+
+    let counts = t.client.get_operation_counts();
+    assert_eq!(counts.inits, 1);
+    assert_eq!(counts.locks, 1);
+    assert_eq!(counts.payouts, 0);
+}
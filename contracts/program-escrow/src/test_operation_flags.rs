@@ -0,0 +1,170 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_operation_flags.rs
+//
+// Tests for per-program operation flags (`set_operation_flags`).
+//
+// Each flag is toggled on its own and must block only its operation:
+//   allow_lock   → lock_program_funds
+//   allow_payout → single_payout / batch_payout / create_pending_claim
+//   allow_claim  → execute_claim
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env, String};
+
+use crate::{OperationFlags, ProgramEscrowContract, ProgramEscrowContractClient};
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    program_id: String,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let payout_key = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&contract_id, &100_000);
+
+    // Whitelist both rate-limited callers so operations can run back to back.
+    client.set_admin(&admin, &0);
+    client.set_whitelist(&payout_key, &true);
+    client.set_whitelist(&contract_id, &true);
+
+    let program_id = String::from_str(&env, "Flags");
+    client.initialize_program(&program_id, &payout_key, &sac.address());
+    client.lock_program_funds(&program_id, &50_000);
+
+    TestSetup {
+        env,
+        client,
+        program_id,
+    }
+}
+
+#[test]
+fn test_all_operations_allowed_by_default() {
+    let t = setup();
+    assert_eq!(
+        t.client.get_operation_flags(&t.program_id),
+        OperationFlags {
+            allow_lock: true,
+            allow_payout: true,
+            allow_claim: true,
+        }
+    );
+}
+
+#[test]
+fn test_disabling_lock_keeps_payouts_and_claims() {
+    let t = setup();
+    t.client
+        .set_operation_flags(&t.program_id, &false, &true, &true);
+
+    assert!(t
+        .client
+        .try_lock_program_funds(&t.program_id, &1_000)
+        .is_err());
+
+    let recipient = Address::generate(&t.env);
+    let claim_id = t
+        .client
+        .single_payout(&t.program_id, &recipient, &1_000)
+        .claim_id;
+    t.client.execute_claim(&t.program_id, &claim_id, &recipient);
+    t.client.batch_payout(
+        &t.program_id,
+        &vec![&t.env, Address::generate(&t.env)],
+        &vec![&t.env, 1_000],
+        &false,
+        &None,
+        &1_000,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Lock disabled for program")]
+fn test_lock_disabled_panics() {
+    let t = setup();
+    t.client
+        .set_operation_flags(&t.program_id, &false, &true, &true);
+    t.client.lock_program_funds(&t.program_id, &1_000);
+}
+
+#[test]
+#[should_panic(expected = "Payout disabled for program")]
+fn test_single_payout_disabled_panics() {
+    let t = setup();
+    t.client
+        .set_operation_flags(&t.program_id, &true, &false, &true);
+    t.client
+        .single_payout(&t.program_id, &Address::generate(&t.env), &1_000);
+}
+
+#[test]
+fn test_disabling_payout_blocks_all_payout_paths_only() {
+    let t = setup();
+    let recipient = Address::generate(&t.env);
+    let claim_id = t
+        .client
+        .single_payout(&t.program_id, &recipient, &1_000)
+        .claim_id;
+
+    t.client
+        .set_operation_flags(&t.program_id, &true, &false, &true);
+
+    assert!(t
+        .client
+        .try_batch_payout(
+            &t.program_id,
+            &vec![&t.env, Address::generate(&t.env)],
+            &vec![&t.env, 1_000],
+            &false,
+            &None,
+            &1_000,
+        )
+        .is_err());
+    assert!(t
+        .client
+        .try_create_pending_claim(&t.program_id, &recipient, &1_000, &10_000)
+        .is_err());
+
+    // Locking and claiming already-reserved funds still work
+    t.client.lock_program_funds(&t.program_id, &1_000);
+    t.client.execute_claim(&t.program_id, &claim_id, &recipient);
+}
+
+#[test]
+#[should_panic(expected = "Claim disabled for program")]
+fn test_claim_disabled_panics() {
+    let t = setup();
+    let recipient = Address::generate(&t.env);
+    let claim_id = t
+        .client
+        .single_payout(&t.program_id, &recipient, &1_000)
+        .claim_id;
+
+    t.client
+        .set_operation_flags(&t.program_id, &true, &true, &false);
+    t.client.execute_claim(&t.program_id, &claim_id, &recipient);
+}
+
+#[test]
+fn test_reenabling_restores_operation() {
+    let t = setup();
+    t.client
+        .set_operation_flags(&t.program_id, &false, &false, &false);
+    t.client
+        .set_operation_flags(&t.program_id, &true, &true, &true);
+
+    let data = t.client.lock_program_funds(&t.program_id, &1_000);
+    assert_eq!(data.remaining_balance, 51_000);
+}
@@ -0,0 +1,105 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_organizer_program_cap.rs
+//
+// Tests for `set_max_programs_per_organizer`.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+use crate::{ProgramEscrowContract, ProgramEscrowContractClient};
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    organizer: Address,
+    token: Address,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let organizer = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+
+    client.set_admin(&admin, &0);
+    client.set_max_programs_per_organizer(&2);
+
+    TestSetup {
+        env,
+        client,
+        organizer,
+        token: sac.address(),
+    }
+}
+
+fn init(t: &TestSetup, id: &str) -> String {
+    let program_id = String::from_str(&t.env, id);
+    t.client
+        .initialize_program(&program_id, &t.organizer, &t.token);
+    program_id
+}
+
+#[test]
+fn test_organizer_can_init_up_to_limit() {
+    let t = setup();
+    assert_eq!(t.client.get_max_programs_per_organizer(), 2);
+
+    init(&t, "P1");
+    init(&t, "P2");
+    assert_eq!(
+        t.client.get_organizer_programs(&t.organizer, &0, &10).len(),
+        2
+    );
+}
+
+#[test]
+#[should_panic(expected = "Organizer program limit reached")]
+fn test_init_beyond_limit_panics() {
+    let t = setup();
+    init(&t, "P1");
+    init(&t, "P2");
+    init(&t, "P3");
+}
+
+#[test]
+fn test_limit_is_per_organizer() {
+    let t = setup();
+    init(&t, "P1");
+    init(&t, "P2");
+
+    let other = Address::generate(&t.env);
+    t.client
+        .initialize_program(&String::from_str(&t.env, "P3"), &other, &t.token);
+}
+
+#[test]
+fn test_closing_a_program_frees_a_slot() {
+    let t = setup();
+    let first = init(&t, "P1");
+    init(&t, "P2");
+    assert!(t
+        .client
+        .try_initialize_program(&String::from_str(&t.env, "P3"), &t.organizer, &t.token)
+        .is_err());
+
+    t.client.close_program(&first, &None);
+    init(&t, "P3");
+}
+
+#[test]
+fn test_zero_removes_the_cap() {
+    let t = setup();
+    t.client.set_max_programs_per_organizer(&0);
+
+    for id in ["P1", "P2", "P3", "P4"] {
+        init(&t, id);
+    }
+}
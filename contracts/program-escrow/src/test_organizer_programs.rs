@@ -0,0 +1,120 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_organizer_programs.rs
+//
+// Tests for the organizer -> programs index behind
+// `get_organizer_programs`.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+
+use crate::{ProgramEscrowContract, ProgramEscrowContractClient};
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    token: Address,
+    organizer: Address,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+    let sac = env.register_stellar_asset_contract_v2(admin);
+
+    TestSetup {
+        organizer: Address::generate(&env),
+        token: sac.address(),
+        env,
+        client,
+    }
+}
+
+fn register(t: &TestSetup, name: &str, organizer: &Address) -> String {
+    let program_id = String::from_str(&t.env, name);
+    t.client
+        .initialize_program(&program_id, organizer, &t.token);
+    program_id
+}
+
+#[test]
+fn test_programs_indexed_by_organizer() {
+    let t = setup();
+    let other = Address::generate(&t.env);
+    let alpha = register(&t, "Alpha", &t.organizer);
+    let beta = register(&t, "Beta", &t.organizer);
+    let gamma = register(&t, "Gamma", &other);
+
+    assert_eq!(
+        t.client.get_organizer_programs(&t.organizer, &0, &10),
+        vec![&t.env, alpha, beta]
+    );
+    assert_eq!(
+        t.client.get_organizer_programs(&other, &0, &10),
+        vec![&t.env, gamma]
+    );
+}
+
+#[test]
+fn test_pagination() {
+    let t = setup();
+    register(&t, "One", &t.organizer);
+    let two = register(&t, "Two", &t.organizer);
+    let three = register(&t, "Three", &t.organizer);
+
+    assert_eq!(
+        t.client.get_organizer_programs(&t.organizer, &1, &5),
+        vec![&t.env, two.clone(), three]
+    );
+    assert_eq!(
+        t.client.get_organizer_programs(&t.organizer, &1, &1),
+        vec![&t.env, two]
+    );
+    assert!(t
+        .client
+        .get_organizer_programs(&t.organizer, &3, &5)
+        .is_empty());
+}
+
+#[test]
+fn test_transfer_moves_program_between_organizers() {
+    let t = setup();
+    let new_organizer = Address::generate(&t.env);
+    let alpha = register(&t, "Alpha", &t.organizer);
+    let beta = register(&t, "Beta", &t.organizer);
+
+    let data = t.client.transfer_program_ownership(&alpha, &new_organizer);
+
+    assert_eq!(data.authorized_payout_key, new_organizer);
+    assert_eq!(
+        t.client.get_program_info(&alpha).authorized_payout_key,
+        new_organizer
+    );
+    assert_eq!(
+        t.client.get_organizer_programs(&t.organizer, &0, &10),
+        vec![&t.env, beta]
+    );
+    assert_eq!(
+        t.client.get_organizer_programs(&new_organizer, &0, &10),
+        vec![&t.env, alpha]
+    );
+}
+
+#[test]
+fn test_cloned_program_is_indexed() {
+    let t = setup();
+    let source = register(&t, "Cohort1", &t.organizer);
+    let clone = String::from_str(&t.env, "Cohort2");
+
+    t.client.clone_program(&source, &clone);
+
+    assert_eq!(
+        t.client.get_organizer_programs(&t.organizer, &0, &10),
+        vec![&t.env, source, clone]
+    );
+}
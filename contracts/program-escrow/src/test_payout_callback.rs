@@ -0,0 +1,165 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_payout_callback.rs
+//
+// Tests for the `on_payout` cross-contract callback.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{
+    contract, contractimpl, symbol_short,
+    testutils::{Address as _, Events},
+    token, Address, Env, IntoVal, String, Symbol, Val, Vec,
+};
+
+use crate::{ClaimStatus, ProgramEscrowContract, ProgramEscrowContractClient};
+
+const LAST: Symbol = symbol_short!("last");
+const CALLS: Symbol = symbol_short!("calls");
+
+/// Records every notification it receives.
+#[contract]
+pub struct RecordingCallback;
+
+#[contractimpl]
+impl RecordingCallback {
+    pub fn on_payout(env: Env, program_id: String, recipient: Address, amount: i128) {
+        let calls: u32 = env.storage().instance().get(&CALLS).unwrap_or(0);
+        env.storage().instance().set(&CALLS, &(calls + 1));
+        env.storage()
+            .instance()
+            .set(&LAST, &(program_id, recipient, amount));
+    }
+
+    pub fn calls(env: Env) -> u32 {
+        env.storage().instance().get(&CALLS).unwrap_or(0)
+    }
+
+    pub fn last(env: Env) -> (String, Address, i128) {
+        env.storage().instance().get(&LAST).unwrap()
+    }
+}
+
+// Separate module: two `#[contractimpl]`s exporting the same fn name can't share one.
+mod failing {
+    use soroban_sdk::{contract, contractimpl, Address, Env, String};
+
+    /// Always fails.
+    #[contract]
+    pub struct FailingCallback;
+
+    #[contractimpl]
+    impl FailingCallback {
+        pub fn on_payout(_env: Env, _program_id: String, _recipient: Address, _amount: i128) {
+            panic!("callback exploded");
+        }
+    }
+}
+use failing::FailingCallback;
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    token: token::Client<'a>,
+    program_id: String,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let payout_key = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    let token = token::Client::new(&env, &sac.address());
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&contract_id, &100_000);
+
+    let program_id = String::from_str(&env, "Badges");
+    client.initialize_program(&program_id, &payout_key, &token.address);
+    client.lock_program_funds(&program_id, &100_000);
+
+    TestSetup {
+        env,
+        client,
+        token,
+        program_id,
+    }
+}
+
+#[test]
+fn test_callback_invoked_after_claim() {
+    let t = setup();
+    let callback_id = t.env.register_contract(None, RecordingCallback);
+    let callback = RecordingCallbackClient::new(&t.env, &callback_id);
+    t.client
+        .set_payout_callback(&t.program_id, &Some(callback_id.clone()));
+    assert_eq!(
+        t.client.get_payout_callback(&t.program_id),
+        Some(callback_id)
+    );
+
+    let recipient = Address::generate(&t.env);
+    let claim_id = t
+        .client
+        .single_payout(&t.program_id, &recipient, &2_500)
+        .claim_id;
+    assert_eq!(callback.calls(), 0);
+
+    t.client.execute_claim(&t.program_id, &claim_id, &recipient);
+
+    assert_eq!(callback.calls(), 1);
+    assert_eq!(callback.last(), (t.program_id.clone(), recipient, 2_500));
+}
+
+#[test]
+fn test_failing_callback_does_not_revert_claim() {
+    let t = setup();
+    let callback_id = t.env.register_contract(None, FailingCallback);
+    t.client
+        .set_payout_callback(&t.program_id, &Some(callback_id));
+
+    let recipient = Address::generate(&t.env);
+    let claim_id = t
+        .client
+        .single_payout(&t.program_id, &recipient, &2_500)
+        .claim_id;
+    t.client.execute_claim(&t.program_id, &claim_id, &recipient);
+
+    let expected: Vec<Val> = (Symbol::new(&t.env, "callback_failed"),).into_val(&t.env);
+    assert!(t
+        .env
+        .events()
+        .all()
+        .iter()
+        .any(|(_, topics, _)| topics == expected));
+
+    assert_eq!(t.token.balance(&recipient), 2_500);
+    assert_eq!(
+        t.client.get_claim_status(&t.program_id, &claim_id),
+        ClaimStatus::Completed
+    );
+}
+
+#[test]
+fn test_cleared_callback_is_not_invoked() {
+    let t = setup();
+    let callback_id = t.env.register_contract(None, RecordingCallback);
+    let callback = RecordingCallbackClient::new(&t.env, &callback_id);
+    t.client
+        .set_payout_callback(&t.program_id, &Some(callback_id));
+    t.client.set_payout_callback(&t.program_id, &None);
+    assert!(t.client.get_payout_callback(&t.program_id).is_none());
+
+    let recipient = Address::generate(&t.env);
+    let claim_id = t
+        .client
+        .single_payout(&t.program_id, &recipient, &1_000)
+        .claim_id;
+    t.client.execute_claim(&t.program_id, &claim_id, &recipient);
+
+    assert_eq!(callback.calls(), 0);
+}
@@ -0,0 +1,94 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_payout_condition.rs
+//
+// Tests for gating `single_payout` on an external condition contract.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{contract, contractimpl, testutils::Address as _, token, Address, Env, String};
+
+use crate::{ProgramEscrowContract, ProgramEscrowContractClient};
+
+/// Condition contract reporting milestones the test marked as complete.
+#[contract]
+pub struct MockCondition;
+
+#[contractimpl]
+impl MockCondition {
+    pub fn complete(env: Env, recipient: Address) {
+        env.storage().instance().set(&recipient, &true);
+    }
+
+    pub fn is_satisfied(env: Env, _program_id: String, recipient: Address) -> bool {
+        env.storage().instance().get(&recipient).unwrap_or(false)
+    }
+}
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    condition: MockConditionClient<'a>,
+    program_id: String,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let payout_key = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&contract_id, &10_000);
+
+    let program_id = String::from_str(&env, "Milestones");
+    client.initialize_program(&program_id, &payout_key, &sac.address());
+    client.lock_program_funds(&program_id, &10_000);
+
+    let condition_id = env.register_contract(None, MockCondition);
+    let condition = MockConditionClient::new(&env, &condition_id);
+    client.set_payout_condition(&program_id, &Some(condition_id));
+
+    TestSetup {
+        env,
+        client,
+        condition,
+        program_id,
+    }
+}
+
+#[test]
+fn test_payout_allowed_when_condition_satisfied() {
+    let t = setup();
+    let recipient = Address::generate(&t.env);
+    t.condition.complete(&recipient);
+
+    t.client.single_payout(&t.program_id, &recipient, &1_000);
+
+    assert_eq!(t.client.get_remaining_balance(&t.program_id), 9_000);
+}
+
+#[test]
+#[should_panic(expected = "Payout condition not met")]
+fn test_payout_refused_when_condition_unsatisfied() {
+    let t = setup();
+    let recipient = Address::generate(&t.env);
+
+    t.client.single_payout(&t.program_id, &recipient, &1_000);
+}
+
+#[test]
+fn test_clearing_condition_ungates_payouts() {
+    let t = setup();
+    let recipient = Address::generate(&t.env);
+
+    t.client.set_payout_condition(&t.program_id, &None);
+    assert_eq!(t.client.get_payout_condition(&t.program_id), None);
+
+    t.client.single_payout(&t.program_id, &recipient, &1_000);
+    assert_eq!(t.client.get_remaining_balance(&t.program_id), 9_000);
+}
@@ -0,0 +1,121 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_payout_fraction_limit.rs
+//
+// Tests for capping a single payout at a fraction of the
+// program's remaining balance.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env, String};
+
+use crate::{ProgramEscrowContract, ProgramEscrowContractClient};
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    program_id: String,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let payout_key = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&contract_id, &10_000);
+
+    client.set_admin(&admin, &0);
+    client.set_whitelist(&payout_key, &true);
+
+    let program_id = String::from_str(&env, "Fraction");
+    client.initialize_program(&program_id, &payout_key, &sac.address());
+    client.lock_program_funds(&program_id, &10_000);
+    client.set_direct_payout_mode(&program_id, &true);
+    client.set_max_payout_fraction_bps(&program_id, &2_500);
+
+    TestSetup {
+        env,
+        client,
+        program_id,
+    }
+}
+
+fn pay(t: &TestSetup, amount: i128) -> i128 {
+    t.client
+        .single_payout(&t.program_id, &Address::generate(&t.env), &amount)
+        .remaining_balance
+}
+
+#[test]
+fn test_payout_at_limit_succeeds() {
+    let t = setup();
+    assert_eq!(t.client.get_max_payout_fraction_bps(&t.program_id), 2_500);
+    assert_eq!(pay(&t, 2_500), 7_500);
+}
+
+#[test]
+#[should_panic(expected = "Payout exceeds balance fraction limit")]
+fn test_payout_over_limit_panics() {
+    let t = setup();
+    pay(&t, 2_501);
+}
+
+#[test]
+#[should_panic(expected = "Payout exceeds balance fraction limit")]
+fn test_limit_tracks_shrinking_balance() {
+    let t = setup();
+    pay(&t, 2_500);
+    pay(&t, 1_875);
+    // 25% of the remaining 5_625 is 1_406
+    pay(&t, 1_407);
+}
+
+#[test]
+fn test_limit_shrinks_with_balance() {
+    let t = setup();
+    pay(&t, 2_500);
+    pay(&t, 1_875);
+    assert_eq!(pay(&t, 1_406), 4_219);
+}
+
+#[test]
+#[should_panic(expected = "Payout exceeds balance fraction limit")]
+fn test_batch_entry_over_limit_panics() {
+    let t = setup();
+    let recipients = vec![&t.env, Address::generate(&t.env), Address::generate(&t.env)];
+    let amounts = vec![&t.env, 2_000_i128, 2_600];
+    t.client
+        .batch_payout(&t.program_id, &recipients, &amounts, &false, &None, &4_600);
+}
+
+#[test]
+fn test_batch_entries_checked_against_starting_balance() {
+    let t = setup();
+    let recipients = vec![&t.env, Address::generate(&t.env), Address::generate(&t.env)];
+    let amounts = vec![&t.env, 2_500_i128, 2_500];
+    let data = t
+        .client
+        .batch_payout(&t.program_id, &recipients, &amounts, &false, &None, &5_000);
+    assert_eq!(data.remaining_balance, 5_000);
+}
+
+#[test]
+fn test_clearing_limit() {
+    let t = setup();
+    t.client.set_max_payout_fraction_bps(&t.program_id, &0);
+    assert_eq!(t.client.get_max_payout_fraction_bps(&t.program_id), 0);
+    assert_eq!(pay(&t, 9_000), 1_000);
+}
+
+#[test]
+#[should_panic(expected = "Payout fraction cannot exceed 10000 bps")]
+fn test_fraction_over_100_percent_panics() {
+    let t = setup();
+    t.client.set_max_payout_fraction_bps(&t.program_id, &10_001);
+}
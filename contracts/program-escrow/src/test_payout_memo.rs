@@ -0,0 +1,124 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_payout_memo.rs
+//
+// Tests for binding payouts to an off-chain encrypted memo via
+// `single_payout_with_memo`.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Events},
+    token, Address, Bytes, BytesN, Env, IntoVal, String, Symbol, Val, Vec,
+};
+
+use crate::{ProgramEscrowContract, ProgramEscrowContractClient};
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    program_id: String,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let payout_key = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&contract_id, &10_000);
+
+    let program_id = String::from_str(&env, "Memo");
+    client.initialize_program(&program_id, &payout_key, &sac.address());
+    client.lock_program_funds(&program_id, &10_000);
+
+    TestSetup {
+        env,
+        client,
+        program_id,
+    }
+}
+
+#[test]
+fn test_memo_hash_round_trips_through_claim_and_history() {
+    let t = setup();
+    let recipient = Address::generate(&t.env);
+    let memo_hash = BytesN::from_array(&t.env, &[7u8; 32]);
+
+    let claim_id = t
+        .client
+        .single_payout_with_memo(&t.program_id, &recipient, &1_000, &memo_hash)
+        .claim_id;
+    assert_eq!(
+        t.client.get_claim(&t.program_id, &claim_id).memo_hash,
+        Some(memo_hash.clone().into())
+    );
+
+    t.client.execute_claim(&t.program_id, &claim_id, &recipient);
+
+    let history = t.client.get_program_info(&t.program_id).payout_history;
+    let record = history.get(history.len() - 1).unwrap();
+    assert_eq!(record.recipient, recipient);
+    assert_eq!(record.memo_hash, Some(memo_hash.into()));
+}
+
+#[test]
+fn test_memo_hash_recorded_on_direct_payout() {
+    let t = setup();
+    let recipient = Address::generate(&t.env);
+    let memo_hash = BytesN::from_array(&t.env, &[9u8; 32]);
+    t.client.add_auto_claim_recipient(&t.program_id, &recipient);
+
+    t.client
+        .single_payout_with_memo(&t.program_id, &recipient, &500, &memo_hash);
+
+    let history = t.client.get_program_info(&t.program_id).payout_history;
+    assert_eq!(history.get(0).unwrap().memo_hash, Some(memo_hash.into()));
+}
+
+#[test]
+fn test_memo_receipt_event_emitted() {
+    let t = setup();
+    let recipient = Address::generate(&t.env);
+    let memo_hash = BytesN::from_array(&t.env, &[3u8; 32]);
+
+    let claim_id = t
+        .client
+        .single_payout_with_memo(&t.program_id, &recipient, &800, &memo_hash)
+        .claim_id;
+
+    let expected: Vec<Val> =
+        (Symbol::new(&t.env, "payout_memo"), recipient.clone()).into_val(&t.env);
+    let mut receipts: Vec<(String, u64, i128, Bytes)> = Vec::new(&t.env);
+    for (_, topics, data) in t.env.events().all().iter() {
+        if topics == expected {
+            receipts.push_back(data.into_val(&t.env));
+        }
+    }
+
+    assert_eq!(receipts.len(), 1);
+    assert_eq!(
+        receipts.get(0).unwrap(),
+        (t.program_id.clone(), claim_id, 800, memo_hash.into())
+    );
+}
+
+#[test]
+fn test_plain_payout_has_no_memo() {
+    let t = setup();
+    let recipient = Address::generate(&t.env);
+
+    let claim_id = t
+        .client
+        .single_payout(&t.program_id, &recipient, &1_000)
+        .claim_id;
+    t.client.execute_claim(&t.program_id, &claim_id, &recipient);
+
+    let history = t.client.get_program_info(&t.program_id).payout_history;
+    assert_eq!(history.get(0).unwrap().memo_hash, None);
+}
@@ -0,0 +1,134 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_payout_reference.rs
+//
+// Tests for indexing payouts by reference and looking them up with
+// `get_payout_by_reference`.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env, String};
+
+use crate::{ProgramEscrowContract, ProgramEscrowContractClient};
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    program_id: String,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let payout_key = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&contract_id, &10_000);
+
+    let program_id = String::from_str(&env, "Referenced");
+    client.initialize_program(&program_id, &payout_key, &sac.address());
+    client.lock_program_funds(&program_id, &10_000);
+
+    TestSetup {
+        env,
+        client,
+        program_id,
+    }
+}
+
+fn reference(t: &TestSetup, value: &str) -> String {
+    String::from_str(&t.env, value)
+}
+
+#[test]
+fn test_lookup_returns_matching_payout() {
+    let t = setup();
+    let alice = Address::generate(&t.env);
+    let bob = Address::generate(&t.env);
+
+    t.client.batch_payout_with_references(
+        &t.program_id,
+        &vec![&t.env, alice.clone(), bob.clone()],
+        &vec![&t.env, 1_000, 2_500],
+        &vec![&t.env, reference(&t, "INV-001"), reference(&t, "INV-002")],
+        &3_500,
+    );
+
+    let first = t
+        .client
+        .get_payout_by_reference(&t.program_id, &reference(&t, "INV-001"))
+        .unwrap();
+    assert_eq!(first.recipient, alice);
+    assert_eq!(first.amount, 1_000);
+
+    let second = t
+        .client
+        .get_payout_by_reference(&t.program_id, &reference(&t, "INV-002"))
+        .unwrap();
+    assert_eq!(second.recipient, bob);
+    assert_eq!(second.amount, 2_500);
+}
+
+#[test]
+fn test_unknown_reference_returns_none() {
+    let t = setup();
+    t.client.batch_payout_with_references(
+        &t.program_id,
+        &vec![&t.env, Address::generate(&t.env)],
+        &vec![&t.env, 500],
+        &vec![&t.env, reference(&t, "INV-001")],
+        &500,
+    );
+
+    assert!(t
+        .client
+        .get_payout_by_reference(&t.program_id, &reference(&t, "INV-404"))
+        .is_none());
+}
+
+#[test]
+fn test_duplicate_reference_returns_first_payout() {
+    let t = setup();
+    let first_recipient = Address::generate(&t.env);
+    let later_recipient = Address::generate(&t.env);
+
+    t.client.batch_payout_with_references(
+        &t.program_id,
+        &vec![&t.env, first_recipient.clone()],
+        &vec![&t.env, 700],
+        &vec![&t.env, reference(&t, "DUP")],
+        &700,
+    );
+    t.client.batch_payout_with_references(
+        &t.program_id,
+        &vec![&t.env, later_recipient],
+        &vec![&t.env, 300],
+        &vec![&t.env, reference(&t, "DUP")],
+        &300,
+    );
+
+    let record = t
+        .client
+        .get_payout_by_reference(&t.program_id, &reference(&t, "DUP"))
+        .unwrap();
+    assert_eq!(record.recipient, first_recipient);
+    assert_eq!(record.amount, 700);
+}
+
+#[test]
+#[should_panic(expected = "References and amounts vectors must have the same length")]
+fn test_mismatched_references_rejected() {
+    let t = setup();
+    t.client.batch_payout_with_references(
+        &t.program_id,
+        &vec![&t.env, Address::generate(&t.env)],
+        &vec![&t.env, 100],
+        &vec![&t.env],
+        &100,
+    );
+}
@@ -0,0 +1,130 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_payout_result.rs
+//
+// Tests for the fee and sponsor breakdown returned by `single_payout`.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, token, Address, Env, String};
+
+use crate::{PayoutResult, ProgramEscrowContract, ProgramEscrowContractClient};
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    program_id: String,
+    fee_recipient: Address,
+    sponsor: Address,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let payout_key = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&contract_id, &100_000);
+
+    client.set_admin(&admin, &0);
+    client.set_whitelist(&payout_key, &true);
+    client.set_whitelist(&contract_id, &true);
+
+    let program_id = String::from_str(&env, "Breakdown");
+    client.initialize_program(&program_id, &payout_key, &sac.address());
+    client.lock_program_funds(&program_id, &100_000);
+
+    TestSetup {
+        fee_recipient: Address::generate(&env),
+        sponsor: Address::generate(&env),
+        env,
+        client,
+        program_id,
+    }
+}
+
+fn enable_fee(t: &TestSetup, bps: i128) {
+    t.client.update_fee_config(
+        &None,
+        &Some(bps),
+        &Some(t.fee_recipient.clone()),
+        &Some(true),
+        &1,
+    );
+}
+
+fn assert_sums(result: &PayoutResult) {
+    assert_eq!(result.gross, result.fee + result.sponsor_split + result.net);
+}
+
+#[test]
+fn test_no_fee_or_split_pays_gross() {
+    let t = setup();
+    let result = t
+        .client
+        .single_payout(&t.program_id, &Address::generate(&t.env), &10_000);
+
+    assert_eq!(result.gross, 10_000);
+    assert_eq!(result.fee, 0);
+    assert_eq!(result.sponsor_split, 0);
+    assert_eq!(result.net, 10_000);
+    assert_eq!(result.remaining_balance, 90_000);
+    assert_ne!(result.claim_id, 0);
+    assert_eq!(
+        t.client.get_claim(&t.program_id, &result.claim_id).amount,
+        result.net
+    );
+}
+
+#[test]
+fn test_fee_only_breakdown() {
+    let t = setup();
+    enable_fee(&t, 250);
+
+    let result = t
+        .client
+        .single_payout(&t.program_id, &Address::generate(&t.env), &10_000);
+
+    assert_eq!(result.fee, 250);
+    assert_eq!(result.net, 9_750);
+    assert_sums(&result);
+}
+
+#[test]
+fn test_fee_and_sponsor_split_breakdown() {
+    let t = setup();
+    enable_fee(&t, 250);
+    t.client
+        .set_sponsor_split(&t.program_id, &t.sponsor, &2_000);
+
+    let result = t
+        .client
+        .single_payout(&t.program_id, &Address::generate(&t.env), &10_000);
+
+    assert_eq!(result.fee, 250);
+    assert_eq!(result.sponsor_split, 1_950);
+    assert_eq!(result.net, 7_800);
+    assert_eq!(result.remaining_balance, 100_000 - 10_000);
+    assert_sums(&result);
+}
+
+#[test]
+fn test_breakdown_sums_with_rounding() {
+    let t = setup();
+    enable_fee(&t, 333);
+    t.client
+        .set_sponsor_split(&t.program_id, &t.sponsor, &1_111);
+
+    for gross in [1, 7, 999, 12_345] {
+        let result = t
+            .client
+            .single_payout(&t.program_id, &Address::generate(&t.env), &gross);
+        assert_eq!(result.gross, gross);
+        assert_sums(&result);
+    }
+}
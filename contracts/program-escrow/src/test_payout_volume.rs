@@ -0,0 +1,128 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_payout_volume.rs
+//
+// Tests for the time-bucketed payout volume returned by `get_payout_volume`.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, Env, String,
+};
+
+use crate::{ProgramEscrowContract, ProgramEscrowContractClient};
+
+const DAY: u64 = 86_400;
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    program_id: String,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let payout_key = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&contract_id, &1_000_000);
+
+    let program_id = String::from_str(&env, "Volume");
+    client.initialize_program(&program_id, &payout_key, &sac.address());
+    client.lock_program_funds(&program_id, &1_000_000);
+
+    TestSetup {
+        env,
+        client,
+        program_id,
+    }
+}
+
+fn pay_at(t: &TestSetup, timestamp: u64, amount: i128) {
+    t.env.ledger().set_timestamp(timestamp);
+    t.client
+        .single_payout(&t.program_id, &Address::generate(&t.env), &amount);
+}
+
+#[test]
+fn test_no_payouts_yields_zero_buckets() {
+    let t = setup();
+    assert_eq!(
+        t.client.get_payout_volume(&t.program_id, &DAY, &3),
+        vec![&t.env, 0, 0, 0]
+    );
+}
+
+#[test]
+fn test_daily_buckets_newest_first() {
+    let t = setup();
+    pay_at(&t, 10 * DAY + 100, 1_000);
+    pay_at(&t, 10 * DAY + 5_000, 500);
+    pay_at(&t, 12 * DAY + 1, 2_000);
+
+    t.env.ledger().set_timestamp(13 * DAY + 60);
+    assert_eq!(
+        t.client.get_payout_volume(&t.program_id, &DAY, &4),
+        vec![&t.env, 0, 2_000, 0, 1_500]
+    );
+}
+
+#[test]
+fn test_weekly_buckets_group_days() {
+    let t = setup();
+    pay_at(&t, 20 * DAY, 100);
+    pay_at(&t, 24 * DAY, 200);
+    pay_at(&t, 30 * DAY, 400);
+
+    t.env.ledger().set_timestamp(30 * DAY + 10);
+    assert_eq!(
+        t.client.get_payout_volume(&t.program_id, &(7 * DAY), &2),
+        vec![&t.env, 600, 100]
+    );
+}
+
+#[test]
+fn test_batch_payouts_are_counted() {
+    let t = setup();
+    t.env.ledger().set_timestamp(5 * DAY);
+    let recipients = vec![&t.env, Address::generate(&t.env), Address::generate(&t.env)];
+    t.client.batch_payout(
+        &t.program_id,
+        &recipients,
+        &vec![&t.env, 300, 700],
+        &false,
+        &None,
+        &1_000,
+    );
+
+    assert_eq!(
+        t.client.get_payout_volume(&t.program_id, &DAY, &1),
+        vec![&t.env, 1_000]
+    );
+}
+
+#[test]
+fn test_days_past_retention_are_dropped() {
+    let t = setup();
+    pay_at(&t, DAY, 1_000);
+    pay_at(&t, 200 * DAY, 50);
+
+    assert_eq!(
+        t.client.get_payout_volume(&t.program_id, &(30 * DAY), &7),
+        vec![&t.env, 50, 0, 0, 0, 0, 0, 0]
+    );
+}
+
+#[test]
+#[should_panic(expected = "Bucket size must be a whole number of days")]
+fn test_partial_day_bucket_rejected() {
+    let t = setup();
+    t.client.get_payout_volume(&t.program_id, &3_600, &24);
+}
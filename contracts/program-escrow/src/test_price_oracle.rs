@@ -0,0 +1,140 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_price_oracle.rs
+//
+// Tests for quote-denominated payouts through a per-program price oracle.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{
+    contract, contractimpl, symbol_short,
+    testutils::{Address as _, Ledger},
+    token, Address, Env, String, Symbol,
+};
+
+use crate::{PriceData, ProgramEscrowContract, ProgramEscrowContractClient};
+
+const PRICE: Symbol = symbol_short!("price");
+
+/// Oracle returning whatever price the test stored.
+#[contract]
+pub struct MockOracle;
+
+#[contractimpl]
+impl MockOracle {
+    pub fn set(env: Env, rate: i128, timestamp: u64) {
+        env.storage()
+            .instance()
+            .set(&PRICE, &PriceData { rate, timestamp });
+    }
+
+    pub fn get_rate(env: Env) -> PriceData {
+        env.storage().instance().get(&PRICE).unwrap()
+    }
+}
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    oracle: MockOracleClient<'a>,
+    program_id: String,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(10_000);
+
+    let admin = Address::generate(&env);
+    let payout_key = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&contract_id, &1_000_000_000);
+
+    client.set_admin(&admin, &0);
+    client.set_whitelist(&payout_key, &true);
+
+    let program_id = String::from_str(&env, "FiatPrizes");
+    client.initialize_program(&program_id, &payout_key, &sac.address());
+    client.lock_program_funds(&program_id, &1_000_000_000);
+
+    let oracle_id = env.register_contract(None, MockOracle);
+    let oracle = MockOracleClient::new(&env, &oracle_id);
+    client.set_price_oracle(&program_id, &oracle_id, &300);
+
+    TestSetup {
+        env,
+        client,
+        oracle,
+        program_id,
+    }
+}
+
+#[test]
+fn test_quoted_payout_converts_at_oracle_rate() {
+    let t = setup();
+    // 2.5 token units per quote unit
+    t.oracle.set(&25_000_000, &10_000);
+
+    let recipient = Address::generate(&t.env);
+    let claim_id = t
+        .client
+        .single_payout_quoted(&t.program_id, &recipient, &400)
+        .claim_id;
+
+    assert_eq!(t.client.get_claim(&t.program_id, &claim_id).amount, 1_000);
+}
+
+#[test]
+fn test_conversion_rounds_down() {
+    let t = setup();
+    // 1/3 token unit per quote unit
+    t.oracle.set(&3_333_333, &10_000);
+
+    let recipient = Address::generate(&t.env);
+    let claim_id = t
+        .client
+        .single_payout_quoted(&t.program_id, &recipient, &10)
+        .claim_id;
+
+    assert_eq!(t.client.get_claim(&t.program_id, &claim_id).amount, 3);
+}
+
+#[test]
+fn test_price_within_max_age_accepted() {
+    let t = setup();
+    t.oracle.set(&10_000_000, &9_700);
+
+    let result = t
+        .client
+        .single_payout_quoted(&t.program_id, &Address::generate(&t.env), &500);
+    assert_eq!(result.remaining_balance, 1_000_000_000 - 500);
+}
+
+#[test]
+#[should_panic(expected = "Stale oracle price")]
+fn test_stale_price_rejected() {
+    let t = setup();
+    t.oracle.set(&10_000_000, &9_699);
+
+    t.client
+        .single_payout_quoted(&t.program_id, &Address::generate(&t.env), &500);
+}
+
+#[test]
+#[should_panic(expected = "Price oracle not configured")]
+fn test_quoted_payout_without_oracle_panics() {
+    let t = setup();
+    let other = String::from_str(&t.env, "NoOracle");
+    t.client.initialize_program(
+        &other,
+        &Address::generate(&t.env),
+        &Address::generate(&t.env),
+    );
+
+    t.client
+        .single_payout_quoted(&other, &Address::generate(&t.env), &500);
+}
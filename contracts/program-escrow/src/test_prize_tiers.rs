@@ -0,0 +1,98 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_prize_tiers.rs
+//
+// Tests for fixed prize tiers awarded once per rank.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env, String};
+
+use crate::{ProgramEscrowContract, ProgramEscrowContractClient};
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    token: token::Client<'a>,
+    program_id: String,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let payout_key = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    let token = token::Client::new(&env, &sac.address());
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&contract_id, &100_000);
+
+    client.set_admin(&admin, &0);
+    client.set_whitelist(&payout_key, &true);
+
+    let program_id = String::from_str(&env, "Podium");
+    client.initialize_program(&program_id, &payout_key, &token.address);
+    client.lock_program_funds(&program_id, &100_000);
+    client.set_direct_payout_mode(&program_id, &true);
+    client.create_prize_tier_template(&program_id, &vec![&env, 5_000, 3_000, 1_000]);
+
+    TestSetup {
+        env,
+        client,
+        token,
+        program_id,
+    }
+}
+
+#[test]
+fn test_each_tier_awarded_once() {
+    let t = setup();
+    let first = Address::generate(&t.env);
+    let second = Address::generate(&t.env);
+    let third = Address::generate(&t.env);
+
+    t.client.award_prize(&t.program_id, &third, &2);
+    t.client.award_prize(&t.program_id, &first, &0);
+    t.client.award_prize(&t.program_id, &second, &1);
+
+    assert_eq!(t.token.balance(&first), 5_000);
+    assert_eq!(t.token.balance(&second), 3_000);
+    assert_eq!(t.token.balance(&third), 1_000);
+    assert_eq!(t.client.get_remaining_balance(&t.program_id), 91_000);
+    assert_eq!(
+        t.client.get_awarded_tiers(&t.program_id),
+        vec![&t.env, 2, 0, 1]
+    );
+}
+
+#[test]
+#[should_panic(expected = "Prize tier already awarded")]
+fn test_second_award_of_tier_rejected() {
+    let t = setup();
+    t.client
+        .award_prize(&t.program_id, &Address::generate(&t.env), &0);
+    t.client
+        .award_prize(&t.program_id, &Address::generate(&t.env), &0);
+}
+
+#[test]
+#[should_panic(expected = "Prize tier not found")]
+fn test_unknown_tier_rejected() {
+    let t = setup();
+    t.client
+        .award_prize(&t.program_id, &Address::generate(&t.env), &3);
+}
+
+#[test]
+#[should_panic(expected = "Prize tiers already awarded")]
+fn test_template_locked_after_first_award() {
+    let t = setup();
+    t.client
+        .award_prize(&t.program_id, &Address::generate(&t.env), &1);
+    t.client
+        .create_prize_tier_template(&t.program_id, &vec![&t.env, 9_000]);
+}
@@ -0,0 +1,207 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_program_admin.rs
+//
+// Tests separating the per-program admin (program config) from
+// the contract admin (global settings).
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, MockAuth, MockAuthInvoke},
+    token, Address, Env, IntoVal, String, Val, Vec,
+};
+
+use crate::{ProgramEscrowContract, ProgramEscrowContractClient};
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    contract_id: Address,
+    admin: Address,
+    payout_key: Address,
+    program_admin: Address,
+    program_id: String,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let payout_key = Address::generate(&env);
+    let program_admin = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&contract_id, &10_000);
+
+    client.set_admin(&admin, &0);
+
+    let program_id = String::from_str(&env, "Scoped");
+    client.initialize_program_with_admin(&program_id, &payout_key, &sac.address(), &program_admin);
+
+    TestSetup {
+        env,
+        client,
+        contract_id,
+        admin,
+        payout_key,
+        program_admin,
+        program_id,
+    }
+}
+
+/// Authorizes only `address` for the next call to `fn_name`.
+fn authorize(t: &TestSetup, address: &Address, fn_name: &str, args: Vec<Val>) {
+    t.env.mock_auths(&[MockAuth {
+        address,
+        invoke: &MockAuthInvoke {
+            contract: &t.contract_id,
+            fn_name,
+            args,
+            sub_invokes: &[],
+        },
+    }]);
+}
+
+fn pause_args(t: &TestSetup) -> Vec<Val> {
+    (t.program_id.clone(), true, false, true).into_val(&t.env)
+}
+
+#[test]
+fn test_program_admin_defaults_to_payout_key() {
+    let t = setup();
+    assert_eq!(t.client.get_program_admin(&t.program_id), t.program_admin);
+
+    let other = String::from_str(&t.env, "Plain");
+    let token = Address::generate(&t.env);
+    t.client.initialize_program(&other, &t.payout_key, &token);
+    assert_eq!(t.client.get_program_admin(&other), t.payout_key);
+}
+
+#[test]
+fn test_program_admin_can_pause_program() {
+    let t = setup();
+    authorize(&t, &t.program_admin, "set_operation_flags", pause_args(&t));
+
+    t.client
+        .set_operation_flags(&t.program_id, &true, &false, &true);
+
+    assert!(!t.client.get_operation_flags(&t.program_id).allow_payout);
+}
+
+#[test]
+fn test_contract_admin_cannot_pause_program() {
+    let t = setup();
+    authorize(&t, &t.admin, "set_operation_flags", pause_args(&t));
+
+    assert!(t
+        .client
+        .try_set_operation_flags(&t.program_id, &true, &false, &true)
+        .is_err());
+}
+
+#[test]
+fn test_program_admin_cannot_change_rate_limits() {
+    let t = setup();
+    let args = (3_600_u64, 5_u32, 0_u64, 1_u64).into_val(&t.env);
+    authorize(&t, &t.program_admin, "update_rate_limit_config", args);
+
+    assert!(t
+        .client
+        .try_update_rate_limit_config(&3_600, &5, &0, &1)
+        .is_err());
+}
+
+#[test]
+fn test_contract_admin_can_change_rate_limits() {
+    let t = setup();
+    let args = (3_600_u64, 5_u32, 0_u64, 1_u64).into_val(&t.env);
+    authorize(&t, &t.admin, "update_rate_limit_config", args);
+
+    t.client.update_rate_limit_config(&3_600, &5, &0, &1);
+
+    assert_eq!(t.client.get_rate_limit_config().max_operations, 5);
+}
+
+#[test]
+fn test_payout_key_cannot_set_program_claim_config() {
+    let t = setup();
+    let args = (t.program_id.clone(), 600_u64, 0_u64, 0_u32, false).into_val(&t.env);
+    authorize(&t, &t.payout_key, "set_program_claim_config", args);
+
+    assert!(t
+        .client
+        .try_set_program_claim_config(&t.program_id, &600, &0, &0, &false)
+        .is_err());
+}
+
+#[test]
+fn test_program_admin_sets_fee_override() {
+    let t = setup();
+    let args = (t.program_id.clone(), 250_i128).into_val(&t.env);
+    authorize(&t, &t.program_admin, "set_program_fee_bps", args);
+
+    t.client.set_program_fee_bps(&t.program_id, &250);
+}
+
+#[test]
+fn test_set_program_admin_hands_over_config_rights() {
+    let t = setup();
+    let successor = Address::generate(&t.env);
+    t.client.set_program_admin(&t.program_id, &successor);
+    assert_eq!(t.client.get_program_admin(&t.program_id), successor);
+
+    authorize(&t, &t.program_admin, "set_operation_flags", pause_args(&t));
+    assert!(t
+        .client
+        .try_set_operation_flags(&t.program_id, &true, &false, &true)
+        .is_err());
+}
+
+/// Registers a program with no assigned program admin.
+fn plain_program(t: &TestSetup) -> String {
+    let program_id = String::from_str(&t.env, "Plain");
+    t.client
+        .initialize_program(&program_id, &t.payout_key, &Address::generate(&t.env));
+    program_id
+}
+
+#[test]
+fn test_payout_key_cannot_set_fee_without_assigned_admin() {
+    let t = setup();
+    let program_id = plain_program(&t);
+    let args = (program_id.clone(), 0_i128).into_val(&t.env);
+    authorize(&t, &t.payout_key, "set_program_fee_bps", args);
+
+    assert!(t.client.try_set_program_fee_bps(&program_id, &0).is_err());
+}
+
+#[test]
+fn test_contract_admin_sets_fee_without_assigned_admin() {
+    let t = setup();
+    let program_id = plain_program(&t);
+    let args = (program_id.clone(), 250_i128).into_val(&t.env);
+    authorize(&t, &t.admin, "set_program_fee_bps", args);
+
+    t.client.set_program_fee_bps(&program_id, &250);
+
+    assert_eq!(t.client.get_program_fee_bps(&program_id), Some(250));
+}
+
+#[test]
+fn test_payout_key_cannot_assign_program_admin() {
+    let t = setup();
+    let program_id = plain_program(&t);
+    let puppet = Address::generate(&t.env);
+    let args = (program_id.clone(), puppet.clone()).into_val(&t.env);
+    authorize(&t, &t.payout_key, "set_program_admin", args);
+
+    assert!(t
+        .client
+        .try_set_program_admin(&program_id, &puppet)
+        .is_err());
+}
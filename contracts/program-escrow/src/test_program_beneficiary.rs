@@ -0,0 +1,135 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_program_beneficiary.rs
+//
+// Tests for sending a program's withdrawals to a beneficiary
+// while the organizer keeps control.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, MockAuth, MockAuthInvoke},
+    token, Address, Env, IntoVal, String,
+};
+
+use crate::{ProgramEscrowContract, ProgramEscrowContractClient};
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    token: token::Client<'a>,
+    contract_id: Address,
+    organizer: Address,
+    program_id: String,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let organizer = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    let token = token::Client::new(&env, &sac.address());
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&contract_id, &10_000);
+
+    client.set_admin(&admin, &0);
+    client.set_whitelist(&organizer, &true);
+
+    let program_id = String::from_str(&env, "Subaccount");
+    client.initialize_program(&program_id, &organizer, &token.address);
+    client.lock_program_funds(&program_id, &10_000);
+
+    TestSetup {
+        env,
+        client,
+        token,
+        contract_id,
+        organizer,
+        program_id,
+    }
+}
+
+/// Authorizes only `address` for the next `withdraw_unlocked_program_funds`.
+fn authorize_withdraw(t: &TestSetup, address: &Address, amount: i128) {
+    t.env.mock_auths(&[MockAuth {
+        address,
+        invoke: &MockAuthInvoke {
+            contract: &t.contract_id,
+            fn_name: "withdraw_unlocked_program_funds",
+            args: (t.program_id.clone(), amount).into_val(&t.env),
+            sub_invokes: &[],
+        },
+    }]);
+}
+
+#[test]
+fn test_beneficiary_defaults_to_organizer() {
+    let t = setup();
+    assert_eq!(t.client.get_program_beneficiary(&t.program_id), t.organizer);
+
+    t.client
+        .withdraw_unlocked_program_funds(&t.program_id, &1_000);
+    assert_eq!(t.token.balance(&t.organizer), 1_000);
+}
+
+#[test]
+fn test_withdraw_goes_to_beneficiary_with_organizer_auth() {
+    let t = setup();
+    let subaccount = Address::generate(&t.env);
+    t.client
+        .set_program_beneficiary(&t.program_id, &Some(subaccount.clone()));
+    assert_eq!(t.client.get_program_beneficiary(&t.program_id), subaccount);
+
+    authorize_withdraw(&t, &t.organizer, 4_000);
+    let data = t
+        .client
+        .withdraw_unlocked_program_funds(&t.program_id, &4_000);
+
+    assert_eq!(data.remaining_balance, 6_000);
+    assert_eq!(t.token.balance(&subaccount), 4_000);
+    assert_eq!(t.token.balance(&t.organizer), 0);
+}
+
+#[test]
+fn test_beneficiary_cannot_withdraw_alone() {
+    let t = setup();
+    let subaccount = Address::generate(&t.env);
+    t.client
+        .set_program_beneficiary(&t.program_id, &Some(subaccount.clone()));
+
+    authorize_withdraw(&t, &subaccount, 4_000);
+    assert!(t
+        .client
+        .try_withdraw_unlocked_program_funds(&t.program_id, &4_000)
+        .is_err());
+    assert_eq!(t.token.balance(&subaccount), 0);
+}
+
+#[test]
+fn test_clearing_beneficiary_restores_organizer() {
+    let t = setup();
+    t.client
+        .set_program_beneficiary(&t.program_id, &Some(Address::generate(&t.env)));
+    t.client.set_program_beneficiary(&t.program_id, &None);
+
+    assert_eq!(t.client.get_program_beneficiary(&t.program_id), t.organizer);
+}
+
+#[test]
+fn test_dust_sweeps_to_beneficiary_by_default() {
+    let t = setup();
+    let subaccount = Address::generate(&t.env);
+    t.client
+        .set_program_beneficiary(&t.program_id, &Some(subaccount.clone()));
+    t.client.set_dust_threshold(&t.token.address, &10);
+    t.client
+        .withdraw_unlocked_program_funds(&t.program_id, &9_995);
+
+    t.client.close_program(&t.program_id, &None);
+    assert_eq!(t.token.balance(&subaccount), 10_000);
+}
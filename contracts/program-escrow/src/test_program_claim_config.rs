@@ -0,0 +1,198 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_program_claim_config.rs
+//
+// Tests for `set_program_claim_config`, its `apply_to_existing` flag and
+// the previous config it returns.
+//
+// - apply_to_existing = true  → pending claims get `created_at + window`
+//                               plus any extension already granted
+// - apply_to_existing = false → pending claims keep their old deadline,
+//                               only new payouts use the new window
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env, String, Vec,
+};
+
+use crate::{ClaimConfig, ProgramEscrowContract, ProgramEscrowContractClient};
+
+const START: u64 = 1_000;
+const DAY: u64 = 86_400;
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    program_id: String,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(START);
+
+    let admin = Address::generate(&env);
+    let payout_key = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&contract_id, &100_000);
+
+    let program_id = String::from_str(&env, "ClaimConfig");
+    client.initialize_program(&program_id, &payout_key, &sac.address());
+    client.lock_program_funds(&program_id, &100_000);
+    client.set_admin(&admin, &0);
+
+    TestSetup {
+        env,
+        client,
+        program_id,
+    }
+}
+
+/// Creates `count` payouts spaced past the rate-limit cooldown.
+fn create_batch(t: &TestSetup, count: u32) -> Vec<u64> {
+    let mut ids = Vec::new(&t.env);
+    for _ in 0..count {
+        t.env.ledger().with_mut(|li| li.timestamp += 60);
+        let id = t
+            .client
+            .single_payout(&t.program_id, &Address::generate(&t.env), &1_000)
+            .claim_id;
+        ids.push_back(id);
+    }
+    ids
+}
+
+#[test]
+fn test_apply_to_existing_recomputes_pending_deadlines() {
+    let t = setup();
+    let ids = create_batch(&t, 5);
+
+    let updated = t
+        .client
+        .set_program_claim_config(&t.program_id, &(3 * DAY), &0, &0, &true)
+        .1;
+    assert_eq!(updated, 5);
+
+    for id in ids.iter() {
+        let claim = t.client.get_claim(&t.program_id, &id);
+        assert_eq!(claim.claim_deadline, claim.created_at + 3 * DAY);
+    }
+}
+
+#[test]
+fn test_without_apply_to_existing_only_new_claims_change() {
+    let t = setup();
+    let ids = create_batch(&t, 5);
+
+    let updated = t
+        .client
+        .set_program_claim_config(&t.program_id, &(3 * DAY), &0, &0, &false)
+        .1;
+    assert_eq!(updated, 0);
+
+    for id in ids.iter() {
+        let claim = t.client.get_claim(&t.program_id, &id);
+        assert_eq!(claim.claim_deadline, claim.created_at + DAY);
+    }
+
+    let new_id = create_batch(&t, 1).get(0).unwrap();
+    let claim = t.client.get_claim(&t.program_id, &new_id);
+    assert_eq!(claim.claim_deadline, claim.created_at + 3 * DAY);
+    assert_eq!(
+        t.client
+            .get_program_claim_config(&t.program_id)
+            .unwrap()
+            .claim_window,
+        3 * DAY
+    );
+}
+
+#[test]
+fn test_resolved_claims_are_not_touched() {
+    let t = setup();
+    let ids = create_batch(&t, 3);
+    let executed = ids.get(0).unwrap();
+    let recipient = t.client.get_claim(&t.program_id, &executed).recipient;
+    t.client.execute_claim(&t.program_id, &executed, &recipient);
+
+    let updated = t
+        .client
+        .set_program_claim_config(&t.program_id, &(2 * DAY), &0, &0, &true)
+        .1;
+    assert_eq!(updated, 2);
+
+    let claim = t.client.get_claim(&t.program_id, &executed);
+    assert_eq!(claim.claim_deadline, claim.created_at + DAY);
+}
+
+#[test]
+fn test_shortened_window_expires_existing_claims() {
+    let t = setup();
+    let id = create_batch(&t, 1).get(0).unwrap();
+    let recipient = t.client.get_claim(&t.program_id, &id).recipient;
+
+    t.client
+        .set_program_claim_config(&t.program_id, &60, &0, &0, &true);
+    t.env.ledger().with_mut(|li| li.timestamp += 120);
+
+    assert!(t
+        .client
+        .try_execute_claim(&t.program_id, &id, &recipient)
+        .is_err());
+}
+
+#[test]
+fn test_set_returns_previous_config() {
+    let t = setup();
+    assert_eq!(t.client.get_program_claim_config(&t.program_id), None);
+
+    let (previous, _) =
+        t.client
+            .set_program_claim_config(&t.program_id, &(2 * DAY), &3_600, &500, &false);
+    assert_eq!(previous, None);
+
+    let (previous, _) =
+        t.client
+            .set_program_claim_config(&t.program_id, &(3 * DAY), &0, &0, &false);
+    assert_eq!(
+        previous,
+        Some(ClaimConfig {
+            claim_window: 2 * DAY,
+            grace_secs: 3_600,
+            late_penalty_bps: 500,
+        })
+    );
+    assert_eq!(
+        t.client.get_program_claim_config(&t.program_id),
+        Some(ClaimConfig {
+            claim_window: 3 * DAY,
+            grace_secs: 0,
+            late_penalty_bps: 0,
+        })
+    );
+}
+
+#[test]
+fn test_apply_to_existing_keeps_granted_extensions() {
+    let t = setup();
+    let ids = create_batch(&t, 2);
+    let extended = ids.get(0).unwrap();
+    let other = ids.get(1).unwrap();
+    t.client
+        .extend_claim_window(&t.program_id, &extended, &3_600);
+
+    t.client
+        .set_program_claim_config(&t.program_id, &(2 * DAY), &0, &0, &true);
+
+    let claim = t.client.get_claim(&t.program_id, &extended);
+    assert_eq!(claim.extended_secs, 3_600);
+    assert_eq!(claim.claim_deadline, claim.created_at + 2 * DAY + 3_600);
+    let claim = t.client.get_claim(&t.program_id, &other);
+    assert_eq!(claim.claim_deadline, claim.created_at + 2 * DAY);
+}
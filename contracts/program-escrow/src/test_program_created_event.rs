@@ -0,0 +1,85 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_program_created_event.rs
+//
+// Tests for the `program_created` catalog event emitted by
+// `initialize_program`.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Events},
+    Address, Env, IntoVal, String, Symbol, Val, Vec,
+};
+
+use crate::{FeeConfig, ProgramEscrowContract, ProgramEscrowContractClient};
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    admin: Address,
+    token: Address,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+
+    TestSetup {
+        env,
+        client,
+        admin,
+        token: sac.address(),
+    }
+}
+
+fn created_events(t: &TestSetup) -> Vec<(String, Option<Address>, Address, Address, FeeConfig)> {
+    let expected: Vec<Val> =
+        (Symbol::new(&t.env, "program_created"), t.token.clone()).into_val(&t.env);
+    let mut found = Vec::new(&t.env);
+    for (_, topics, data) in t.env.events().all().iter() {
+        if topics == expected {
+            found.push_back(data.into_val(&t.env));
+        }
+    }
+    found
+}
+
+#[test]
+fn test_initialize_emits_program_created() {
+    let t = setup();
+    t.client.set_admin(&t.admin, &0);
+    let organizer = Address::generate(&t.env);
+    let program_id = String::from_str(&t.env, "Catalogued");
+
+    t.client
+        .initialize_program(&program_id, &organizer, &t.token);
+
+    let events = created_events(&t);
+    assert_eq!(events.len(), 1);
+    let (id, admin, emitted_organizer, token, fee_config) = events.get(0).unwrap();
+    assert_eq!(id, program_id);
+    assert_eq!(admin, Some(t.admin.clone()));
+    assert_eq!(emitted_organizer, organizer);
+    assert_eq!(token, t.token);
+    assert_eq!(fee_config, t.client.get_fee_config());
+    assert!(!fee_config.fee_enabled);
+}
+
+#[test]
+fn test_program_created_without_admin() {
+    let t = setup();
+    let organizer = Address::generate(&t.env);
+
+    t.client
+        .initialize_program(&String::from_str(&t.env, "NoAdmin"), &organizer, &t.token);
+
+    let events = created_events(&t);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events.get(0).unwrap().1, None);
+}
@@ -0,0 +1,127 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_program_fee_override.rs
+//
+// Tests for per-program payout fee overrides.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env, String};
+
+use crate::{ProgramEscrowContract, ProgramEscrowContractClient};
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    token: token::Client<'a>,
+    fee_recipient: Address,
+    custom: String,
+    standard: String,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let payout_key = Address::generate(&env);
+    let fee_recipient = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    let token = token::Client::new(&env, &sac.address());
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&contract_id, &200_000);
+
+    client.set_admin(&admin, &0);
+    client.set_whitelist(&payout_key, &true);
+    client.set_whitelist(&contract_id, &true);
+
+    let custom = String::from_str(&env, "Custom");
+    let standard = String::from_str(&env, "Standard");
+    for program_id in [&custom, &standard] {
+        client.initialize_program(program_id, &payout_key, &token.address);
+        client.lock_program_funds(program_id, &100_000);
+    }
+    // `initialize_program` resets the fee config, so enable fees afterwards.
+    client.update_fee_config(
+        &None,
+        &Some(100),
+        &Some(fee_recipient.clone()),
+        &Some(true),
+        &1,
+    );
+
+    TestSetup {
+        env,
+        client,
+        token,
+        fee_recipient,
+        custom,
+        standard,
+    }
+}
+
+#[test]
+fn test_override_differs_from_global_on_identical_payouts() {
+    let t = setup();
+    t.client.set_program_fee_bps(&t.custom, &500);
+    assert_eq!(t.client.get_program_fee_bps(&t.custom), Some(500));
+    assert_eq!(t.client.get_program_fee_bps(&t.standard), None);
+
+    let recipient = Address::generate(&t.env);
+    let custom_claim = t
+        .client
+        .single_payout(&t.custom, &recipient, &10_000)
+        .claim_id;
+    let standard_claim = t
+        .client
+        .single_payout(&t.standard, &recipient, &10_000)
+        .claim_id;
+
+    assert_eq!(t.client.get_claim(&t.custom, &custom_claim).amount, 9_500);
+    assert_eq!(
+        t.client.get_claim(&t.standard, &standard_claim).amount,
+        9_900
+    );
+    assert_eq!(t.token.balance(&t.fee_recipient), 600);
+}
+
+#[test]
+fn test_override_applies_to_batch_payout() {
+    let t = setup();
+    t.client.set_program_fee_bps(&t.custom, &0);
+
+    let recipient = Address::generate(&t.env);
+    let recipients = vec![&t.env, recipient.clone()];
+    let amounts = vec![&t.env, 10_000i128];
+    t.client
+        .batch_payout(&t.custom, &recipients, &amounts, &false, &None, &10_000);
+    assert_eq!(t.token.balance(&recipient), 10_000);
+
+    t.client
+        .batch_payout(&t.standard, &recipients, &amounts, &false, &None, &10_000);
+    assert_eq!(t.token.balance(&recipient), 19_900);
+    assert_eq!(t.token.balance(&t.fee_recipient), 100);
+}
+
+#[test]
+fn test_clearing_override_restores_global_rate() {
+    let t = setup();
+    t.client.set_program_fee_bps(&t.custom, &500);
+    t.client.clear_program_fee_bps(&t.custom);
+
+    let claim_id = t
+        .client
+        .single_payout(&t.custom, &Address::generate(&t.env), &10_000)
+        .claim_id;
+    assert_eq!(t.client.get_claim(&t.custom, &claim_id).amount, 9_900);
+}
+
+#[test]
+#[should_panic(expected = "Invalid payout fee rate")]
+fn test_override_above_max_panics() {
+    let t = setup();
+    t.client.set_program_fee_bps(&t.custom, &1_001);
+}
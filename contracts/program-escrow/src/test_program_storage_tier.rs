@@ -0,0 +1,197 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_program_storage_tier.rs
+//
+// Tests for keeping program data in persistent or temporary storage.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{
+        storage::{Persistent as _, Temporary as _},
+        Address as _, Ledger,
+    },
+    token, vec, Address, Env, String,
+};
+
+use crate::program_storage::{PERSISTENT_PROGRAM_TTL, TEMPORARY_PROGRAM_TTL};
+use crate::{
+    DataKey, ProgramData, ProgramEscrowContract, ProgramEscrowContractClient, StorageTier,
+};
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    token: Address,
+    payout_key: Address,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let payout_key = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&contract_id, &10_000);
+
+    // keep the contract instance itself alive while ledgers advance
+    env.as_contract(&contract_id, || {
+        env.storage()
+            .instance()
+            .extend_ttl(PERSISTENT_PROGRAM_TTL, PERSISTENT_PROGRAM_TTL)
+    });
+
+    TestSetup {
+        env,
+        client,
+        token: sac.address(),
+        payout_key,
+    }
+}
+
+fn program_key(env: &Env, name: &str) -> DataKey {
+    DataKey::Program(String::from_str(env, name))
+}
+
+fn advance_ledgers(env: &Env, ledgers: u32) {
+    env.ledger().with_mut(|li| li.sequence_number += ledgers);
+}
+
+#[test]
+fn test_default_program_uses_persistent_storage() {
+    let t = setup();
+    let program_id = String::from_str(&t.env, "Default");
+    t.client
+        .initialize_program(&program_id, &t.payout_key, &t.token);
+
+    let ttl = t.env.as_contract(&t.client.address, || {
+        t.env
+            .storage()
+            .persistent()
+            .get_ttl(&program_key(&t.env, "Default"))
+    });
+    assert_eq!(ttl, PERSISTENT_PROGRAM_TTL);
+}
+
+#[test]
+fn test_temporary_program_works_within_lifetime() {
+    let t = setup();
+    let program_id = String::from_str(&t.env, "Ephemeral");
+    t.client.initialize_program_with_storage(
+        &program_id,
+        &t.payout_key,
+        &t.token,
+        &StorageTier::Temporary,
+    );
+    t.client.lock_program_funds(&program_id, &10_000);
+    t.client
+        .single_payout(&program_id, &Address::generate(&t.env), &4_000);
+
+    advance_ledgers(&t.env, TEMPORARY_PROGRAM_TTL - 1);
+
+    assert!(t.client.program_exists(&program_id));
+    let info = t.client.get_program_info(&program_id);
+    assert_eq!(info.total_funds, 10_000);
+    assert_eq!(info.remaining_balance, 6_000);
+
+    let ttl = t.env.as_contract(&t.client.address, || {
+        t.env
+            .storage()
+            .temporary()
+            .get_ttl(&program_key(&t.env, "Ephemeral"))
+    });
+    assert_eq!(ttl, 1);
+}
+
+#[test]
+fn test_temporary_program_expires_but_persistent_survives() {
+    let t = setup();
+    let temporary = String::from_str(&t.env, "Ephemeral");
+    let persistent = String::from_str(&t.env, "LongLived");
+    t.client.initialize_program_with_storage(
+        &temporary,
+        &t.payout_key,
+        &t.token,
+        &StorageTier::Temporary,
+    );
+    t.client.initialize_program_with_storage(
+        &persistent,
+        &t.payout_key,
+        &t.token,
+        &StorageTier::Persistent,
+    );
+    t.client.lock_program_funds(&persistent, &5_000);
+
+    advance_ledgers(&t.env, TEMPORARY_PROGRAM_TTL + 1);
+
+    assert!(!t.client.program_exists(&temporary));
+    assert!(t.client.program_exists(&persistent));
+    assert_eq!(t.client.get_remaining_balance(&persistent), 5_000);
+}
+
+#[test]
+#[should_panic(expected = "Program already exists")]
+fn test_expired_temporary_id_cannot_be_registered_again() {
+    let t = setup();
+    let program_id = String::from_str(&t.env, "Ephemeral");
+    t.client.initialize_program_with_storage(
+        &program_id,
+        &t.payout_key,
+        &t.token,
+        &StorageTier::Temporary,
+    );
+    t.client.lock_program_funds(&program_id, &5_000);
+
+    // simulate the entry lapsing without archiving the contract's other data
+    t.env.as_contract(&t.client.address, || {
+        t.env
+            .storage()
+            .temporary()
+            .remove(&program_key(&t.env, "Ephemeral"))
+    });
+    assert!(!t.client.program_exists(&program_id));
+
+    t.client
+        .initialize_program(&program_id, &Address::generate(&t.env), &t.token);
+}
+
+#[test]
+fn test_legacy_instance_program_is_loaded_and_migrated() {
+    let t = setup();
+    let program_id = String::from_str(&t.env, "Legacy");
+    let key = program_key(&t.env, "Legacy");
+    // a program written before storage tiers existed
+    t.env.as_contract(&t.client.address, || {
+        t.env.storage().instance().set(
+            &key,
+            &ProgramData {
+                program_id: program_id.clone(),
+                total_funds: 3_000,
+                remaining_balance: 3_000,
+                authorized_payout_key: t.payout_key.clone(),
+                payout_history: vec![&t.env],
+                token_address: t.token.clone(),
+            },
+        );
+    });
+
+    assert!(t.client.program_exists(&program_id));
+    assert_eq!(t.client.get_remaining_balance(&program_id), 3_000);
+
+    t.client.lock_program_funds(&program_id, &1_000);
+
+    let (in_instance, in_persistent) = t.env.as_contract(&t.client.address, || {
+        (
+            t.env.storage().instance().has(&key),
+            t.env.storage().persistent().has(&key),
+        )
+    });
+    assert!(!in_instance);
+    assert!(in_persistent);
+    assert_eq!(t.client.get_remaining_balance(&program_id), 4_000);
+}
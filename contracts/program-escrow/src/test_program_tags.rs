@@ -0,0 +1,104 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_program_tags.rs
+//
+// Tests for tagging programs and listing them by tag.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{symbol_short, testutils::Address as _, vec, Address, Env, String};
+
+use crate::{ProgramEscrowContract, ProgramEscrowContractClient};
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    token: Address,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+    let token = env.register_stellar_asset_contract_v2(admin).address();
+
+    TestSetup { env, client, token }
+}
+
+fn create_program(t: &TestSetup, id: &str) -> String {
+    let program_id = String::from_str(&t.env, id);
+    t.client
+        .initialize_program(&program_id, &Address::generate(&t.env), &t.token);
+    program_id
+}
+
+#[test]
+fn test_programs_listed_by_tag() {
+    let t = setup();
+    let defi = create_program(&t, "Defi");
+    let game = create_program(&t, "Game");
+    let both = create_program(&t, "Both");
+
+    t.client
+        .set_program_tags(&defi, &vec![&t.env, symbol_short!("defi")]);
+    t.client
+        .set_program_tags(&game, &vec![&t.env, symbol_short!("gaming")]);
+    t.client.set_program_tags(
+        &both,
+        &vec![&t.env, symbol_short!("defi"), symbol_short!("gaming")],
+    );
+
+    assert_eq!(
+        t.client
+            .get_programs_by_tag(&symbol_short!("defi"), &0, &10),
+        vec![&t.env, defi.clone(), both.clone()]
+    );
+    assert_eq!(
+        t.client
+            .get_programs_by_tag(&symbol_short!("gaming"), &0, &10),
+        vec![&t.env, game, both.clone()]
+    );
+    assert_eq!(
+        t.client
+            .get_programs_by_tag(&symbol_short!("defi"), &1, &10),
+        vec![&t.env, both]
+    );
+    assert_eq!(
+        t.client.get_programs_by_tag(&symbol_short!("nft"), &0, &10),
+        vec![&t.env]
+    );
+}
+
+#[test]
+fn test_retagging_updates_index() {
+    let t = setup();
+    let program_id = create_program(&t, "Retag");
+
+    t.client.set_program_tags(
+        &program_id,
+        &vec![&t.env, symbol_short!("defi"), symbol_short!("defi")],
+    );
+    assert_eq!(
+        t.client.get_program_tags(&program_id),
+        vec![&t.env, symbol_short!("defi")]
+    );
+
+    t.client
+        .set_program_tags(&program_id, &vec![&t.env, symbol_short!("gaming")]);
+    assert_eq!(
+        t.client
+            .get_programs_by_tag(&symbol_short!("defi"), &0, &10),
+        vec![&t.env]
+    );
+    assert_eq!(
+        t.client
+            .get_programs_by_tag(&symbol_short!("gaming"), &0, &10),
+        vec![&t.env, program_id.clone()]
+    );
+
+    t.client.set_program_tags(&program_id, &vec![&t.env]);
+    assert_eq!(t.client.get_program_tags(&program_id), vec![&t.env]);
+}
@@ -0,0 +1,83 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_program_tvl_cap.rs
+//
+// Tests for the per-program TVL cap enforced in `lock_program_funds`.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, token, Address, Env, String};
+
+use crate::{ProgramEscrowContract, ProgramEscrowContractClient};
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    token: Address,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&contract_id, &10_000);
+
+    client.set_admin(&admin, &0);
+
+    TestSetup {
+        env,
+        client,
+        token: sac.address(),
+    }
+}
+
+fn register(t: &TestSetup, name: &str) -> String {
+    let program_id = String::from_str(&t.env, name);
+    t.client
+        .initialize_program(&program_id, &Address::generate(&t.env), &t.token);
+    program_id
+}
+
+#[test]
+fn test_lock_up_to_cap_succeeds() {
+    let t = setup();
+    let program_id = register(&t, "Capped");
+    t.client.set_max_program_tvl(&t.token, &1_000);
+
+    t.client.lock_program_funds(&program_id, &600);
+    let data = t.client.lock_program_funds(&program_id, &400);
+
+    assert_eq!(data.remaining_balance, 1_000);
+    assert_eq!(t.client.get_max_program_tvl(&t.token), Some(1_000));
+}
+
+#[test]
+#[should_panic(expected = "Program TVL cap exceeded")]
+fn test_lock_over_cap_panics() {
+    let t = setup();
+    let program_id = register(&t, "Capped");
+    t.client.set_max_program_tvl(&t.token, &1_000);
+
+    t.client.lock_program_funds(&program_id, &1_000);
+    t.client.lock_program_funds(&program_id, &1);
+}
+
+#[test]
+fn test_cap_is_per_program_not_global() {
+    let t = setup();
+    let first = register(&t, "First");
+    let second = register(&t, "Second");
+    t.client.set_max_program_tvl(&t.token, &1_000);
+
+    t.client.lock_program_funds(&first, &1_000);
+    t.client.lock_program_funds(&second, &1_000);
+
+    assert_eq!(t.client.get_remaining_balance(&first), 1_000);
+    assert_eq!(t.client.get_remaining_balance(&second), 1_000);
+    assert!(t.client.try_lock_program_funds(&second, &1).is_err());
+}
@@ -0,0 +1,186 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_rate_limit_backoff.rs
+//
+// Tests for backoff rate limiting, where early operations
+// are rejected, operations crowding the cooldown escalate it
+// and wider spacing relaxes it again.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env, String,
+};
+
+use crate::anti_abuse::RateLimitMode;
+use crate::{ProgramEscrowContract, ProgramEscrowContractClient};
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    backend: Address,
+    token: Address,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.set_admin(&admin, &0);
+    client.update_rate_limit_config(&86_400, &100, &60, &1);
+    client.set_rate_limit_mode(&RateLimitMode::Backoff);
+    client.set_rate_limit_backoff(&2, &1_000, &0);
+
+    TestSetup {
+        backend: Address::generate(&env),
+        token: Address::generate(&env),
+        env,
+        client,
+    }
+}
+
+/// Performs one rate-limited operation for the backend key at `timestamp`.
+fn operate_at(t: &TestSetup, timestamp: u64) {
+    t.env.ledger().set_timestamp(timestamp);
+    let mut name = [b'P'; 5];
+    let mut rest = timestamp;
+    for digit in name[1..].iter_mut().rev() {
+        *digit = b'0' + (rest % 10) as u8;
+        rest /= 10;
+    }
+    t.client
+        .initialize_program(&String::from_bytes(&t.env, &name), &t.backend, &t.token);
+}
+
+fn cooldown(t: &TestSetup) -> u64 {
+    t.client
+        .get_rate_limit_status(&t.backend)
+        .effective_cooldown
+}
+
+#[test]
+fn test_status_starts_at_base_cooldown() {
+    let t = setup();
+    let status = t.client.get_rate_limit_status(&t.backend);
+    assert_eq!(status.mode, RateLimitMode::Backoff);
+    assert_eq!(status.effective_cooldown, 60);
+    assert_eq!(status.backoff_level, 0);
+    assert_eq!(status.available_ops, 100);
+    assert_eq!(status.cooldown_ends_at, 0);
+}
+
+#[test]
+fn test_default_backoff_has_no_grace() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+    assert_eq!(client.get_rate_limit_backoff().grace_violations, 0);
+}
+
+#[test]
+fn test_cooldown_grows_after_crowded_operations() {
+    let t = setup();
+    operate_at(&t, 1_000);
+    assert_eq!(cooldown(&t), 60);
+
+    operate_at(&t, 1_060);
+    assert_eq!(cooldown(&t), 120);
+    operate_at(&t, 1_180);
+    assert_eq!(cooldown(&t), 240);
+    operate_at(&t, 1_420);
+    assert_eq!(cooldown(&t), 480);
+
+    let status = t.client.get_rate_limit_status(&t.backend);
+    assert_eq!(status.backoff_level, 3);
+    assert_eq!(status.cooldown_ends_at, 1_420 + 480);
+}
+
+#[test]
+#[should_panic(expected = "Operation in cooldown period")]
+fn test_early_operation_is_rejected() {
+    let t = setup();
+    operate_at(&t, 1_000);
+    operate_at(&t, 1_010);
+}
+
+#[test]
+fn test_rejected_operation_does_not_escalate() {
+    let t = setup();
+    operate_at(&t, 1_000);
+    t.env.ledger().set_timestamp(1_010);
+    assert!(t
+        .client
+        .try_initialize_program(&String::from_str(&t.env, "Early"), &t.backend, &t.token)
+        .is_err());
+    assert_eq!(t.client.get_rate_limit_status(&t.backend).backoff_level, 0);
+    assert_eq!(cooldown(&t), 60);
+}
+
+#[test]
+fn test_grace_delays_escalation() {
+    let t = setup();
+    t.client.set_rate_limit_backoff(&2, &1_000, &1);
+    operate_at(&t, 1_000);
+    operate_at(&t, 1_060);
+    assert_eq!(t.client.get_rate_limit_status(&t.backend).backoff_level, 1);
+    assert_eq!(cooldown(&t), 60);
+    operate_at(&t, 1_120);
+    assert_eq!(cooldown(&t), 120);
+}
+
+#[test]
+fn test_cooldown_is_capped() {
+    let t = setup();
+    t.client.set_rate_limit_backoff(&4, &500, &0);
+    operate_at(&t, 1_000);
+    operate_at(&t, 1_060);
+    assert_eq!(cooldown(&t), 240);
+    operate_at(&t, 1_300);
+    assert_eq!(cooldown(&t), 500);
+    operate_at(&t, 1_800);
+    assert_eq!(cooldown(&t), 500);
+}
+
+#[test]
+fn test_cooldown_shrinks_after_spaced_operations() {
+    let t = setup();
+    operate_at(&t, 1_000);
+    operate_at(&t, 1_060);
+    operate_at(&t, 1_180);
+    operate_at(&t, 1_420);
+    assert_eq!(cooldown(&t), 480);
+
+    operate_at(&t, 1_420 + 960);
+    assert_eq!(cooldown(&t), 240);
+    operate_at(&t, 2_380 + 480);
+    assert_eq!(cooldown(&t), 120);
+    operate_at(&t, 2_860 + 240);
+    assert_eq!(cooldown(&t), 60);
+    assert_eq!(t.client.get_rate_limit_status(&t.backend).backoff_level, 0);
+}
+
+#[test]
+fn test_reset_clears_backoff() {
+    let t = setup();
+    operate_at(&t, 1_000);
+    operate_at(&t, 1_060);
+    assert_eq!(cooldown(&t), 120);
+
+    t.client.reset_rate_limit_window(&t.backend);
+    assert_eq!(cooldown(&t), 60);
+}
+
+#[test]
+#[should_panic(expected = "Operation in cooldown period")]
+fn test_fixed_window_mode_rejects_first_violation() {
+    let t = setup();
+    t.client.set_rate_limit_mode(&RateLimitMode::FixedWindow);
+    operate_at(&t, 1_000);
+    operate_at(&t, 1_010);
+}
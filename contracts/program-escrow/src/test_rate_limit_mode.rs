@@ -0,0 +1,118 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_rate_limit_mode.rs
+//
+// Tests for leaky-bucket rate limiting.
+//
+// A fixed window lets a caller spend its whole quota at the end of one
+// window and again at the start of the next. The leaky bucket refills
+// continuously, so the same burst pattern is rejected.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env, String,
+};
+
+use crate::anti_abuse::RateLimitMode;
+use crate::{ProgramEscrowContract, ProgramEscrowContractClient};
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    backend: Address,
+    token: Address,
+}
+
+fn setup<'a>(mode: RateLimitMode) -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.set_admin(&admin, &0);
+    client.update_rate_limit_config(&3600, &10, &0, &1);
+    client.set_rate_limit_mode(&mode);
+
+    TestSetup {
+        backend: Address::generate(&env),
+        token: Address::generate(&env),
+        env,
+        client,
+    }
+}
+
+/// Registers `count` programs with the shared backend key, each one a
+/// rate-limited operation.
+fn register_n(t: &TestSetup, prefix: u32, count: u32) {
+    for i in 0..count {
+        let name = [b'P', b'0' + prefix as u8, b'-', b'0' + i as u8];
+        t.client.initialize_program(
+            &String::from_bytes(&t.env, &name),
+            &t.backend,
+            &t.token,
+        );
+    }
+}
+
+fn burst_across_window_edge(t: &TestSetup) {
+    // One op opens the window, nine more just before it closes,
+    // then a full quota right after it resets.
+    register_n(t, 0, 1);
+    t.env.ledger().set_timestamp(1_000 + 3_599);
+    register_n(t, 1, 9);
+    t.env.ledger().set_timestamp(1_000 + 3_600);
+    register_n(t, 2, 10);
+}
+
+#[test]
+fn test_fixed_window_allows_double_burst() {
+    let t = setup(RateLimitMode::FixedWindow);
+    burst_across_window_edge(&t);
+
+    assert_eq!(t.client.get_program_count(), 20);
+    assert_eq!(t.client.get_available_ops(&t.backend), 0);
+}
+
+#[test]
+#[should_panic(expected = "Rate limit exceeded")]
+fn test_leaky_bucket_rejects_double_burst() {
+    let t = setup(RateLimitMode::LeakyBucket);
+    burst_across_window_edge(&t);
+}
+
+#[test]
+fn test_leaky_bucket_refills_continuously() {
+    let t = setup(RateLimitMode::LeakyBucket);
+    assert_eq!(t.client.get_rate_limit_mode(), RateLimitMode::LeakyBucket);
+    assert_eq!(t.client.get_available_ops(&t.backend), 10);
+
+    register_n(&t, 0, 10);
+    assert_eq!(t.client.get_available_ops(&t.backend), 0);
+
+    // 10 ops per 3600s refills one op every 360s.
+    t.env.ledger().set_timestamp(1_000 + 359);
+    assert_eq!(t.client.get_available_ops(&t.backend), 0);
+    t.env.ledger().set_timestamp(1_000 + 720);
+    assert_eq!(t.client.get_available_ops(&t.backend), 2);
+
+    register_n(&t, 1, 2);
+    assert_eq!(t.client.get_available_ops(&t.backend), 0);
+    assert!(t
+        .client
+        .try_initialize_program(&String::from_str(&t.env, "Extra"), &t.backend, &t.token)
+        .is_err());
+}
+
+#[test]
+fn test_leaky_bucket_caps_at_max_operations() {
+    let t = setup(RateLimitMode::LeakyBucket);
+    register_n(&t, 0, 3);
+
+    t.env.ledger().set_timestamp(1_000 + 100_000);
+    assert_eq!(t.client.get_available_ops(&t.backend), 10);
+}
@@ -0,0 +1,121 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_rate_limit_predictor.rs
+//
+// Tests for `operations_until_blocked` / `can_perform_operations`.
+//
+// Each test drives real rate-limited operations and checks the
+// predictor against the point where they actually start failing.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env, String,
+};
+
+use crate::anti_abuse::RateLimitMode;
+use crate::{ProgramEscrowContract, ProgramEscrowContractClient};
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    backend: Address,
+    token: Address,
+}
+
+fn setup<'a>(cooldown: u64) -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.set_admin(&admin, &0);
+    client.update_rate_limit_config(&3600, &5, &cooldown, &1);
+
+    TestSetup {
+        backend: Address::generate(&env),
+        token: Address::generate(&env),
+        env,
+        client,
+    }
+}
+
+/// Registers programs with the backend key until one is rejected,
+/// returning how many succeeded.
+fn run_until_blocked(t: &TestSetup, prefix: u8) -> u32 {
+    let mut done = 0u32;
+    loop {
+        let name = [b'P', prefix, b'-', b'a' + done as u8];
+        let result = t.client.try_initialize_program(
+            &String::from_bytes(&t.env, &name),
+            &t.backend,
+            &t.token,
+        );
+        if result.is_err() {
+            return done;
+        }
+        done += 1;
+    }
+}
+
+#[test]
+fn test_predictor_matches_fixed_window_limit() {
+    let t = setup(0);
+    assert_eq!(t.client.operations_until_blocked(&t.backend), 5);
+    assert!(t.client.can_perform_operations(&t.backend, &5));
+    assert!(!t.client.can_perform_operations(&t.backend, &6));
+
+    assert_eq!(run_until_blocked(&t, b'0'), 5);
+    assert_eq!(t.client.operations_until_blocked(&t.backend), 0);
+    assert!(!t.client.can_perform_operations(&t.backend, &1));
+
+    t.env.ledger().set_timestamp(1_000 + 3_600);
+    let predicted = t.client.operations_until_blocked(&t.backend);
+    assert_eq!(predicted, 5);
+    assert_eq!(run_until_blocked(&t, b'1'), predicted);
+}
+
+#[test]
+fn test_predictor_accounts_for_cooldown() {
+    let t = setup(60);
+    assert_eq!(t.client.operations_until_blocked(&t.backend), 1);
+    assert!(!t.client.can_perform_operations(&t.backend, &2));
+
+    assert_eq!(run_until_blocked(&t, b'0'), 1);
+    assert_eq!(t.client.operations_until_blocked(&t.backend), 0);
+
+    t.env.ledger().set_timestamp(1_000 + 59);
+    assert_eq!(t.client.operations_until_blocked(&t.backend), 0);
+    assert_eq!(run_until_blocked(&t, b'1'), 0);
+
+    t.env.ledger().set_timestamp(1_000 + 60);
+    assert_eq!(t.client.operations_until_blocked(&t.backend), 1);
+    assert_eq!(run_until_blocked(&t, b'2'), 1);
+}
+
+#[test]
+fn test_predictor_matches_leaky_bucket() {
+    let t = setup(0);
+    t.client.set_rate_limit_mode(&RateLimitMode::LeakyBucket);
+
+    assert_eq!(run_until_blocked(&t, b'0'), 5);
+
+    // 5 ops per 3600s refills one op every 720s.
+    t.env.ledger().set_timestamp(1_000 + 1_440);
+    let predicted = t.client.operations_until_blocked(&t.backend);
+    assert_eq!(predicted, 2);
+    assert_eq!(run_until_blocked(&t, b'1'), predicted);
+}
+
+#[test]
+fn test_whitelisted_address_is_never_blocked() {
+    let t = setup(60);
+    t.client.set_whitelist(&t.backend, &true);
+
+    assert_eq!(t.client.operations_until_blocked(&t.backend), u32::MAX);
+    assert!(t.client.can_perform_operations(&t.backend, &100));
+}
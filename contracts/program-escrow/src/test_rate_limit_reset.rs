@@ -0,0 +1,105 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_rate_limit_reset.rs
+//
+// Tests for the admin `reset_rate_limit_window` escape hatch.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger},
+    Address, Env, IntoVal, String, Symbol, Val, Vec,
+};
+
+use crate::anti_abuse::RateLimitMode;
+use crate::{ProgramEscrowContract, ProgramEscrowContractClient};
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    backend: Address,
+    token: Address,
+}
+
+fn setup<'a>(cooldown: u64) -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.set_admin(&admin, &0);
+    client.update_rate_limit_config(&3600, &2, &cooldown, &1);
+
+    TestSetup {
+        backend: Address::generate(&env),
+        token: Address::generate(&env),
+        env,
+        client,
+    }
+}
+
+fn register(t: &TestSetup, tag: u8) -> bool {
+    let name = [b'R', b'-', tag];
+    t.client
+        .try_initialize_program(&String::from_bytes(&t.env, &name), &t.backend, &t.token)
+        .is_ok()
+}
+
+#[test]
+fn test_reset_allows_operations_after_limit() {
+    let t = setup(0);
+    assert!(register(&t, b'a'));
+    assert!(register(&t, b'b'));
+    assert!(!register(&t, b'c'));
+
+    t.client.reset_rate_limit_window(&t.backend);
+
+    assert_eq!(t.client.get_available_ops(&t.backend), 2);
+    assert!(register(&t, b'c'));
+    assert!(register(&t, b'd'));
+    assert!(!register(&t, b'e'));
+}
+
+#[test]
+fn test_reset_clears_cooldown() {
+    let t = setup(60);
+    assert!(register(&t, b'a'));
+    assert!(!register(&t, b'b'));
+
+    t.client.reset_rate_limit_window(&t.backend);
+
+    assert!(register(&t, b'b'));
+}
+
+#[test]
+fn test_reset_refills_leaky_bucket() {
+    let t = setup(0);
+    t.client.set_rate_limit_mode(&RateLimitMode::LeakyBucket);
+    assert!(register(&t, b'a'));
+    assert!(register(&t, b'b'));
+    assert!(!register(&t, b'c'));
+
+    t.client.reset_rate_limit_window(&t.backend);
+
+    assert!(register(&t, b'c'));
+}
+
+#[test]
+fn test_reset_emits_event() {
+    let t = setup(0);
+    t.client.reset_rate_limit_window(&t.backend);
+
+    let expected: Vec<Val> =
+        (Symbol::new(&t.env, "rate_limit_reset"), t.backend.clone()).into_val(&t.env);
+    let emitted = t
+        .env
+        .events()
+        .all()
+        .iter()
+        .filter(|(_, topics, _)| *topics == expected)
+        .count();
+    assert_eq!(emitted, 1);
+}
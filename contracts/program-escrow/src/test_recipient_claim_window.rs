@@ -0,0 +1,146 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_recipient_claim_window.rs
+//
+// Tests for per-recipient claim windows and their precedence:
+// explicit deadline > per-recipient > program > global.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, Env, String,
+};
+
+use crate::{PayoutMode, ProgramEscrowContract, ProgramEscrowContractClient};
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    program_id: String,
+    trusted: Address,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let payout_key = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&contract_id, &100_000);
+
+    let program_id = String::from_str(&env, "Windows");
+    client.initialize_program(&program_id, &payout_key, &sac.address());
+    client.lock_program_funds(&program_id, &100_000);
+
+    // Payouts below run at a non-zero timestamp, so skip the cooldown
+    client.set_admin(&admin, &0);
+    client.set_whitelist(&payout_key, &true);
+    env.ledger().set_timestamp(1_000);
+
+    TestSetup {
+        trusted: Address::generate(&env),
+        env,
+        client,
+        program_id,
+    }
+}
+
+fn deadline_of_single_payout(t: &TestSetup, recipient: &Address) -> u64 {
+    let claim_id = t
+        .client
+        .single_payout(&t.program_id, recipient, &100)
+        .claim_id;
+    t.client.get_claim(&t.program_id, &claim_id).claim_deadline
+}
+
+#[test]
+fn test_recipient_window_overrides_program_window() {
+    let t = setup();
+    let regular = Address::generate(&t.env);
+    t.client
+        .set_program_claim_config(&t.program_id, &3_600, &0, &0, &false);
+    t.client
+        .set_recipient_claim_window(&t.program_id, &t.trusted, &30_000);
+
+    assert_eq!(deadline_of_single_payout(&t, &t.trusted), 31_000);
+    assert_eq!(deadline_of_single_payout(&t, &regular), 4_600);
+}
+
+#[test]
+fn test_recipient_window_overrides_global_window() {
+    let t = setup();
+    t.client
+        .set_recipient_claim_window(&t.program_id, &t.trusted, &500);
+
+    assert_eq!(deadline_of_single_payout(&t, &t.trusted), 1_500);
+    assert_eq!(
+        deadline_of_single_payout(&t, &Address::generate(&t.env)),
+        1_000 + t.client.get_claim_window()
+    );
+}
+
+#[test]
+fn test_explicit_deadline_overrides_recipient_window() {
+    let t = setup();
+    t.client
+        .set_recipient_claim_window(&t.program_id, &t.trusted, &30_000);
+
+    let claim_id = t
+        .client
+        .create_pending_claim(&t.program_id, &t.trusted, &100, &2_000);
+
+    assert_eq!(
+        t.client.get_claim(&t.program_id, &claim_id).claim_deadline,
+        2_000
+    );
+}
+
+#[test]
+fn test_batch_claims_use_recipient_window() {
+    let t = setup();
+    let regular = Address::generate(&t.env);
+    t.client
+        .set_program_claim_config(&t.program_id, &3_600, &0, &0, &false);
+    t.client
+        .set_recipient_claim_window(&t.program_id, &t.trusted, &30_000);
+
+    t.client.batch_payout(
+        &t.program_id,
+        &vec![&t.env, t.trusted.clone(), regular.clone()],
+        &vec![&t.env, 100, 100],
+        &false,
+        &Some(vec![&t.env, PayoutMode::Claim, PayoutMode::Claim]),
+        &200,
+    );
+
+    let ids = t.client.get_pending_claim_ids(&t.program_id);
+    let first = t.client.get_claim(&t.program_id, &ids.get(0).unwrap());
+    let second = t.client.get_claim(&t.program_id, &ids.get(1).unwrap());
+    assert_eq!(first.recipient, t.trusted);
+    assert_eq!(first.claim_deadline, 31_000);
+    assert_eq!(second.claim_deadline, 4_600);
+}
+
+#[test]
+fn test_zero_clears_recipient_window() {
+    let t = setup();
+    t.client
+        .set_program_claim_config(&t.program_id, &3_600, &0, &0, &false);
+    t.client
+        .set_recipient_claim_window(&t.program_id, &t.trusted, &30_000);
+    t.client
+        .set_recipient_claim_window(&t.program_id, &t.trusted, &0);
+
+    assert_eq!(
+        t.client
+            .get_recipient_claim_window(&t.program_id, &t.trusted),
+        None
+    );
+    assert_eq!(deadline_of_single_payout(&t, &t.trusted), 4_600);
+}
@@ -0,0 +1,162 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_recipient_freeze.rs
+//
+// Tests for freezing a recipient within a single program.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env, String};
+
+use crate::{BatchSkipReason, ClaimStatus, ProgramEscrowContract, ProgramEscrowContractClient};
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    token: token::Client<'a>,
+    disputed: String,
+    other: String,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let payout_key = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    let token = token::Client::new(&env, &sac.address());
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&contract_id, &20_000);
+
+    client.set_admin(&admin, &0);
+    client.set_whitelist(&payout_key, &true);
+    client.set_whitelist(&contract_id, &true);
+
+    let disputed = String::from_str(&env, "Disputed");
+    let other = String::from_str(&env, "Other");
+    for program_id in [&disputed, &other] {
+        client.initialize_program(program_id, &payout_key, &token.address);
+        client.lock_program_funds(program_id, &10_000);
+    }
+
+    TestSetup {
+        env,
+        client,
+        token,
+        disputed,
+        other,
+    }
+}
+
+#[test]
+fn test_frozen_recipient_still_payable_in_other_program() {
+    let t = setup();
+    let recipient = Address::generate(&t.env);
+    t.client.freeze_recipient(&t.disputed, &recipient);
+
+    assert!(t.client.is_recipient_frozen(&t.disputed, &recipient));
+    assert!(!t.client.is_recipient_frozen(&t.other, &recipient));
+    assert!(t
+        .client
+        .try_single_payout(&t.disputed, &recipient, &1_000)
+        .is_err());
+    assert!(t
+        .client
+        .try_batch_payout(
+            &t.disputed,
+            &vec![&t.env, recipient.clone()],
+            &vec![&t.env, 1_000],
+            &false,
+            &None,
+            &1_000,
+        )
+        .is_err());
+
+    let claim_id = t
+        .client
+        .single_payout(&t.other, &recipient, &1_000)
+        .claim_id;
+    t.client.execute_claim(&t.other, &claim_id, &recipient);
+    assert_eq!(t.token.balance(&recipient), 1_000);
+}
+
+#[test]
+fn test_frozen_claim_executes_after_unfreeze() {
+    let t = setup();
+    let recipient = Address::generate(&t.env);
+    let claim_id = t
+        .client
+        .single_payout(&t.disputed, &recipient, &2_000)
+        .claim_id;
+
+    t.client.freeze_recipient(&t.disputed, &recipient);
+    assert!(t
+        .client
+        .try_execute_claim(&t.disputed, &claim_id, &recipient)
+        .is_err());
+    assert_eq!(
+        t.client.get_claim_status(&t.disputed, &claim_id),
+        ClaimStatus::Pending
+    );
+
+    t.client.unfreeze_recipient(&t.disputed, &recipient);
+    assert!(!t.client.is_recipient_frozen(&t.disputed, &recipient));
+    t.client.execute_claim(&t.disputed, &claim_id, &recipient);
+    assert_eq!(t.token.balance(&recipient), 2_000);
+}
+
+#[test]
+fn test_lenient_batch_skips_frozen_recipient() {
+    let t = setup();
+    let frozen = Address::generate(&t.env);
+    let paid = Address::generate(&t.env);
+    t.client.freeze_recipient(&t.disputed, &frozen);
+
+    let result = t.client.batch_payout_lenient(
+        &t.disputed,
+        &vec![&t.env, frozen.clone(), paid.clone()],
+        &vec![&t.env, 1_000, 1_000],
+    );
+
+    assert_eq!(result.skipped_indices, vec![&t.env, 0]);
+    assert_eq!(
+        result.skip_reasons,
+        vec![&t.env, BatchSkipReason::RecipientFrozen]
+    );
+    assert_eq!(t.token.balance(&paid), 1_000);
+    assert_eq!(t.token.balance(&frozen), 0);
+}
+
+#[test]
+fn test_frozen_recipient_rejected_for_multisig_payout() {
+    let t = setup();
+    let signer_a = Address::generate(&t.env);
+    let signer_b = Address::generate(&t.env);
+    t.client.set_multisig_payout_config(
+        &t.disputed,
+        &vec![&t.env, signer_a.clone(), signer_b.clone()],
+        &2,
+    );
+    let recipient = Address::generate(&t.env);
+    let request_id = t
+        .client
+        .request_multisig_payout(&t.disputed, &recipient, &1_000);
+    t.client
+        .approve_multisig_payout(&t.disputed, &request_id, &signer_a);
+
+    t.client.freeze_recipient(&t.disputed, &recipient);
+
+    assert!(t
+        .client
+        .try_request_multisig_payout(&t.disputed, &recipient, &1_000)
+        .is_err());
+    assert!(t
+        .client
+        .try_approve_multisig_payout(&t.disputed, &request_id, &signer_b)
+        .is_err());
+    assert_eq!(t.token.balance(&recipient), 0);
+}
@@ -0,0 +1,124 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_recipient_payouts.rs
+//
+// Tests for `get_payouts_for_recipient`.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env, String, Vec};
+
+use crate::{ProgramEscrowContract, ProgramEscrowContractClient};
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    program_id: String,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let payout_key = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&contract_id, &100_000);
+
+    client.set_admin(&admin, &0);
+    client.set_whitelist(&payout_key, &true);
+
+    let program_id = String::from_str(&env, "Indexed");
+    client.initialize_program(&program_id, &payout_key, &sac.address());
+    client.lock_program_funds(&program_id, &100_000);
+    client.set_direct_payout_mode(&program_id, &true);
+
+    TestSetup {
+        env,
+        client,
+        program_id,
+    }
+}
+
+fn amounts_for(t: &TestSetup, recipient: &Address, offset: u32, limit: u32) -> Vec<i128> {
+    let mut amounts = Vec::new(&t.env);
+    for record in t
+        .client
+        .get_payouts_for_recipient(&t.program_id, recipient, &offset, &limit)
+        .iter()
+    {
+        assert_eq!(&record.recipient, recipient);
+        amounts.push_back(record.amount);
+    }
+    amounts
+}
+
+#[test]
+fn test_filters_to_target_recipient_in_order() {
+    let t = setup();
+    let alice = Address::generate(&t.env);
+    let bob = Address::generate(&t.env);
+    let carol = Address::generate(&t.env);
+
+    t.client.single_payout(&t.program_id, &alice, &100);
+    t.client.single_payout(&t.program_id, &bob, &200);
+    t.client.single_payout(&t.program_id, &alice, &300);
+    let recipients = vec![&t.env, carol.clone(), alice.clone(), bob.clone()];
+    let amounts = vec![&t.env, 400_i128, 500, 600];
+    t.client
+        .batch_payout(&t.program_id, &recipients, &amounts, &false, &None, &1_500);
+    t.client.single_payout(&t.program_id, &carol, &700);
+
+    assert_eq!(
+        amounts_for(&t, &alice, 0, 10),
+        vec![&t.env, 100_i128, 300, 500]
+    );
+    assert_eq!(amounts_for(&t, &bob, 0, 10), vec![&t.env, 200_i128, 600]);
+    assert_eq!(amounts_for(&t, &carol, 0, 10), vec![&t.env, 400_i128, 700]);
+}
+
+#[test]
+fn test_paginates_recipient_payouts() {
+    let t = setup();
+    let alice = Address::generate(&t.env);
+    let bob = Address::generate(&t.env);
+    for i in 1..=5 {
+        t.client.single_payout(&t.program_id, &alice, &(i * 10));
+        t.client.single_payout(&t.program_id, &bob, &1);
+    }
+
+    assert_eq!(amounts_for(&t, &alice, 1, 2), vec![&t.env, 20_i128, 30]);
+    assert_eq!(amounts_for(&t, &alice, 3, 10), vec![&t.env, 40_i128, 50]);
+    assert!(amounts_for(&t, &alice, 5, 10).is_empty());
+    assert!(amounts_for(&t, &alice, 0, 0).is_empty());
+}
+
+#[test]
+fn test_recipient_without_payouts_returns_empty() {
+    let t = setup();
+    t.client
+        .single_payout(&t.program_id, &Address::generate(&t.env), &100);
+    assert!(amounts_for(&t, &Address::generate(&t.env), 0, 10).is_empty());
+}
+
+#[test]
+fn test_claimed_payouts_are_indexed() {
+    let t = setup();
+    t.client.set_direct_payout_mode(&t.program_id, &false);
+    let alice = Address::generate(&t.env);
+
+    t.client.single_payout(&t.program_id, &alice, &250);
+    assert!(amounts_for(&t, &alice, 0, 10).is_empty());
+
+    let claim_id = t
+        .client
+        .get_pending_claim_ids(&t.program_id)
+        .get(0)
+        .unwrap();
+    t.client.execute_claim(&t.program_id, &claim_id, &alice);
+    assert_eq!(amounts_for(&t, &alice, 0, 10), vec![&t.env, 250_i128]);
+}
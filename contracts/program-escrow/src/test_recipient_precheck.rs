@@ -0,0 +1,124 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_recipient_precheck.rs
+//
+// Tests for checking that a recipient can hold the program's
+// token before `single_payout` transfers to it.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{contract, contractimpl, testutils::Address as _, Address, Env, String};
+
+use crate::{ProgramEscrowContract, ProgramEscrowContractClient};
+
+/// Token that rejects addresses the test marked as lacking a trustline.
+#[contract]
+pub struct MockToken;
+
+#[contractimpl]
+impl MockToken {
+    pub fn reject(env: Env, id: Address) {
+        env.storage().instance().set(&id, &true);
+    }
+
+    pub fn balance(env: Env, id: Address) -> i128 {
+        if env.storage().instance().has(&id) {
+            panic!("trustline entry is missing for account");
+        }
+        0
+    }
+
+    pub fn transfer(env: Env, _from: Address, to: Address, _amount: i128) {
+        if env.storage().instance().has(&to) {
+            panic!("trustline entry is missing for account");
+        }
+    }
+}
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    token: MockTokenClient<'a>,
+    program_id: String,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let payout_key = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let token_id = env.register_contract(None, MockToken);
+    let token = MockTokenClient::new(&env, &token_id);
+
+    let program_id = String::from_str(&env, "Precheck");
+    client.initialize_program(&program_id, &payout_key, &token_id);
+    client.lock_program_funds(&program_id, &10_000);
+    client.set_direct_payout_mode(&program_id, &true);
+
+    TestSetup {
+        env,
+        client,
+        token,
+        program_id,
+    }
+}
+
+fn rejected_recipient(t: &TestSetup) -> Address {
+    let recipient = Address::generate(&t.env);
+    t.token.reject(&recipient);
+    recipient
+}
+
+#[test]
+fn test_precheck_disabled_by_default() {
+    let t = setup();
+    assert!(!t.client.is_recipient_precheck(&t.program_id));
+
+    t.client.set_recipient_precheck(&t.program_id, &true);
+    assert!(t.client.is_recipient_precheck(&t.program_id));
+}
+
+#[test]
+#[should_panic(expected = "Recipient cannot receive token")]
+fn test_precheck_surfaces_clear_error() {
+    let t = setup();
+    t.client.set_recipient_precheck(&t.program_id, &true);
+    let recipient = rejected_recipient(&t);
+
+    t.client.single_payout(&t.program_id, &recipient, &1_000);
+}
+
+#[test]
+#[should_panic(expected = "Recipient cannot receive token")]
+fn test_precheck_applies_before_claim_creation() {
+    let t = setup();
+    t.client.set_direct_payout_mode(&t.program_id, &false);
+    t.client.set_recipient_precheck(&t.program_id, &true);
+    let recipient = rejected_recipient(&t);
+
+    t.client.single_payout(&t.program_id, &recipient, &1_000);
+}
+
+#[test]
+#[should_panic(expected = "trustline entry is missing")]
+fn test_without_precheck_token_error_surfaces() {
+    let t = setup();
+    let recipient = rejected_recipient(&t);
+
+    t.client.single_payout(&t.program_id, &recipient, &1_000);
+}
+
+#[test]
+fn test_precheck_allows_receivable_recipient() {
+    let t = setup();
+    t.client.set_recipient_precheck(&t.program_id, &true);
+
+    let result = t
+        .client
+        .single_payout(&t.program_id, &Address::generate(&t.env), &1_000);
+    assert_eq!(result.remaining_balance, 9_000);
+}
@@ -0,0 +1,109 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_recipient_schedules.rs
+//
+// Tests for `get_schedules_for_recipient`.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env, String,
+};
+
+use crate::{ProgramEscrowContract, ProgramEscrowContractClient};
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    program_id: String,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let admin = Address::generate(&env);
+    let payout_key = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&contract_id, &100_000);
+
+    client.set_admin(&admin, &0);
+    client.set_whitelist(&payout_key, &true);
+
+    let program_id = String::from_str(&env, "Vesting");
+    client.initialize_program(&program_id, &payout_key, &sac.address());
+    client.lock_program_funds(&program_id, &100_000);
+
+    TestSetup {
+        env,
+        client,
+        program_id,
+    }
+}
+
+fn schedule(t: &TestSetup, recipient: &Address, amount: i128, release_timestamp: u64) {
+    t.client
+        .create_program_release_schedule(&t.program_id, &amount, &release_timestamp, recipient);
+}
+
+#[test]
+fn test_returns_only_matching_recipient_schedules() {
+    let t = setup();
+    let alice = Address::generate(&t.env);
+    let bob = Address::generate(&t.env);
+
+    schedule(&t, &alice, 1_000, 2_000);
+    schedule(&t, &bob, 2_000, 3_000);
+    schedule(&t, &alice, 3_000, 4_000);
+    schedule(&t, &bob, 4_000, 5_000);
+    schedule(&t, &alice, 5_000, 6_000);
+
+    let alice_schedules = t.client.get_schedules_for_recipient(&t.program_id, &alice);
+    assert_eq!(alice_schedules.len(), 3);
+    for (schedule, (id, amount)) in alice_schedules
+        .iter()
+        .zip([(1, 1_000), (3, 3_000), (5, 5_000)])
+    {
+        assert_eq!(schedule.recipient, alice);
+        assert_eq!(schedule.schedule_id, id);
+        assert_eq!(schedule.amount, amount);
+    }
+
+    let bob_schedules = t.client.get_schedules_for_recipient(&t.program_id, &bob);
+    assert_eq!(bob_schedules.len(), 2);
+    assert_eq!(bob_schedules.get(0).unwrap().schedule_id, 2);
+    assert_eq!(bob_schedules.get(1).unwrap().schedule_id, 4);
+}
+
+#[test]
+fn test_includes_released_schedules() {
+    let t = setup();
+    let alice = Address::generate(&t.env);
+    schedule(&t, &alice, 1_000, 2_000);
+    schedule(&t, &alice, 2_000, 9_000);
+
+    t.env.ledger().set_timestamp(2_000);
+    t.client.release_prog_schedule_automatic(&t.program_id, &1);
+
+    let schedules = t.client.get_schedules_for_recipient(&t.program_id, &alice);
+    assert_eq!(schedules.len(), 2);
+    assert!(schedules.get(0).unwrap().released);
+    assert!(!schedules.get(1).unwrap().released);
+}
+
+#[test]
+fn test_unknown_recipient_has_no_schedules() {
+    let t = setup();
+    schedule(&t, &Address::generate(&t.env), 1_000, 2_000);
+
+    let schedules = t
+        .client
+        .get_schedules_for_recipient(&t.program_id, &Address::generate(&t.env));
+    assert!(schedules.is_empty());
+}
@@ -0,0 +1,121 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_remainder_policy.rs
+//
+// Tests for `batch_payout_by_shares` and where each `RemainderPolicy`
+// routes the rounding remainder.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env, String, Vec};
+
+use crate::{ProgramEscrowContract, ProgramEscrowContractClient, RemainderPolicy};
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    token: token::Client<'a>,
+    program_id: String,
+    recipients: Vec<Address>,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let payout_key = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    let token = token::Client::new(&env, &sac.address());
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&contract_id, &10_000);
+
+    let program_id = String::from_str(&env, "Shares");
+    client.initialize_program(&program_id, &payout_key, &token.address);
+    client.lock_program_funds(&program_id, &10_000);
+
+    let recipients = vec![
+        &env,
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    ];
+
+    TestSetup {
+        env,
+        client,
+        token,
+        program_id,
+        recipients,
+    }
+}
+
+/// Pays 101 units at shares 1:3:1, which leaves a remainder of 1.
+fn pay(t: &TestSetup, policy: RemainderPolicy) -> (i128, i128, i128, i128) {
+    let data = t.client.batch_payout_by_shares(
+        &t.program_id,
+        &t.recipients,
+        &vec![&t.env, 1, 3, 1],
+        &101,
+        &policy,
+    );
+    (
+        t.token.balance(&t.recipients.get(0).unwrap()),
+        t.token.balance(&t.recipients.get(1).unwrap()),
+        t.token.balance(&t.recipients.get(2).unwrap()),
+        data.remaining_balance,
+    )
+}
+
+#[test]
+fn test_last_policy_pays_remainder_to_last_recipient() {
+    let t = setup();
+    let (a, b, c, remaining) = pay(&t, RemainderPolicy::Last);
+
+    assert_eq!((a, b, c), (20, 60, 21));
+    assert_eq!(a + b + c + remaining, 10_000);
+}
+
+#[test]
+fn test_first_policy_pays_remainder_to_first_recipient() {
+    let t = setup();
+    let (a, b, c, remaining) = pay(&t, RemainderPolicy::First);
+
+    assert_eq!((a, b, c), (21, 60, 20));
+    assert_eq!(a + b + c + remaining, 10_000);
+}
+
+#[test]
+fn test_largest_share_policy_pays_remainder_to_largest_share() {
+    let t = setup();
+    let (a, b, c, remaining) = pay(&t, RemainderPolicy::LargestShare);
+
+    assert_eq!((a, b, c), (20, 61, 20));
+    assert_eq!(a + b + c + remaining, 10_000);
+}
+
+#[test]
+fn test_burn_policy_keeps_remainder_in_balance() {
+    let t = setup();
+    let (a, b, c, remaining) = pay(&t, RemainderPolicy::Burn);
+
+    assert_eq!((a, b, c), (20, 60, 20));
+    assert_eq!(remaining, 9_900);
+    assert_eq!(a + b + c + remaining, 10_000);
+}
+
+#[test]
+#[should_panic(expected = "Recipients and shares vectors must have the same length")]
+fn test_mismatched_shares_rejected() {
+    let t = setup();
+    t.client.batch_payout_by_shares(
+        &t.program_id,
+        &t.recipients,
+        &vec![&t.env, 1, 1],
+        &100,
+        &RemainderPolicy::Last,
+    );
+}
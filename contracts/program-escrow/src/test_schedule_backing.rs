@@ -0,0 +1,109 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_schedule_backing.rs
+//
+// Tests that release schedules can never commit more than the
+// program's remaining balance.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env, String,
+};
+
+use crate::{ProgramEscrowContract, ProgramEscrowContractClient};
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    program_id: String,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let admin = Address::generate(&env);
+    let payout_key = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&contract_id, &20_000);
+
+    client.set_admin(&admin, &0);
+    client.set_whitelist(&payout_key, &true);
+
+    let program_id = String::from_str(&env, "Backing");
+    client.initialize_program(&program_id, &payout_key, &sac.address());
+    client.lock_program_funds(&program_id, &10_000);
+
+    TestSetup {
+        env,
+        client,
+        program_id,
+    }
+}
+
+fn schedule(t: &TestSetup, amount: i128) {
+    t.client.create_program_release_schedule(
+        &t.program_id,
+        &amount,
+        &5_000,
+        &Address::generate(&t.env),
+    );
+}
+
+#[test]
+fn test_schedules_up_to_balance_succeed() {
+    let t = setup();
+    schedule(&t, 6_000);
+    schedule(&t, 4_000);
+    assert_eq!(
+        t.client.get_schedule_totals(&t.program_id).total_pending,
+        10_000
+    );
+}
+
+#[test]
+#[should_panic(expected = "Insufficient balance to schedule")]
+fn test_over_scheduling_panics() {
+    let t = setup();
+    schedule(&t, 6_000);
+    schedule(&t, 4_001);
+}
+
+#[test]
+#[should_panic(expected = "Insufficient balance to schedule")]
+fn test_pending_claims_count_against_schedules() {
+    let t = setup();
+    t.client
+        .single_payout(&t.program_id, &Address::generate(&t.env), &3_000);
+    schedule(&t, 7_001);
+}
+
+#[test]
+fn test_scheduling_resumes_after_top_up() {
+    let t = setup();
+    schedule(&t, 10_000);
+    assert!(t
+        .client
+        .try_create_program_release_schedule(
+            &t.program_id,
+            &1_000,
+            &5_000,
+            &Address::generate(&t.env),
+        )
+        .is_err());
+
+    t.env.ledger().set_timestamp(1_100);
+    t.client.lock_program_funds(&t.program_id, &1_000);
+    schedule(&t, 1_000);
+    assert_eq!(
+        t.client.get_schedule_totals(&t.program_id).total_pending,
+        11_000
+    );
+}
@@ -0,0 +1,112 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_schedule_release_denylist.rs
+//
+// Tests that release schedules are held back when their
+// recipient is denylisted or frozen after scheduling.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env, String,
+};
+
+use crate::{ProgramEscrowContract, ProgramEscrowContractClient};
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    token: token::Client<'a>,
+    program_id: String,
+    recipient: Address,
+    schedule_id: u64,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let admin = Address::generate(&env);
+    let payout_key = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&contract_id, &10_000);
+    let token = token::Client::new(&env, &sac.address());
+
+    client.set_admin(&admin, &0);
+    client.set_whitelist(&payout_key, &true);
+
+    let program_id = String::from_str(&env, "Vesting");
+    client.initialize_program(&program_id, &payout_key, &sac.address());
+    client.lock_program_funds(&program_id, &10_000);
+
+    let recipient = Address::generate(&env);
+    let schedule_id = client.single_payout_scheduled(&program_id, &recipient, &3_000, &5_000);
+
+    TestSetup {
+        env,
+        client,
+        token,
+        program_id,
+        recipient,
+        schedule_id,
+    }
+}
+
+#[test]
+fn test_release_skipped_for_denylisted_recipient() {
+    let t = setup();
+    t.client
+        .add_to_denylist(&t.program_id, &t.recipient, &false);
+
+    t.env.ledger().set_timestamp(5_000);
+    t.client
+        .release_prog_schedule_automatic(&t.program_id, &t.schedule_id);
+
+    let schedule = t
+        .client
+        .get_program_release_schedule(&t.program_id, &t.schedule_id);
+    assert!(!schedule.released);
+    assert_eq!(t.token.balance(&t.recipient), 0);
+    assert_eq!(t.client.get_remaining_balance(&t.program_id), 10_000);
+}
+
+#[test]
+fn test_skipped_schedule_releases_once_unblocked() {
+    let t = setup();
+    t.client
+        .add_to_denylist(&t.program_id, &t.recipient, &false);
+    t.client
+        .release_program_schedule_manual(&t.program_id, &t.schedule_id);
+    assert_eq!(t.token.balance(&t.recipient), 0);
+
+    t.client.remove_from_denylist(&t.program_id, &t.recipient);
+    t.env.ledger().set_timestamp(5_000);
+    t.client
+        .release_prog_schedule_automatic(&t.program_id, &t.schedule_id);
+
+    assert_eq!(t.token.balance(&t.recipient), 3_000);
+    assert_eq!(t.client.get_remaining_balance(&t.program_id), 7_000);
+}
+
+#[test]
+fn test_release_skipped_for_frozen_recipient() {
+    let t = setup();
+    t.client.freeze_recipient(&t.program_id, &t.recipient);
+
+    t.env.ledger().set_timestamp(5_000);
+    t.client
+        .release_prog_schedule_automatic(&t.program_id, &t.schedule_id);
+
+    assert_eq!(t.token.balance(&t.recipient), 0);
+    assert!(
+        !t.client
+            .get_program_release_schedule(&t.program_id, &t.schedule_id)
+            .released
+    );
+}
@@ -0,0 +1,145 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_schedule_totals.rs
+//
+// Tests for the running vesting totals in `get_schedule_totals`.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env, String,
+};
+
+use crate::{ProgramEscrowContract, ProgramEscrowContractClient, ScheduleTotals};
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    program_id: String,
+    recipient: Address,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let admin = Address::generate(&env);
+    let payout_key = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&contract_id, &100_000);
+
+    client.set_admin(&admin, &0);
+    client.set_whitelist(&payout_key, &true);
+    client.set_whitelist(&contract_id, &true);
+
+    let program_id = String::from_str(&env, "Vesting");
+    client.initialize_program(&program_id, &payout_key, &sac.address());
+    client.lock_program_funds(&program_id, &100_000);
+
+    TestSetup {
+        recipient: Address::generate(&env),
+        env,
+        client,
+        program_id,
+    }
+}
+
+/// Recomputes the totals from individual schedules.
+fn reconcile(t: &TestSetup) -> (i128, i128) {
+    let mut released = 0;
+    let mut pending = 0;
+    for schedule in t
+        .client
+        .get_all_prog_release_schedules(&t.program_id)
+        .iter()
+    {
+        if schedule.released {
+            released += schedule.amount;
+        } else {
+            pending += schedule.amount;
+        }
+    }
+    (released, pending)
+}
+
+#[test]
+fn test_empty_program_has_zero_totals() {
+    let t = setup();
+    assert_eq!(
+        t.client.get_schedule_totals(&t.program_id),
+        ScheduleTotals {
+            total_scheduled: 0,
+            total_released: 0,
+            total_pending: 0,
+            next_release_ts: None,
+        }
+    );
+}
+
+#[test]
+fn test_totals_reconcile_after_create_and_release() {
+    let t = setup();
+    t.client
+        .create_program_release_schedule(&t.program_id, &1_000, &3_000, &t.recipient);
+    t.client
+        .create_program_release_schedule(&t.program_id, &2_000, &2_000, &t.recipient);
+    t.client
+        .create_program_release_schedule(&t.program_id, &4_000, &5_000, &t.recipient);
+
+    let totals = t.client.get_schedule_totals(&t.program_id);
+    assert_eq!(totals.total_scheduled, 7_000);
+    assert_eq!(totals.total_pending, 7_000);
+    assert_eq!(totals.next_release_ts, Some(2_000));
+
+    t.env.ledger().set_timestamp(3_000);
+    t.client.release_prog_schedule_automatic(&t.program_id, &2);
+    t.client.release_program_schedule_manual(&t.program_id, &1);
+
+    let totals = t.client.get_schedule_totals(&t.program_id);
+    let (released, pending) = reconcile(&t);
+    assert_eq!(totals.total_released, released);
+    assert_eq!(totals.total_pending, pending);
+    assert_eq!(totals.total_released, 3_000);
+    assert_eq!(totals.total_pending, 4_000);
+    assert_eq!(totals.total_scheduled, 7_000);
+    assert_eq!(totals.next_release_ts, Some(5_000));
+}
+
+#[test]
+fn test_cancellation_drops_out_of_totals() {
+    let t = setup();
+    t.client
+        .create_program_release_schedule(&t.program_id, &1_000, &2_000, &t.recipient);
+    t.client
+        .create_program_release_schedule(&t.program_id, &3_000, &4_000, &t.recipient);
+
+    t.client.cancel_program_release_schedule(&t.program_id, &1);
+
+    let totals = t.client.get_schedule_totals(&t.program_id);
+    assert_eq!(totals.total_scheduled, 3_000);
+    assert_eq!(totals.total_pending, 3_000);
+    assert_eq!(totals.total_released, 0);
+    assert_eq!(totals.next_release_ts, Some(4_000));
+    assert_eq!(reconcile(&t), (0, 3_000));
+}
+
+#[test]
+fn test_next_release_cleared_when_all_released() {
+    let t = setup();
+    t.client
+        .create_program_release_schedule(&t.program_id, &1_000, &2_000, &t.recipient);
+
+    t.env.ledger().set_timestamp(2_000);
+    t.client.release_program_schedule_manual(&t.program_id, &1);
+
+    let totals = t.client.get_schedule_totals(&t.program_id);
+    assert_eq!(totals.total_released, 1_000);
+    assert_eq!(totals.total_pending, 0);
+    assert_eq!(totals.next_release_ts, None);
+}
@@ -0,0 +1,174 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_scheduled_payout.rs
+//
+// Tests for `single_payout_scheduled`, which reserves a payout
+// as a release schedule instead of paying it immediately.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env, String,
+};
+
+use crate::{ProgramEscrowContract, ProgramEscrowContractClient};
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    token: token::Client<'a>,
+    program_id: String,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let admin = Address::generate(&env);
+    let payout_key = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&contract_id, &10_000);
+    let token = token::Client::new(&env, &sac.address());
+
+    client.set_admin(&admin, &0);
+    client.set_whitelist(&payout_key, &true);
+
+    let program_id = String::from_str(&env, "Scheduled");
+    client.initialize_program(&program_id, &payout_key, &sac.address());
+    client.lock_program_funds(&program_id, &10_000);
+
+    TestSetup {
+        env,
+        client,
+        token,
+        program_id,
+    }
+}
+
+#[test]
+fn test_scheduled_payout_creates_schedule_and_reserves_funds() {
+    let t = setup();
+    let recipient = Address::generate(&t.env);
+
+    let schedule_id = t
+        .client
+        .single_payout_scheduled(&t.program_id, &recipient, &4_000, &5_000);
+
+    let schedule = t
+        .client
+        .get_program_release_schedule(&t.program_id, &schedule_id);
+    assert_eq!(schedule.recipient, recipient);
+    assert_eq!(schedule.amount, 4_000);
+    assert_eq!(schedule.release_timestamp, 5_000);
+    assert!(!schedule.released);
+    assert_eq!(
+        t.client.get_schedule_totals(&t.program_id).total_pending,
+        4_000
+    );
+    assert_eq!(t.token.balance(&recipient), 0);
+
+    // The reserved amount cannot be scheduled a second time
+    let result = t.client.try_single_payout_scheduled(
+        &t.program_id,
+        &Address::generate(&t.env),
+        &6_001,
+        &5_000,
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_scheduled_payout_released_when_due() {
+    let t = setup();
+    let recipient = Address::generate(&t.env);
+    let schedule_id = t
+        .client
+        .single_payout_scheduled(&t.program_id, &recipient, &4_000, &5_000);
+
+    t.env.ledger().set_timestamp(4_999);
+    assert!(t
+        .client
+        .try_release_prog_schedule_automatic(&t.program_id, &schedule_id)
+        .is_err());
+
+    t.env.ledger().set_timestamp(5_000);
+    t.client
+        .release_prog_schedule_automatic(&t.program_id, &schedule_id);
+    assert_eq!(t.token.balance(&recipient), 4_000);
+    assert!(
+        t.client
+            .get_program_release_schedule(&t.program_id, &schedule_id)
+            .released
+    );
+}
+
+#[test]
+#[should_panic(expected = "Recipient is denylisted")]
+fn test_scheduled_payout_rejects_denylisted_recipient() {
+    let t = setup();
+    let recipient = Address::generate(&t.env);
+    t.client.add_to_denylist(&t.program_id, &recipient, &false);
+    t.client
+        .single_payout_scheduled(&t.program_id, &recipient, &1_000, &5_000);
+}
+
+#[test]
+#[should_panic(expected = "Program paused by velocity guard")]
+fn test_scheduled_payout_rejected_while_velocity_paused() {
+    let t = setup();
+    t.client.set_velocity_guard(&t.program_id, &1_000, &3_600);
+    t.client
+        .single_payout(&t.program_id, &Address::generate(&t.env), &2_000);
+    assert!(t.client.is_program_paused(&t.program_id));
+
+    t.client
+        .single_payout_scheduled(&t.program_id, &Address::generate(&t.env), &1_000, &5_000);
+}
+
+#[test]
+fn test_scheduled_payout_returns_created_schedule_ids() {
+    let t = setup();
+    let recipient = Address::generate(&t.env);
+    t.client.create_program_release_schedule(
+        &t.program_id,
+        &1_000,
+        &3_000,
+        &Address::generate(&t.env),
+    );
+
+    t.env.ledger().set_timestamp(1_100);
+    let schedule_id = t
+        .client
+        .single_payout_scheduled(&t.program_id, &recipient, &2_000, &5_000);
+
+    assert_eq!(schedule_id, 2);
+    assert_eq!(
+        t.client
+            .get_program_release_schedule(&t.program_id, &schedule_id)
+            .recipient,
+        recipient
+    );
+}
+
+#[test]
+fn test_daily_cap_counted_only_at_release() {
+    let t = setup();
+    t.client.set_daily_payout_cap(&t.program_id, &4_000);
+    let recipient = Address::generate(&t.env);
+
+    let schedule_id = t
+        .client
+        .single_payout_scheduled(&t.program_id, &recipient, &4_000, &5_000);
+
+    t.env.ledger().set_timestamp(5_000);
+    t.client
+        .release_prog_schedule_automatic(&t.program_id, &schedule_id);
+
+    assert_eq!(t.token.balance(&recipient), 4_000);
+}
@@ -0,0 +1,140 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_settlement_batching.rs
+//
+// Tests for queueing `single_payout` transfers and settling them with
+// one transfer per recipient.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env, String,
+};
+
+use crate::{ProgramEscrowContract, ProgramEscrowContractClient};
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    token: token::Client<'a>,
+    program_id: String,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let payout_key = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    let token = token::Client::new(&env, &sac.address());
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&contract_id, &10_000);
+
+    let program_id = String::from_str(&env, "Batched");
+    client.initialize_program(&program_id, &payout_key, &token.address);
+    client.lock_program_funds(&program_id, &10_000);
+
+    // Payouts below run at a non-zero timestamp, so skip the cooldown
+    client.set_admin(&admin, &0);
+    client.set_whitelist(&payout_key, &true);
+
+    TestSetup {
+        env,
+        client,
+        token,
+        program_id,
+    }
+}
+
+#[test]
+fn test_flush_makes_one_transfer_per_recipient() {
+    let t = setup();
+    let alice = Address::generate(&t.env);
+    let bob = Address::generate(&t.env);
+    t.client
+        .set_settlement_batching(&t.program_id, &true, &0, &0);
+
+    for amount in [100, 200, 300] {
+        t.client.single_payout(&t.program_id, &alice, &amount);
+    }
+    t.client.single_payout(&t.program_id, &bob, &400);
+    t.client.single_payout(&t.program_id, &bob, &500);
+
+    assert_eq!(t.client.get_pending_settlements(&t.program_id).len(), 5);
+    assert_eq!(t.token.balance(&alice), 0);
+    assert_eq!(t.client.get_remaining_balance(&t.program_id), 8_500);
+
+    assert_eq!(t.client.flush_settlements(&t.program_id), 2);
+
+    assert_eq!(t.token.balance(&alice), 600);
+    assert_eq!(t.token.balance(&bob), 900);
+    assert!(t.client.get_pending_settlements(&t.program_id).is_empty());
+
+    let history = t.client.get_program_info(&t.program_id).payout_history;
+    assert_eq!(history.len(), 2);
+    assert_eq!(history.get(0).unwrap().amount, 600);
+    assert_eq!(history.get(1).unwrap().amount, 900);
+}
+
+#[test]
+fn test_threshold_triggers_flush() {
+    let t = setup();
+    let alice = Address::generate(&t.env);
+    t.client
+        .set_settlement_batching(&t.program_id, &true, &3, &0);
+
+    t.client.single_payout(&t.program_id, &alice, &100);
+    t.client.single_payout(&t.program_id, &alice, &100);
+    assert_eq!(t.token.balance(&alice), 0);
+
+    let claim_id = t.client.single_payout(&t.program_id, &alice, &100).claim_id;
+
+    assert_eq!(claim_id, 0);
+    assert_eq!(t.token.balance(&alice), 300);
+    assert!(t.client.get_pending_settlements(&t.program_id).is_empty());
+}
+
+#[test]
+fn test_max_delay_triggers_flush() {
+    let t = setup();
+    let alice = Address::generate(&t.env);
+    t.client
+        .set_settlement_batching(&t.program_id, &true, &0, &60);
+
+    t.env.ledger().set_timestamp(1_000);
+    t.client.single_payout(&t.program_id, &alice, &100);
+    t.env.ledger().set_timestamp(1_030);
+    t.client.single_payout(&t.program_id, &alice, &100);
+    assert_eq!(t.token.balance(&alice), 0);
+
+    t.env.ledger().set_timestamp(1_060);
+    t.client.single_payout(&t.program_id, &alice, &100);
+
+    assert_eq!(t.token.balance(&alice), 300);
+}
+
+#[test]
+fn test_disabling_flushes_queue() {
+    let t = setup();
+    let alice = Address::generate(&t.env);
+    t.client
+        .set_settlement_batching(&t.program_id, &true, &0, &0);
+    t.client.single_payout(&t.program_id, &alice, &250);
+
+    t.client
+        .set_settlement_batching(&t.program_id, &false, &0, &0);
+
+    assert_eq!(t.token.balance(&alice), 250);
+    assert!(
+        !t.client
+            .get_settlement_config(&t.program_id)
+            .unwrap()
+            .enabled
+    );
+    assert!(t.client.get_pending_settlements(&t.program_id).is_empty());
+}
@@ -0,0 +1,92 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_single_payout_claim_id.rs
+//
+// Tests for `single_payout` reserving a pending claim and returning its id.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, token, Address, Env, String};
+
+use crate::{ClaimStatus, ProgramEscrowContract, ProgramEscrowContractClient};
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    token: token::Client<'a>,
+    program_id: String,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let payout_key = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    let token = token::Client::new(&env, &sac.address());
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&contract_id, &100_000);
+
+    let program_id = String::from_str(&env, "ClaimIdProgram");
+    client.initialize_program(&program_id, &payout_key, &token.address);
+    client.lock_program_funds(&program_id, &100_000);
+    client.set_admin(&admin, &0);
+
+    TestSetup {
+        env,
+        client,
+        token,
+        program_id,
+    }
+}
+
+#[test]
+fn test_single_payout_returns_distinct_claim_ids() {
+    let t = setup();
+    let r1 = Address::generate(&t.env);
+    let r2 = Address::generate(&t.env);
+
+    let first = t.client.single_payout(&t.program_id, &r1, &1_000).claim_id;
+    let result = t.client.single_payout(&t.program_id, &r2, &2_000);
+    let second = result.claim_id;
+
+    assert_ne!(first, second);
+    assert_eq!(result.remaining_balance, 97_000);
+    assert_eq!(t.client.get_claim(&t.program_id, &first).recipient, r1);
+    assert_eq!(t.client.get_claim(&t.program_id, &second).amount, 2_000);
+}
+
+#[test]
+fn test_returned_claim_id_can_be_executed() {
+    let t = setup();
+    let recipient = Address::generate(&t.env);
+
+    let claim_id = t
+        .client
+        .single_payout(&t.program_id, &recipient, &5_000)
+        .claim_id;
+    assert_eq!(
+        t.client
+            .get_program_info(&t.program_id)
+            .payout_history
+            .len(),
+        0
+    );
+    assert_eq!(t.token.balance(&recipient), 0);
+    assert_eq!(
+        t.client.get_claim_status(&t.program_id, &claim_id),
+        ClaimStatus::Pending
+    );
+
+    t.client.execute_claim(&t.program_id, &claim_id, &recipient);
+
+    let program = t.client.get_program_info(&t.program_id);
+    assert_eq!(t.token.balance(&recipient), 5_000);
+    assert_eq!(program.remaining_balance, 95_000);
+    assert_eq!(program.payout_history.len(), 1);
+    assert_eq!(program.payout_history.get(0).unwrap().amount, 5_000);
+}
@@ -0,0 +1,125 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_slash_claim.rs
+//
+// Tests for `slash_claim` on conditional grants.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Events},
+    token, Address, Env, IntoVal, String, Symbol, Val, Vec,
+};
+
+use crate::{ClaimStatus, ProgramEscrowContract, ProgramEscrowContractClient};
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    token: token::Client<'a>,
+    program_id: String,
+    recipient: Address,
+    claim_id: u64,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let payout_key = Address::generate(&env);
+    let recipient = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    let token = token::Client::new(&env, &sac.address());
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&contract_id, &10_000);
+
+    let program_id = String::from_str(&env, "Grant");
+    client.initialize_program(&program_id, &payout_key, &token.address);
+    client.lock_program_funds(&program_id, &10_000);
+    let claim_id = client.create_pending_claim(&program_id, &recipient, &4_000, &5_000);
+
+    TestSetup {
+        env,
+        client,
+        token,
+        program_id,
+        recipient,
+        claim_id,
+    }
+}
+
+#[test]
+fn test_partial_slash_reduces_claim() {
+    let t = setup();
+
+    let slashed = t.client.slash_claim(&t.program_id, &t.claim_id, &2_500);
+
+    assert_eq!(slashed, 1_000);
+    assert_eq!(t.client.get_remaining_balance(&t.program_id), 7_000);
+    let claim = t.client.get_claim(&t.program_id, &t.claim_id);
+    assert_eq!(claim.amount, 3_000);
+    assert_eq!(claim.slashed_amount, 1_000);
+
+    t.client
+        .execute_claim(&t.program_id, &t.claim_id, &t.recipient);
+    assert_eq!(t.token.balance(&t.recipient), 3_000);
+}
+
+#[test]
+fn test_full_slash_revokes_claim() {
+    let t = setup();
+
+    let slashed = t.client.slash_claim(&t.program_id, &t.claim_id, &10_000);
+
+    assert_eq!(slashed, 4_000);
+    assert_eq!(t.client.get_remaining_balance(&t.program_id), 10_000);
+    assert_eq!(
+        t.client.get_claim_status(&t.program_id, &t.claim_id),
+        ClaimStatus::Revoked
+    );
+    assert!(t
+        .client
+        .try_execute_claim(&t.program_id, &t.claim_id, &t.recipient)
+        .is_err());
+    assert_eq!(t.token.balance(&t.recipient), 0);
+}
+
+#[test]
+fn test_slash_is_recorded_in_event() {
+    let t = setup();
+    t.client.slash_claim(&t.program_id, &t.claim_id, &500);
+
+    let expected: Vec<Val> = (Symbol::new(&t.env, "ClmSlsh"),).into_val(&t.env);
+    let mut slashes: Vec<(String, u64, Address, u32, i128, i128)> = Vec::new(&t.env);
+    for (_, topics, data) in t.env.events().all().iter() {
+        if topics == expected {
+            slashes.push_back(data.into_val(&t.env));
+        }
+    }
+
+    assert_eq!(
+        slashes,
+        Vec::from_array(
+            &t.env,
+            [(
+                t.program_id.clone(),
+                t.claim_id,
+                t.recipient.clone(),
+                500,
+                200,
+                3_800
+            )]
+        )
+    );
+}
+
+#[test]
+#[should_panic(expected = "Slash cannot exceed 10000 bps")]
+fn test_slash_above_100_percent_rejected() {
+    let t = setup();
+    t.client.slash_claim(&t.program_id, &t.claim_id, &10_001);
+}
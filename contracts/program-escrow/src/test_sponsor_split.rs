@@ -0,0 +1,105 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_sponsor_split.rs
+//
+// Tests for the program-configured sponsor split on `single_payout`.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, token, Address, Env, String};
+
+use crate::{ProgramEscrowContract, ProgramEscrowContractClient};
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    token: token::Client<'a>,
+    program_id: String,
+    sponsor: Address,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let payout_key = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    let token = token::Client::new(&env, &sac.address());
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&contract_id, &100_000);
+
+    let program_id = String::from_str(&env, "Sponsored");
+    client.initialize_program(&program_id, &payout_key, &token.address);
+    client.lock_program_funds(&program_id, &100_000);
+
+    TestSetup {
+        sponsor: Address::generate(&env),
+        env,
+        client,
+        token,
+        program_id,
+    }
+}
+
+#[test]
+fn test_sponsor_split_divides_payout() {
+    let t = setup();
+    let recipient = Address::generate(&t.env);
+    t.client.set_sponsor_split(&t.program_id, &t.sponsor, &2_000); // 20%
+
+    let result = t.client.single_payout(&t.program_id, &recipient, &10_000);
+
+    assert_eq!(t.token.balance(&t.sponsor), 2_000);
+    assert_eq!(
+        t.client.get_claim(&t.program_id, &result.claim_id).amount,
+        8_000
+    );
+    assert_eq!(result.remaining_balance, 90_000);
+}
+
+#[test]
+fn test_both_legs_recorded_in_history() {
+    let t = setup();
+    let recipient = Address::generate(&t.env);
+    t.client.set_sponsor_split(&t.program_id, &t.sponsor, &2_500);
+
+    let claim_id = t.client.single_payout(&t.program_id, &recipient, &4_000).claim_id;
+    t.client.execute_claim(&t.program_id, &claim_id, &recipient);
+
+    let history = t.client.get_program_info(&t.program_id).payout_history;
+    assert_eq!(history.len(), 2);
+    assert_eq!(history.get(0).unwrap().recipient, t.sponsor);
+    assert_eq!(history.get(0).unwrap().amount, 1_000);
+    assert_eq!(history.get(1).unwrap().recipient, recipient);
+    assert_eq!(history.get(1).unwrap().amount, 3_000);
+    assert_eq!(t.token.balance(&recipient), 3_000);
+}
+
+#[test]
+fn test_zero_bps_removes_split() {
+    let t = setup();
+    let recipient = Address::generate(&t.env);
+    t.client.set_sponsor_split(&t.program_id, &t.sponsor, &1_000);
+    assert_eq!(
+        t.client.get_sponsor_split(&t.program_id).unwrap().bps,
+        1_000
+    );
+
+    t.client.set_sponsor_split(&t.program_id, &t.sponsor, &0);
+    assert!(t.client.get_sponsor_split(&t.program_id).is_none());
+
+    let claim_id = t.client.single_payout(&t.program_id, &recipient, &5_000).claim_id;
+    assert_eq!(t.token.balance(&t.sponsor), 0);
+    assert_eq!(t.client.get_claim(&t.program_id, &claim_id).amount, 5_000);
+}
+
+#[test]
+#[should_panic(expected = "Sponsor split cannot exceed 10000 bps")]
+fn test_split_above_100_percent_rejected() {
+    let t = setup();
+    t.client.set_sponsor_split(&t.program_id, &t.sponsor, &10_001);
+}
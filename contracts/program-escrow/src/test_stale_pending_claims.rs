@@ -0,0 +1,100 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_stale_pending_claims.rs
+//
+// Tests for listing pending claims that recipients have left
+// unclaimed for a while.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, Env, String,
+};
+
+use crate::{ProgramEscrowContract, ProgramEscrowContractClient};
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    program_id: String,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let admin = Address::generate(&env);
+    let payout_key = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&contract_id, &100_000);
+
+    let program_id = String::from_str(&env, "Nudges");
+    client.initialize_program(&program_id, &payout_key, &sac.address());
+    client.lock_program_funds(&program_id, &100_000);
+
+    TestSetup {
+        env,
+        client,
+        program_id,
+    }
+}
+
+fn claim_at(t: &TestSetup, timestamp: u64) -> u64 {
+    t.env.ledger().set_timestamp(timestamp);
+    t.client
+        .single_payout(&t.program_id, &Address::generate(&t.env), &1_000)
+        .claim_id
+}
+
+#[test]
+fn test_only_sufficiently_old_claims_are_stale() {
+    let t = setup();
+    let oldest = claim_at(&t, 2_000);
+    let older = claim_at(&t, 12_000);
+    let recent = claim_at(&t, 22_000);
+
+    t.env.ledger().set_timestamp(31_000);
+    assert_eq!(
+        t.client.get_stale_pending_claims(&t.program_id, &15_000),
+        vec![&t.env, oldest, older]
+    );
+    assert_eq!(
+        t.client.get_stale_pending_claims(&t.program_id, &5_000),
+        vec![&t.env, oldest, older, recent]
+    );
+    assert_eq!(
+        t.client.get_stale_pending_claims(&t.program_id, &30_000),
+        vec![&t.env]
+    );
+}
+
+#[test]
+fn test_executed_and_expired_claims_are_not_stale() {
+    let t = setup();
+    let expiring = claim_at(&t, 2_000);
+    let waiting = claim_at(&t, 12_000);
+    t.env.ledger().set_timestamp(13_000);
+    let recipient = Address::generate(&t.env);
+    let executed = t
+        .client
+        .single_payout(&t.program_id, &recipient, &1_000)
+        .claim_id;
+    t.client.execute_claim(&t.program_id, &executed, &recipient);
+
+    // The default 24h window has passed for the first claim only
+    t.env.ledger().set_timestamp(90_000);
+    assert_eq!(
+        t.client.get_pending_claim_ids(&t.program_id),
+        vec![&t.env, expiring, waiting]
+    );
+    assert_eq!(
+        t.client.get_stale_pending_claims(&t.program_id, &15_000),
+        vec![&t.env, waiting]
+    );
+}
@@ -0,0 +1,121 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_tier_rate_limits.rs
+//
+// Tests for identity-tier multipliers on the rate limiter.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env, String,
+};
+
+use crate::anti_abuse::{IdentityTier, RateLimitMode, TierRateMultipliers};
+use crate::{ProgramEscrowContract, ProgramEscrowContractClient};
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    token: Address,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.set_admin(&admin, &0);
+    client.update_rate_limit_config(&3600, &3, &0, &1);
+    client.set_tier_rate_multipliers(&2, &3, &5);
+
+    TestSetup {
+        token: Address::generate(&env),
+        env,
+        client,
+    }
+}
+
+/// Registers programs with `backend` until one is rejected, returning how
+/// many succeeded.
+fn run_until_blocked(t: &TestSetup, backend: &Address, prefix: u8) -> u32 {
+    let mut done = 0u32;
+    loop {
+        let name = [b'T', prefix, b'-', b'a' + done as u8];
+        let result =
+            t.client
+                .try_initialize_program(&String::from_bytes(&t.env, &name), backend, &t.token);
+        if result.is_err() {
+            return done;
+        }
+        done += 1;
+    }
+}
+
+#[test]
+fn test_premium_caller_gets_more_operations() {
+    let t = setup();
+    let unverified = Address::generate(&t.env);
+    let premium = Address::generate(&t.env);
+    t.client.set_address_tier(&premium, &IdentityTier::Premium);
+
+    assert_eq!(run_until_blocked(&t, &unverified, b'u'), 3);
+    assert_eq!(run_until_blocked(&t, &premium, b'p'), 15);
+}
+
+#[test]
+fn test_each_tier_uses_its_multiplier() {
+    let t = setup();
+    let basic = Address::generate(&t.env);
+    let verified = Address::generate(&t.env);
+    t.client.set_address_tier(&basic, &IdentityTier::Basic);
+    t.client
+        .set_address_tier(&verified, &IdentityTier::Verified);
+
+    assert_eq!(t.client.get_available_ops(&basic), 6);
+    assert_eq!(t.client.get_available_ops(&verified), 9);
+    assert_eq!(run_until_blocked(&t, &verified, b'v'), 9);
+}
+
+#[test]
+fn test_tier_multiplier_scales_leaky_bucket() {
+    let t = setup();
+    t.client.set_rate_limit_mode(&RateLimitMode::LeakyBucket);
+    let premium = Address::generate(&t.env);
+    t.client.set_address_tier(&premium, &IdentityTier::Premium);
+
+    assert_eq!(run_until_blocked(&t, &premium, b'p'), 15);
+
+    // 15 ops per 3600s refills one op every 240s.
+    t.env.ledger().set_timestamp(1_000 + 480);
+    assert_eq!(t.client.get_available_ops(&premium), 2);
+}
+
+#[test]
+fn test_tier_defaults() {
+    let t = setup();
+    let address = Address::generate(&t.env);
+    assert_eq!(
+        t.client.get_address_tier(&address),
+        IdentityTier::Unverified
+    );
+    assert_eq!(
+        t.client.get_tier_rate_multipliers(),
+        TierRateMultipliers {
+            basic: 2,
+            verified: 3,
+            premium: 5,
+        }
+    );
+}
+
+#[test]
+#[should_panic(expected = "Tier multiplier must be at least 1")]
+fn test_zero_multiplier_panics() {
+    let t = setup();
+    t.client.set_tier_rate_multipliers(&1, &0, &2);
+}
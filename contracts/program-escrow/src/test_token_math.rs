@@ -245,3 +245,99 @@ fn fee_monotonic_with_amount() {
         prev = fee;
     }
 }
+
+// ===========================================================================
+// 7. format_amount / parse_amount
+// ===========================================================================
+
+use soroban_sdk::{testutils::Address as _, token, Address, Env, String};
+
+use crate::{ProgramEscrowContract, ProgramEscrowContractClient};
+
+fn fmt(env: &Env, raw: i128, decimals: u32) -> String {
+    token_math::format_amount(env, raw, decimals).unwrap()
+}
+
+fn parse(env: &Env, s: &str, decimals: u32) -> Option<i128> {
+    token_math::parse_amount(&String::from_str(env, s), decimals)
+}
+
+#[test]
+fn format_zero_pads_fraction() {
+    let env = Env::default();
+    assert_eq!(fmt(&env, 5, 7), String::from_str(&env, "0.0000005"));
+    assert_eq!(fmt(&env, 5, 6), String::from_str(&env, "0.000005"));
+    assert_eq!(fmt(&env, 1_050_000, 6), String::from_str(&env, "1.05"));
+}
+
+#[test]
+fn format_trims_trailing_zeros_and_point() {
+    let env = Env::default();
+    assert_eq!(fmt(&env, 12_500_000, 6), String::from_str(&env, "12.5"));
+    assert_eq!(fmt(&env, 100_0000000, 7), String::from_str(&env, "100"));
+    assert_eq!(fmt(&env, 0, 7), String::from_str(&env, "0"));
+    assert_eq!(fmt(&env, -25, 2), String::from_str(&env, "-0.25"));
+}
+
+#[test]
+fn round_trip_6_and_7_decimals() {
+    let env = Env::default();
+    for decimals in [6_u32, 7] {
+        for raw in [0_i128, 1, 9, 10, 123_456, 1_000_000, 10_000_001, 987_654_321_012] {
+            let s = fmt(&env, raw, decimals);
+            assert_eq!(token_math::parse_amount(&s, decimals), Some(raw));
+        }
+    }
+}
+
+#[test]
+fn parse_truncates_excess_precision() {
+    let env = Env::default();
+    // 8 fraction digits into a 6-decimal token: the last two are dropped
+    assert_eq!(parse(&env, "1.23456789", 6), Some(1_234_567));
+    assert_eq!(parse(&env, "0.0000009", 6), Some(0));
+    assert_eq!(parse(&env, "0.0000009", 7), Some(9));
+}
+
+#[test]
+fn parse_pads_short_fraction() {
+    let env = Env::default();
+    assert_eq!(parse(&env, "12.5", 7), Some(125_000_000));
+    assert_eq!(parse(&env, "3", 6), Some(3_000_000));
+    assert_eq!(parse(&env, "007.10", 6), Some(7_100_000));
+}
+
+#[test]
+fn parse_rejects_malformed() {
+    let env = Env::default();
+    for s in ["", "-", ".5", "5.", "1.2.3", "1,5", "abc", " 1"] {
+        assert_eq!(parse(&env, s, 7), None, "accepted {:?}", s);
+    }
+}
+
+#[test]
+fn parse_overflow_returns_none() {
+    let env = Env::default();
+    assert_eq!(parse(&env, "99999999999999999999999999999999999999999", 7), None);
+}
+
+#[test]
+fn contract_formats_with_program_token_decimals() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(Address::generate(&env));
+    assert_eq!(token::Client::new(&env, &sac.address()).decimals(), 7);
+
+    let program_id = String::from_str(&env, "Display");
+    client.initialize_program(&program_id, &Address::generate(&env), &sac.address());
+
+    let s = client.format_amount(&program_id, &12_500_000);
+    assert_eq!(s, String::from_str(&env, "1.25"));
+    assert_eq!(client.parse_amount(&program_id, &s), 12_500_000);
+    assert!(client
+        .try_parse_amount(&program_id, &String::from_str(&env, "1.2.5"))
+        .is_err());
+}
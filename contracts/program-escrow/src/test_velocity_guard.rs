@@ -0,0 +1,134 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_velocity_guard.rs
+//
+// Tests for the payout velocity guard and `unpause_program`.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger},
+    token, vec, Address, Env, IntoVal, String, Symbol, Val, Vec,
+};
+
+use crate::{ProgramEscrowContract, ProgramEscrowContractClient};
+
+const HOUR: u64 = 3_600;
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    program_id: String,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let payout_key = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&contract_id, &100_000);
+
+    let program_id = String::from_str(&env, "Guarded");
+    client.initialize_program(&program_id, &payout_key, &sac.address());
+    client.set_admin(&admin, &0);
+    client.set_whitelist(&payout_key, &true);
+    client.lock_program_funds(&program_id, &100_000);
+    client.set_velocity_guard(&program_id, &5_000, &HOUR);
+
+    TestSetup {
+        env,
+        client,
+        program_id,
+    }
+}
+
+fn pay(t: &TestSetup, amount: i128) {
+    t.client
+        .single_payout(&t.program_id, &Address::generate(&t.env), &amount);
+}
+
+#[test]
+fn test_payouts_within_limit_do_not_pause() {
+    let t = setup();
+    pay(&t, 2_000);
+    pay(&t, 3_000);
+
+    assert!(!t.client.is_program_paused(&t.program_id));
+}
+
+#[test]
+fn test_burst_over_limit_pauses_program() {
+    let t = setup();
+    pay(&t, 2_000);
+    pay(&t, 2_000);
+    pay(&t, 2_000);
+
+    assert!(t.client.is_program_paused(&t.program_id));
+    assert_eq!(t.client.get_remaining_balance(&t.program_id), 94_000);
+
+    let expected: Vec<Val> = (Symbol::new(&t.env, "velocity_breach"),).into_val(&t.env);
+    let mut breaches: Vec<(String, i128, i128, u64)> = Vec::new(&t.env);
+    for (_, topics, data) in t.env.events().all().iter() {
+        if topics == expected {
+            breaches.push_back(data.into_val(&t.env));
+        }
+    }
+    assert_eq!(
+        breaches,
+        vec![&t.env, (t.program_id.clone(), 6_000, 5_000, HOUR)]
+    );
+}
+
+#[test]
+#[should_panic(expected = "Program paused by velocity guard")]
+fn test_next_payout_blocked_after_breach() {
+    let t = setup();
+    pay(&t, 6_000);
+
+    pay(&t, 1);
+}
+
+#[test]
+#[should_panic(expected = "Program paused by velocity guard")]
+fn test_batch_payout_blocked_after_breach() {
+    let t = setup();
+    pay(&t, 6_000);
+
+    let recipient = Address::generate(&t.env);
+    t.client.batch_payout(
+        &t.program_id,
+        &vec![&t.env, recipient],
+        &vec![&t.env, 100],
+        &false,
+        &None,
+        &100,
+    );
+}
+
+#[test]
+fn test_outflow_resets_each_window() {
+    let t = setup();
+    pay(&t, 4_000);
+    t.env.ledger().set_timestamp(HOUR + 1);
+    pay(&t, 4_000);
+
+    assert!(!t.client.is_program_paused(&t.program_id));
+}
+
+#[test]
+fn test_unpause_program_resumes_payouts() {
+    let t = setup();
+    pay(&t, 6_000);
+
+    t.client.unpause_program(&t.program_id);
+    assert!(!t.client.is_program_paused(&t.program_id));
+
+    pay(&t, 4_000);
+    assert!(!t.client.is_program_paused(&t.program_id));
+}
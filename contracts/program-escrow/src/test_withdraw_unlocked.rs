@@ -0,0 +1,106 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/test_withdraw_unlocked.rs
+//
+// Tests for organizer withdrawal of unlocked program funds,
+// including withdrawal to a separate destination address.
+// ============================================================
+
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env, String,
+};
+
+use crate::{ProgramEscrowContract, ProgramEscrowContractClient};
+
+struct TestSetup<'a> {
+    env: Env,
+    client: ProgramEscrowContractClient<'a>,
+    token: token::Client<'a>,
+    organizer: Address,
+    program_id: String,
+}
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let admin = Address::generate(&env);
+    let organizer = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    let token = token::Client::new(&env, &sac.address());
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&contract_id, &10_000);
+
+    client.set_admin(&admin, &0);
+    client.set_whitelist(&organizer, &true);
+
+    let program_id = String::from_str(&env, "Withdrawable");
+    client.initialize_program(&program_id, &organizer, &token.address);
+    client.lock_program_funds(&program_id, &10_000);
+
+    TestSetup {
+        env,
+        client,
+        token,
+        organizer,
+        program_id,
+    }
+}
+
+#[test]
+fn test_withdraw_to_distinct_destination() {
+    let t = setup();
+    let cold_wallet = Address::generate(&t.env);
+
+    let data = t
+        .client
+        .withdraw_unlocked_prog_funds_to(&t.program_id, &4_000, &cold_wallet);
+
+    assert_eq!(data.remaining_balance, 6_000);
+    assert_eq!(t.token.balance(&cold_wallet), 4_000);
+    assert_eq!(t.token.balance(&t.organizer), 0);
+}
+
+#[test]
+fn test_withdraw_defaults_to_organizer() {
+    let t = setup();
+
+    let data = t
+        .client
+        .withdraw_unlocked_program_funds(&t.program_id, &2_500);
+
+    assert_eq!(data.remaining_balance, 7_500);
+    assert_eq!(t.token.balance(&t.organizer), 2_500);
+}
+
+#[test]
+fn test_reserved_funds_cannot_be_withdrawn() {
+    let t = setup();
+    let recipient = Address::generate(&t.env);
+    t.client.single_payout(&t.program_id, &recipient, &3_000);
+    t.client
+        .create_program_release_schedule(&t.program_id, &2_000, &5_000, &recipient);
+
+    assert!(t
+        .client
+        .try_withdraw_unlocked_program_funds(&t.program_id, &5_001)
+        .is_err());
+    let data = t
+        .client
+        .withdraw_unlocked_program_funds(&t.program_id, &5_000);
+    assert_eq!(data.remaining_balance, 2_000);
+}
+
+#[test]
+#[should_panic(expected = "Insufficient unlocked balance")]
+fn test_withdraw_more_than_balance_panics() {
+    let t = setup();
+    t.client
+        .withdraw_unlocked_prog_funds_to(&t.program_id, &10_001, &Address::generate(&t.env));
+}
@@ -12,6 +12,15 @@
 //! Stellar tokens can have different decimal places (e.g. 7 for XLM/stroops,
 //! 6 for USDC). The helpers here convert between decimal scales using floor
 //! rounding when scaling down (higher → lower precision).
+//!
+//! ## Display Strings
+//!
+//! `format_amount` / `parse_amount` convert raw units to and from decimal
+//! strings for receipts and event payloads. Formatting is exact; parsing
+//! truncates fraction digits beyond the token's precision, in line with the
+//! round-down policy above.
+
+use soroban_sdk::{Env, String};
 
 /// Basis-point denominator (1 bp = 0.01%).
 pub const BASIS_POINTS: i128 = 10_000;
@@ -66,3 +75,127 @@ pub fn to_base_units(amount: i128, decimals: u32) -> Option<i128> {
     let factor = 10_i128.checked_pow(decimals)?;
     amount.checked_mul(factor)
 }
+
+/// Longest decimal string accepted by `parse_amount` / produced by
+/// `format_amount` (sign, 39 integer digits, point and 38 fraction digits).
+const MAX_AMOUNT_STR_LEN: usize = 80;
+
+/// Maximum token decimals supported by the string helpers (`10^38` is the
+/// largest power of ten that fits in `u128`).
+pub const MAX_DISPLAY_DECIMALS: u32 = 38;
+
+/// Format raw token units as a decimal string.
+///
+/// The fraction is zero-padded to the token's precision and trailing zeros
+/// are trimmed, e.g. `format_amount(env, 5, 7)` → `"0.0000005"` and
+/// `format_amount(env, 12_500_000, 6)` → `"12.5"`. Whole amounts have no
+/// decimal point. Returns `None` if `decimals > MAX_DISPLAY_DECIMALS`.
+pub fn format_amount(env: &Env, raw: i128, decimals: u32) -> Option<String> {
+    if decimals > MAX_DISPLAY_DECIMALS {
+        return None;
+    }
+    let factor = 10_u128.pow(decimals);
+    let abs = raw.unsigned_abs();
+    let mut whole = abs / factor;
+    let mut frac = abs % factor;
+
+    let mut buf = [0u8; MAX_AMOUNT_STR_LEN];
+    let mut len = 0;
+    if raw < 0 {
+        buf[len] = b'-';
+        len += 1;
+    }
+
+    // Integer digits, most significant first
+    let mut digits = [0u8; 39];
+    let mut count = 0;
+    loop {
+        digits[count] = b'0' + (whole % 10) as u8;
+        count += 1;
+        whole /= 10;
+        if whole == 0 {
+            break;
+        }
+    }
+    while count > 0 {
+        count -= 1;
+        buf[len] = digits[count];
+        len += 1;
+    }
+
+    if frac > 0 {
+        // Fraction digits zero-padded to `decimals`, then trailing zeros trimmed
+        let mut frac_digits = [b'0'; MAX_DISPLAY_DECIMALS as usize];
+        let mut i = decimals as usize;
+        while i > 0 {
+            i -= 1;
+            frac_digits[i] = b'0' + (frac % 10) as u8;
+            frac /= 10;
+        }
+        let mut end = decimals as usize;
+        while frac_digits[end - 1] == b'0' {
+            end -= 1;
+        }
+        buf[len] = b'.';
+        len += 1;
+        buf[len..len + end].copy_from_slice(&frac_digits[..end]);
+        len += end;
+    }
+
+    Some(String::from_bytes(env, &buf[..len]))
+}
+
+/// Parse a decimal string into raw token units.
+///
+/// Accepts an optional leading `-`, at least one integer digit and an
+/// optional fraction (`"12"`, `"12.5"`, `"-0.000001"`). Fraction digits beyond
+/// `decimals` are truncated. Returns `None` for malformed input, overflow or
+/// `decimals > MAX_DISPLAY_DECIMALS`.
+pub fn parse_amount(s: &String, decimals: u32) -> Option<i128> {
+    if decimals > MAX_DISPLAY_DECIMALS {
+        return None;
+    }
+    let len = s.len() as usize;
+    if len == 0 || len > MAX_AMOUNT_STR_LEN {
+        return None;
+    }
+    let mut buf = [0u8; MAX_AMOUNT_STR_LEN];
+    s.copy_into_slice(&mut buf[..len]);
+    let bytes = &buf[..len];
+
+    let (negative, body) = match bytes[0] {
+        b'-' => (true, &bytes[1..]),
+        _ => (false, bytes),
+    };
+    let (whole_part, frac_part) = match body.iter().position(|b| *b == b'.') {
+        Some(dot) => (&body[..dot], Some(&body[dot + 1..])),
+        None => (body, None),
+    };
+    if whole_part.is_empty() || frac_part.is_some_and(|f| f.is_empty()) {
+        return None;
+    }
+
+    let mut whole: i128 = 0;
+    for b in whole_part {
+        if !b.is_ascii_digit() {
+            return None;
+        }
+        whole = whole.checked_mul(10)?.checked_add((b - b'0') as i128)?;
+    }
+
+    let mut frac: i128 = 0;
+    let mut frac_len: u32 = 0;
+    for b in frac_part.unwrap_or(&[]) {
+        if !b.is_ascii_digit() {
+            return None;
+        }
+        if frac_len < decimals {
+            frac = frac * 10 + (b - b'0') as i128;
+            frac_len += 1;
+        }
+    }
+    frac = frac.checked_mul(10_i128.checked_pow(decimals - frac_len)?)?;
+
+    let raw = to_base_units(whole, decimals)?.checked_add(frac)?;
+    Some(if negative { -raw } else { raw })
+}
@@ -0,0 +1,136 @@
+// ============================================================
+// FILE: contracts/program-escrow/src/velocity_guard.rs
+//
+// Circuit breaker that pauses a program on anomalous payout velocity.
+//
+// The admin configures a maximum outflow per fixed window. Every payout
+// adds its gross amount to the current window; once the total exceeds the
+// maximum, the program is paused and a `velocity_breach` event is emitted.
+// The breaching payout itself still settles, but every later payout is
+// rejected until the admin calls `unpause_program`. This bounds how much a
+// compromised payout key can drain before someone intervenes.
+//
+// Storage:
+//   DataKey::VelocityGuard(String)      → VelocityGuard
+//   DataKey::VelocityState(String)      → VelocityState
+//   DataKey::VelocityPaused(String)     → bool, present while paused
+// ============================================================
+
+use crate::DataKey;
+use soroban_sdk::{contracttype, symbol_short, Env, String, Symbol};
+
+const GUARD_SET: Symbol = symbol_short!("vel_set");
+const PROGRAM_UNPAUSED: Symbol = symbol_short!("prg_unpse");
+
+/// Outflow limit for a program.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VelocityGuard {
+    pub max_outflow_per_window: i128,
+    pub window_secs: u64,
+}
+
+/// Outflow recorded in the current window.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VelocityState {
+    pub window_start: u64,
+    pub outflow: i128,
+}
+
+/// Configures the velocity guard for a program and starts a fresh window.
+pub fn set_guard(env: &Env, program_id: &String, max_outflow_per_window: i128, window_secs: u64) {
+    if max_outflow_per_window <= 0 || window_secs == 0 {
+        panic!("Invalid velocity guard");
+    }
+
+    let guard = VelocityGuard {
+        max_outflow_per_window,
+        window_secs,
+    };
+    env.storage()
+        .instance()
+        .set(&DataKey::VelocityGuard(program_id.clone()), &guard);
+    env.storage()
+        .instance()
+        .remove(&DataKey::VelocityState(program_id.clone()));
+
+    env.events()
+        .publish((GUARD_SET,), (program_id.clone(), guard));
+}
+
+/// Returns the velocity guard for a program, if any.
+pub fn get_guard(env: &Env, program_id: &String) -> Option<VelocityGuard> {
+    env.storage()
+        .instance()
+        .get(&DataKey::VelocityGuard(program_id.clone()))
+}
+
+/// Whether the program has been paused by the guard.
+pub fn is_paused(env: &Env, program_id: &String) -> bool {
+    env.storage()
+        .instance()
+        .has(&DataKey::VelocityPaused(program_id.clone()))
+}
+
+/// Panics if the program has been paused by the guard.
+pub fn require_not_paused(env: &Env, program_id: &String) {
+    if is_paused(env, program_id) {
+        panic!("Program paused by velocity guard");
+    }
+}
+
+/// Adds `amount` to the current window and pauses the program if the
+/// window's outflow now exceeds the configured maximum.
+pub fn record(env: &Env, program_id: &String, amount: i128) {
+    let guard = match get_guard(env, program_id) {
+        Some(guard) => guard,
+        None => return,
+    };
+
+    let now = env.ledger().timestamp();
+    let state_key = DataKey::VelocityState(program_id.clone());
+    let mut state: VelocityState =
+        env.storage()
+            .instance()
+            .get(&state_key)
+            .unwrap_or(VelocityState {
+                window_start: now,
+                outflow: 0,
+            });
+    if now.saturating_sub(state.window_start) >= guard.window_secs {
+        state.window_start = now;
+        state.outflow = 0;
+    }
+    state.outflow += amount;
+    env.storage().instance().set(&state_key, &state);
+
+    if state.outflow > guard.max_outflow_per_window && !is_paused(env, program_id) {
+        env.storage()
+            .instance()
+            .set(&DataKey::VelocityPaused(program_id.clone()), &true);
+        env.events().publish(
+            (Symbol::new(env, "velocity_breach"),),
+            (
+                program_id.clone(),
+                state.outflow,
+                guard.max_outflow_per_window,
+                guard.window_secs,
+            ),
+        );
+    }
+}
+
+/// Lifts a velocity pause and resets the window so the next payout starts
+/// from zero.
+pub fn unpause(env: &Env, program_id: &String) {
+    env.storage()
+        .instance()
+        .remove(&DataKey::VelocityPaused(program_id.clone()));
+    env.storage()
+        .instance()
+        .remove(&DataKey::VelocityState(program_id.clone()));
+
+    env.events()
+        .publish((PROGRAM_UNPAUSED,), (program_id.clone(),));
+}
@@ -3,7 +3,7 @@
 //! Handles off-chain identity claims, signature verification, and tier-based limits
 
 use soroban_sdk::xdr::ToXdr;
-use soroban_sdk::{contracttype, Address, Bytes, BytesN, Env};
+use soroban_sdk::{contracttype, Address, Bytes, BytesN, Env, Vec};
 
 use crate::Error;
 
@@ -39,6 +39,39 @@ pub struct AddressIdentity {
     pub last_updated: u64,
 }
 
+/// Matching claims collected so far toward a tier's issuer quorum
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingIdentityQuorum {
+    pub tier: IdentityTier,
+    pub risk_score: u32,
+    pub expiry: u64,
+    pub issuers: Vec<Address>,
+}
+
+/// Aggregate counts of stored identities, for compliance dashboards
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IdentityStats {
+    pub unverified_count: u32,
+    pub basic_count: u32,
+    pub verified_count: u32,
+    pub premium_count: u32,
+    /// Stored identities (of any tier) whose claim has expired
+    pub expired_count: u32,
+}
+
+/// Risk score bands used to derive a claim's tier instead of trusting the
+/// tier the issuer supplied. Each field is the highest risk score (inclusive)
+/// that still earns the tier; anything above `basic_max_risk` is unverified.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RiskTierBands {
+    pub premium_max_risk: u32,  // e.g., 20
+    pub verified_max_risk: u32, // e.g., 50
+    pub basic_max_risk: u32,    // e.g., 80
+}
+
 /// Configuration for tier-based transaction limits
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -79,6 +112,40 @@ impl Default for TierLimits {
     }
 }
 
+impl TierLimits {
+    /// Transaction limit configured for `tier`
+    pub fn limit_for(&self, tier: &IdentityTier) -> i128 {
+        match tier {
+            IdentityTier::Unverified => self.unverified_limit,
+            IdentityTier::Basic => self.basic_limit,
+            IdentityTier::Verified => self.verified_limit,
+            IdentityTier::Premium => self.premium_limit,
+        }
+    }
+}
+
+impl RiskTierBands {
+    /// Bands must be ascending and within the 0-100 risk score range
+    pub fn is_valid(&self) -> bool {
+        self.premium_max_risk <= self.verified_max_risk
+            && self.verified_max_risk <= self.basic_max_risk
+            && self.basic_max_risk <= 100
+    }
+
+    /// Tier earned by `risk_score`
+    pub fn tier_for(&self, risk_score: u32) -> IdentityTier {
+        if risk_score <= self.premium_max_risk {
+            IdentityTier::Premium
+        } else if risk_score <= self.verified_max_risk {
+            IdentityTier::Verified
+        } else if risk_score <= self.basic_max_risk {
+            IdentityTier::Basic
+        } else {
+            IdentityTier::Unverified
+        }
+    }
+}
+
 impl Default for RiskThresholds {
     fn default() -> Self {
         Self {
@@ -137,6 +204,59 @@ pub fn is_claim_expired(env: &Env, expiry: u64) -> bool {
     now >= expiry
 }
 
+/// Check a claim's expiry is no further than `max_validity_secs` from now
+pub fn is_validity_within_cap(env: &Env, expiry: u64, max_validity_secs: Option<u64>) -> bool {
+    match max_validity_secs {
+        Some(max) => expiry <= env.ledger().timestamp().saturating_add(max),
+        None => true,
+    }
+}
+
+/// Check whether an address whose identity is `existing` may take a claim for
+/// `tier` now. Upgrades are always allowed; refreshes and downgrades must wait
+/// `cooldown_secs` after the last update.
+pub fn is_update_allowed(
+    env: &Env,
+    existing: Option<&AddressIdentity>,
+    tier: &IdentityTier,
+    cooldown_secs: u64,
+) -> bool {
+    match existing {
+        Some(identity) if *tier <= identity.tier => {
+            env.ledger().timestamp() >= identity.last_updated.saturating_add(cooldown_secs)
+        }
+        _ => true,
+    }
+}
+
+/// Add `claim`'s issuer to a pending quorum. A claim whose tier, risk score
+/// or expiry differs from the pending one starts a new quorum.
+pub fn add_quorum_vote(
+    env: &Env,
+    pending: Option<PendingIdentityQuorum>,
+    claim: &IdentityClaim,
+) -> PendingIdentityQuorum {
+    let mut quorum = match pending {
+        Some(p)
+            if p.tier == claim.tier
+                && p.risk_score == claim.risk_score
+                && p.expiry == claim.expiry =>
+        {
+            p
+        }
+        _ => PendingIdentityQuorum {
+            tier: claim.tier.clone(),
+            risk_score: claim.risk_score,
+            expiry: claim.expiry,
+            issuers: Vec::new(env),
+        },
+    };
+    if !quorum.issuers.contains(&claim.issuer) {
+        quorum.issuers.push_back(claim.issuer.clone());
+    }
+    quorum
+}
+
 /// Validate claim format and fields
 pub fn validate_claim(claim: &IdentityClaim) -> Result<(), Error> {
     // Validate risk score is in valid range (0-100)
@@ -147,27 +267,30 @@ pub fn validate_claim(claim: &IdentityClaim) -> Result<(), Error> {
     Ok(())
 }
 
-/// Calculate effective transaction limit based on tier and risk score
+/// Calculate effective transaction limit based on tier and risk score.
+///
+/// A risk-adjusted limit never drops below `min_payout` (capped at the tier
+/// limit itself), so rounding cannot lock out high-risk users on small
+/// tiers. Only an explicit multiplier of 0 blocks them entirely.
 pub fn calculate_effective_limit(
     env: &Env,
     identity: &AddressIdentity,
     tier_limits: &TierLimits,
     risk_thresholds: &RiskThresholds,
+    min_payout: i128,
 ) -> i128 {
     // Get tier-based limit
-    let tier_limit = match identity.tier {
-        IdentityTier::Unverified => tier_limits.unverified_limit,
-        IdentityTier::Basic => tier_limits.basic_limit,
-        IdentityTier::Verified => tier_limits.verified_limit,
-        IdentityTier::Premium => tier_limits.premium_limit,
-    };
+    let tier_limit = tier_limits.limit_for(&identity.tier);
 
     // Apply risk-based adjustment if risk score is high
     if identity.risk_score >= risk_thresholds.high_risk_threshold {
         // Reduce limit by risk multiplier percentage
         let multiplier = risk_thresholds.high_risk_multiplier as i128;
+        if multiplier == 0 {
+            return 0;
+        }
         let risk_adjusted_limit = (tier_limit * multiplier) / 100;
-        risk_adjusted_limit
+        risk_adjusted_limit.max(min_payout.min(tier_limit))
     } else {
         tier_limit
     }
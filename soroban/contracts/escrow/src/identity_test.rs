@@ -109,6 +109,113 @@ fn test_set_risk_thresholds() {
     // Function should not panic
 }
 
+#[test]
+fn test_get_risk_thresholds_defaults() {
+    let env = Env::default();
+    let (client, _contract_id, _admin, _depositor, _contributor, _issuer, _token_client) =
+        setup_with_identity(&env, 10_000i128);
+
+    assert_eq!(client.get_risk_thresholds(), RiskThresholds::default());
+}
+
+#[test]
+fn test_custom_risk_thresholds_adjust_enforced_limit() {
+    let env = Env::default();
+    let (client, _contract_id, _admin, depositor, _contributor, _issuer, _token_client) =
+        setup_with_identity(&env, 1000_0000000);
+
+    // A threshold of 0 treats every address as high risk: 40% of 100 tokens
+    client.set_risk_thresholds(&0, &40);
+
+    let thresholds = client.get_risk_thresholds();
+    assert_eq!(thresholds.high_risk_threshold, 0);
+    assert_eq!(thresholds.high_risk_multiplier, 40);
+    assert_eq!(client.get_effective_limit(&depositor), 40_0000000);
+
+    let deadline = env.ledger().timestamp() + 1000;
+    let result = client.try_lock_funds(&depositor, &1u64, &50_0000000, &deadline);
+    assert_eq!(result, Err(Ok(Error::TransactionExceedsLimit)));
+    client.lock_funds(&depositor, &2u64, &40_0000000, &deadline);
+}
+
+#[test]
+fn test_set_risk_thresholds_rejects_values_over_100() {
+    let env = Env::default();
+    let (client, _contract_id, _admin, _depositor, _contributor, _issuer, _token_client) =
+        setup_with_identity(&env, 10_000i128);
+
+    assert_eq!(
+        client.try_set_risk_thresholds(&101, &50),
+        Err(Ok(Error::InvalidRiskThresholds))
+    );
+    assert_eq!(
+        client.try_set_risk_thresholds(&70, &101),
+        Err(Ok(Error::InvalidRiskThresholds))
+    );
+    assert_eq!(client.get_risk_thresholds(), RiskThresholds::default());
+}
+
+#[test]
+fn test_risk_adjusted_limit_floors_at_min_payout() {
+    let env = Env::default();
+    let identity = AddressIdentity::default();
+    let tier_limits = TierLimits {
+        unverified_limit: 15,
+        basic_limit: 0,
+        verified_limit: 0,
+        premium_limit: 0,
+    };
+    let thresholds = RiskThresholds {
+        high_risk_threshold: 0,
+        high_risk_multiplier: 50,
+    };
+
+    // 50% of 15 rounds down to 7; the floor lifts it to 10
+    assert_eq!(
+        identity::calculate_effective_limit(&env, &identity, &tier_limits, &thresholds, 0),
+        7
+    );
+    assert_eq!(
+        identity::calculate_effective_limit(&env, &identity, &tier_limits, &thresholds, 10),
+        10
+    );
+    // The floor never exceeds the tier limit itself
+    assert_eq!(
+        identity::calculate_effective_limit(&env, &identity, &tier_limits, &thresholds, 20),
+        15
+    );
+
+    // An explicit 0 multiplier still blocks the address
+    let blocked = RiskThresholds {
+        high_risk_multiplier: 0,
+        ..thresholds
+    };
+    assert_eq!(
+        identity::calculate_effective_limit(&env, &identity, &tier_limits, &blocked, 10),
+        0
+    );
+}
+
+#[test]
+fn test_min_payout_applies_to_enforced_limit() {
+    let env = Env::default();
+    let (client, _contract_id, _admin, depositor, _contributor, _issuer, _token_client) =
+        setup_with_identity(&env, 1000_0000000);
+
+    client.set_tier_limits(&15, &1000_0000000, &10000_0000000, &100000_0000000);
+    client.set_risk_thresholds(&0, &50);
+    assert_eq!(client.get_effective_limit(&depositor), 7);
+
+    client.set_min_payout(&10);
+    assert_eq!(client.get_min_payout(), 10);
+    assert_eq!(client.get_effective_limit(&depositor), 10);
+
+    let deadline = env.ledger().timestamp() + 1000;
+    client.lock_funds(&depositor, &1u64, &10, &deadline);
+    let result = client.try_lock_funds(&depositor, &2u64, &11, &deadline);
+    assert_eq!(result, Err(Ok(Error::TransactionExceedsLimit)));
+}
+
 #[test]
 fn test_get_address_identity_default() {
     let env = Env::default();
@@ -181,3 +288,478 @@ fn test_lock_funds_within_limits() {
     let escrow = client.get_escrow(&bounty_id);
     assert_eq!(escrow.amount, amount);
 }
+
+#[test]
+fn test_get_tier_limits_defaults() {
+    let env = Env::default();
+    let (client, _contract_id, _admin, _depositor, _contributor, _issuer, _token_client) =
+        setup_with_identity(&env, 10_000i128);
+
+    assert_eq!(client.get_tier_limits(), TierLimits::default());
+    assert_eq!(client.get_tier_limit(&IdentityTier::Unverified), 100_0000000);
+    assert_eq!(client.get_tier_limit(&IdentityTier::Premium), 100000_0000000);
+}
+
+#[test]
+fn test_get_tier_limits_reflects_custom_limits() {
+    let env = Env::default();
+    let (client, _contract_id, _admin, depositor, _contributor, _issuer, _token_client) =
+        setup_with_identity(&env, 1000_0000000);
+
+    client.set_tier_limits(&200_0000000, &2000_0000000, &20000_0000000, &200000_0000000);
+
+    let limits = client.get_tier_limits();
+    assert_eq!(limits.unverified_limit, 200_0000000);
+    assert_eq!(limits.premium_limit, 200000_0000000);
+    assert_eq!(client.get_tier_limit(&IdentityTier::Basic), 2000_0000000);
+    assert_eq!(client.get_tier_limit(&IdentityTier::Verified), 20000_0000000);
+
+    // Enforcement follows the new unverified limit: 150 tokens now fits, 250 does not
+    let deadline = env.ledger().timestamp() + 1000;
+    client.lock_funds(&depositor, &1u64, &150_0000000, &deadline);
+    let result = client.try_lock_funds(&depositor, &2u64, &250_0000000, &deadline);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_submit_claim_rejects_overlong_validity() {
+    let env = Env::default();
+    let (client, _contract_id, _admin, depositor, _contributor, issuer, _token_client) =
+        setup_with_identity(&env, 10_000i128);
+    env.ledger().set_timestamp(1_000);
+
+    client.set_max_identity_validity(&86_400);
+    assert_eq!(client.get_max_identity_validity(), Some(86_400));
+
+    let claim = IdentityClaim {
+        address: depositor,
+        tier: IdentityTier::Verified,
+        risk_score: 10,
+        expiry: 1_000 + 86_401,
+        issuer,
+    };
+    let result = client.try_submit_identity_claim(
+        &claim,
+        &BytesN::from_array(&env, &[0u8; 64]),
+        &BytesN::from_array(&env, &[0u8; 32]),
+    );
+    assert_eq!(result, Err(Ok(Error::IdentityValidityTooLong)));
+}
+
+#[test]
+fn test_validity_cap_accepts_expiry_within_cap() {
+    let env = Env::default();
+    env.ledger().set_timestamp(1_000);
+
+    assert!(identity::is_validity_within_cap(&env, 1_000 + 86_400, Some(86_400)));
+    assert!(!identity::is_validity_within_cap(&env, 1_000 + 86_401, Some(86_400)));
+    assert!(identity::is_validity_within_cap(&env, u64::MAX, None));
+}
+
+#[test]
+fn test_clearing_validity_cap() {
+    let env = Env::default();
+    let (client, _contract_id, _admin, _depositor, _contributor, _issuer, _token_client) =
+        setup_with_identity(&env, 10_000i128);
+
+    client.set_max_identity_validity(&86_400);
+    client.set_max_identity_validity(&0);
+    assert_eq!(client.get_max_identity_validity(), None);
+}
+
+#[test]
+fn test_tier_issuer_quorum_defaults_to_one() {
+    let env = Env::default();
+    let (client, _contract_id, _admin, _depositor, _contributor, _issuer, _token_client) =
+        setup_with_identity(&env, 10_000i128);
+
+    assert_eq!(client.get_tier_issuer_quorum(&IdentityTier::Premium), 1);
+
+    client.set_tier_issuer_quorum(&IdentityTier::Premium, &2);
+    assert_eq!(client.get_tier_issuer_quorum(&IdentityTier::Premium), 2);
+    assert_eq!(client.get_tier_issuer_quorum(&IdentityTier::Verified), 1);
+
+    client.set_tier_issuer_quorum(&IdentityTier::Premium, &0);
+    assert_eq!(client.get_tier_issuer_quorum(&IdentityTier::Premium), 1);
+}
+
+#[test]
+fn test_quorum_vote_counts_distinct_matching_issuers() {
+    let env = Env::default();
+    let address = Address::generate(&env);
+    let first_issuer = Address::generate(&env);
+    let second_issuer = Address::generate(&env);
+    let claim = IdentityClaim {
+        address,
+        tier: IdentityTier::Premium,
+        risk_score: 5,
+        expiry: 10_000,
+        issuer: first_issuer.clone(),
+    };
+
+    // A single issuer leaves the quorum pending, even if it submits twice
+    let pending = identity::add_quorum_vote(&env, None, &claim);
+    let pending = identity::add_quorum_vote(&env, Some(pending), &claim);
+    assert_eq!(pending.issuers.len(), 1);
+
+    let second = IdentityClaim {
+        issuer: second_issuer,
+        ..claim.clone()
+    };
+    let pending = identity::add_quorum_vote(&env, Some(pending), &second);
+    assert_eq!(pending.issuers.len(), 2);
+    assert_eq!(pending.tier, IdentityTier::Premium);
+}
+
+#[test]
+fn test_quorum_vote_resets_on_mismatched_claim() {
+    let env = Env::default();
+    let claim = IdentityClaim {
+        address: Address::generate(&env),
+        tier: IdentityTier::Premium,
+        risk_score: 5,
+        expiry: 10_000,
+        issuer: Address::generate(&env),
+    };
+    let pending = identity::add_quorum_vote(&env, None, &claim);
+
+    let different = IdentityClaim {
+        risk_score: 40,
+        issuer: Address::generate(&env),
+        ..claim
+    };
+    let pending = identity::add_quorum_vote(&env, Some(pending), &different);
+
+    assert_eq!(pending.issuers.len(), 1);
+    assert_eq!(pending.risk_score, 40);
+}
+
+fn store_identity(
+    env: &Env,
+    contract_id: &Address,
+    address: &Address,
+    tier: IdentityTier,
+    expiry: u64,
+) {
+    store_identity_with_risk(env, contract_id, address, tier, 10, expiry);
+}
+
+fn store_identity_with_risk(
+    env: &Env,
+    contract_id: &Address,
+    address: &Address,
+    tier: IdentityTier,
+    risk_score: u32,
+    expiry: u64,
+) {
+    let identity = AddressIdentity {
+        tier,
+        risk_score,
+        expiry,
+        last_updated: env.ledger().timestamp(),
+    };
+    env.as_contract(contract_id, || {
+        EscrowContract::store_identity(env, address, &identity);
+    });
+}
+
+#[test]
+fn test_identity_stats_empty() {
+    let env = Env::default();
+    let (client, _contract_id, _admin, _depositor, _contributor, _issuer, _token_client) =
+        setup_with_identity(&env, 10_000i128);
+
+    let stats = client.get_identity_stats();
+    assert_eq!(stats.basic_count, 0);
+    assert_eq!(stats.premium_count, 0);
+    assert_eq!(stats.expired_count, 0);
+}
+
+#[test]
+fn test_identity_stats_counts_by_tier_and_expiry() {
+    let env = Env::default();
+    env.ledger().set_timestamp(1_000);
+    let (client, contract_id, _admin, _depositor, _contributor, _issuer, _token_client) =
+        setup_with_identity(&env, 10_000i128);
+
+    let first_basic = Address::generate(&env);
+    let second_basic = Address::generate(&env);
+    let verified = Address::generate(&env);
+    let expiring = Address::generate(&env);
+    let store = |address: &Address, tier: IdentityTier, expiry: u64| {
+        store_identity(&env, &contract_id, address, tier, expiry)
+    };
+    store(&first_basic, IdentityTier::Basic, 10_000);
+    store(&second_basic, IdentityTier::Basic, 10_000);
+    store(&verified, IdentityTier::Verified, 10_000);
+    store(&expiring, IdentityTier::Premium, 2_000);
+
+    let stats = client.get_identity_stats();
+    assert_eq!(stats.unverified_count, 0);
+    assert_eq!(stats.basic_count, 2);
+    assert_eq!(stats.verified_count, 1);
+    assert_eq!(stats.premium_count, 1);
+    assert_eq!(stats.expired_count, 0);
+
+    // The premium claim lapses but still counts toward its tier
+    env.ledger().set_timestamp(2_000);
+    let stats = client.get_identity_stats();
+    assert_eq!(stats.premium_count, 1);
+    assert_eq!(stats.expired_count, 1);
+}
+
+#[test]
+fn test_identity_stats_track_tier_changes() {
+    let env = Env::default();
+    let (client, contract_id, _admin, _depositor, _contributor, _issuer, _token_client) =
+        setup_with_identity(&env, 10_000i128);
+
+    let address = Address::generate(&env);
+    store_identity(&env, &contract_id, &address, IdentityTier::Basic, 10_000);
+    store_identity(&env, &contract_id, &address, IdentityTier::Premium, 10_000);
+
+    let stats = client.get_identity_stats();
+    assert_eq!(stats.basic_count, 0);
+    assert_eq!(stats.premium_count, 1);
+}
+
+#[test]
+fn test_revoke_identity_updates_stats() {
+    let env = Env::default();
+    let (client, contract_id, _admin, _depositor, _contributor, _issuer, _token_client) =
+        setup_with_identity(&env, 10_000i128);
+
+    let address = Address::generate(&env);
+    store_identity(&env, &contract_id, &address, IdentityTier::Verified, 10_000);
+    client.revoke_identity(&address);
+
+    assert_eq!(client.get_identity_stats().verified_count, 0);
+    let identity = client.get_address_identity(&address);
+    assert_eq!(identity.tier, IdentityTier::Unverified);
+    assert_eq!(
+        client.try_revoke_identity(&address),
+        Err(Ok(Error::IdentityNotFound))
+    );
+}
+
+#[test]
+fn test_risk_tier_bands_disabled_by_default() {
+    let env = Env::default();
+    let (client, _contract_id, _admin, _depositor, _contributor, _issuer, _token_client) =
+        setup_with_identity(&env, 10_000i128);
+
+    assert_eq!(client.get_risk_tier_bands(), None);
+}
+
+#[test]
+fn test_set_and_clear_risk_tier_bands() {
+    let env = Env::default();
+    let (client, _contract_id, _admin, _depositor, _contributor, _issuer, _token_client) =
+        setup_with_identity(&env, 10_000i128);
+
+    let bands = RiskTierBands {
+        premium_max_risk: 20,
+        verified_max_risk: 50,
+        basic_max_risk: 80,
+    };
+    client.set_risk_tier_bands(&Some(bands.clone()));
+    assert_eq!(client.get_risk_tier_bands(), Some(bands));
+
+    client.set_risk_tier_bands(&None);
+    assert_eq!(client.get_risk_tier_bands(), None);
+}
+
+#[test]
+fn test_set_risk_tier_bands_rejects_invalid_bands() {
+    let env = Env::default();
+    let (client, _contract_id, _admin, _depositor, _contributor, _issuer, _token_client) =
+        setup_with_identity(&env, 10_000i128);
+
+    let descending = RiskTierBands {
+        premium_max_risk: 50,
+        verified_max_risk: 20,
+        basic_max_risk: 80,
+    };
+    assert_eq!(
+        client.try_set_risk_tier_bands(&Some(descending)),
+        Err(Ok(Error::InvalidRiskTierBands))
+    );
+
+    let out_of_range = RiskTierBands {
+        premium_max_risk: 20,
+        verified_max_risk: 50,
+        basic_max_risk: 101,
+    };
+    assert_eq!(
+        client.try_set_risk_tier_bands(&Some(out_of_range)),
+        Err(Ok(Error::InvalidRiskTierBands))
+    );
+}
+
+#[test]
+fn test_risk_tier_bands_assign_tier_from_risk_score() {
+    let bands = RiskTierBands {
+        premium_max_risk: 20,
+        verified_max_risk: 50,
+        basic_max_risk: 80,
+    };
+
+    assert_eq!(bands.tier_for(0), IdentityTier::Premium);
+    assert_eq!(bands.tier_for(20), IdentityTier::Premium);
+    assert_eq!(bands.tier_for(21), IdentityTier::Verified);
+    assert_eq!(bands.tier_for(50), IdentityTier::Verified);
+    assert_eq!(bands.tier_for(51), IdentityTier::Basic);
+    assert_eq!(bands.tier_for(80), IdentityTier::Basic);
+    assert_eq!(bands.tier_for(81), IdentityTier::Unverified);
+    assert_eq!(bands.tier_for(100), IdentityTier::Unverified);
+}
+
+#[test]
+fn test_identity_update_cooldown_defaults_to_zero() {
+    let env = Env::default();
+    let (client, _contract_id, _admin, _depositor, _contributor, _issuer, _token_client) =
+        setup_with_identity(&env, 10_000i128);
+
+    assert_eq!(client.get_identity_update_cooldown(), 0);
+
+    client.set_identity_update_cooldown(&3_600);
+    assert_eq!(client.get_identity_update_cooldown(), 3_600);
+
+    client.set_identity_update_cooldown(&0);
+    assert_eq!(client.get_identity_update_cooldown(), 0);
+}
+
+#[test]
+fn test_rapid_same_tier_resubmission_rejected() {
+    let env = Env::default();
+    env.ledger().set_timestamp(1_000);
+    let (client, contract_id, _admin, depositor, _contributor, issuer, _token_client) =
+        setup_with_identity(&env, 10_000i128);
+
+    client.set_identity_update_cooldown(&3_600);
+    store_identity(
+        &env,
+        &contract_id,
+        &depositor,
+        IdentityTier::Verified,
+        100_000,
+    );
+
+    env.ledger().set_timestamp(1_000 + 60);
+    let claim = IdentityClaim {
+        address: depositor,
+        tier: IdentityTier::Verified,
+        risk_score: 10,
+        expiry: 100_000,
+        issuer,
+    };
+    let result = client.try_submit_identity_claim(
+        &claim,
+        &BytesN::from_array(&env, &[0u8; 64]),
+        &BytesN::from_array(&env, &[0u8; 32]),
+    );
+    assert_eq!(result, Err(Ok(Error::IdentityUpdateTooSoon)));
+}
+
+#[test]
+fn test_update_cooldown_exempts_upgrades() {
+    let env = Env::default();
+    env.ledger().set_timestamp(1_000);
+    let existing = AddressIdentity {
+        tier: IdentityTier::Verified,
+        risk_score: 10,
+        expiry: 100_000,
+        last_updated: 1_000,
+    };
+
+    // An upgrade passes immediately
+    assert!(identity::is_update_allowed(
+        &env,
+        Some(&existing),
+        &IdentityTier::Premium,
+        3_600
+    ));
+    // Refreshes and downgrades wait out the cooldown
+    assert!(!identity::is_update_allowed(
+        &env,
+        Some(&existing),
+        &IdentityTier::Verified,
+        3_600
+    ));
+    assert!(!identity::is_update_allowed(
+        &env,
+        Some(&existing),
+        &IdentityTier::Basic,
+        3_600
+    ));
+
+    env.ledger().set_timestamp(1_000 + 3_600);
+    assert!(identity::is_update_allowed(
+        &env,
+        Some(&existing),
+        &IdentityTier::Basic,
+        3_600
+    ));
+    // First-time submissions are never held back
+    assert!(identity::is_update_allowed(
+        &env,
+        None,
+        &IdentityTier::Basic,
+        3_600
+    ));
+}
+
+#[test]
+fn test_auto_denylist_threshold_config() {
+    let env = Env::default();
+    let (client, _contract_id, _admin, _depositor, _contributor, _issuer, _token_client) =
+        setup_with_identity(&env, 10_000i128);
+
+    assert_eq!(client.get_auto_denylist_threshold(), None);
+    client.set_auto_denylist_threshold(&Some(70));
+    assert_eq!(client.get_auto_denylist_threshold(), Some(70));
+    assert_eq!(
+        client.try_set_auto_denylist_threshold(&Some(101)),
+        Err(Ok(Error::InvalidRiskThresholds))
+    );
+    client.set_auto_denylist_threshold(&None);
+    assert_eq!(client.get_auto_denylist_threshold(), None);
+}
+
+#[test]
+fn test_high_risk_identity_auto_blocked_until_reverified() {
+    let env = Env::default();
+    env.ledger().set_timestamp(1_000);
+    let (client, contract_id, _admin, depositor, contributor, _issuer, token_client) =
+        setup_with_identity(&env, 10_000i128);
+
+    client.set_auto_denylist_threshold(&Some(70));
+    store_identity_with_risk(
+        &env,
+        &contract_id,
+        &contributor,
+        IdentityTier::Verified,
+        90,
+        100_000,
+    );
+    assert!(client.is_auto_denylisted(&contributor));
+
+    client.lock_funds(&depositor, &1u64, &1_000, &5_000);
+    assert_eq!(
+        client.try_release_funds(&1u64, &contributor),
+        Err(Ok(Error::AutoDenylisted))
+    );
+
+    // Re-verification with a lower score restores payout eligibility
+    store_identity_with_risk(
+        &env,
+        &contract_id,
+        &contributor,
+        IdentityTier::Verified,
+        20,
+        100_000,
+    );
+    assert!(!client.is_auto_denylisted(&contributor));
+    client.release_funds(&1u64, &contributor);
+    assert_eq!(token_client.balance(&contributor), 11_000);
+}
@@ -35,6 +35,13 @@ pub enum Error {
     JurisdictionPaused = 107,
     JurisdictionKycRequired = 108,
     JurisdictionAmountExceeded = 109,
+    IdentityValidityTooLong = 110,
+    InvalidRiskThresholds = 111,
+    InvalidMinPayout = 112,
+    IdentityNotFound = 113,
+    InvalidRiskTierBands = 114,
+    IdentityUpdateTooSoon = 115,
+    AutoDenylisted = 116,
 }
 
 #[contracttype]
@@ -95,6 +102,15 @@ pub enum DataKey {
     TierLimits,
     RiskThresholds,
     ReentrancyGuard,
+    MaxIdentityValidity,
+    TierIssuerQuorum(IdentityTier),
+    PendingIdentityQuorum(Address),
+    MinPayout,
+    IdentityTierCount(IdentityTier),
+    IdentityIndex,
+    RiskTierBands,
+    IdentityUpdateCooldown,
+    AutoDenylistThreshold,
 }
 
 #[contract]
@@ -288,7 +304,22 @@ impl EscrowContract {
         Ok(())
     }
 
-    /// Configure risk-based adjustments (admin only)
+    /// Query the configured transaction limits for every tier
+    pub fn get_tier_limits(env: Env) -> TierLimits {
+        env.storage()
+            .persistent()
+            .get(&DataKey::TierLimits)
+            .unwrap_or_default()
+    }
+
+    /// Query the transaction limit unlocked by a single tier
+    pub fn get_tier_limit(env: Env, tier: IdentityTier) -> i128 {
+        Self::get_tier_limits(env).limit_for(&tier)
+    }
+
+    /// Configure risk-based adjustments (admin only). Both values are
+    /// percentages: a risk score at or above `high_risk_threshold` limits the
+    /// address to `high_risk_multiplier`% of its tier limit.
     pub fn set_risk_thresholds(
         env: Env,
         high_risk_threshold: u32,
@@ -301,6 +332,10 @@ impl EscrowContract {
             .ok_or(Error::NotInitialized)?;
         admin.require_auth();
 
+        if high_risk_threshold > 100 || high_risk_multiplier > 100 {
+            return Err(Error::InvalidRiskThresholds);
+        }
+
         let thresholds = RiskThresholds {
             high_risk_threshold,
             high_risk_multiplier,
@@ -312,10 +347,231 @@ impl EscrowContract {
         Ok(())
     }
 
-    /// Submit an identity claim for verification and storage
+    /// Query the configured risk-based adjustments
+    pub fn get_risk_thresholds(env: Env) -> RiskThresholds {
+        env.storage()
+            .persistent()
+            .get(&DataKey::RiskThresholds)
+            .unwrap_or_default()
+    }
+
+    /// Derive the tier of submitted claims from their risk score using `bands`
+    /// instead of the tier the issuer supplied (admin only). `None` turns
+    /// automatic assignment off again.
+    pub fn set_risk_tier_bands(env: Env, bands: Option<RiskTierBands>) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        match bands {
+            Some(bands) => {
+                if !bands.is_valid() {
+                    return Err(Error::InvalidRiskTierBands);
+                }
+                env.storage()
+                    .persistent()
+                    .set(&DataKey::RiskTierBands, &bands);
+            }
+            None => env.storage().persistent().remove(&DataKey::RiskTierBands),
+        }
+        Ok(())
+    }
+
+    /// Query the risk score bands used for automatic tier assignment, if enabled
+    pub fn get_risk_tier_bands(env: Env) -> Option<RiskTierBands> {
+        env.storage().persistent().get(&DataKey::RiskTierBands)
+    }
+
+    /// Block payouts to any address whose stored identity has a risk score
+    /// above `threshold` (admin only), without a manual denylist entry. A
+    /// later claim with a lower score lifts the block. `None` turns it off.
+    pub fn set_auto_denylist_threshold(env: Env, threshold: Option<u32>) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        match threshold {
+            Some(threshold) => {
+                if threshold > 100 {
+                    return Err(Error::InvalidRiskThresholds);
+                }
+                env.storage()
+                    .persistent()
+                    .set(&DataKey::AutoDenylistThreshold, &threshold);
+            }
+            None => env
+                .storage()
+                .persistent()
+                .remove(&DataKey::AutoDenylistThreshold),
+        }
+        Ok(())
+    }
+
+    /// Query the risk score above which payouts are blocked, if enabled
+    pub fn get_auto_denylist_threshold(env: Env) -> Option<u32> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::AutoDenylistThreshold)
+    }
+
+    /// Check whether payouts to `address` are blocked by its risk score
+    pub fn is_auto_denylisted(env: Env, address: Address) -> bool {
+        let threshold = match Self::get_auto_denylist_threshold(env.clone()) {
+            Some(threshold) => threshold,
+            None => return false,
+        };
+        let identity: Option<AddressIdentity> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::AddressIdentity(address));
+        identity.is_some_and(|id| id.risk_score > threshold)
+    }
+
+    /// Set the floor for risk-adjusted limits (admin only), so scaling a small
+    /// tier limit by `high_risk_multiplier` cannot round it down to an
+    /// unusable value. Passing 0 removes the floor.
+    pub fn set_min_payout(env: Env, min_payout: i128) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        if min_payout < 0 {
+            return Err(Error::InvalidMinPayout);
+        }
+        if min_payout == 0 {
+            env.storage().persistent().remove(&DataKey::MinPayout);
+        } else {
+            env.storage()
+                .persistent()
+                .set(&DataKey::MinPayout, &min_payout);
+        }
+        Ok(())
+    }
+
+    /// Query the floor for risk-adjusted limits (0 if unset)
+    pub fn get_min_payout(env: Env) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::MinPayout)
+            .unwrap_or(0)
+    }
+
+    /// Cap how far in the future a submitted claim's expiry may be, forcing
+    /// periodic re-verification (admin only). Passing 0 removes the cap.
+    pub fn set_max_identity_validity(env: Env, max_validity_secs: u64) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        if max_validity_secs == 0 {
+            env.storage()
+                .persistent()
+                .remove(&DataKey::MaxIdentityValidity);
+        } else {
+            env.storage()
+                .persistent()
+                .set(&DataKey::MaxIdentityValidity, &max_validity_secs);
+        }
+        Ok(())
+    }
+
+    /// Query the maximum identity claim validity, if capped
+    pub fn get_max_identity_validity(env: Env) -> Option<u64> {
+        env.storage().persistent().get(&DataKey::MaxIdentityValidity)
+    }
+
+    /// Require `cooldown_secs` between identity updates for the same address
+    /// (admin only). Upgrades to a higher tier are exempt. Passing 0 removes
+    /// the cooldown.
+    pub fn set_identity_update_cooldown(env: Env, cooldown_secs: u64) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        if cooldown_secs == 0 {
+            env.storage()
+                .persistent()
+                .remove(&DataKey::IdentityUpdateCooldown);
+        } else {
+            env.storage()
+                .persistent()
+                .set(&DataKey::IdentityUpdateCooldown, &cooldown_secs);
+        }
+        Ok(())
+    }
+
+    /// Query the minimum gap between identity updates (0 if unset)
+    pub fn get_identity_update_cooldown(env: Env) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::IdentityUpdateCooldown)
+            .unwrap_or(0)
+    }
+
+    /// Require `quorum` distinct authorized issuers to submit matching claims
+    /// before an address is granted `tier` (admin only). 0 or 1 removes the
+    /// requirement.
+    pub fn set_tier_issuer_quorum(env: Env, tier: IdentityTier, quorum: u32) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        if quorum <= 1 {
+            env.storage()
+                .persistent()
+                .remove(&DataKey::TierIssuerQuorum(tier));
+        } else {
+            env.storage()
+                .persistent()
+                .set(&DataKey::TierIssuerQuorum(tier), &quorum);
+        }
+        Ok(())
+    }
+
+    /// Query the number of issuers required to grant a tier (1 if unset)
+    pub fn get_tier_issuer_quorum(env: Env, tier: IdentityTier) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::TierIssuerQuorum(tier))
+            .unwrap_or(1)
+    }
+
+    /// Query the partially collected issuer quorum for an address, if any
+    pub fn get_pending_identity_quorum(env: Env, address: Address) -> Option<PendingIdentityQuorum> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::PendingIdentityQuorum(address))
+    }
+
+    /// Submit an identity claim for verification and storage.
+    ///
+    /// For tiers with an issuer quorum (see `set_tier_issuer_quorum`) the
+    /// claim is held as pending until enough distinct issuers have submitted
+    /// matching claims; only then is the identity stored.
+    ///
+    /// When risk tier bands are configured (see `set_risk_tier_bands`) the
+    /// claim's tier is ignored and derived from its risk score instead.
     pub fn submit_identity_claim(
         env: Env,
-        claim: IdentityClaim,
+        mut claim: IdentityClaim,
         signature: BytesN<64>,
         issuer_pubkey: BytesN<32>,
     ) -> Result<(), Error> {
@@ -339,6 +595,39 @@ impl EscrowContract {
             return Err(Error::ClaimExpired);
         }
 
+        // Reject claims valid for longer than the contract allows
+        let max_validity: Option<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::MaxIdentityValidity);
+        if !identity::is_validity_within_cap(&env, claim.expiry, max_validity) {
+            env.events().publish(
+                (soroban_sdk::symbol_short!("claim"), claim.address.clone()),
+                soroban_sdk::symbol_short!("too_long"),
+            );
+            return Err(Error::IdentityValidityTooLong);
+        }
+
+        // Only upgrades may replace an identity updated within the cooldown
+        let cooldown = Self::get_identity_update_cooldown(env.clone());
+        if cooldown > 0 {
+            let existing: Option<AddressIdentity> = env
+                .storage()
+                .persistent()
+                .get(&DataKey::AddressIdentity(claim.address.clone()));
+            let tier = match Self::get_risk_tier_bands(env.clone()) {
+                Some(bands) => bands.tier_for(claim.risk_score),
+                None => claim.tier.clone(),
+            };
+            if !identity::is_update_allowed(&env, existing.as_ref(), &tier, cooldown) {
+                env.events().publish(
+                    (soroban_sdk::symbol_short!("claim"), claim.address.clone()),
+                    soroban_sdk::symbol_short!("too_soon"),
+                );
+                return Err(Error::IdentityUpdateTooSoon);
+            }
+        }
+
         // Check if issuer is authorized
         let is_authorized: bool = env
             .storage()
@@ -357,6 +646,31 @@ impl EscrowContract {
         // Verify claim signature
         identity::verify_claim_signature(&env, &claim, &signature, &issuer_pubkey)?;
 
+        // The signature covers the issuer's tier; bands override it afterwards
+        if let Some(bands) = Self::get_risk_tier_bands(env.clone()) {
+            claim.tier = bands.tier_for(claim.risk_score);
+        }
+
+        // Hold the claim until the tier's issuer quorum is met
+        let quorum = Self::get_tier_issuer_quorum(env.clone(), claim.tier.clone());
+        if quorum > 1 {
+            let quorum_key = DataKey::PendingIdentityQuorum(claim.address.clone());
+            let pending = identity::add_quorum_vote(
+                &env,
+                env.storage().persistent().get(&quorum_key),
+                &claim,
+            );
+            if pending.issuers.len() < quorum {
+                env.events().publish(
+                    (soroban_sdk::symbol_short!("claim"), claim.address.clone()),
+                    (soroban_sdk::symbol_short!("pending"), pending.issuers.len(), quorum),
+                );
+                env.storage().persistent().set(&quorum_key, &pending);
+                return Ok(());
+            }
+            env.storage().persistent().remove(&quorum_key);
+        }
+
         // Store identity data for the address
         let now = env.ledger().timestamp();
         let identity_data = AddressIdentity {
@@ -366,10 +680,7 @@ impl EscrowContract {
             last_updated: now,
         };
 
-        env.storage().persistent().set(
-            &DataKey::AddressIdentity(claim.address.clone()),
-            &identity_data,
-        );
+        Self::store_identity(&env, &claim.address, &identity_data);
 
         // Emit event for successful claim submission
         env.events().publish(
@@ -380,6 +691,118 @@ impl EscrowContract {
         Ok(())
     }
 
+    /// Remove an address's stored identity, returning it to the unverified
+    /// tier (admin only)
+    pub fn revoke_identity(env: Env, address: Address) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        let key = DataKey::AddressIdentity(address.clone());
+        let identity: AddressIdentity = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(Error::IdentityNotFound)?;
+        env.storage().persistent().remove(&key);
+        Self::adjust_tier_count(&env, &identity.tier, false);
+
+        let mut index: soroban_sdk::Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::IdentityIndex)
+            .unwrap_or(soroban_sdk::Vec::new(&env));
+        if let Some(i) = index.first_index_of(&address) {
+            index.remove(i);
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKey::IdentityIndex, &index);
+
+        env.events().publish(
+            (soroban_sdk::symbol_short!("claim"), address),
+            soroban_sdk::symbol_short!("revoked"),
+        );
+
+        Ok(())
+    }
+
+    /// Query counts of stored identities per tier, plus how many of them
+    /// have expired as of the current ledger. Expired identities still count
+    /// toward their tier until replaced or revoked.
+    pub fn get_identity_stats(env: Env) -> IdentityStats {
+        let index: soroban_sdk::Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::IdentityIndex)
+            .unwrap_or(soroban_sdk::Vec::new(&env));
+        let mut expired_count = 0;
+        for address in index.iter() {
+            let identity: Option<AddressIdentity> = env
+                .storage()
+                .persistent()
+                .get(&DataKey::AddressIdentity(address));
+            if let Some(id) = identity {
+                if identity::is_claim_expired(&env, id.expiry) {
+                    expired_count += 1;
+                }
+            }
+        }
+
+        IdentityStats {
+            unverified_count: Self::tier_count(&env, IdentityTier::Unverified),
+            basic_count: Self::tier_count(&env, IdentityTier::Basic),
+            verified_count: Self::tier_count(&env, IdentityTier::Verified),
+            premium_count: Self::tier_count(&env, IdentityTier::Premium),
+            expired_count,
+        }
+    }
+
+    /// Internal: Store an identity, keeping the per-tier counters and the
+    /// identity index in sync
+    fn store_identity(env: &Env, address: &Address, identity_data: &AddressIdentity) {
+        let key = DataKey::AddressIdentity(address.clone());
+        let previous: Option<AddressIdentity> = env.storage().persistent().get(&key);
+        match previous {
+            Some(previous) => Self::adjust_tier_count(env, &previous.tier, false),
+            None => {
+                let mut index: soroban_sdk::Vec<Address> = env
+                    .storage()
+                    .persistent()
+                    .get(&DataKey::IdentityIndex)
+                    .unwrap_or(soroban_sdk::Vec::new(env));
+                index.push_back(address.clone());
+                env.storage()
+                    .persistent()
+                    .set(&DataKey::IdentityIndex, &index);
+            }
+        }
+        Self::adjust_tier_count(env, &identity_data.tier, true);
+        env.storage().persistent().set(&key, identity_data);
+    }
+
+    fn tier_count(env: &Env, tier: IdentityTier) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::IdentityTierCount(tier))
+            .unwrap_or(0)
+    }
+
+    fn adjust_tier_count(env: &Env, tier: &IdentityTier, increment: bool) {
+        let count = Self::tier_count(env, tier.clone());
+        let count = if increment {
+            count + 1
+        } else {
+            count.saturating_sub(1)
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::IdentityTierCount(tier.clone()), &count);
+    }
+
     /// Query identity data for an address
     pub fn get_address_identity(env: Env, address: Address) -> AddressIdentity {
         let identity: Option<AddressIdentity> = env
@@ -405,19 +828,17 @@ impl EscrowContract {
     pub fn get_effective_limit(env: Env, address: Address) -> i128 {
         let identity = Self::get_address_identity(env.clone(), address);
 
-        let tier_limits: TierLimits = env
-            .storage()
-            .persistent()
-            .get(&DataKey::TierLimits)
-            .unwrap_or_default();
-
-        let risk_thresholds: RiskThresholds = env
-            .storage()
-            .persistent()
-            .get(&DataKey::RiskThresholds)
-            .unwrap_or_default();
+        let tier_limits = Self::get_tier_limits(env.clone());
+        let risk_thresholds = Self::get_risk_thresholds(env.clone());
+        let min_payout = Self::get_min_payout(env.clone());
 
-        identity::calculate_effective_limit(&env, &identity, &tier_limits, &risk_thresholds)
+        identity::calculate_effective_limit(
+            &env,
+            &identity,
+            &tier_limits,
+            &risk_thresholds,
+            min_payout,
+        )
     }
 
     /// Check if an address has a valid (non-expired) claim
@@ -556,6 +977,14 @@ impl EscrowContract {
             return Err(Error::InsufficientBalance);
         }
 
+        if Self::is_auto_denylisted(env.clone(), contributor.clone()) {
+            env.events().publish(
+                (symbol_short!("denylist"), contributor.clone()),
+                symbol_short!("risk"),
+            );
+            return Err(Error::AutoDenylisted);
+        }
+
         Self::enforce_release_jurisdiction(
             &env,
             &contributor,